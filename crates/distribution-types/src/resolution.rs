@@ -65,6 +65,26 @@ impl Resolution {
         self.packages.values().map(Requirement::from)
     }
 
+    /// Retain only the packages that match the given predicate.
+    #[must_use]
+    pub fn filter(self, predicate: impl Fn(&PackageName) -> bool) -> Self {
+        let packages = self
+            .packages
+            .into_iter()
+            .filter(|(name, _)| predicate(name))
+            .collect::<BTreeMap<_, _>>();
+        let hashes = self
+            .hashes
+            .into_iter()
+            .filter(|(name, _)| packages.contains_key(name))
+            .collect();
+        Self {
+            packages,
+            hashes,
+            diagnostics: self.diagnostics,
+        }
+    }
+
     /// Return the [`ResolutionDiagnostic`]s that were produced during resolution.
     pub fn diagnostics(&self) -> &[ResolutionDiagnostic] {
         &self.diagnostics
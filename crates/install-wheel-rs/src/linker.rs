@@ -81,7 +81,13 @@ pub fn install_wheel(
         LibKind::Pure => &layout.scheme.purelib,
         LibKind::Plat => &layout.scheme.platlib,
     };
-    let num_unpacked = link_mode.link_wheel_files(site_packages, &wheel, locks)?;
+    // Use extended-length paths, as the contents of some wheels exceed the `MAX_PATH` limit on
+    // Windows.
+    let num_unpacked = link_mode.link_wheel_files(
+        uv_fs::verbatim_path(site_packages),
+        uv_fs::verbatim_path(wheel.as_ref()),
+        locks,
+    )?;
     debug!(name, "Extracted {num_unpacked} files");
 
     // Read the RECORD file.
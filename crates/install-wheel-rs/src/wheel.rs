@@ -215,12 +215,17 @@ pub(crate) fn windows_script_launcher(
         archive.finish().expect(error_msg);
     }
 
-    let python = python_executable.as_ref();
-    let python_path = python.simplified_display().to_string();
-
     let mut launcher: Vec<u8> = Vec::with_capacity(launcher_bin.len() + payload.len());
     launcher.extend_from_slice(launcher_bin);
     launcher.extend_from_slice(&payload);
+
+    Ok(append_launcher_python(launcher, python_executable.as_ref()))
+}
+
+/// Append the path to the Python executable, followed by its length and the magic number, to a
+/// Windows launcher binary and its script payload.
+fn append_launcher_python(mut launcher: Vec<u8>, python_executable: &Path) -> Vec<u8> {
+    let python_path = python_executable.simplified_display().to_string();
     launcher.extend_from_slice(python_path.as_bytes());
     launcher.extend_from_slice(
         &u32::try_from(python_path.as_bytes().len())
@@ -228,8 +233,43 @@ pub(crate) fn windows_script_launcher(
             .to_le_bytes(),
     );
     launcher.extend_from_slice(&LAUNCHER_MAGIC_NUMBER);
+    launcher
+}
+
+/// Split a Windows launcher, as created by [`windows_script_launcher`], into the launcher binary
+/// with its script payload, and the embedded path to the Python executable.
+///
+/// Returns `None` if the data isn't a launcher.
+fn split_windows_launcher(launcher: &[u8]) -> Option<(&[u8], &str)> {
+    let rest = launcher.strip_suffix(&LAUNCHER_MAGIC_NUMBER)?;
+    let (rest, length) = rest.split_at(rest.len().checked_sub(4)?);
+    let length = usize::try_from(u32::from_le_bytes(length.try_into().ok()?)).ok()?;
+    let (prefix, python_path) = rest.split_at(rest.len().checked_sub(length)?);
+    Some((prefix, std::str::from_utf8(python_path).ok()?))
+}
+
+/// Returns the path to the Python executable embedded in a Windows launcher, as created by
+/// [`windows_script_launcher`].
+///
+/// Returns `None` if the data isn't a launcher.
+pub fn windows_launcher_python(launcher: &[u8]) -> Option<&Path> {
+    let (_, python_path) = split_windows_launcher(launcher)?;
+    Some(Path::new(python_path))
+}
 
-    Ok(launcher)
+/// Replace the path to the Python executable embedded in a Windows launcher, as created by
+/// [`windows_script_launcher`], e.g., after moving the environment that contains it.
+///
+/// Returns `None` if the data isn't a launcher.
+pub fn relocate_windows_launcher(
+    launcher: &[u8],
+    python_executable: impl AsRef<Path>,
+) -> Option<Vec<u8>> {
+    let (prefix, _) = split_windows_launcher(launcher)?;
+    Some(append_launcher_python(
+        prefix.to_vec(),
+        python_executable.as_ref(),
+    ))
 }
 
 /// Returns a [`PathBuf`] to `python[w].exe` for script execution.
@@ -765,7 +805,8 @@ mod test {
     use crate::Error;
 
     use super::{
-        get_script_executable, parse_key_value_file, parse_wheel_file, read_record_file, Script,
+        append_launcher_python, get_script_executable, parse_key_value_file, parse_wheel_file,
+        read_record_file, relocate_windows_launcher, windows_launcher_python, Script,
     };
 
     #[test]
@@ -1029,4 +1070,29 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn relocate_launcher() {
+        let launcher = append_launcher_python(
+            b"launcher and payload".to_vec(),
+            Path::new("/old/venv/Scripts/python.exe"),
+        );
+        assert_eq!(
+            windows_launcher_python(&launcher),
+            Some(Path::new("/old/venv/Scripts/python.exe"))
+        );
+
+        let relocated =
+            relocate_windows_launcher(&launcher, Path::new("/new/venv/Scripts/python.exe"))
+                .unwrap();
+        assert!(relocated.starts_with(b"launcher and payload"));
+        assert_eq!(
+            windows_launcher_python(&relocated),
+            Some(Path::new("/new/venv/Scripts/python.exe"))
+        );
+
+        // Data without the magic number isn't a launcher.
+        assert_eq!(windows_launcher_python(b"#!/usr/bin/python"), None);
+        assert_eq!(windows_launcher_python(b"UVUV"), None);
+    }
 }
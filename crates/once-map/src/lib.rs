@@ -29,8 +29,8 @@ impl<K: Eq + Hash, V: Clone, H: BuildHasher + Clone> OnceMap<K, V, H> {
 
     /// Register that you want to start a job.
     ///
-    /// If this method returns `true`, you need to start a job and call [`OnceMap::done`] eventually
-    /// or other tasks will hang. If it returns `false`, this job is already in progress and you
+    /// If this method returns `true`, you need to start a job and call [`OnceMap::done`] (or
+    /// [`OnceMap::cancel`], if the job fails) eventually or other tasks will hang. If it returns `false`, this job is already in progress and you
     /// can [`OnceMap::wait`] for the result.
     pub fn register(&self, key: K) -> bool {
         let entry = self.items.entry(key);
@@ -50,9 +50,23 @@ impl<K: Eq + Hash, V: Clone, H: BuildHasher + Clone> OnceMap<K, V, H> {
         }
     }
 
+    /// Abandon a job you registered, e.g., because it failed.
+    ///
+    /// Any tasks waiting for the result are woken, and receive `None` from [`OnceMap::wait`]. Has no
+    /// effect if the job was already completed.
+    pub fn cancel(&self, key: &K) {
+        if let Some((_, Value::Waiting(notify))) = self
+            .items
+            .remove_if(key, |_, value| matches!(value, Value::Waiting(_)))
+        {
+            notify.notify_waiters();
+        }
+    }
+
     /// Wait for the result of a job that is running.
     ///
-    /// Will hang if [`OnceMap::done`] isn't called for this key.
+    /// Returns `None` if the job was never registered, or was cancelled. Will hang if neither
+    /// [`OnceMap::done`] nor [`OnceMap::cancel`] is called for this key.
     pub async fn wait(&self, key: &K) -> Option<V> {
         let notify = {
             let entry = self.items.get(key)?;
@@ -65,18 +79,21 @@ impl<K: Eq + Hash, V: Clone, H: BuildHasher + Clone> OnceMap<K, V, H> {
         // Register the waiter for calls to `notify_waiters`.
         let notification = pin!(notify.notified());
 
-        // Make sure the value wasn't inserted in-between us checking the map and registering the waiter.
-        if let Value::Filled(value) = self.items.get(key).expect("map is append-only").value() {
+        // Make sure the value wasn't inserted (or the job cancelled) in-between us checking the map
+        // and registering the waiter.
+        if let Value::Filled(value) = self.items.get(key)?.value() {
             return Some(value.clone());
         };
 
-        // Wait until the value is inserted.
+        // Wait until the value is inserted, or the job is cancelled.
         notification.await;
 
-        let entry = self.items.get(key).expect("map is append-only");
+        // If the job was cancelled, the entry was removed (and may have been registered again by
+        // another task since).
+        let entry = self.items.get(key)?;
         match entry.value() {
             Value::Filled(value) => Some(value.clone()),
-            Value::Waiting(_) => unreachable!("notify was called"),
+            Value::Waiting(_) => None,
         }
     }
 
@@ -146,6 +146,14 @@ impl VerbatimUrl {
         self.given.as_deref()
     }
 
+    /// Return the original string as given by the user, if it references any environment
+    /// variables (e.g., `https://${HOST}/...`).
+    pub fn templated(&self) -> Option<&str> {
+        self.given
+            .as_deref()
+            .filter(|given| ENV_VAR_RE.is_match(given))
+    }
+
     /// Return the underlying [`Url`].
     pub fn raw(&self) -> &Url {
         &self.url
@@ -287,6 +295,10 @@ pub enum VerbatimUrlError {
     Normalization(PathBuf, #[source] std::io::Error),
 }
 
+/// A reference to an environment variable, in the `${MY_VARIABLE_1}` format.
+static ENV_VAR_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?P<var>\$\{(?P<name>[A-Z0-9_]+)})").unwrap());
+
 /// Expand all available environment variables.
 ///
 /// This is modeled off of pip's environment variable expansion, which states:
@@ -310,10 +322,7 @@ pub fn expand_env_vars(s: &str) -> Cow<'_, str> {
         project_root.to_string_lossy().to_string()
     });
 
-    static RE: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r"(?P<var>\$\{(?P<name>[A-Z0-9_]+)})").unwrap());
-
-    RE.replace_all(s, |caps: &regex::Captures<'_>| {
+    ENV_VAR_RE.replace_all(s, |caps: &regex::Captures<'_>| {
         let name = caps.name("name").unwrap().as_str();
         std::env::var(name).unwrap_or_else(|_| match name {
             "PROJECT_ROOT" => PROJECT_ROOT_FRAGMENT.to_string(),
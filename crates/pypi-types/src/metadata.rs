@@ -433,6 +433,100 @@ impl RequiresDist {
     }
 }
 
+/// The license of a distribution, as declared in its core metadata.
+///
+/// The license is read from (in order of precedence) the `License-Expression` field, a short
+/// `License` field, or the `License ::` trove classifiers. Note that classifiers are
+/// self-reported, and may be incomplete or inaccurate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum License {
+    /// An SPDX license expression, from the `License-Expression` field (e.g., `MIT OR Apache-2.0`).
+    Expression(String),
+    /// A free-form license name, from the `License` field (e.g., `BSD`).
+    Declared(String),
+    /// The license classifiers, with the `License ::` prefix stripped (e.g., `OSI Approved :: MIT
+    /// License`).
+    Classifiers(Vec<String>),
+    /// No license could be detected.
+    Unknown,
+}
+
+impl License {
+    /// The maximum length of a `License` field that is treated as a license name, rather than as
+    /// the full text of the license.
+    const MAX_DECLARED_LENGTH: usize = 64;
+
+    /// Parse the [`License`] from a `METADATA` or `PKG-INFO` file.
+    pub fn parse_metadata(content: &[u8]) -> Result<Self, MetadataError> {
+        let headers = Headers::parse(content)?;
+
+        if let Some(expression) = headers
+            .get_first_value("License-Expression")
+            .map(|expression| expression.trim().to_string())
+            .filter(|expression| !expression.is_empty())
+        {
+            return Ok(Self::Expression(expression));
+        }
+
+        // The `License` field frequently contains the full text of the license, which can't be
+        // summarized; in that case, fall back to the classifiers.
+        if let Some(license) = headers
+            .get_first_value("License")
+            .map(|license| license.trim().to_string())
+            .filter(|license| {
+                !license.is_empty()
+                    && !license.contains('\n')
+                    && license.len() <= Self::MAX_DECLARED_LENGTH
+            })
+        {
+            return Ok(Self::Declared(license));
+        }
+
+        let classifiers = headers
+            .get_all_values("Classifier")
+            .filter_map(|classifier| {
+                classifier
+                    .strip_prefix("License ::")
+                    .map(|license| license.trim().to_string())
+            })
+            .collect::<Vec<_>>();
+        if !classifiers.is_empty() {
+            return Ok(Self::Classifiers(classifiers));
+        }
+
+        Ok(Self::Unknown)
+    }
+
+    /// Returns `true` if no license could be detected.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown)
+    }
+
+    /// Return the name of the metadata field from which the license was read, if any.
+    pub fn source(&self) -> Option<&'static str> {
+        match self {
+            Self::Expression(_) => Some("License-Expression"),
+            Self::Declared(_) => Some("License"),
+            Self::Classifiers(_) => Some("Classifier"),
+            Self::Unknown => None,
+        }
+    }
+}
+
+impl std::fmt::Display for License {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Expression(expression) => write!(f, "{expression}"),
+            Self::Declared(license) => write!(f, "{license}"),
+            Self::Classifiers(classifiers) => {
+                // If there are multiple classifiers, list them all rather than guessing.
+                write!(f, "{}", classifiers.join("; "))
+            }
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
 /// The headers of a distribution metadata file.
 #[derive(Debug)]
 struct Headers<'a>(Vec<mailparse::MailHeader<'a>>);
@@ -474,7 +568,7 @@ mod tests {
 
     use crate::MetadataError;
 
-    use super::Metadata23;
+    use super::{License, Metadata23};
 
     #[test]
     fn test_parse_metadata() {
@@ -618,4 +712,38 @@ mod tests {
         );
         assert_eq!(meta.provides_extras, vec!["dotenv".parse().unwrap()]);
     }
+
+    #[test]
+    fn test_parse_license() {
+        let s = "Metadata-Version: 2.4\nName: asdf\nVersion: 1.0\nLicense-Expression: MIT OR Apache-2.0\nLicense: BSD";
+        let license = License::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(
+            license,
+            License::Expression("MIT OR Apache-2.0".to_string())
+        );
+
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nLicense: BSD\nClassifier: License :: OSI Approved :: MIT License";
+        let license = License::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(license, License::Declared("BSD".to_string()));
+
+        // A license field containing the full license text falls back to the classifiers.
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nLicense: Copyright (c) 2024\n        \n        Permission is hereby granted, free of charge, to any person obtaining a copy\nClassifier: License :: OSI Approved :: MIT License\nClassifier: Programming Language :: Python";
+        let license = License::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(
+            license,
+            License::Classifiers(vec!["OSI Approved :: MIT License".to_string()])
+        );
+
+        // Multiple classifiers are retained, rather than picking one.
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nClassifier: License :: OSI Approved :: MIT License\nClassifier: License :: OSI Approved :: Apache Software License";
+        let license = License::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(
+            license.to_string(),
+            "OSI Approved :: MIT License; OSI Approved :: Apache Software License"
+        );
+
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nLicense: UNKNOWN";
+        let license = License::parse_metadata(s.as_bytes()).unwrap();
+        assert!(license.is_unknown());
+    }
 }
@@ -412,6 +412,7 @@ impl SourceBuild {
         interpreter: &Interpreter,
         build_context: &impl BuildContext,
         source_build_context: SourceBuildContext,
+        package_name: Option<&PackageName>,
         version_id: String,
         setup_py: SetupPyStrategy,
         config_settings: ConfigSettings,
@@ -435,21 +436,29 @@ impl SourceBuild {
             Self::extract_pep517_backend(&source_tree, setup_py, &default_backend)
                 .map_err(|err| *err)?;
 
+        // Prefer the name from the `pyproject.toml`, if available.
+        let package_name = project
+            .as_ref()
+            .map(|project| &project.name)
+            .or(package_name);
+
         // Create a virtual environment, or install into the shared environment if requested.
-        let venv = match build_isolation {
-            BuildIsolation::Isolated => uv_virtualenv::create_venv(
+        let venv = if let Some(venv) = build_isolation.shared_environment(package_name) {
+            venv.clone()
+        } else {
+            uv_virtualenv::create_venv(
                 temp_dir.path(),
                 interpreter.clone(),
                 uv_virtualenv::Prompt::None,
                 false,
                 false,
-            )?,
-            BuildIsolation::Shared(venv) => venv.clone(),
+                false,
+            )?
         };
 
         // Setup the build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        if build_isolation.is_isolated() {
+        if build_isolation.is_isolated(package_name) {
             let resolved_requirements = Self::get_resolved_requirements(
                 build_context,
                 source_build_context,
@@ -499,7 +508,7 @@ impl SourceBuild {
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
         let runner = PythonRunner::new(concurrent_builds);
-        if build_isolation.is_isolated() {
+        if build_isolation.is_isolated(package_name) {
             if let Some(pep517_backend) = &pep517_backend {
                 create_pep517_build_environment(
                     &runner,
@@ -748,6 +757,9 @@ impl SourceBuild {
     /// Build a source distribution from an archive (`.zip` or `.tar.gz`), return the location of the
     /// built wheel.
     ///
+    /// For [`BuildKind::Sdist`], the source tree is instead built into a source distribution, and
+    /// the location of the built source distribution is returned.
+    ///
     /// The location will be inside `temp_dir`, i.e. you must use the wheel before dropping the temp
     /// dir.
     ///
@@ -767,23 +779,25 @@ impl SourceBuild {
             rename_with_retry(from, to).await?;
             Ok(filename)
         } else {
-            if self.build_kind != BuildKind::Wheel {
-                return Err(Error::EditableSetupPy);
-            }
+            let command = match self.build_kind {
+                BuildKind::Wheel => "bdist_wheel",
+                BuildKind::Sdist => "sdist",
+                BuildKind::Editable => return Err(Error::EditableSetupPy),
+            };
             // We checked earlier that setup.py exists.
             let span = info_span!(
                 "run_python_script",
-                script="setup.py bdist_wheel",
+                script=format!("setup.py {command}"),
                 python_version = %self.venv.interpreter().python_version()
             );
             let output = self
                 .runner
-                .run_setup_py(&self.venv, "bdist_wheel", &self.source_tree)
+                .run_setup_py(&self.venv, command, &self.source_tree)
                 .instrument(span)
                 .await?;
             if !output.status.success() {
                 return Err(Error::from_command_output(
-                    "Failed building wheel through setup.py".to_string(),
+                    format!("Failed building {} through setup.py", self.build_kind),
                     &output,
                     &self.version_id,
                 ));
@@ -793,7 +807,8 @@ impl SourceBuild {
             let [dist_wheel] = dist_dir.as_slice() else {
                 return Err(Error::from_command_output(
                     format!(
-                        "Expected exactly wheel in `dist/` after invoking setup.py, found {dist_dir:?}"
+                        "Expected exactly one {} in `dist/` after invoking setup.py, found {dist_dir:?}",
+                        self.build_kind
                     ),
                     &output,
                     &self.version_id)
@@ -813,12 +828,18 @@ impl SourceBuild {
         wheel_dir: &Path,
         pep517_backend: &Pep517Backend,
     ) -> Result<String, Error> {
-        let metadata_directory = self
-            .metadata_directory
-            .as_deref()
-            .map_or("None".to_string(), |path| {
-                format!(r#""{}""#, path.escape_for_python())
-            });
+        // Unlike `build_wheel`, `build_sdist` doesn't accept a metadata directory.
+        let metadata_directory = if self.build_kind == BuildKind::Sdist {
+            String::new()
+        } else {
+            let metadata_directory = self
+                .metadata_directory
+                .as_deref()
+                .map_or("None".to_string(), |path| {
+                    format!(r#""{}""#, path.escape_for_python())
+                });
+            format!(", {metadata_directory}")
+        };
 
         // Write the hook output to a file so that we can read it back reliably.
         let outfile = self
@@ -827,7 +848,7 @@ impl SourceBuild {
             .join(format!("build_{}.txt", self.build_kind));
 
         debug!(
-            r#"Calling `{}.build_{}("{}", {}, {})`"#,
+            r#"Calling `{}.build_{}("{}", {}{})`"#,
             pep517_backend.backend,
             self.build_kind,
             wheel_dir.escape_for_python(),
@@ -838,7 +859,7 @@ impl SourceBuild {
             r#"
             {}
 
-            wheel_filename = backend.build_{}("{}", {}, {})
+            wheel_filename = backend.build_{}("{}", {}{})
             with open("{}", "w") as fp:
                 fp.write(wheel_filename)
             "#,
@@ -26,6 +26,17 @@ pub struct CacheArgs {
     /// Linux, and `{FOLDERID_LocalAppData}\uv\cache` on Windows.
     #[arg(global = true, long, env = "UV_CACHE_DIR")]
     pub cache_dir: Option<PathBuf>,
+
+    /// Print a summary of cache hits and misses at the end of the command.
+    ///
+    /// The summary is also printed when running with `-vv`.
+    #[arg(
+        global = true,
+        long,
+        env = "UV_CACHE_STATS",
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub cache_stats: bool,
 }
 
 impl Cache {
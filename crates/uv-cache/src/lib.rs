@@ -1,9 +1,11 @@
 use std::cmp::max;
+use std::ffi::OsStr;
 use std::fmt::{Display, Formatter};
 use std::io;
 use std::io::Write;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
 
 use fs_err as fs;
@@ -12,6 +14,7 @@ use tracing::debug;
 
 pub use archive::ArchiveId;
 use distribution_types::InstalledDist;
+use pep440_rs::Version;
 use pypi_types::Metadata23;
 use uv_fs::{cachedir, directories};
 use uv_normalize::PackageName;
@@ -21,6 +24,7 @@ pub use crate::by_timestamp::CachedByTimestamp;
 pub use crate::cli::CacheArgs;
 use crate::removal::{rm_rf, Removal};
 pub use crate::timestamp::Timestamp;
+pub use crate::usage::{PackageUsage, Usage};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 
@@ -29,7 +33,9 @@ mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
 mod removal;
+pub mod stats;
 mod timestamp;
+mod usage;
 mod wheel;
 
 /// A [`CacheEntry`] which may or may not exist yet.
@@ -339,6 +345,21 @@ impl Cache {
         Ok(summary)
     }
 
+    /// Remove a single version of a package from the cache.
+    ///
+    /// Returns the number of entries removed from the cache.
+    pub fn remove_version(
+        &self,
+        name: &PackageName,
+        version: &Version,
+    ) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+        for bucket in CacheBucket::iter() {
+            summary += bucket.remove_version(self, name, version)?;
+        }
+        Ok(summary)
+    }
+
     /// Run the garbage collector on the cache, removing any dangling entries.
     pub fn prune(&self) -> Result<Removal, io::Error> {
         let mut summary = Removal::default();
@@ -801,6 +822,137 @@ impl CacheBucket {
         Ok(summary)
     }
 
+    /// Remove a single version of a package from the cache bucket.
+    fn remove_version(
+        self,
+        cache: &Cache,
+        name: &PackageName,
+        version: &Version,
+    ) -> Result<Removal, io::Error> {
+        /// Returns `true` if the [`Path`] represents a built wheel for the given package version.
+        fn is_match(path: &Path, name: &PackageName, version: &Version) -> bool {
+            let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
+                return false;
+            };
+            let Ok(metadata) = rmp_serde::from_slice::<Metadata23>(&metadata) else {
+                return false;
+            };
+            metadata.name == *name && metadata.version == *version
+        }
+
+        /// Returns `true` if the file name (e.g., `flask-3.0.0-py3-none-any.http`) represents a
+        /// wheel for the given version.
+        fn is_wheel_match(file_name: &OsStr, version: &Version) -> bool {
+            file_name
+                .to_str()
+                .and_then(|file_name| file_name.split('-').nth(1))
+                .and_then(|candidate| Version::from_str(candidate).ok())
+                .is_some_and(|candidate| candidate == *version)
+        }
+
+        /// Returns `true` if the directory name (e.g., `3.0.0`) matches the given version.
+        fn is_version_match(path: &Path, version: &Version) -> bool {
+            path.file_name()
+                .and_then(OsStr::to_str)
+                .and_then(|candidate| Version::from_str(candidate).ok())
+                .is_some_and(|candidate| candidate == *version)
+        }
+
+        /// Remove any wheels for the given version from a package directory.
+        fn remove_wheels(directory: &Path, version: &Version) -> Result<Removal, io::Error> {
+            let mut summary = Removal::default();
+            let entries = match fs::read_dir(directory) {
+                Ok(entries) => entries,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(summary),
+                Err(err) => return Err(err),
+            };
+            for entry in entries {
+                let entry = entry?;
+                if is_wheel_match(&entry.file_name(), version) {
+                    summary += rm_rf(entry.path())?;
+                }
+            }
+            Ok(summary)
+        }
+
+        let mut summary = Removal::default();
+        match self {
+            Self::Wheels => {
+                // For `pypi` wheels, we expect a directory per package (indexed by name), followed
+                // by an entry per wheel (indexed by filename).
+                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                summary += remove_wheels(&root.join(name.to_string()), version)?;
+
+                // For alternate indices, we expect a directory for every index, followed by a
+                // directory per package (indexed by name).
+                let root = cache.bucket(self).join(WheelCacheKind::Index);
+                for directory in directories(root) {
+                    summary += remove_wheels(&directory.join(name.to_string()), version)?;
+                }
+
+                // For direct URLs, we expect a directory for every URL, followed by a
+                // directory per package (indexed by name).
+                let root = cache.bucket(self).join(WheelCacheKind::Url);
+                for directory in directories(root) {
+                    summary += remove_wheels(&directory.join(name.to_string()), version)?;
+                }
+            }
+            Self::SourceDistributions => {
+                // For `pypi` source distributions, we expect a directory per package (indexed by
+                // name), followed by a directory per version.
+                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                for directory in directories(root.join(name.to_string())) {
+                    if is_version_match(&directory, version) {
+                        summary += rm_rf(directory)?;
+                    }
+                }
+
+                // For alternate indices, we expect a directory for every index, followed by a
+                // directory per package (indexed by name), followed by a directory per version.
+                let root = cache.bucket(self).join(WheelCacheKind::Index);
+                for index in directories(root) {
+                    for directory in directories(index.join(name.to_string())) {
+                        if is_version_match(&directory, version) {
+                            summary += rm_rf(directory)?;
+                        }
+                    }
+                }
+
+                // For direct URLs and local dependencies, we expect a directory for every URL or
+                // path, followed by a directory per build. To determine whether the entry is
+                // relevant, we need to search for a wheel matching the package name and version.
+                for kind in [WheelCacheKind::Url, WheelCacheKind::Path] {
+                    let root = cache.bucket(self).join(kind);
+                    for entry in directories(root) {
+                        if directories(&entry).any(|build| is_match(&build, name, version)) {
+                            summary += rm_rf(entry)?;
+                        }
+                    }
+                }
+
+                // For Git dependencies, we expect a directory for every repository, followed by a
+                // directory for every SHA.
+                let root = cache.bucket(self).join(WheelCacheKind::Git);
+                for repository in directories(root) {
+                    for sha in directories(repository) {
+                        if is_match(&sha, name, version) {
+                            summary += rm_rf(sha)?;
+                        }
+                    }
+                }
+            }
+            Self::Simple | Self::FlatIndex => {
+                // Index metadata is stored per package (or per index), rather than per version, so
+                // remove it entirely; it will be refreshed on the next request.
+                summary += self.remove(cache, name)?;
+            }
+            Self::Git | Self::Interpreter | Self::Archive | Self::Builds | Self::Environments => {
+                // Nothing to do.
+            }
+        }
+        Ok(summary)
+    }
+
     /// Return an iterator over all cache buckets.
     pub fn iter() -> impl Iterator<Item = Self> {
         [
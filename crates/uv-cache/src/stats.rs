@@ -0,0 +1,110 @@
+//! Hit and miss counters for the cache, aggregated over the lifetime of a command.
+//!
+//! Counting is disabled by default, in which case recording an event is a single relaxed atomic
+//! load.
+
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+/// Whether cache statistics are being collected.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static STATS: CacheStats = CacheStats {
+    metadata_hits: AtomicU64::new(0),
+    metadata_misses: AtomicU64::new(0),
+    wheel_hits: AtomicU64::new(0),
+    built_wheel_hits: AtomicU64::new(0),
+    environment_hits: AtomicU64::new(0),
+    environment_misses: AtomicU64::new(0),
+};
+
+/// An event to record in the cache statistics.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CacheEvent {
+    /// A cached HTTP response (e.g., registry metadata) was used, possibly after revalidation.
+    MetadataHit,
+    /// A cached HTTP response was missing or stale, and had to be fetched.
+    MetadataMiss,
+    /// A wheel was found in the archive cache, and didn't need to be downloaded.
+    WheelHit,
+    /// A wheel built from a source distribution was found in the cache, and didn't need to be
+    /// rebuilt.
+    BuiltWheelHit,
+    /// A cached environment (e.g., for `uv tool run`) was reused.
+    EnvironmentHit,
+    /// A cached environment had to be created.
+    EnvironmentMiss,
+}
+
+/// Enable the collection of cache statistics.
+pub fn enable() {
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+/// Record a [`CacheEvent`], if statistics are enabled.
+#[inline]
+pub fn record(event: CacheEvent) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let counter = match event {
+        CacheEvent::MetadataHit => &STATS.metadata_hits,
+        CacheEvent::MetadataMiss => &STATS.metadata_misses,
+        CacheEvent::WheelHit => &STATS.wheel_hits,
+        CacheEvent::BuiltWheelHit => &STATS.built_wheel_hits,
+        CacheEvent::EnvironmentHit => &STATS.environment_hits,
+        CacheEvent::EnvironmentMiss => &STATS.environment_misses,
+    };
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Return a snapshot of the cache statistics, if statistics are enabled.
+pub fn summary() -> Option<CacheSummary> {
+    if !ENABLED.load(Ordering::SeqCst) {
+        return None;
+    }
+    Some(CacheSummary {
+        metadata_hits: STATS.metadata_hits.load(Ordering::SeqCst),
+        metadata_misses: STATS.metadata_misses.load(Ordering::SeqCst),
+        wheel_hits: STATS.wheel_hits.load(Ordering::SeqCst),
+        built_wheel_hits: STATS.built_wheel_hits.load(Ordering::SeqCst),
+        environment_hits: STATS.environment_hits.load(Ordering::SeqCst),
+        environment_misses: STATS.environment_misses.load(Ordering::SeqCst),
+    })
+}
+
+/// The live counters for each [`CacheEvent`].
+struct CacheStats {
+    metadata_hits: AtomicU64,
+    metadata_misses: AtomicU64,
+    wheel_hits: AtomicU64,
+    built_wheel_hits: AtomicU64,
+    environment_hits: AtomicU64,
+    environment_misses: AtomicU64,
+}
+
+/// A point-in-time snapshot of the cache statistics.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CacheSummary {
+    pub metadata_hits: u64,
+    pub metadata_misses: u64,
+    pub wheel_hits: u64,
+    pub built_wheel_hits: u64,
+    pub environment_hits: u64,
+    pub environment_misses: u64,
+}
+
+impl Display for CacheSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cache: {} metadata hits, {} metadata misses, {} wheel hits, {} built wheel hits, {} environment hits, {} environment misses",
+            self.metadata_hits,
+            self.metadata_misses,
+            self.wheel_hits,
+            self.built_wheel_hits,
+            self.environment_hits,
+            self.environment_misses,
+        )
+    }
+}
@@ -0,0 +1,146 @@
+//! Compute the disk usage of the cache.
+
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use rustc_hash::FxHashMap;
+
+use pep440_rs::Version;
+use uv_fs::directories;
+use uv_normalize::PackageName;
+
+use crate::wheel::WheelCacheKind;
+use crate::{Cache, CacheBucket};
+
+/// The disk usage of a single package version in the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageUsage {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The version of the package.
+    pub version: Version,
+    /// The total size of the cached wheels and source distributions, in bytes.
+    pub size_bytes: u64,
+    /// The most recent access time across the package's cache entries, if known.
+    pub last_accessed: Option<SystemTime>,
+}
+
+/// The disk usage of the cache.
+#[derive(Debug, Default)]
+pub struct Usage {
+    /// The total size of the cache, in bytes.
+    ///
+    /// Note: this is a measure of the exact byte size (as opposed to the block size), and will
+    /// over-count hard-linked files.
+    pub total_bytes: u64,
+    /// The cached package versions, sorted by size (largest first).
+    pub packages: Vec<PackageUsage>,
+}
+
+impl Cache {
+    /// Compute the disk usage of the cache, in aggregate and per package version.
+    ///
+    /// Only wheels and source distributions from registries are attributed to packages, since
+    /// direct URL, path, and Git entries aren't indexed by package name.
+    pub fn usage(&self) -> Result<Usage, io::Error> {
+        let total_bytes = size(self.root())?;
+
+        let mut packages: FxHashMap<(PackageName, Version), PackageUsage> = FxHashMap::default();
+        for bucket in [CacheBucket::Wheels, CacheBucket::SourceDistributions] {
+            // For `pypi` entries, we expect a directory per package (indexed by name); for
+            // alternate indices, a directory for every index, followed by a directory per package.
+            let root = self.bucket(bucket);
+            let indexes = std::iter::once(root.join(WheelCacheKind::Pypi))
+                .chain(directories(root.join(WheelCacheKind::Index)));
+
+            for index in indexes {
+                for package in directories(index) {
+                    let Some(name) = package
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .and_then(|name| PackageName::from_str(name).ok())
+                    else {
+                        continue;
+                    };
+
+                    for entry in fs_err::read_dir(&package)? {
+                        let entry = entry?;
+                        let file_name = entry.file_name();
+                        let version = if bucket == CacheBucket::Wheels {
+                            // e.g., `flask-3.0.0-py3-none-any.http`
+                            file_name
+                                .to_str()
+                                .and_then(|file_name| file_name.split('-').nth(1))
+                        } else {
+                            // e.g., `3.0.0`
+                            file_name.to_str()
+                        };
+                        let Some(version) =
+                            version.and_then(|version| Version::from_str(version).ok())
+                        else {
+                            continue;
+                        };
+
+                        // Follow the entry, if it's a link to an unzipped wheel in the archive.
+                        let path = entry.path();
+                        let size_bytes = match fs_err::canonicalize(&path) {
+                            Ok(target) => size(&target)?,
+                            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                            Err(err) => return Err(err),
+                        };
+                        let last_accessed = fs_err::metadata(&path)?.accessed().ok();
+
+                        let usage = packages
+                            .entry((name.clone(), version.clone()))
+                            .or_insert_with(|| PackageUsage {
+                                name: name.clone(),
+                                version,
+                                size_bytes: 0,
+                                last_accessed: None,
+                            });
+                        usage.size_bytes += size_bytes;
+                        usage.last_accessed = usage.last_accessed.max(last_accessed);
+                    }
+                }
+            }
+        }
+
+        let mut packages = packages.into_values().collect::<Vec<_>>();
+        packages.sort_by(|a, b| {
+            b.size_bytes
+                .cmp(&a.size_bytes)
+                .then_with(|| a.name.cmp(&b.name))
+                .then_with(|| a.version.cmp(&b.version))
+        });
+
+        Ok(Usage {
+            total_bytes,
+            packages,
+        })
+    }
+}
+
+/// Return the total size of the files in a directory (or of a single file), in bytes, without
+/// following symbolic links.
+fn size(path: &Path) -> Result<u64, io::Error> {
+    let mut total = 0;
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err)
+                if err
+                    .io_error()
+                    .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+            {
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if entry.file_type().is_file() {
+            total += entry.metadata()?.len();
+        }
+    }
+    Ok(total)
+}
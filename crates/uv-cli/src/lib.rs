@@ -5,15 +5,18 @@ use std::str::FromStr;
 
 use anyhow::{anyhow, Result};
 use clap::{Args, Parser, Subcommand};
+use url::Url;
 
 use distribution_types::{FlatIndexLocation, IndexUrl};
+use pep440_rs::{Version, VersionSpecifiers};
 use pep508_rs::Requirement;
 use pypi_types::VerbatimParsedUrl;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ConfigSettingEntry, IndexStrategy, KeyringProviderType, PackageNameSpecifier, TargetTriple,
+    AddBounds, ConfigSettingEntry, ExportFormat, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, TargetTriple,
 };
-use uv_normalize::{ExtraName, PackageName};
+use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_python::{PythonFetch, PythonPreference, PythonVersion};
 use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
 
@@ -140,6 +143,19 @@ pub struct GlobalArgs {
     #[arg(global = true, long)]
     pub python_fetch: Option<PythonFetch>,
 
+    /// Mirror URLs from which to download managed Python installations.
+    ///
+    /// Mirrors are tried in order before falling back to the upstream source, and each download
+    /// is verified against the known SHA-256 digest of the distribution. When provided via the
+    /// environment, multiple mirrors may be separated by spaces.
+    #[arg(
+        global = true,
+        long,
+        env = "UV_PYTHON_INSTALL_MIRROR",
+        value_delimiter = ' '
+    )]
+    pub python_install_mirror: Option<Vec<String>>,
+
     /// Whether to enable experimental, preview features.
     #[arg(global = true, long, hide = true, env = "UV_PREVIEW", value_parser = clap::builder::BoolishValueParser::new(), overrides_with("no_preview"))]
     pub preview: bool,
@@ -279,9 +295,12 @@ pub enum CacheCommand {
     /// Clear the cache, removing all entries or those linked to specific packages.
     Clean(CleanArgs),
     /// Prune all unreachable objects from the cache.
-    Prune,
+    #[command(disable_version_flag = true)]
+    Prune(PruneArgs),
     /// Show the cache directory.
     Dir,
+    /// Show the size of the cache, and the cached packages that use the most space.
+    Info(InfoArgs),
 }
 
 #[derive(Args, Debug)]
@@ -291,6 +310,31 @@ pub struct CleanArgs {
     pub package: Vec<PackageName>,
 }
 
+#[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PruneArgs {
+    /// Remove the cached wheels, source distributions, and metadata for the given package,
+    /// rather than pruning unreachable objects.
+    #[arg(long)]
+    pub package: Option<PackageName>,
+
+    /// Only remove cache entries for the given version of the package.
+    #[arg(long, requires = "package")]
+    pub version: Option<Version>,
+}
+
+#[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct InfoArgs {
+    /// Display the cache information as JSON.
+    #[arg(long)]
+    pub json: bool,
+
+    /// The number of cached packages to display, ordered by size.
+    #[arg(long, default_value_t = 10)]
+    pub top: usize,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipNamespace {
@@ -382,6 +426,13 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Lock(LockArgs),
+    /// Export the project's lockfile to an alternate format.
+    #[clap(hide = true)]
+    #[command(
+        after_help = "Use `uv help export` for more details.",
+        after_long_help = ""
+    )]
+    Export(ExportArgs),
     /// Add one or more packages to the project requirements.
     #[clap(hide = true)]
     #[command(
@@ -399,6 +450,13 @@ pub enum ProjectCommand {
     /// Display the dependency tree for the project.
     #[clap(hide = true)]
     Tree(TreeArgs),
+    /// Build the project and upload its distributions to a registry.
+    #[clap(hide = true)]
+    #[command(
+        after_help = "Use `uv help publish` for more details.",
+        after_long_help = ""
+    )]
+    Publish(PublishArgs),
 }
 
 /// A re-implementation of `Option`, used to avoid Clap's automatic `Option` flattening in
@@ -448,6 +506,14 @@ fn parse_file_path(input: &str) -> Result<PathBuf, String> {
     }
 }
 
+/// Parse a `KEY=VALUE` string into an environment variable assignment.
+fn parse_env_var(input: &str) -> Result<(String, String), String> {
+    match input.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("expected `KEY=VALUE`, found `{input}`")),
+    }
+}
+
 /// Parse a string into a [`PathBuf`], mapping the empty string to `None`.
 fn parse_maybe_file_path(input: &str) -> Result<Maybe<PathBuf>, String> {
     if input.is_empty() {
@@ -644,6 +710,8 @@ pub struct PipCompileArgs {
     /// Alias for `--only-binary :all:`.
     #[arg(
         long,
+        env = "UV_NO_BUILD",
+        value_parser = clap::builder::BoolishValueParser::new(),
         conflicts_with = "no_binary",
         conflicts_with = "only_binary",
         overrides_with("build")
@@ -666,6 +734,8 @@ pub struct PipCompileArgs {
     ///
     /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
     /// Clear previously specified packages with `:none:`.
+    ///
+    /// If unset, `UV_NO_BINARY=true` disables binaries for all packages.
     #[arg(long, conflicts_with = "no_build")]
     pub no_binary: Option<Vec<PackageNameSpecifier>>,
 
@@ -930,6 +1000,8 @@ pub struct PipSyncArgs {
     /// Alias for `--only-binary :all:`.
     #[arg(
         long,
+        env = "UV_NO_BUILD",
+        value_parser = clap::builder::BoolishValueParser::new(),
         conflicts_with = "no_binary",
         conflicts_with = "only_binary",
         overrides_with("build")
@@ -952,6 +1024,8 @@ pub struct PipSyncArgs {
     ///
     /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
     /// Clear previously specified packages with `:none:`.
+    ///
+    /// If unset, `UV_NO_BINARY=true` disables binaries for all packages.
     #[arg(long, conflicts_with = "no_build")]
     pub no_binary: Option<Vec<PackageNameSpecifier>>,
 
@@ -1220,6 +1294,8 @@ pub struct PipInstallArgs {
     /// Alias for `--only-binary :all:`.
     #[arg(
         long,
+        env = "UV_NO_BUILD",
+        value_parser = clap::builder::BoolishValueParser::new(),
         conflicts_with = "no_binary",
         conflicts_with = "only_binary",
         overrides_with("build")
@@ -1242,6 +1318,8 @@ pub struct PipInstallArgs {
     ///
     /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
     /// Clear previously specified packages with `:none:`.
+    ///
+    /// If unset, `UV_NO_BINARY=true` disables binaries for all packages.
     #[arg(long, conflicts_with = "no_build")]
     pub no_binary: Option<Vec<PackageNameSpecifier>>,
 
@@ -1797,6 +1875,34 @@ pub struct InitArgs {
     /// Do not create a readme file.
     #[arg(long)]
     pub no_readme: bool,
+
+    /// The template to use for the project.
+    ///
+    /// Accepts a built-in template, a path to a local template directory, or a Git URL (e.g.,
+    /// `git+https://github.com/user/template`).
+    ///
+    /// Built-in templates:
+    /// - `lib`: A library, with a package in `src` (the default).
+    /// - `app`: An application, with a `__main__.py` and a `[project.scripts]` entry point.
+    ///
+    /// In a template directory, `{{project_name}}`, `{{module_name}}`, `{{python_version}}`, and
+    /// `{{author}}` are substituted in file names and contents.
+    #[arg(long, verbatim_doc_comment)]
+    pub template: Option<String>,
+
+    /// Create an application, equivalent to `--template app`.
+    #[arg(long, conflicts_with = "template")]
+    pub app: bool,
+
+    /// Do not set up the application to be built and installed as a package.
+    ///
+    /// Sets `tool.uv.package = false`, such that `uv sync` and `uv run` install the project's
+    /// dependencies, but never the project itself. Instead of a package in `src` and a script
+    /// entry point, a `main.py` is created in the project root.
+    ///
+    /// Only supported for applications (i.e., with `--app` or `--template app`).
+    #[arg(long)]
+    pub no_package: bool,
 }
 
 #[derive(Args)]
@@ -1825,6 +1931,38 @@ pub struct RunArgs {
     #[arg(long, overrides_with("dev"))]
     pub no_dev: bool,
 
+    /// Include dependencies from the given dependency group; may be provided more than once.
+    ///
+    /// Groups are declared under `[dependency-groups]` in the `pyproject.toml`. The `dev` group,
+    /// which includes `tool.uv.dev-dependencies`, is included by default.
+    #[arg(long, conflicts_with = "only_group")]
+    pub group: Vec<GroupName>,
+
+    /// Only include dependencies from the given dependency group; may be provided more than once.
+    ///
+    /// The project itself is installed, but its dependencies, and any other groups, are omitted.
+    #[arg(long, conflicts_with_all = ["group", "dev", "no_dev"])]
+    pub only_group: Vec<GroupName>,
+
+    /// Perform an exact sync, removing extraneous packages.
+    ///
+    /// By default, `uv run` will make the minimum necessary changes to satisfy the requirements,
+    /// leaving any extraneous packages in the environment untouched.
+    ///
+    /// Only applies to the project environment; the environment for any `--with` requirements is
+    /// created from scratch on each invocation.
+    #[arg(long, overrides_with("inexact"))]
+    pub exact: bool,
+
+    /// Do not remove extraneous packages present in the environment.
+    #[arg(long, overrides_with("exact"), hide = true)]
+    pub inexact: bool,
+
+    /// Perform a dry run of the project sync, i.e., print the changes that would be made to the
+    /// environment, then exit without modifying it or running the command.
+    #[arg(long)]
+    pub dry_run: bool,
+
     /// The command to run.
     #[command(subcommand)]
     pub command: ExternalCommand,
@@ -1841,6 +1979,24 @@ pub struct RunArgs {
     #[arg(long, conflicts_with = "locked")]
     pub frozen: bool,
 
+    /// Treat select warnings as errors, exiting with a distinct exit code for each category.
+    ///
+    /// Applies to the selection of yanked versions (exit code `3`), existing virtual environments
+    /// that are ignored due to a missing Python interpreter (`4`), and direct URL dependencies that
+    /// are locked without a hash (`5`). To enable a subset of these categories, use the `strict`
+    /// setting.
+    #[arg(long, overrides_with("no_strict"))]
+    pub strict: bool,
+
+    #[arg(long, overrides_with("strict"), hide = true)]
+    pub no_strict: bool,
+
+    /// The path to the lockfile to use, relative to the workspace root.
+    ///
+    /// Defaults to `uv.lock`.
+    #[arg(long, env = "UV_LOCKFILE")]
+    pub lockfile: Option<PathBuf>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -1867,6 +2023,28 @@ pub struct RunArgs {
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
     pub python: Option<String>,
+
+    /// Use the active virtual environment (i.e., per `VIRTUAL_ENV`) instead of the project's
+    /// `.venv` directory.
+    ///
+    /// The active environment is validated against the project's Python requirement, and will be
+    /// recreated if incompatible.
+    #[arg(long)]
+    pub active: bool,
+
+    /// Disable output buffering in the command, by setting `PYTHONUNBUFFERED=1` in its
+    /// environment.
+    ///
+    /// Python buffers its standard output when it isn't attached to a terminal (e.g., when piped
+    /// or run in CI), which can delay output until the buffer fills or the process exits. uv
+    /// itself never buffers the command's output: the standard streams are inherited directly.
+    ///
+    /// Unless already set, `PYTHONIOENCODING` is also set to `utf-8`.
+    #[arg(long, overrides_with("no_unbuffered"))]
+    pub unbuffered: bool,
+
+    #[arg(long, overrides_with("unbuffered"), hide = true)]
+    pub no_unbuffered: bool,
 }
 
 #[derive(Args)]
@@ -1892,14 +2070,115 @@ pub struct SyncArgs {
     pub dev: bool,
 
     /// Omit development dependencies.
+    ///
+    /// Equivalent to `--exclude-group dev`.
     #[arg(long, overrides_with("dev"))]
     pub no_dev: bool,
 
-    /// Does not clean the environment.
+    /// Exclude dependencies from the given dependency group; may be provided more than once.
     ///
-    /// When omitted, any extraneous installations will be removed.
+    /// Packages that are only reachable through an excluded group will not be installed.
     #[arg(long)]
-    pub no_clean: bool,
+    pub exclude_group: Vec<GroupName>,
+
+    /// Include dependencies from the given dependency group; may be provided more than once.
+    ///
+    /// Groups are declared under `[dependency-groups]` in the `pyproject.toml`. The `dev` group,
+    /// which includes `tool.uv.dev-dependencies`, is included by default.
+    #[arg(long, conflicts_with = "only_group")]
+    pub group: Vec<GroupName>,
+
+    /// Only include dependencies from the given dependency group; may be provided more than once.
+    ///
+    /// The project itself is installed, but its dependencies, and any other groups, are omitted.
+    #[arg(long, conflicts_with_all = ["group", "dev", "no_dev"])]
+    pub only_group: Vec<GroupName>,
+
+    /// Install any editable dependencies, including the project and any workspace members, as
+    /// non-editable.
+    #[arg(long)]
+    pub no_editable: bool,
+
+    /// Do not install the current project.
+    ///
+    /// By default, the current project is installed into the environment with all of its
+    /// dependencies. The `--no-install-project` option allows the project to be excluded, but all
+    /// of its dependencies are still installed. This is particularly useful in situations like
+    /// building Docker images where installing the project separately from its dependencies
+    /// allows optimal layer caching.
+    #[arg(long)]
+    pub no_install_project: bool,
+
+    /// Do not install any workspace members, including the root project.
+    ///
+    /// By default, all of the workspace members and their dependencies are installed into the
+    /// environment. The `--no-install-workspace` option allows exclusion of all the workspace
+    /// members while retaining their dependencies.
+    #[arg(long)]
+    pub no_install_workspace: bool,
+
+    /// Do not install the given package(s).
+    ///
+    /// By default, all of the project's dependencies are installed into the environment. The
+    /// `--no-install-package` option allows exclusion of specific packages. Note this can result
+    /// in a broken environment, and should be used with caution.
+    #[arg(long)]
+    pub no_install_package: Vec<PackageName>,
+
+    /// Do not remove extraneous packages present in the environment.
+    ///
+    /// When enabled, uv will make the minimum necessary changes to satisfy the requirements.
+    ///
+    /// By default, syncing will remove any extraneous packages from the environment, unless
+    /// `--inexact` is provided.
+    #[arg(long, alias = "no-clean", overrides_with("exact"))]
+    pub inexact: bool,
+
+    /// Perform an exact sync, removing extraneous packages.
+    #[arg(long, overrides_with("inexact"), hide = true)]
+    pub exact: bool,
+
+    /// Fail if the lockfile is missing any requirements that apply to the current environment.
+    ///
+    /// By default, uv warns if a requirement whose markers match the current environment (e.g.,
+    /// a `platform_machine` that wasn't anticipated when locking) is missing from the lockfile.
+    #[arg(long)]
+    pub strict_markers: bool,
+
+    /// Require a matching hash for each distribution installed from the lockfile.
+    ///
+    /// By default, uv verifies any hashes recorded in the lockfile, but installs distributions
+    /// without a recorded hash as-is. With `--require-hashes`, a distribution without a hash (e.g.,
+    /// a Git dependency) is an error. Local directories, including editables, can't be hashed, and
+    /// are exempt.
+    #[arg(
+        long,
+        env = "UV_REQUIRE_HASHES",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_hashes"),
+    )]
+    pub require_hashes: bool,
+
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub no_require_hashes: bool,
+
+    /// Perform a dry run, i.e., don't actually modify the environment or lockfile, but resolve
+    /// the dependencies and print the changes that would be made.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Exit with a non-zero status if the dry run would make any changes to the environment.
+    #[arg(long, requires = "dry_run")]
+    pub check: bool,
+
+    /// Remove any invalid distributions from the environment before syncing.
+    ///
+    /// Distributions with malformed metadata (e.g., an empty `METADATA` file, or an invalid
+    /// version in the `.dist-info` directory name), as sometimes left behind by other tools, are
+    /// ignored with a warning. With `--repair`, their `.dist-info` directories are removed, such
+    /// that the packages can be reinstalled cleanly.
+    #[arg(long)]
+    pub repair: bool,
 
     /// Assert that the `uv.lock` will remain unchanged.
     #[arg(long, conflicts_with = "frozen")]
@@ -1909,6 +2188,24 @@ pub struct SyncArgs {
     #[arg(long, conflicts_with = "locked")]
     pub frozen: bool,
 
+    /// Treat select warnings as errors, exiting with a distinct exit code for each category.
+    ///
+    /// Applies to the selection of yanked versions (exit code `3`), existing virtual environments
+    /// that are ignored due to a missing Python interpreter (`4`), and direct URL dependencies that
+    /// are locked without a hash (`5`). To enable a subset of these categories, use the `strict`
+    /// setting.
+    #[arg(long, overrides_with("no_strict"))]
+    pub strict: bool,
+
+    #[arg(long, overrides_with("strict"), hide = true)]
+    pub no_strict: bool,
+
+    /// The path to the lockfile to use, relative to the workspace root.
+    ///
+    /// Defaults to `uv.lock`.
+    #[arg(long, env = "UV_LOCKFILE")]
+    pub lockfile: Option<PathBuf>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -1918,6 +2215,13 @@ pub struct SyncArgs {
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
+    /// Sync a specific package in the workspace.
+    ///
+    /// The package is installed along with its dependencies and its own development dependencies;
+    /// the development dependencies of other workspace members are omitted.
+    #[arg(long)]
+    pub package: Option<PackageName>,
+
     /// The Python interpreter to use to build the run environment.
     ///
     /// By default, uv uses the virtual environment in the current working directory or any parent
@@ -1931,6 +2235,33 @@ pub struct SyncArgs {
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
     pub python: Option<String>,
+
+    /// Use the active virtual environment (i.e., per `VIRTUAL_ENV`) instead of the project's
+    /// `.venv` directory.
+    ///
+    /// The active environment is validated against the project's Python requirement, and will be
+    /// recreated if incompatible.
+    #[arg(long)]
+    pub active: bool,
+
+    /// Provide an alternative prompt prefix for the project environment, if it's created.
+    ///
+    /// By default, the prompt is set to the name of the workspace root project, or the name of
+    /// the workspace directory.
+    ///
+    /// Possible values:
+    /// - `.`: Use the current directory name.
+    /// - Any string: Use the given string.
+    #[arg(long, verbatim_doc_comment)]
+    pub prompt: Option<String>,
+
+    /// Install seed packages (`pip`, along with `setuptools` and `wheel` on Python 3.11 and
+    /// earlier) into the project environment, if it's created.
+    ///
+    /// Seed packages are retained by subsequent syncs, including when the environment is
+    /// recreated.
+    #[arg(long)]
+    pub seed: bool,
 }
 
 #[derive(Args)]
@@ -1944,6 +2275,70 @@ pub struct LockArgs {
     #[arg(long, conflicts_with = "locked")]
     pub frozen: bool,
 
+    /// Treat select warnings as errors, exiting with a distinct exit code for each category.
+    ///
+    /// Applies to the selection of yanked versions (exit code `3`), existing virtual environments
+    /// that are ignored due to a missing Python interpreter (`4`), and direct URL dependencies that
+    /// are locked without a hash (`5`). To enable a subset of these categories, use the `strict`
+    /// setting.
+    #[arg(long, overrides_with("no_strict"))]
+    pub strict: bool,
+
+    #[arg(long, overrides_with("strict"), hide = true)]
+    pub no_strict: bool,
+
+    /// The path to the lockfile to use, relative to the workspace root.
+    ///
+    /// Defaults to `uv.lock`.
+    #[arg(long, env = "UV_LOCKFILE")]
+    pub lockfile: Option<PathBuf>,
+
+    /// Lock the project for the given platforms only; may be provided more than once.
+    ///
+    /// By default, the lockfile covers all platforms. When provided, dependencies that are not
+    /// relevant on any of the given platforms (based on their `sys_platform` and
+    /// `platform_machine` markers) are omitted from the lockfile.
+    ///
+    /// The platforms are recorded in the lockfile and reused by subsequent locks, and syncing on a
+    /// platform that isn't covered by the lockfile fails.
+    #[arg(long)]
+    pub python_platform: Vec<TargetTriple>,
+
+    /// Lock the project for the given range of Python versions only, e.g., `>=3.12`.
+    ///
+    /// The range is intersected with the workspace `requires-python`, and must overlap with it. The
+    /// range is recorded in the `uv.lock` and respected by subsequent operations (e.g., `uv sync`),
+    /// which reject interpreters outside of it. To widen the range again, provide the workspace
+    /// `requires-python` (or any range that contains it).
+    #[arg(long, value_name = "SPECIFIERS", conflicts_with = "frozen")]
+    pub python_range: Option<VersionSpecifiers>,
+
+    /// Resolve the project without the given direct dependency; may be provided more than once.
+    ///
+    /// Intended for debugging resolution failures, e.g., to check whether the project resolves
+    /// without a given package. The versions of the remaining packages are printed on success.
+    ///
+    /// This only affects the current invocation: the `uv.lock` is left unchanged, unless `--write`
+    /// is provided.
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["locked", "frozen"])]
+    pub exclude_package: Vec<PackageName>,
+
+    /// Write the resolution to the `uv.lock` when using `--exclude-package`.
+    #[arg(long, requires = "exclude_package")]
+    pub write: bool,
+
+    /// Check if the `uv.lock` is up-to-date, without writing it.
+    ///
+    /// Exits with a non-zero status, and prints the packages that would be added, removed, or
+    /// updated, if the lockfile would change.
+    #[arg(long, conflicts_with_all = ["locked", "frozen", "exclude_package"])]
+    pub check: bool,
+
+    /// Perform a dry run, i.e., resolve the project and print the changes that would be made to
+    /// the `uv.lock`, without writing it.
+    #[arg(long, conflicts_with_all = ["locked", "frozen", "exclude_package"])]
+    pub dry_run: bool,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -1968,11 +2363,128 @@ pub struct LockArgs {
     pub python: Option<String>,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ExportArgs {
+    /// The format to which `uv.lock` should be exported.
+    ///
+    /// The `dockerfile` format emits a `Dockerfile` that installs the project's dependencies
+    /// (from `uv.lock`) before copying in the project source, to take advantage of layer caching.
+    ///
+    /// The `license-csv` format emits the license of each exported package, as read from the
+    /// metadata of the packages installed in the project environment (i.e., after `uv sync`).
+    /// Licenses derived from classifiers are self-reported and may be inaccurate; packages without
+    /// a detectable license are listed as `unknown`.
+    #[arg(long, value_enum, default_value_t = ExportFormat::default())]
+    pub format: ExportFormat,
+
+    /// The base image to use in the generated `Dockerfile`.
+    ///
+    /// Defaults to the `python:<major>.<minor>-slim` image matching the project interpreter.
+    ///
+    /// Only applies to `--format dockerfile`.
+    #[arg(long)]
+    pub base_image: Option<String>,
+
+    /// Include optional dependencies from the extra group name; may be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Include hashes for all dependencies.
+    #[arg(long, overrides_with("no_hashes"), hide = true)]
+    pub hashes: bool,
+
+    /// Omit hashes in the generated output.
+    #[arg(long, overrides_with("hashes"))]
+    pub no_hashes: bool,
+
+    /// Include development dependencies.
+    #[arg(long, overrides_with("no_dev"), hide = true)]
+    pub dev: bool,
+
+    /// Omit development dependencies.
+    #[arg(long, overrides_with("dev"))]
+    pub no_dev: bool,
+
+    /// Do not emit the workspace members themselves, only their dependencies.
+    ///
+    /// Useful when the project is installed separately from its dependencies, e.g., in a
+    /// `Dockerfile`.
+    #[arg(long)]
+    pub no_emit_project: bool,
+
+    /// Write the exported output to the given file, in addition to stdout.
+    #[arg(long, short)]
+    pub output_file: Option<PathBuf>,
+
+    /// The platform for which requirements should be exported.
+    ///
+    /// Only packages that are required on the given platform are exported, rather than every
+    /// package in the lockfile. Represented as a "target triple", like `x86_64-unknown-linux-gnu`
+    /// or `aarch64-apple-darwin`.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
+
+    /// The Python version for which requirements should be exported (e.g., `3.11`).
+    ///
+    /// Only packages that are required on the given Python version are exported, rather than every
+    /// package in the lockfile.
+    #[arg(long)]
+    pub python_version: Option<PythonVersion>,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    #[arg(long, conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Assert that a `uv.lock` exists, without updating it.
+    #[arg(long, conflicts_with = "locked")]
+    pub frozen: bool,
+
+    /// The path to the lockfile to use, relative to the workspace root.
+    ///
+    /// Defaults to `uv.lock`.
+    #[arg(long, env = "UV_LOCKFILE")]
+    pub lockfile: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// By default, uv uses the virtual environment in the current working directory or any parent
+    /// directory, falling back to searching for a Python executable in `PATH`. The `--python`
+    /// option allows you to specify a different interpreter.
+    ///
+    /// Supported formats:
+    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
+    ///   `python3.10` on Linux and macOS.
+    /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
+    /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
+    #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
+    pub python: Option<String>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct AddArgs {
     /// The packages to add, as PEP 508 requirements (e.g., `ruff==0.5.0`).
-    #[arg(required = true)]
+    ///
+    /// With `--git` or `--path`, the name of the package to add (e.g., `ruff`), which may be
+    /// omitted to infer the name from the source.
+    #[arg(required_unless_present_any = ["git", "path"])]
     pub requirements: Vec<String>,
 
     /// Add the requirements as development dependencies.
@@ -1980,16 +2492,32 @@ pub struct AddArgs {
     pub dev: bool,
 
     /// Add the requirements to the specified optional dependency group.
-    #[arg(long, conflicts_with("dev"))]
+    #[arg(long, conflicts_with("dev"), value_parser = extra_name_with_clap_error)]
     pub optional: Option<ExtraName>,
 
-    #[arg(long, overrides_with = "no_editable", hide = true)]
+    /// Add the requirements as editables.
+    ///
+    /// Only applies to local directories, e.g., as provided via `--path`.
+    #[arg(long, overrides_with = "no_editable")]
     pub editable: bool,
 
     /// Don't add the requirements as editables.
     #[arg(long, overrides_with = "editable")]
     pub no_editable: bool,
 
+    /// Add the requirement from the given Git repository.
+    ///
+    /// For example, `uv add ruff --git https://github.com/astral-sh/ruff`. The repository is
+    /// written to `tool.uv.sources`, along with any `--tag`, `--branch`, or `--rev`.
+    #[arg(long, value_name = "URL", conflicts_with_all = ["path", "raw_sources"])]
+    pub git: Option<String>,
+
+    /// Add the requirement from the given local path, either a directory or an archive.
+    ///
+    /// The path is written to `tool.uv.sources`, relative to the current working directory.
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["git", "raw_sources"])]
+    pub path: Option<PathBuf>,
+
     /// Add source requirements to the `project.dependencies` section of the `pyproject.toml`.
     ///
     /// Without this flag uv will try to use `tool.uv.sources` for any sources.
@@ -1997,21 +2525,30 @@ pub struct AddArgs {
     pub raw_sources: bool,
 
     /// Specific commit to use when adding from Git.
-    #[arg(long)]
+    #[arg(long, conflicts_with_all = ["tag", "branch", "path"])]
     pub rev: Option<String>,
 
     /// Tag to use when adding from git.
-    #[arg(long)]
+    #[arg(long, conflicts_with_all = ["rev", "branch", "path"])]
     pub tag: Option<String>,
 
     /// Branch to use when adding from git.
-    #[arg(long)]
+    #[arg(long, conflicts_with_all = ["rev", "tag", "path"])]
     pub branch: Option<String>,
 
     /// Extras to activate for the dependency; may be provided more than once.
     #[arg(long)]
     pub extra: Option<Vec<ExtraName>>,
 
+    /// The version bounds to write for requirements that are added without a version specifier.
+    ///
+    /// The bounds are derived from the locked version of each requirement. Explicit version
+    /// specifiers (e.g., `requests>=2`) are always written as given.
+    ///
+    /// By default, no version specifier is written, such that the lockfile is the only pin.
+    #[arg(long, value_enum)]
+    pub bounds: Option<AddBounds>,
+
     /// Assert that the `uv.lock` will remain unchanged.
     #[arg(long, conflicts_with = "frozen")]
     pub locked: bool,
@@ -2047,6 +2584,18 @@ pub struct AddArgs {
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
     pub python: Option<String>,
+
+    /// Add the dependency to the inline metadata of the given PEP 723 script, rather than to a
+    /// project.
+    ///
+    /// If the script doesn't contain a `# /// script` block, one is created, with a
+    /// `requires-python` derived from the discovered interpreter.
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["dev", "optional", "package", "git", "path", "rev", "tag", "branch", "locked"]
+    )]
+    pub script: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -2061,9 +2610,15 @@ pub struct RemoveArgs {
     pub dev: bool,
 
     /// Remove the requirements from the specified optional dependency group.
-    #[arg(long, conflicts_with("dev"))]
+    #[arg(long, conflicts_with("dev"), value_parser = extra_name_with_clap_error)]
     pub optional: Option<ExtraName>,
 
+    /// Retain the optional dependency group in the `pyproject.toml` if it becomes empty.
+    ///
+    /// By default, empty groups are removed from `project.optional-dependencies`.
+    #[arg(long, requires = "optional")]
+    pub keep_empty: bool,
+
     /// Assert that the `uv.lock` will remain unchanged.
     #[arg(long, conflicts_with = "frozen")]
     pub locked: bool,
@@ -2072,6 +2627,12 @@ pub struct RemoveArgs {
     #[arg(long, conflicts_with = "locked")]
     pub frozen: bool,
 
+    /// Update the `uv.lock` file, but avoid syncing the virtual environment.
+    ///
+    /// By default, packages that are no longer required are removed from the environment.
+    #[arg(long, conflicts_with = "frozen")]
+    pub no_sync: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -2099,6 +2660,15 @@ pub struct RemoveArgs {
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
     pub python: Option<String>,
+
+    /// Remove the dependency from the inline metadata of the given PEP 723 script, rather than
+    /// from a project.
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["dev", "optional", "package", "locked"]
+    )]
+    pub script: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -2107,6 +2677,21 @@ pub struct TreeArgs {
     #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
+    /// Include development dependencies.
+    #[arg(long, overrides_with("no_dev"), hide = true)]
+    pub dev: bool,
+
+    /// Omit development dependencies.
+    #[arg(long, overrides_with("dev"), conflicts_with = "only_dev")]
+    pub no_dev: bool,
+
+    /// Only include development dependencies.
+    ///
+    /// The workspace members are displayed, but their dependencies are omitted in favor of their
+    /// development dependencies.
+    #[arg(long, conflicts_with = "dev")]
+    pub only_dev: bool,
+
     /// Assert that the `uv.lock` will remain unchanged.
     #[arg(long, conflicts_with = "frozen")]
     pub locked: bool,
@@ -2115,6 +2700,12 @@ pub struct TreeArgs {
     #[arg(long, conflicts_with = "locked")]
     pub frozen: bool,
 
+    /// The path to the lockfile to use, relative to the workspace root.
+    ///
+    /// Defaults to `uv.lock`.
+    #[arg(long, env = "UV_LOCKFILE")]
+    pub lockfile: Option<PathBuf>,
+
     #[command(flatten)]
     pub build: BuildArgs,
 
@@ -2135,6 +2726,89 @@ pub struct TreeArgs {
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
     pub python: Option<String>,
+
+    /// The platform for which the tree should be displayed.
+    ///
+    /// Markers in the lockfile are evaluated against a synthetic environment for the given
+    /// platform, rather than against the current interpreter. Represented as a "target triple",
+    /// like `x86_64-unknown-linux-gnu` or `aarch64-apple-darwin`.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
+
+    /// The Python version for which the tree should be displayed (e.g., `3.11`).
+    ///
+    /// Markers in the lockfile are evaluated against the given version, rather than against the
+    /// version of the current interpreter. The version need not be installed.
+    #[arg(long)]
+    pub python_version: Option<PythonVersion>,
+
+    /// Annotate each package with its license.
+    ///
+    /// Licenses are read from the metadata of the packages installed in the project environment
+    /// (i.e., after `uv sync`), without accessing the network. The `License-Expression` field is
+    /// preferred, followed by the `License` field and the `License ::` classifiers. Classifiers
+    /// are self-reported by package authors and may be inaccurate. Packages for which no license
+    /// could be detected (including those that aren't installed) are reported as `unknown`.
+    #[arg(long)]
+    pub licenses: bool,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PublishArgs {
+    /// The URL of the registry to upload to.
+    ///
+    /// Defaults to PyPI (`https://upload.pypi.org/legacy/`), unless a different index is
+    /// configured via `--index-url` or `index-url`, in which case the distributions are uploaded
+    /// to that index, omitting any trailing `/simple` path segment (e.g., `https://example.com/pypi`
+    /// for an index at `https://example.com/pypi/simple`).
+    #[arg(long, env = "UV_PUBLISH_URL")]
+    pub repository: Option<Url>,
+
+    /// The username to use when uploading.
+    ///
+    /// If a password is provided without a username, the password is treated as an API token
+    /// (i.e., the username `__token__` is used).
+    ///
+    /// If neither is provided, uv uses the credentials configured for the registry, if any (e.g.,
+    /// via a `.netrc` file, the keyring, or the configured index URLs).
+    #[arg(long, short, env = "UV_PUBLISH_USERNAME")]
+    pub username: Option<String>,
+
+    /// The password (or API token) to use when uploading.
+    #[arg(long, env = "UV_PUBLISH_PASSWORD")]
+    pub password: Option<String>,
+
+    /// Skip distributions that already exist in the registry, rather than failing.
+    #[arg(long)]
+    pub skip_existing: bool,
+
+    /// Publish a specific package in the workspace.
+    #[arg(long)]
+    pub package: Option<PackageName>,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use to build the distributions.
+    ///
+    /// By default, uv uses the virtual environment in the current working directory or any parent
+    /// directory, falling back to searching for a Python executable in `PATH`. The `--python`
+    /// option allows you to specify a different interpreter.
+    ///
+    /// Supported formats:
+    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
+    ///   `python3.10` on Linux and macOS.
+    /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
+    /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
+    #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
+    pub python: Option<String>,
 }
 
 #[derive(Args)]
@@ -2161,6 +2835,10 @@ pub enum ToolCommand {
     List(ToolListArgs),
     /// Uninstall a tool.
     Uninstall(ToolUninstallArgs),
+    /// List tools running in the background.
+    Ps,
+    /// Stop a tool running in the background.
+    Stop(ToolStopArgs),
     /// Ensure that the tool executable directory is on `PATH`.
     #[command(alias = "ensurepath")]
     UpdateShell,
@@ -2175,12 +2853,12 @@ pub struct ToolRunArgs {
     ///
     /// By default, the package to install is assumed to match the command name.
     ///
-    /// The name of the command can include an exact version in the format `<package>@<version>`.
+    /// The name of the command can include an exact version in the format `<package>@<version>`,
+    /// or a version specifier in the format `<package>@<specifier>` (e.g., `ruff@>=0.4,<0.5`).
     ///
-    /// If more complex version specification is desired or if the command is provided by a different
-    /// package, use `--from`.
+    /// If the command is provided by a different package, use `--from`.
     #[command(subcommand)]
-    pub command: ExternalCommand,
+    pub command: Option<ExternalCommand>,
 
     /// Use the given package to provide the command.
     ///
@@ -2192,6 +2870,93 @@ pub struct ToolRunArgs {
     #[arg(long)]
     pub with: Vec<String>,
 
+    /// Run the command in the given directory.
+    ///
+    /// By default, the command is run in the current working directory.
+    #[arg(long)]
+    pub working_dir: Option<PathBuf>,
+
+    /// Run the command in the background, detached from the terminal.
+    ///
+    /// The command's output is written to log files, and the process can be managed with
+    /// `uv tool ps` and `uv tool stop`.
+    #[arg(long)]
+    pub detach: bool,
+
+    /// Terminate the command if it runs for longer than the given number of seconds.
+    ///
+    /// Once the timeout elapses, the command is sent `SIGTERM`, followed by `SIGKILL` if it
+    /// doesn't exit promptly, and uv exits with status code 124.
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        env = "UV_TOOL_RUN_TIMEOUT",
+        conflicts_with = "detach"
+    )]
+    pub timeout: Option<u64>,
+
+    /// Disable output buffering in the command, by setting `PYTHONUNBUFFERED=1` in its
+    /// environment.
+    ///
+    /// Python buffers its standard output when it isn't attached to a terminal (e.g., when piped
+    /// or run in CI), which can delay output until the buffer fills or the process exits. uv
+    /// itself never buffers the command's output: the standard streams are inherited directly.
+    ///
+    /// Unless already set, `PYTHONIOENCODING` is also set to `utf-8`.
+    #[arg(long, overrides_with("no_unbuffered"))]
+    pub unbuffered: bool,
+
+    #[arg(long, overrides_with("unbuffered"), hide = true)]
+    pub no_unbuffered: bool,
+
+    /// Set an environment variable for the command, as `KEY=VALUE`.
+    ///
+    /// May be provided multiple times. Takes precedence over any values read from `--env-file`.
+    #[arg(long, value_parser = parse_env_var)]
+    pub env: Vec<(String, String)>,
+
+    /// Load environment variables for the command from a dotenv-format file.
+    ///
+    /// Each line is expected to be a `KEY=VALUE` assignment. Blank lines and lines starting with
+    /// `#` are ignored.
+    #[arg(long, value_parser = parse_file_path)]
+    pub env_file: Option<PathBuf>,
+
+    /// Run the command in a clean environment, rather than inheriting uv's environment variables.
+    ///
+    /// Only the variables provided via `--env` and `--env-file` are set, along with the `PATH`
+    /// computed for the tool environment (and `PYTHONPATH`, with `--propagate-pythonpath`).
+    #[arg(long)]
+    pub no_inherit_env: bool,
+
+    /// Add the tool environment's `site-packages` to `PYTHONPATH` for the command.
+    ///
+    /// Since Python ignores `.pth` files in `PYTHONPATH` entries, the directories they reference
+    /// (e.g., the sources of editable installs) are added as well.
+    ///
+    /// By default, only `PATH` is modified: the tool's entry points already run with the tool
+    /// environment's interpreter, and exporting `PYTHONPATH` would leak the tool's packages into
+    /// any other Python processes that the tool spawns.
+    #[arg(long)]
+    pub propagate_pythonpath: bool,
+
+    /// List the executables provided by the `--from` package, rather than running a command.
+    ///
+    /// The tool environment is created if necessary, but nothing is run.
+    #[arg(long, requires = "from")]
+    pub list_entrypoints: bool,
+
+    /// If the command is provided by a dependency of the requested package, run it from that
+    /// dependency without prompting.
+    ///
+    /// By default, uv prompts for confirmation in interactive terminals, and otherwise warns and
+    /// runs the command from the requested package's environment.
+    ///
+    /// Similarly, reinstall a tool installed in editable mode whose project metadata has changed
+    /// without prompting.
+    #[arg(long)]
+    pub yes: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -2232,6 +2997,14 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub with: Vec<String>,
 
+    /// Install the tool in editable mode from a local directory.
+    ///
+    /// The directory must contain a `pyproject.toml` or `setup.py`. Changes to the tool's source
+    /// are reflected without reinstalling; if its `pyproject.toml` changes (e.g., to add a
+    /// dependency), `uv tool run` will warn that the tool should be reinstalled.
+    #[arg(long, short)]
+    pub editable: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -2247,6 +3020,21 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub force: bool,
 
+    /// Install into the tool environment in-place, rather than staging a copy to restore if the
+    /// installation fails.
+    ///
+    /// Useful on filesystems that don't support atomic renames.
+    #[arg(long)]
+    pub no_atomic: bool,
+
+    /// A Python script to run with the tool's interpreter after the tool environment is
+    /// populated.
+    ///
+    /// If the script exits with a non-zero status, the installation fails. The script is stored
+    /// in the tool receipt, and re-run when the tool is reinstalled or upgraded.
+    #[arg(long, value_name = "PATH")]
+    pub post_install_script: Option<PathBuf>,
+
     /// The Python interpreter to use to build the tool environment.
     ///
     /// By default, uv will search for a Python executable in the `PATH`. uv ignores virtual
@@ -2293,6 +3081,15 @@ pub struct ToolUninstallArgs {
     pub all: bool,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ToolStopArgs {
+    /// The process ID or executable name of the background tool to stop.
+    ///
+    /// If an executable name is provided, all of its background runs are stopped.
+    pub target: String,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PythonNamespace {
@@ -2411,6 +3208,22 @@ pub struct IndexArgs {
     #[arg(long, env = "UV_EXTRA_INDEX_URL", value_delimiter = ' ', value_parser = parse_index_url)]
     pub extra_index_url: Option<Vec<Maybe<IndexUrl>>>,
 
+    /// The URL of the default package index, for this invocation only.
+    ///
+    /// Takes precedence over `--index-url` and any index configured in `pyproject.toml` or
+    /// `uv.toml`.
+    #[arg(long, value_parser = parse_index_url)]
+    pub default_index: Option<Maybe<IndexUrl>>,
+
+    /// Extra URLs of package indexes to use for this invocation only; may be provided more than
+    /// once.
+    ///
+    /// All indexes provided via this flag take priority over those specified via
+    /// `--extra-index-url` and the default index. When multiple `--index` flags are provided,
+    /// earlier values take priority.
+    #[arg(long, value_parser = parse_index_url)]
+    pub index: Option<Vec<Maybe<IndexUrl>>>,
+
     /// Locations to search for candidate distributions, in addition to those found in the registry
     /// indexes.
     ///
@@ -2451,12 +3264,45 @@ pub struct RefreshArgs {
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct BuildArgs {
+    /// Disable isolation when building source distributions.
+    ///
+    /// Assumes that build dependencies specified by PEP 518 are already installed.
+    #[arg(
+        long,
+        env = "UV_NO_BUILD_ISOLATION",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("build_isolation")
+    )]
+    pub no_build_isolation: bool,
+
+    #[arg(long, overrides_with("no_build_isolation"), hide = true)]
+    pub build_isolation: bool,
+
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the packages' build dependencies specified by PEP 518 are already installed.
+    #[arg(long)]
+    pub no_build_isolation_package: Vec<PackageName>,
+
+    /// Use legacy `setuptools` behavior when building source distributions without a
+    /// `pyproject.toml`.
+    #[arg(long, overrides_with("no_legacy_setup_py"))]
+    pub legacy_setup_py: bool,
+
+    #[arg(long, overrides_with("legacy_setup_py"), hide = true)]
+    pub no_legacy_setup_py: bool,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
     /// already-built source distributions will be reused, but operations that require building
     /// distributions will exit with an error.
-    #[arg(long, overrides_with("build"))]
+    #[arg(
+        long,
+        env = "UV_NO_BUILD",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("build")
+    )]
     pub no_build: bool,
 
     #[arg(long, overrides_with("no_build"), hide = true)]
@@ -2470,7 +3316,12 @@ pub struct BuildArgs {
     ///
     /// The given packages will be built and installed from source. The resolver will still use
     /// pre-built wheels to extract package metadata, if available.
-    #[arg(long, overrides_with("binary"))]
+    #[arg(
+        long,
+        env = "UV_NO_BINARY",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("binary")
+    )]
     pub no_binary: bool,
 
     #[arg(long, overrides_with("no_binary"), hide = true)]
@@ -2545,7 +3396,13 @@ pub struct InstallerArgs {
     /// When enabled, uv will process the entire site-packages directory (including packages that
     /// are not being modified by the current operation) for consistency. Like pip, it will also
     /// ignore errors.
-    #[arg(long, alias = "compile", overrides_with("no_compile_bytecode"))]
+    #[arg(
+        long,
+        alias = "compile",
+        env = "UV_COMPILE_BYTECODE",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_compile_bytecode")
+    )]
     pub compile_bytecode: bool,
 
     #[arg(
@@ -2727,7 +3584,13 @@ pub struct ResolverInstallerArgs {
     /// When enabled, uv will process the entire site-packages directory (including packages that
     /// are not being modified by the current operation) for consistency. Like pip, it will also
     /// ignore errors.
-    #[arg(long, alias = "compile", overrides_with("no_compile_bytecode"))]
+    #[arg(
+        long,
+        alias = "compile",
+        env = "UV_COMPILE_BYTECODE",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_compile_bytecode")
+    )]
     pub compile_bytecode: bool,
 
     #[arg(
@@ -2741,9 +3604,12 @@ pub struct ResolverInstallerArgs {
 
 #[derive(Args)]
 pub struct DisplayTreeArgs {
-    /// Maximum display depth of the dependency tree
-    #[arg(long, short, default_value_t = 255)]
-    pub depth: u8,
+    /// Maximum display depth of the dependency tree.
+    ///
+    /// Dependencies beyond the given depth are elided, as indicated by `...`. By default, the
+    /// full dependency tree is displayed.
+    #[arg(long, short)]
+    pub depth: Option<usize>,
 
     /// Prune the given package from the display of the dependency tree.
     #[arg(long)]
@@ -2758,6 +3624,7 @@ pub struct DisplayTreeArgs {
     /// further occurrences will not re-display its dependencies,
     /// and will include a (*) to indicate it has already been shown.
     /// This flag will cause those duplicates to be repeated.
+    /// Dependency cycles are never expanded, and are marked with (cycle) instead.
     #[arg(long)]
     pub no_dedupe: bool,
 
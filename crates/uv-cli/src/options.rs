@@ -1,3 +1,4 @@
+use distribution_types::IndexUrl;
 use uv_cache::Refresh;
 use uv_configuration::ConfigSettings;
 use uv_resolver::PreReleaseMode;
@@ -147,18 +148,15 @@ impl From<IndexArgs> for PipOptions {
         let IndexArgs {
             index_url,
             extra_index_url,
+            default_index,
+            index,
             no_index,
             find_links,
         } = args;
 
         Self {
-            index_url: index_url.and_then(Maybe::into_option),
-            extra_index_url: extra_index_url.map(|extra_index_urls| {
-                extra_index_urls
-                    .into_iter()
-                    .filter_map(Maybe::into_option)
-                    .collect()
-            }),
+            index_url: default_index.or(index_url).and_then(Maybe::into_option),
+            extra_index_url: extra_index_urls(index, extra_index_url),
             no_index: if no_index { Some(true) } else { None },
             find_links,
             ..PipOptions::default()
@@ -183,6 +181,11 @@ pub fn installer_options(installer_args: InstallerArgs, build_args: BuildArgs) -
     } = installer_args;
 
     let BuildArgs {
+        no_build_isolation,
+        build_isolation,
+        no_build_isolation_package,
+        legacy_setup_py,
+        no_legacy_setup_py,
         no_build,
         build,
         no_build_package,
@@ -192,13 +195,11 @@ pub fn installer_options(installer_args: InstallerArgs, build_args: BuildArgs) -
     } = build_args;
 
     InstallerOptions {
-        index_url: index_args.index_url.and_then(Maybe::into_option),
-        extra_index_url: index_args.extra_index_url.map(|extra_index_urls| {
-            extra_index_urls
-                .into_iter()
-                .filter_map(Maybe::into_option)
-                .collect()
-        }),
+        index_url: index_args
+            .default_index
+            .or(index_args.index_url)
+            .and_then(Maybe::into_option),
+        extra_index_url: extra_index_urls(index_args.index, index_args.extra_index_url),
         no_index: if index_args.no_index {
             Some(true)
         } else {
@@ -211,6 +212,9 @@ pub fn installer_options(installer_args: InstallerArgs, build_args: BuildArgs) -
         keyring_provider,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+        no_build_isolation: flag(no_build_isolation, build_isolation),
+        no_build_isolation_package: Some(no_build_isolation_package),
+        legacy_setup_py: flag(legacy_setup_py, no_legacy_setup_py),
         exclude_newer,
         link_mode,
         compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
@@ -239,6 +243,11 @@ pub fn resolver_options(resolver_args: ResolverArgs, build_args: BuildArgs) -> R
     } = resolver_args;
 
     let BuildArgs {
+        no_build_isolation,
+        build_isolation,
+        no_build_isolation_package,
+        legacy_setup_py,
+        no_legacy_setup_py,
         no_build,
         build,
         no_build_package,
@@ -248,13 +257,11 @@ pub fn resolver_options(resolver_args: ResolverArgs, build_args: BuildArgs) -> R
     } = build_args;
 
     ResolverOptions {
-        index_url: index_args.index_url.and_then(Maybe::into_option),
-        extra_index_url: index_args.extra_index_url.map(|extra_index_urls| {
-            extra_index_urls
-                .into_iter()
-                .filter_map(Maybe::into_option)
-                .collect()
-        }),
+        index_url: index_args
+            .default_index
+            .or(index_args.index_url)
+            .and_then(Maybe::into_option),
+        extra_index_url: extra_index_urls(index_args.index, index_args.extra_index_url),
         no_index: if index_args.no_index {
             Some(true)
         } else {
@@ -273,7 +280,11 @@ pub fn resolver_options(resolver_args: ResolverArgs, build_args: BuildArgs) -> R
         },
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+        no_build_isolation: flag(no_build_isolation, build_isolation),
+        no_build_isolation_package: Some(no_build_isolation_package),
+        legacy_setup_py: flag(legacy_setup_py, no_legacy_setup_py),
         exclude_newer,
+        python_platforms: None,
         link_mode,
         no_build: flag(no_build, build),
         no_build_package: Some(no_build_package),
@@ -308,6 +319,11 @@ pub fn resolver_installer_options(
     } = resolver_installer_args;
 
     let BuildArgs {
+        no_build_isolation,
+        build_isolation,
+        no_build_isolation_package,
+        legacy_setup_py,
+        no_legacy_setup_py,
         no_build,
         build,
         no_build_package,
@@ -317,13 +333,11 @@ pub fn resolver_installer_options(
     } = build_args;
 
     ResolverInstallerOptions {
-        index_url: index_args.index_url.and_then(Maybe::into_option),
-        extra_index_url: index_args.extra_index_url.map(|extra_index_urls| {
-            extra_index_urls
-                .into_iter()
-                .filter_map(Maybe::into_option)
-                .collect()
-        }),
+        index_url: index_args
+            .default_index
+            .or(index_args.index_url)
+            .and_then(Maybe::into_option),
+        extra_index_url: extra_index_urls(index_args.index, index_args.extra_index_url),
         no_index: if index_args.no_index {
             Some(true)
         } else {
@@ -344,7 +358,12 @@ pub fn resolver_installer_options(
         },
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+        no_build_isolation: flag(no_build_isolation, build_isolation),
+        no_build_isolation_package: Some(no_build_isolation_package),
+        legacy_setup_py: flag(legacy_setup_py, no_legacy_setup_py),
         exclude_newer,
+        exclude_newer_index: None,
+        python_platforms: None,
         link_mode,
         compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
         no_build: flag(no_build, build),
@@ -353,3 +372,21 @@ pub fn resolver_installer_options(
         no_binary_package: Some(no_binary_package),
     }
 }
+
+/// Merge the `--index` and `--extra-index-url` arguments, giving priority to the former.
+fn extra_index_urls(
+    index: Option<Vec<Maybe<IndexUrl>>>,
+    extra_index_url: Option<Vec<Maybe<IndexUrl>>>,
+) -> Option<Vec<IndexUrl>> {
+    if index.is_none() && extra_index_url.is_none() {
+        return None;
+    }
+    Some(
+        index
+            .into_iter()
+            .flatten()
+            .chain(extra_index_url.into_iter().flatten())
+            .filter_map(Maybe::into_option)
+            .collect(),
+    )
+}
@@ -7,6 +7,7 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info_span, instrument, trace, warn, Instrument};
 
+use uv_cache::stats::CacheEvent;
 use uv_cache::{CacheEntry, Freshness};
 use uv_fs::write_atomic;
 
@@ -240,6 +241,12 @@ impl CachedClient {
                 cache_policy,
             }
         };
+        uv_cache::stats::record(match cached_response {
+            CachedResponse::FreshCache(_) | CachedResponse::NotModified { .. } => {
+                CacheEvent::MetadataHit
+            }
+            CachedResponse::ModifiedOrNew { .. } => CacheEvent::MetadataMiss,
+        });
         match cached_response {
             CachedResponse::FreshCache(cached) => match Payload::from_aligned_bytes(cached.data) {
                 Ok(payload) => Ok(payload),
@@ -39,7 +39,7 @@ impl Error {
     }
 
     /// Returns `true` if this error corresponds to an offline error.
-    pub(crate) fn is_offline(&self) -> bool {
+    pub fn is_offline(&self) -> bool {
         matches!(&*self.kind, ErrorKind::Offline(_))
     }
 
@@ -0,0 +1,18 @@
+/// The version bounds to write to the `pyproject.toml` when adding a requirement without a
+/// version specifier.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AddBounds {
+    /// Omit the version specifier, such that the lockfile is the only pin (e.g., `requests`).
+    #[default]
+    None,
+    /// Require at least the locked version (e.g., `requests>=2.32.3`).
+    Lower,
+    /// Require exactly the locked version (e.g., `requests==2.32.3`).
+    Exact,
+    /// Require at least the locked version, up to the next breaking version, incrementing the
+    /// first non-zero component (e.g., `requests>=2.32.3,<3` or `anyio>=0.4.1,<0.5`).
+    Caret,
+}
@@ -21,6 +21,8 @@ pub enum BuildKind {
     Wheel,
     /// A PEP 660 editable installation wheel build
     Editable,
+    /// A PEP 517 source distribution build
+    Sdist,
 }
 
 impl Display for BuildKind {
@@ -28,6 +30,7 @@ impl Display for BuildKind {
         match self {
             Self::Wheel => f.write_str("wheel"),
             Self::Editable => f.write_str("editable"),
+            Self::Sdist => f.write_str("sdist"),
         }
     }
 }
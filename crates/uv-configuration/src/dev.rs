@@ -0,0 +1,72 @@
+use uv_normalize::GroupName;
+
+/// The dependency groups to include when installing a project.
+#[derive(Debug, Default, Clone)]
+pub struct DevSpecification {
+    /// The groups to include.
+    include: Vec<GroupName>,
+    /// The groups to exclude, taking precedence over any included groups.
+    exclude: Vec<GroupName>,
+    /// Whether to install only the included groups, omitting the project's own dependencies.
+    only: bool,
+}
+
+impl DevSpecification {
+    /// Determine the dependency groups to use based on the command-line arguments.
+    ///
+    /// The `default` group is included unless `dev` is `false` or `--only-group` is provided.
+    pub fn from_args(
+        dev: bool,
+        group: Vec<GroupName>,
+        only_group: Vec<GroupName>,
+        exclude_group: Vec<GroupName>,
+        default: &GroupName,
+    ) -> Self {
+        if only_group.is_empty() {
+            let include = dev
+                .then(|| default.clone())
+                .into_iter()
+                .chain(group)
+                .collect();
+            Self {
+                include,
+                exclude: exclude_group,
+                only: false,
+            }
+        } else {
+            Self {
+                include: only_group,
+                exclude: exclude_group,
+                only: true,
+            }
+        }
+    }
+
+    /// Include the given groups alongside the project's dependencies.
+    pub fn include(groups: Vec<GroupName>) -> Self {
+        Self {
+            include: groups,
+            exclude: Vec::new(),
+            only: false,
+        }
+    }
+
+    /// Returns an iterator over the groups to install.
+    pub fn groups(&self) -> impl Iterator<Item = &GroupName> {
+        self.include
+            .iter()
+            .filter(|group| !self.exclude.contains(group))
+    }
+
+    /// Returns an iterator over all group names referenced by the specification, whether
+    /// included or excluded.
+    pub fn names(&self) -> impl Iterator<Item = &GroupName> {
+        self.include.iter().chain(self.exclude.iter())
+    }
+
+    /// Returns `true` if only the included groups should be installed, omitting the project's own
+    /// dependencies.
+    pub fn only(&self) -> bool {
+        self.only
+    }
+}
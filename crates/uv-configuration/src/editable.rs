@@ -0,0 +1,20 @@
+/// Whether to install editable sources (e.g., workspace members) as editables.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EditableMode {
+    /// Install editable sources as editables.
+    #[default]
+    Editable,
+    /// Build and install editable sources as regular wheels.
+    NonEditable,
+}
+
+impl EditableMode {
+    /// Determine the editable mode based on the command-line arguments.
+    pub fn from_args(no_editable: bool) -> Self {
+        if no_editable {
+            Self::NonEditable
+        } else {
+            Self::Editable
+        }
+    }
+}
@@ -0,0 +1,14 @@
+/// The format to use when exporting a `uv.lock` file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum ExportFormat {
+    /// Export in `requirements.txt` format.
+    #[default]
+    #[cfg_attr(feature = "clap", clap(alias = "requirements.txt"))]
+    RequirementsTxt,
+    /// Export as a `Dockerfile` that installs the project's dependencies and workspace members in
+    /// separate layers.
+    Dockerfile,
+    /// Export a CSV inventory of each package's license, as read from the installed metadata.
+    LicenseCsv,
+}
@@ -0,0 +1,61 @@
+use std::collections::BTreeSet;
+
+use tracing::debug;
+
+use uv_normalize::PackageName;
+
+/// Packages to omit when installing from a lockfile.
+#[derive(Debug, Default, Clone)]
+pub struct InstallOptions {
+    /// Omit the project itself.
+    no_install_project: bool,
+    /// Omit all workspace members, including the project itself.
+    no_install_workspace: bool,
+    /// Omit the given packages.
+    no_install_package: Vec<PackageName>,
+}
+
+impl InstallOptions {
+    pub fn new(
+        no_install_project: bool,
+        no_install_workspace: bool,
+        no_install_package: Vec<PackageName>,
+    ) -> Self {
+        Self {
+            no_install_project,
+            no_install_workspace,
+            no_install_package,
+        }
+    }
+
+    /// Returns `true` if the given package should be installed.
+    ///
+    /// The `project_name` is the name of the current project (if any), and `members` is the set
+    /// of workspace members.
+    pub fn include_package(
+        &self,
+        package: &PackageName,
+        project_name: Option<&PackageName>,
+        members: &BTreeSet<PackageName>,
+    ) -> bool {
+        // If `--no-install-project` is set, remove the project itself.
+        if self.no_install_project && project_name == Some(package) {
+            debug!("Omitting `{package}` from resolution due to `--no-install-project`");
+            return false;
+        }
+
+        // If `--no-install-workspace` is set, remove the project and any workspace members.
+        if self.no_install_workspace && members.contains(package) {
+            debug!("Omitting `{package}` from resolution due to `--no-install-workspace`");
+            return false;
+        }
+
+        // If `--no-install-package` is provided, remove the requested packages.
+        if self.no_install_package.contains(package) {
+            debug!("Omitting `{package}` from resolution due to `--no-install-package`");
+            return false;
+        }
+
+        true
+    }
+}
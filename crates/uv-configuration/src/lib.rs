@@ -1,25 +1,37 @@
 pub use authentication::*;
+pub use bounds::*;
 pub use build_options::*;
 pub use concurrency::*;
 pub use config_settings::*;
 pub use constraints::*;
+pub use dev::*;
+pub use editable::*;
+pub use export_format::*;
 pub use extras::*;
 pub use hash::*;
+pub use install_options::*;
 pub use name_specifiers::*;
 pub use overrides::*;
 pub use package_options::*;
 pub use preview::*;
+pub use strict::*;
 pub use target_triple::*;
 
 mod authentication;
+mod bounds;
 mod build_options;
 mod concurrency;
 mod config_settings;
 mod constraints;
+mod dev;
+mod editable;
+mod export_format;
 mod extras;
 mod hash;
+mod install_options;
 mod name_specifiers;
 mod overrides;
 mod package_options;
 mod preview;
+mod strict;
 mod target_triple;
@@ -0,0 +1,30 @@
+/// A category of user-facing warnings that can be promoted to an error via `--strict`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum StrictCategory {
+    /// A yanked version was selected during resolution.
+    Yanked,
+    /// An existing virtual environment was ignored, as its Python interpreter no longer exists.
+    MissingInterpreter,
+    /// A direct URL dependency was locked without a hash.
+    UnhashedUrl,
+}
+
+impl StrictCategory {
+    /// Returns all [`StrictCategory`] variants.
+    pub fn all() -> impl Iterator<Item = Self> {
+        [Self::Yanked, Self::MissingInterpreter, Self::UnhashedUrl].into_iter()
+    }
+}
+
+impl std::fmt::Display for StrictCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Yanked => write!(f, "yanked"),
+            Self::MissingInterpreter => write!(f, "missing-interpreter"),
+            Self::UnhashedUrl => write!(f, "unhashed-url"),
+        }
+    }
+}
@@ -1,13 +1,15 @@
 use tracing::debug;
 
-use pep508_rs::MarkerEnvironment;
+use pep508_rs::{
+    MarkerEnvironment, MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString,
+};
 use platform_tags::{Arch, Os, Platform};
 
 /// The supported target triples. Each triple consists of an architecture, vendor, and operating
 /// system.
 ///
 /// See: <https://doc.rust-lang.org/nightly/rustc/platform-support.html>
-#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, serde::Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -23,11 +25,13 @@ pub enum TargetTriple {
 
     /// An x86 Windows target.
     #[cfg_attr(feature = "clap", value(name = "x86_64-pc-windows-msvc"))]
+    #[serde(rename = "x86_64-pc-windows-msvc")]
     #[cfg_attr(feature = "schemars", schemars(rename = "x86_64-pc-windows-msvc"))]
     X8664PcWindowsMsvc,
 
     /// An x86 Linux target. Equivalent to `x86_64-manylinux_2_17`.
     #[cfg_attr(feature = "clap", value(name = "x86_64-unknown-linux-gnu"))]
+    #[serde(rename = "x86_64-unknown-linux-gnu")]
     #[cfg_attr(feature = "schemars", schemars(rename = "x86_64-unknown-linux-gnu"))]
     X8664UnknownLinuxGnu,
 
@@ -36,6 +40,7 @@ pub enum TargetTriple {
     /// By default, assumes the least-recent, non-EOL macOS version (12.0), but respects
     /// the `MACOSX_DEPLOYMENT_TARGET` environment variable if set.
     #[cfg_attr(feature = "clap", value(name = "aarch64-apple-darwin"))]
+    #[serde(rename = "aarch64-apple-darwin")]
     #[cfg_attr(feature = "schemars", schemars(rename = "aarch64-apple-darwin"))]
     Aarch64AppleDarwin,
 
@@ -44,56 +49,88 @@ pub enum TargetTriple {
     /// By default, assumes the least-recent, non-EOL macOS version (12.0), but respects
     /// the `MACOSX_DEPLOYMENT_TARGET` environment variable if set.
     #[cfg_attr(feature = "clap", value(name = "x86_64-apple-darwin"))]
+    #[serde(rename = "x86_64-apple-darwin")]
     #[cfg_attr(feature = "schemars", schemars(rename = "x86_64-apple-darwin"))]
     X8664AppleDarwin,
 
     /// An ARM64 Linux target. Equivalent to `aarch64-manylinux_2_17`.
     #[cfg_attr(feature = "clap", value(name = "aarch64-unknown-linux-gnu"))]
+    #[serde(rename = "aarch64-unknown-linux-gnu")]
     #[cfg_attr(feature = "schemars", schemars(rename = "aarch64-unknown-linux-gnu"))]
     Aarch64UnknownLinuxGnu,
 
     /// An ARM64 Linux target.
     #[cfg_attr(feature = "clap", value(name = "aarch64-unknown-linux-musl"))]
+    #[serde(rename = "aarch64-unknown-linux-musl")]
     #[cfg_attr(feature = "schemars", schemars(rename = "aarch64-unknown-linux-musl"))]
     Aarch64UnknownLinuxMusl,
 
     /// An `x86_64` Linux target.
     #[cfg_attr(feature = "clap", value(name = "x86_64-unknown-linux-musl"))]
+    #[serde(rename = "x86_64-unknown-linux-musl")]
     #[cfg_attr(feature = "schemars", schemars(rename = "x86_64-unknown-linux-musl"))]
     X8664UnknownLinuxMusl,
 
     /// An `x86_64` target for the `manylinux_2_17` platform.
     #[cfg_attr(feature = "clap", value(name = "x86_64-manylinux_2_17"))]
+    #[serde(rename = "x86_64-manylinux_2_17")]
     #[cfg_attr(feature = "schemars", schemars(rename = "x86_64-manylinux_2_17"))]
     X8664Manylinux217,
 
     /// An `x86_64` target for the `manylinux_2_28` platform.
     #[cfg_attr(feature = "clap", value(name = "x86_64-manylinux_2_28"))]
+    #[serde(rename = "x86_64-manylinux_2_28")]
     #[cfg_attr(feature = "schemars", schemars(rename = "x86_64-manylinux_2_28"))]
     X8664Manylinux228,
 
     /// An `x86_64` target for the `manylinux_2_31` platform.
     #[cfg_attr(feature = "clap", value(name = "x86_64-manylinux_2_31"))]
+    #[serde(rename = "x86_64-manylinux_2_31")]
     #[cfg_attr(feature = "schemars", schemars(rename = "x86_64-manylinux_2_31"))]
     X8664Manylinux231,
 
     /// An ARM64 target for the `manylinux_2_17` platform.
     #[cfg_attr(feature = "clap", value(name = "aarch64-manylinux_2_17"))]
+    #[serde(rename = "aarch64-manylinux_2_17")]
     #[cfg_attr(feature = "schemars", schemars(rename = "aarch64-manylinux_2_17"))]
     Aarch64Manylinux217,
 
     /// An ARM64 target for the `manylinux_2_28` platform.
     #[cfg_attr(feature = "clap", value(name = "aarch64-manylinux_2_28"))]
+    #[serde(rename = "aarch64-manylinux_2_28")]
     #[cfg_attr(feature = "schemars", schemars(rename = "aarch64-manylinux_2_28"))]
     Aarch64Manylinux228,
 
     /// An ARM64 target for the `manylinux_2_31` platform.
     #[cfg_attr(feature = "clap", value(name = "aarch64-manylinux_2_31"))]
+    #[serde(rename = "aarch64-manylinux_2_31")]
     #[cfg_attr(feature = "schemars", schemars(rename = "aarch64-manylinux_2_31"))]
     Aarch64Manylinux231,
 }
 
 impl TargetTriple {
+    /// Return the name of the target, as accepted by `--python-platform`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Windows => "windows",
+            Self::Linux => "linux",
+            Self::Macos => "macos",
+            Self::X8664PcWindowsMsvc => "x86_64-pc-windows-msvc",
+            Self::X8664UnknownLinuxGnu => "x86_64-unknown-linux-gnu",
+            Self::Aarch64AppleDarwin => "aarch64-apple-darwin",
+            Self::X8664AppleDarwin => "x86_64-apple-darwin",
+            Self::Aarch64UnknownLinuxGnu => "aarch64-unknown-linux-gnu",
+            Self::Aarch64UnknownLinuxMusl => "aarch64-unknown-linux-musl",
+            Self::X8664UnknownLinuxMusl => "x86_64-unknown-linux-musl",
+            Self::X8664Manylinux217 => "x86_64-manylinux_2_17",
+            Self::X8664Manylinux228 => "x86_64-manylinux_2_28",
+            Self::X8664Manylinux231 => "x86_64-manylinux_2_31",
+            Self::Aarch64Manylinux217 => "aarch64-manylinux_2_17",
+            Self::Aarch64Manylinux228 => "aarch64-manylinux_2_28",
+            Self::Aarch64Manylinux231 => "aarch64-manylinux_2_31",
+        }
+    }
+
     /// Return the [`Platform`] for the target.
     pub fn platform(self) -> Platform {
         match self {
@@ -305,6 +342,29 @@ impl TargetTriple {
             .with_platform_release(self.platform_release())
             .with_platform_version(self.platform_version())
     }
+
+    /// Return a [`MarkerTree`] that matches environments on the target platform, based on the
+    /// target's `sys_platform` and `platform_machine` markers.
+    pub fn marker_tree(self) -> MarkerTree {
+        MarkerTree::And(vec![
+            MarkerTree::Expression(MarkerExpression::String {
+                key: MarkerValueString::SysPlatform,
+                operator: MarkerOperator::Equal,
+                value: self.sys_platform().to_string(),
+            }),
+            MarkerTree::Expression(MarkerExpression::String {
+                key: MarkerValueString::PlatformMachine,
+                operator: MarkerOperator::Equal,
+                value: self.platform_machine().to_string(),
+            }),
+        ])
+    }
+}
+
+impl std::fmt::Display for TargetTriple {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Return the macOS deployment target as parsed from the environment.
@@ -101,6 +101,7 @@ pub(crate) async fn build(args: BuildArgs) -> Result<PathBuf> {
         build_dispatch.interpreter(),
         &build_dispatch,
         SourceBuildContext::default(),
+        None,
         args.sdist.display().to_string(),
         setup_py,
         config_settings.clone(),
@@ -328,6 +328,7 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             self.interpreter,
             self,
             self.source_build_context.clone(),
+            dist.map(distribution_types::Name::name),
             version_id.to_string(),
             self.setup_py,
             self.config_settings.clone(),
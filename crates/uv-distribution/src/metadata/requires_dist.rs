@@ -13,8 +13,8 @@ use crate::Metadata;
 
 /// The name of the global `dev-dependencies` group.
 ///
-/// Internally, we model dependency groups as a generic concept; but externally, we only expose the
-/// `dev-dependencies` group.
+/// Internally, we model dependency groups as a generic concept; `tool.uv.dev-dependencies` are
+/// exposed as the `dev` group, alongside any groups declared under `dependency-groups`.
 pub static DEV_DEPENDENCIES: Lazy<GroupName> =
     Lazy::new(|| GroupName::new("dev".to_string()).unwrap());
 
@@ -66,7 +66,8 @@ impl RequiresDist {
         project_workspace: &ProjectWorkspace,
         preview_mode: PreviewMode,
     ) -> Result<Self, MetadataError> {
-        // Collect any `tool.uv.sources` and `tool.uv.dev_dependencies` from `pyproject.toml`.
+        // Collect any `tool.uv.sources`, `tool.uv.dev_dependencies`, and `dependency-groups` from
+        // `pyproject.toml`.
         let empty = BTreeMap::default();
         let sources = project_workspace
             .current_project()
@@ -78,34 +79,41 @@ impl RequiresDist {
             .unwrap_or(&empty);
 
         let dev_dependencies = {
-            let dev_dependencies = project_workspace
-                .current_project()
-                .pyproject_toml()
+            let pyproject_toml = project_workspace.current_project().pyproject_toml();
+
+            // Combine `tool.uv.dev-dependencies` with any PEP 735 `dependency-groups`, treating the
+            // former as the `dev` group.
+            let dev_dependencies = pyproject_toml
                 .tool
                 .as_ref()
                 .and_then(|tool| tool.uv.as_ref())
                 .and_then(|uv| uv.dev_dependencies.as_ref())
                 .into_iter()
                 .flatten()
-                .cloned()
-                .map(|requirement| {
-                    let requirement_name = requirement.name.clone();
-                    lower_requirement(
-                        requirement,
-                        &metadata.name,
-                        project_workspace.project_root(),
-                        sources,
-                        project_workspace.workspace(),
-                        preview_mode,
-                    )
-                    .map_err(|err| MetadataError::LoweringError(requirement_name.clone(), err))
-                })
-                .collect::<Result<Vec<_>, _>>()?;
-            if dev_dependencies.is_empty() {
-                BTreeMap::default()
-            } else {
-                BTreeMap::from([(DEV_DEPENDENCIES.clone(), dev_dependencies)])
+                .map(|requirement| (&*DEV_DEPENDENCIES, requirement));
+            let dependency_groups = pyproject_toml.dependency_groups.iter().flatten().flat_map(
+                |(group, requirements)| {
+                    requirements
+                        .iter()
+                        .map(move |requirement| (group, requirement))
+                },
+            );
+
+            let mut groups: BTreeMap<GroupName, Vec<pypi_types::Requirement>> = BTreeMap::new();
+            for (group, requirement) in dev_dependencies.chain(dependency_groups) {
+                let requirement_name = requirement.name.clone();
+                let requirement = lower_requirement(
+                    requirement.clone(),
+                    &metadata.name,
+                    project_workspace.project_root(),
+                    sources,
+                    project_workspace.workspace(),
+                    preview_mode,
+                )
+                .map_err(|err| MetadataError::LoweringError(requirement_name, err))?;
+                groups.entry(group.clone()).or_default().push(requirement);
             }
+            groups
         };
 
         let requires_dist = metadata
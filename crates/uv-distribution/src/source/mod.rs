@@ -21,6 +21,7 @@ use distribution_types::{
 use install_wheel_rs::metadata::read_archive_metadata;
 use platform_tags::Tags;
 use pypi_types::{HashDigest, Metadata23, ParsedArchiveUrl};
+use uv_cache::stats::CacheEvent;
 use uv_cache::{
     ArchiveTimestamp, CacheBucket, CacheEntry, CacheShard, CachedByTimestamp, Freshness, Timestamp,
     WheelCache,
@@ -467,6 +468,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // If the cache contains a compatible wheel, return it.
         if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
+            uv_cache::stats::record(CacheEvent::BuiltWheelHit);
             return Ok(built_wheel.with_hashes(revision.into_hashes()));
         }
 
@@ -697,6 +699,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // If the cache contains a compatible wheel, return it.
         if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
+            uv_cache::stats::record(CacheEvent::BuiltWheelHit);
             return Ok(built_wheel);
         }
 
@@ -911,6 +914,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // If the cache contains a compatible wheel, return it.
         if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
+            uv_cache::stats::record(CacheEvent::BuiltWheelHit);
             return Ok(built_wheel);
         }
 
@@ -1152,6 +1156,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // If the cache contains a compatible wheel, return it.
         if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard) {
+            uv_cache::stats::record(CacheEvent::BuiltWheelHit);
             return Ok(built_wheel);
         }
 
@@ -16,6 +16,8 @@ pub enum Error {
     NonSingularArchive(Vec<OsString>),
     #[error("The top-level of the archive must only contain a list directory, but it's empty")]
     EmptyArchive,
+    #[error("Archive member `{member}` can't be extracted, as `{name}` is a reserved filename on Windows")]
+    ReservedFilename { member: String, name: String },
 }
 
 impl Error {
@@ -7,3 +7,21 @@ pub mod stream;
 mod sync;
 mod tar;
 mod vendor;
+
+/// Validate that the given archive member can be extracted on the current platform.
+///
+/// On Windows, files can't use reserved device names (e.g., `aux.py`), in any path component.
+fn validate_member(member: &str) -> Result<(), Error> {
+    if cfg!(windows) {
+        if let Some(name) = member
+            .split(['/', '\\'])
+            .find(|component| uv_fs::is_reserved_filename(component))
+        {
+            return Err(Error::ReservedFilename {
+                member: member.to_string(),
+                name: name.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
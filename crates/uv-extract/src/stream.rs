@@ -24,9 +24,11 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
     let mut directories = FxHashSet::default();
 
     while let Some(mut entry) = zip.next_with_entry().await? {
-        // Construct the (expected) path to the file on-disk.
+        // Construct the (expected) path to the file on-disk. Use an extended-length path, as the
+        // contents of some wheels exceed the `MAX_PATH` limit on Windows.
         let path = entry.reader().entry().filename().as_str()?;
-        let path = target.join(path);
+        crate::validate_member(path)?;
+        let path = uv_fs::verbatim_path(&target.join(path)).into_owned();
         let is_dir = entry.reader().entry().dir()?;
 
         // Either create the directory or write the file to disk.
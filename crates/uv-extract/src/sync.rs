@@ -27,8 +27,11 @@ pub fn unzip<R: Send + std::io::Read + std::io::Seek + HasLength>(
                 return Ok(());
             };
 
-            // Create necessary parent directories.
-            let path = target.join(enclosed_name);
+            crate::validate_member(file.name())?;
+
+            // Create necessary parent directories. Use an extended-length path, as the contents
+            // of some wheels exceed the `MAX_PATH` limit on Windows.
+            let path = uv_fs::verbatim_path(&target.join(enclosed_name)).into_owned();
             if file.is_dir() {
                 let mut directories = directories.lock().unwrap();
                 if directories.insert(path.clone()) {
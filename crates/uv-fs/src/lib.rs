@@ -185,6 +185,30 @@ pub async fn rename_with_retry(
     }
 }
 
+/// Recursively copy a directory and its contents.
+///
+/// Symlinks are copied as symlinks on Unix, and followed elsewhere.
+pub fn copy_dir_all(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> std::io::Result<()> {
+    let (src, dst) = (src.as_ref(), dst.as_ref());
+    fs_err::create_dir_all(dst)?;
+    for entry in fs_err::read_dir(src)? {
+        let entry = entry?;
+        let target = dst.join(entry.file_name());
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            copy_dir_all(entry.path(), target)?;
+        } else if file_type.is_symlink() {
+            #[cfg(unix)]
+            std::os::unix::fs::symlink(fs_err::read_link(entry.path())?, target)?;
+            #[cfg(not(unix))]
+            fs_err::copy(entry.path(), target)?;
+        } else {
+            fs_err::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
 /// Iterate over the subdirectories of a directory.
 ///
 /// If the directory does not exist, returns an empty iterator.
@@ -254,6 +278,28 @@ pub fn files(path: impl AsRef<Path>) -> impl Iterator<Item = PathBuf> {
 pub struct LockedFile(fs_err::File);
 
 impl LockedFile {
+    /// Attempt to acquire an exclusive lock on the given path, without blocking.
+    ///
+    /// Returns `None` if the lock is held by another process.
+    pub fn try_acquire(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Option<Self>, std::io::Error> {
+        let file = fs_err::File::create(path.as_ref())?;
+        trace!("Checking lock for `{resource}`");
+        match file.file().try_lock_exclusive() {
+            Ok(()) => {
+                debug!("Acquired lock for `{resource}`");
+                Ok(Some(Self(file)))
+            }
+            Err(err) => {
+                // Log error code and enum kind to help debugging more exotic failures
+                debug!("Try lock error: {:?}", err);
+                Ok(None)
+            }
+        }
+    }
+
     pub fn acquire(path: impl AsRef<Path>, resource: impl Display) -> Result<Self, std::io::Error> {
         let file = fs_err::File::create(path.as_ref())?;
         trace!("Checking lock for `{resource}`");
@@ -251,6 +251,83 @@ pub fn absolutize_path(path: &Path) -> Result<Cow<Path>, std::io::Error> {
     path.absolutize_from(CWD.simplified())
 }
 
+/// Convert an absolute path to an extended-length path on Windows (e.g., `\\?\C:\foo`), such
+/// that it isn't subject to the `MAX_PATH` limit of 260 characters.
+///
+/// Extended-length paths are passed to the filesystem as-is, so the path is normalized first
+/// (i.e., `.` and `..` components are resolved, and forward slashes are replaced).
+///
+/// Relative paths, paths that are already extended-length, and device paths are returned
+/// unchanged. On other platforms, this is a no-op.
+pub fn verbatim_path(path: &Path) -> Cow<'_, Path> {
+    #[cfg(windows)]
+    {
+        use std::ffi::OsString;
+        use std::path::Prefix;
+
+        let mut components = path.components();
+        let Some(Component::Prefix(prefix)) = components.next() else {
+            return Cow::Borrowed(path);
+        };
+        if components.next() != Some(Component::RootDir) {
+            return Cow::Borrowed(path);
+        }
+
+        let mut verbatim = match prefix.kind() {
+            Prefix::Disk(_) => {
+                let mut verbatim = OsString::from(r"\\?\");
+                verbatim.push(prefix.as_os_str());
+                verbatim
+            }
+            Prefix::UNC(server, share) => {
+                let mut verbatim = OsString::from(r"\\?\UNC\");
+                verbatim.push(server);
+                verbatim.push(r"\");
+                verbatim.push(share);
+                verbatim
+            }
+            _ => return Cow::Borrowed(path),
+        };
+
+        let normalized = normalize_path(components.as_path());
+        verbatim.push(r"\");
+        for (index, component) in normalized.components().enumerate() {
+            if index > 0 {
+                verbatim.push(r"\");
+            }
+            verbatim.push(component.as_os_str());
+        }
+
+        Cow::Owned(PathBuf::from(verbatim))
+    }
+
+    #[cfg(not(windows))]
+    Cow::Borrowed(path)
+}
+
+/// Returns `true` if the given file name is reserved on Windows (e.g., `aux.py` or `CON`).
+///
+/// Windows reserves the names of legacy devices, regardless of case or extension; such files
+/// can't be created.
+///
+/// See: <https://learn.microsoft.com/en-us/windows/win32/fileio/naming-a-file#naming-conventions>
+pub fn is_reserved_filename(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or(name).trim_end_matches(' ');
+    let stem = stem.to_ascii_uppercase();
+    match stem.as_str() {
+        "CON" | "PRN" | "AUX" | "NUL" => true,
+        _ => stem
+            .strip_prefix("COM")
+            .or_else(|| stem.strip_prefix("LPT"))
+            .is_some_and(|suffix| {
+                matches!(
+                    suffix,
+                    "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" | "¹" | "²" | "³"
+                )
+            }),
+    }
+}
+
 /// Like `fs_err::canonicalize`, but with permissive failures on Windows.
 ///
 /// On Windows, we can't canonicalize the resolved path to Pythons that are installed via the
@@ -510,4 +587,50 @@ mod tests {
             assert_eq!(normalize_path(Path::new(input)), Path::new(expected));
         }
     }
+
+    #[test]
+    fn test_reserved_filename() {
+        for name in [
+            "aux.py",
+            "CON",
+            "con.txt",
+            "Nul.tar.gz",
+            "com1",
+            "LPT9.dat",
+            "aux .py",
+        ] {
+            assert!(is_reserved_filename(name), "{name}");
+        }
+        for name in [
+            "auxiliary.py",
+            "console",
+            "com10",
+            "lpt0",
+            "_aux.py",
+            "nul_",
+        ] {
+            assert!(!is_reserved_filename(name), "{name}");
+        }
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_verbatim_path() {
+        assert_eq!(
+            verbatim_path(Path::new(r"C:\Users\ferris\..\crab\./site-packages")),
+            Path::new(r"\\?\C:\Users\crab\site-packages")
+        );
+        assert_eq!(
+            verbatim_path(Path::new(r"\\server\share\venv")),
+            Path::new(r"\\?\UNC\server\share\venv")
+        );
+        assert_eq!(
+            verbatim_path(Path::new(r"\\?\C:\Users")),
+            Path::new(r"\\?\C:\Users")
+        );
+        assert_eq!(
+            verbatim_path(Path::new(r"site-packages")),
+            Path::new("site-packages")
+        );
+    }
 }
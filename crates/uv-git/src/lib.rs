@@ -1,7 +1,7 @@
 use std::str::FromStr;
 use url::Url;
 
-pub use crate::git::GitReference;
+pub use crate::git::{GitReference, CHECKOUT_READY_LOCK};
 pub use crate::resolver::{
     GitResolver, GitResolverError, RepositoryReference, ResolvedRepositoryReference,
 };
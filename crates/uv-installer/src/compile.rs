@@ -2,6 +2,7 @@ use std::num::NonZeroUsize;
 use std::panic::AssertUnwindSafe;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::sync::Arc;
 use std::time::Duration;
 use std::{io, panic};
 
@@ -61,20 +62,22 @@ pub enum CompileError {
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
 ///
 /// We've confirmed that both uv and pip (as of 24.0.0) remove the `__pycache__` directory.
-#[instrument(skip(python_executable))]
+///
+/// At most `concurrency` interpreters are launched. If a [`Reporter`] is provided, it's notified
+/// as each file is compiled.
+#[instrument(skip(python_executable, reporter))]
 pub async fn compile_tree(
     dir: &Path,
     python_executable: &Path,
+    concurrency: usize,
     cache: &Path,
+    reporter: Option<Arc<dyn Reporter>>,
 ) -> Result<usize, CompileError> {
     debug_assert!(
         dir.is_absolute(),
         "compileall doesn't work with relative paths"
     );
-    let worker_count = std::thread::available_parallelism().unwrap_or_else(|err| {
-        warn_user!("Couldn't determine number of cores, compiling with a single thread: {err}");
-        NonZeroUsize::MIN
-    });
+    let worker_count = NonZeroUsize::new(concurrency).unwrap_or(NonZeroUsize::MIN);
 
     // A larger buffer is significantly faster than just 1 or the worker count.
     let (sender, receiver) = async_channel::bounded::<PathBuf>(worker_count.get() * 10);
@@ -93,6 +96,7 @@ pub async fn compile_tree(
             python_executable.to_path_buf(),
             pip_compileall_py.clone(),
             receiver.clone(),
+            reporter.clone(),
         );
 
         // Spawn each worker on a dedicated thread.
@@ -118,9 +122,8 @@ pub async fn compile_tree(
     // Make sure the channel gets closed when all workers exit.
     drop(receiver);
 
-    // Start the producer, sending all `.py` files to workers.
-    let mut source_files = 0;
-    let mut send_error = None;
+    // Collect all `.py` files up front, so that progress can be reported against a known total.
+    let mut source_files = Vec::new();
     let walker = WalkDir::new(dir)
         .into_iter()
         // Otherwise we stumble over temporary files from `compileall`.
@@ -129,15 +132,24 @@ pub async fn compile_tree(
         let entry = entry?;
         // https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L593-L604
         if entry.metadata()?.is_file() && entry.path().extension().is_some_and(|ext| ext == "py") {
-            source_files += 1;
-            if let Err(err) = sender.send(entry.path().to_owned()).await {
-                // The workers exited.
-                // If e.g. something with the Python interpreter is wrong, the workers have exited
-                // with an error. We try to report this informative error and only if that fails,
-                // report the send error.
-                send_error = Some(err);
-                break;
-            }
+            source_files.push(entry.into_path());
+        }
+    }
+    let source_count = source_files.len();
+    if let Some(reporter) = reporter.as_ref() {
+        reporter.on_compile_start(source_count);
+    }
+
+    // Start the producer, sending all `.py` files to workers.
+    let mut send_error = None;
+    for source_file in source_files {
+        if let Err(err) = sender.send(source_file).await {
+            // The workers exited.
+            // If e.g. something with the Python interpreter is wrong, the workers have exited
+            // with an error. We try to report this informative error and only if that fails,
+            // report the send error.
+            send_error = Some(err);
+            break;
         }
     }
 
@@ -145,8 +157,13 @@ pub async fn compile_tree(
     // up to worker_count * 10 items in the queue.
     drop(sender);
 
+    let results = futures::future::join_all(worker_handles).await;
+    if let Some(reporter) = reporter.as_ref() {
+        reporter.on_compile_complete();
+    }
+
     // Make sure all workers exit regularly, avoid hiding errors.
-    for result in futures::future::join_all(worker_handles).await {
+    for result in results {
         match result {
             // There spawning earlier errored due to a panic in a task.
             Err(_) => return Err(CompileError::Join),
@@ -162,7 +179,7 @@ pub async fn compile_tree(
         return Err(CompileError::WorkerDisappeared(send_error));
     }
 
-    Ok(source_files)
+    Ok(source_count)
 }
 
 async fn worker(
@@ -170,6 +187,7 @@ async fn worker(
     interpreter: PathBuf,
     pip_compileall_py: PathBuf,
     receiver: Receiver<PathBuf>,
+    reporter: Option<Arc<dyn Reporter>>,
 ) -> Result<(), CompileError> {
     fs_err::tokio::write(&pip_compileall_py, COMPILEALL_SCRIPT)
         .await
@@ -204,7 +222,7 @@ async fn worker(
         Ok(child_stderr_collected)
     });
 
-    let result = worker_main_loop(receiver, child_stdin, &mut child_stdout).await;
+    let result = worker_main_loop(receiver, child_stdin, &mut child_stdout, reporter).await;
     // Reap the process to avoid zombies.
     let _ = bytecode_compiler.kill().await;
 
@@ -321,10 +339,11 @@ async fn worker_main_loop(
     receiver: Receiver<PathBuf>,
     mut child_stdin: ChildStdin,
     child_stdout: &mut BufReader<ChildStdout>,
+    reporter: Option<Arc<dyn Reporter>>,
 ) -> Result<(), CompileError> {
     let mut out_line = String::new();
-    while let Ok(source_file) = receiver.recv().await {
-        let source_file = source_file.display().to_string();
+    while let Ok(path) = receiver.recv().await {
+        let source_file = path.display().to_string();
         if source_file.contains(['\r', '\n']) {
             warn_user!("Path contains newline, skipping: {source_file:?}");
             continue;
@@ -363,6 +382,21 @@ async fn worker_main_loop(
         if actual != source_file {
             return Err(CompileError::WrongPath(source_file, actual.to_string()));
         }
+
+        if let Some(reporter) = reporter.as_ref() {
+            reporter.on_compile_progress(&path);
+        }
     }
     Ok(())
 }
+
+pub trait Reporter: Send + Sync {
+    /// Callback to invoke when bytecode compilation starts, with the number of files to compile.
+    fn on_compile_start(&self, files: usize);
+
+    /// Callback to invoke when a file is compiled.
+    fn on_compile_progress(&self, path: &Path);
+
+    /// Callback to invoke when bytecode compilation is complete.
+    fn on_compile_complete(&self);
+}
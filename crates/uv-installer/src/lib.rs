@@ -1,8 +1,10 @@
-pub use compile::{compile_tree, CompileError};
+pub use compile::{compile_tree, CompileError, Reporter as CompileReporter};
 pub use installer::{Installer, Reporter as InstallReporter};
 pub use plan::{Plan, Planner};
-pub use preparer::{Preparer, Reporter as PrepareReporter};
-pub use site_packages::{SatisfiesResult, SitePackages, SitePackagesDiagnostic};
+pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter};
+pub use site_packages::{
+    InvalidDistribution, SatisfiesResult, SitePackages, SitePackagesDiagnostic,
+};
 pub use uninstall::{uninstall, UninstallError};
 
 mod compile;
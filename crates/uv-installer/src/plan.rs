@@ -14,6 +14,7 @@ use distribution_types::{
 };
 use platform_tags::Tags;
 use pypi_types::{Requirement, RequirementSource};
+use uv_cache::stats::CacheEvent;
 use uv_cache::{ArchiveTimestamp, Cache, CacheBucket, WheelCache};
 use uv_configuration::{BuildOptions, Reinstall};
 use uv_distribution::{
@@ -146,6 +147,7 @@ impl<'a> Planner<'a> {
                         .get(&requirement.name)
                         .find(|(version, _)| specifier.contains(version))
                     {
+                        uv_cache::stats::record(CacheEvent::WheelHit);
                         debug!("Requirement already cached: {distribution}");
                         cached.push(CachedDist::Registry(distribution.clone()));
                         continue;
@@ -212,6 +214,7 @@ impl<'a> Planner<'a> {
                                     cache.archive(&archive.id),
                                 );
 
+                                uv_cache::stats::record(CacheEvent::WheelHit);
                                 debug!("URL wheel requirement already cached: {cached_dist}");
                                 cached.push(CachedDist::Url(cached_dist));
                                 continue;
@@ -228,6 +231,7 @@ impl<'a> Planner<'a> {
                         // the filename in advance.
                         if let Some(wheel) = built_index.url(&sdist)? {
                             let cached_dist = wheel.into_url_dist(url.clone());
+                            uv_cache::stats::record(CacheEvent::BuiltWheelHit);
                             debug!("URL source requirement already cached: {cached_dist}");
                             cached.push(CachedDist::Url(cached_dist));
                             continue;
@@ -255,6 +259,7 @@ impl<'a> Planner<'a> {
                     // the filename in advance.
                     if let Some(wheel) = built_index.git(&sdist) {
                         let cached_dist = wheel.into_url_dist(url.clone());
+                        uv_cache::stats::record(CacheEvent::BuiltWheelHit);
                         debug!("Git source requirement already cached: {cached_dist}");
                         cached.push(CachedDist::Url(cached_dist));
                         continue;
@@ -288,6 +293,7 @@ impl<'a> Planner<'a> {
                     // the filename in advance.
                     if let Some(wheel) = built_index.directory(&sdist)? {
                         let cached_dist = wheel.into_url_dist(url.clone());
+                        uv_cache::stats::record(CacheEvent::BuiltWheelHit);
                         debug!("Directory source requirement already cached: {cached_dist}");
                         cached.push(CachedDist::Url(cached_dist));
                         continue;
@@ -365,6 +371,7 @@ impl<'a> Planner<'a> {
                                         cache.archive(&archive.id),
                                     );
 
+                                    uv_cache::stats::record(CacheEvent::WheelHit);
                                     debug!("Path wheel requirement already cached: {cached_dist}");
                                     cached.push(CachedDist::Url(cached_dist));
                                     continue;
@@ -383,6 +390,7 @@ impl<'a> Planner<'a> {
                         // the filename in advance.
                         if let Some(wheel) = built_index.path(&sdist)? {
                             let cached_dist = wheel.into_url_dist(url.clone());
+                            uv_cache::stats::record(CacheEvent::BuiltWheelHit);
                             debug!("Path source requirement already cached: {cached_dist}");
                             cached.push(CachedDist::Url(cached_dist));
                             continue;
@@ -1,10 +1,11 @@
 use std::collections::BTreeSet;
 use std::iter::Flatten;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use fs_err as fs;
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use tracing::debug;
 use url::Url;
 
 use distribution_types::{
@@ -12,9 +13,11 @@ use distribution_types::{
 };
 use pep440_rs::{Version, VersionSpecifiers};
 use pypi_types::{Requirement, VerbatimParsedUrl};
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_types::InstalledPackagesProvider;
+use uv_warnings::warn_user_once;
 
 use crate::satisfies::RequirementSatisfaction;
 
@@ -34,6 +37,9 @@ pub struct SitePackages {
     by_name: FxHashMap<PackageName, Vec<usize>>,
     /// The installed editable distributions, keyed by URL.
     by_url: FxHashMap<Url, Vec<usize>>,
+    /// The distributions that could not be read (e.g., due to malformed metadata), and so were
+    /// omitted from the index.
+    invalid: Vec<InvalidDistribution>,
 }
 
 impl SitePackages {
@@ -47,6 +53,7 @@ impl SitePackages {
         let mut distributions: Vec<Option<InstalledDist>> = Vec::new();
         let mut by_name = FxHashMap::default();
         let mut by_url = FxHashMap::default();
+        let mut invalid = Vec::new();
 
         for site_packages in interpreter.site_packages() {
             // Read the site-packages directory.
@@ -75,19 +82,42 @@ impl SitePackages {
                         distributions,
                         by_name,
                         by_url,
+                        invalid,
                     });
                 }
                 Err(err) => return Err(err).context("Failed to read site-packages directory"),
             };
 
             // Index all installed packages by name.
+            // Distributions that can't be read (e.g., as left behind by other tools) are skipped,
+            // such that they're treated as missing, rather than failing the entire operation.
             for path in site_packages {
-                let Some(dist_info) = InstalledDist::try_from_path(&path)
-                    .with_context(|| format!("Failed to read metadata: from {}", path.display()))?
-                else {
-                    continue;
+                let dist_info = match InstalledDist::try_from_path(&path) {
+                    Ok(Some(dist_info)) => dist_info,
+                    Ok(None) => continue,
+                    Err(err) => {
+                        let dist = InvalidDistribution::new(path, format!("{err:#}"));
+                        warn_user_once!("{dist}");
+                        invalid.push(dist);
+                        continue;
+                    }
                 };
 
+                // A `.dist-info` directory must contain a non-empty `METADATA` file.
+                if let InstalledDist::Registry(_) | InstalledDist::Url(_) = &dist_info {
+                    let is_empty = fs::metadata(path.join("METADATA"))
+                        .map_or(true, |metadata| metadata.len() == 0);
+                    if is_empty {
+                        let dist = InvalidDistribution::new(
+                            path,
+                            "The `METADATA` file is missing or empty".to_string(),
+                        );
+                        warn_user_once!("{dist}");
+                        invalid.push(dist);
+                        continue;
+                    }
+                }
+
                 let idx = distributions.len();
 
                 // Index the distribution by name.
@@ -111,9 +141,15 @@ impl SitePackages {
             distributions,
             by_name,
             by_url,
+            invalid,
         })
     }
 
+    /// Returns the distributions that could not be read, and so were omitted from the index.
+    pub fn invalid(&self) -> &[InvalidDistribution] {
+        &self.invalid
+    }
+
     /// Returns an iterator over the installed distributions.
     pub fn iter(&self) -> impl Iterator<Item = &InstalledDist> {
         self.distributions.iter().flatten()
@@ -330,10 +366,15 @@ impl SitePackages {
                         }
                     }
 
-                    // Recurse into the dependencies.
-                    let metadata = distribution
-                        .metadata()
-                        .with_context(|| format!("Failed to read metadata for: {distribution}"))?;
+                    // Recurse into the dependencies. If the metadata can't be read, treat the
+                    // requirement as unsatisfied, such that the distribution is reinstalled.
+                    let metadata = match distribution.metadata() {
+                        Ok(metadata) => metadata,
+                        Err(err) => {
+                            debug!("Failed to read metadata for {distribution}: {err:#}");
+                            return Ok(SatisfiesResult::Unsatisfied(entry.requirement.to_string()));
+                        }
+                    };
 
                     // Add the dependencies to the queue.
                     for dependency in metadata.requires_dist {
@@ -341,10 +382,18 @@ impl SitePackages {
                             self.interpreter.markers(),
                             entry.requirement.extras(),
                         ) {
+                            let mut dependency = Requirement::from(dependency);
+
+                            // Self-referential extras (e.g., `foo[all]` depending on `foo[bar]`)
+                            // are satisfied by the same distribution. Drop the (already
+                            // evaluated) marker, such that each extra is only expanded once,
+                            // regardless of the extra through which it was reached.
+                            if dependency.name == *distribution.name() {
+                                dependency.marker = None;
+                            }
+
                             let dependency = UnresolvedRequirementSpecification {
-                                requirement: UnresolvedRequirement::Named(Requirement::from(
-                                    dependency,
-                                )),
+                                requirement: UnresolvedRequirement::Named(dependency),
                                 hashes: vec![],
                             };
                             if seen.insert(dependency.clone()) {
@@ -366,6 +415,47 @@ impl SitePackages {
     }
 }
 
+/// A distribution in the environment that could not be read, e.g., due to a malformed version in
+/// its `.dist-info` directory name or an empty `METADATA` file.
+#[derive(Debug, Clone)]
+pub struct InvalidDistribution {
+    /// The path to the `.dist-info` (or `.egg-info`, or `.egg-link`) entry.
+    path: PathBuf,
+    /// The reason the distribution is invalid.
+    reason: String,
+}
+
+impl InvalidDistribution {
+    fn new(path: PathBuf, reason: String) -> Self {
+        Self { path, reason }
+    }
+
+    /// Returns the path to the invalid entry.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Remove the invalid entry from the environment.
+    pub fn remove(&self) -> Result<(), std::io::Error> {
+        if self.path.is_dir() {
+            fs::remove_dir_all(&self.path)
+        } else {
+            fs::remove_file(&self.path)
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Ignoring invalid distribution at `{}`: {}",
+            self.path.user_display(),
+            self.reason
+        )
+    }
+}
+
 /// We check if all requirements are already satisfied, recursing through the requirements tree.
 #[derive(Debug)]
 pub enum SatisfiesResult {
@@ -109,6 +109,8 @@ pub enum VersionRequest {
     Major(u8),
     MajorMinor(u8, u8),
     MajorMinorPatch(u8, u8, u8),
+    /// A free-threaded (GIL-disabled) build of the given major and minor version, e.g., `3.13t`.
+    FreeThreaded(u8, u8),
     Range(VersionSpecifiers),
 }
 
@@ -473,9 +475,9 @@ fn find_all_minor(
                 .collect::<Vec<_>>();
             Either::Left(all_minors.into_iter())
         }
-        VersionRequest::MajorMinor(_, _) | VersionRequest::MajorMinorPatch(_, _, _) => {
-            Either::Right(iter::empty())
-        }
+        VersionRequest::MajorMinor(_, _)
+        | VersionRequest::MajorMinorPatch(_, _, _)
+        | VersionRequest::FreeThreaded(_, _) => Either::Right(iter::empty()),
     }
 }
 
@@ -1336,6 +1338,12 @@ impl VersionRequest {
                 Some(Cow::Owned(format!("python{major}{extension}"))),
                 Some(python),
             ],
+            Self::FreeThreaded(major, minor) => [
+                Some(Cow::Owned(format!("python{major}.{minor}t{extension}"))),
+                Some(Cow::Owned(format!("python{major}.{minor}{extension}"))),
+                Some(Cow::Owned(format!("python{major}{extension}"))),
+                Some(python),
+            ],
         }
     }
 
@@ -1379,6 +1387,12 @@ impl VersionRequest {
                         Some(Cow::Owned(format!("{name}{major}{extension}"))),
                         Some(python),
                     ],
+                    Self::FreeThreaded(major, minor) => [
+                        Some(Cow::Owned(format!("{name}{major}.{minor}t{extension}"))),
+                        Some(Cow::Owned(format!("{name}{major}.{minor}{extension}"))),
+                        Some(Cow::Owned(format!("{name}{major}{extension}"))),
+                        Some(python),
+                    ],
                 }
             })
             .chain(self.default_names())
@@ -1400,6 +1414,10 @@ impl VersionRequest {
                     interpreter.python_patch(),
                 ) == (*major, *minor, *patch)
             }
+            Self::FreeThreaded(major, minor) => {
+                (interpreter.python_major(), interpreter.python_minor()) == (*major, *minor)
+                    && interpreter.gil_disabled()
+            }
             Self::Range(specifiers) => specifiers.contains(interpreter.python_version()),
         }
     }
@@ -1415,6 +1433,9 @@ impl VersionRequest {
                 (version.major(), version.minor(), version.patch())
                     == (*major, *minor, Some(*patch))
             }
+            Self::FreeThreaded(major, minor) => {
+                (version.major(), version.minor()) == (*major, *minor)
+            }
             Self::Range(specifiers) => specifiers.contains(&version.version),
         }
     }
@@ -1429,6 +1450,9 @@ impl VersionRequest {
             Self::MajorMinorPatch(self_major, self_minor, _) => {
                 (*self_major, *self_minor) == (major, minor)
             }
+            Self::FreeThreaded(self_major, self_minor) => {
+                (*self_major, *self_minor) == (major, minor)
+            }
             Self::Range(specifiers) => {
                 specifiers.contains(&Version::new([u64::from(major), u64::from(minor)]))
             }
@@ -1445,6 +1469,9 @@ impl VersionRequest {
             Self::MajorMinorPatch(self_major, self_minor, self_patch) => {
                 (*self_major, *self_minor, *self_patch) == (major, minor, patch)
             }
+            Self::FreeThreaded(self_major, self_minor) => {
+                (*self_major, *self_minor) == (major, minor)
+            }
             Self::Range(specifiers) => specifiers.contains(&Version::new([
                 u64::from(major),
                 u64::from(minor),
@@ -1460,6 +1487,7 @@ impl VersionRequest {
             Self::Major(..) => false,
             Self::MajorMinor(..) => false,
             Self::MajorMinorPatch(..) => true,
+            Self::FreeThreaded(..) => false,
             Self::Range(_) => false,
         }
     }
@@ -1474,9 +1502,15 @@ impl VersionRequest {
             Self::Major(major) => Self::Major(major),
             Self::MajorMinor(major, minor) => Self::MajorMinor(major, minor),
             Self::MajorMinorPatch(major, minor, _) => Self::MajorMinor(major, minor),
+            Self::FreeThreaded(..) => self,
             Self::Range(_) => self,
         }
     }
+
+    /// Return true if a free-threaded build is requested.
+    pub(crate) fn is_free_threaded(&self) -> bool {
+        matches!(self, Self::FreeThreaded(..))
+    }
 }
 
 impl FromStr for VersionRequest {
@@ -1493,6 +1527,14 @@ impl FromStr for VersionRequest {
             Some(VersionRequest::MajorMinor(major, minor))
         }
 
+        // e.g. `3.13t`, `313t`
+        if let Some(version) = s.strip_suffix('t') {
+            return match Self::from_str(version) {
+                Ok(Self::MajorMinor(major, minor)) => Ok(Self::FreeThreaded(major, minor)),
+                _ => Err(Error::InvalidVersionRequest(s.to_string())),
+            };
+        }
+
         // e.g. `3`, `38`, `312`
         if let Some(request) = parse_nosep(s) {
             Ok(request)
@@ -1543,6 +1585,7 @@ impl fmt::Display for VersionRequest {
             Self::MajorMinorPatch(major, minor, patch) => {
                 write!(f, "{major}.{minor}.{patch}")
             }
+            Self::FreeThreaded(major, minor) => write!(f, "{major}.{minor}t"),
             Self::Range(specifiers) => write!(f, "{specifiers}"),
         }
     }
@@ -1757,6 +1800,17 @@ mod tests {
                 VersionRequest::from_str("3.8").unwrap()
             )
         );
+        assert_eq!(
+            PythonRequest::parse("3.13t"),
+            PythonRequest::Version(VersionRequest::FreeThreaded(3, 13))
+        );
+        assert_eq!(
+            PythonRequest::parse("cpython@3.13t"),
+            PythonRequest::ImplementationVersion(
+                ImplementationName::CPython,
+                VersionRequest::FreeThreaded(3, 13)
+            )
+        );
         assert_eq!(
             PythonRequest::parse("pypy@3.10"),
             PythonRequest::ImplementationVersion(
@@ -1827,6 +1881,10 @@ mod tests {
                 .to_canonical_string(),
             ">=3.12, <3.13"
         );
+        assert_eq!(
+            PythonRequest::Version(VersionRequest::FreeThreaded(3, 13)).to_canonical_string(),
+            "3.13t"
+        );
         assert_eq!(
             PythonRequest::ExecutableName("foo".to_string()).to_canonical_string(),
             "foo"
@@ -1923,6 +1981,18 @@ mod tests {
             VersionRequest::from_str("3100").unwrap(),
             VersionRequest::MajorMinor(3, 100)
         );
+        assert_eq!(
+            VersionRequest::from_str("3.13t").unwrap(),
+            VersionRequest::FreeThreaded(3, 13)
+        );
+        assert_eq!(
+            VersionRequest::from_str("313t").unwrap(),
+            VersionRequest::FreeThreaded(3, 13)
+        );
+        assert!(matches!(
+            VersionRequest::from_str("3.13.1t"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
         assert!(
             // Test for overflow
             matches!(
@@ -1,15 +1,14 @@
 use std::fmt::Display;
 use std::io;
 use std::path::{Path, PathBuf};
-use std::pin::Pin;
 use std::str::FromStr;
-use std::task::{Context, Poll};
 
 use futures::TryStreamExt;
 use owo_colors::OwoColorize;
+use reqwest::header::{ACCEPT_RANGES, RANGE};
+use reqwest::StatusCode;
 use thiserror::Error;
-use tokio::io::{AsyncRead, ReadBuf};
-use tokio_util::compat::FuturesAsyncReadCompatExt;
+use tokio::io::AsyncWriteExt;
 use tracing::{debug, instrument};
 use url::Url;
 
@@ -69,6 +68,38 @@ pub enum Error {
     InvalidRequestPlatform(#[from] platform::Error),
     #[error("No download found for request: {}", _0.green())]
     NoDownloadFound(PythonDownloadRequest),
+    #[error("Invalid Python install mirror: {0}")]
+    InvalidMirror(String, #[source] url::ParseError),
+    #[error("Failed to download `{installation}` from any source:{failures}")]
+    MirrorsExhausted {
+        installation: String,
+        failures: DownloadFailures,
+    },
+}
+
+/// The base URL of the upstream Python distributions, which mirrors are expected to replicate.
+pub const UPSTREAM_URL: &str =
+    "https://github.com/indygreg/python-build-standalone/releases/download";
+
+/// The number of times an interrupted download is resumed before giving up.
+const MAX_RESUME_ATTEMPTS: usize = 3;
+
+/// The errors encountered for each source when attempting to download a Python distribution.
+#[derive(Debug)]
+pub struct DownloadFailures(Vec<(Url, Error)>);
+
+impl Display for DownloadFailures {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (url, err) in &self.0 {
+            write!(f, "\n  - {}: {err}", url.cyan())?;
+            let mut source = std::error::Error::source(err);
+            while let Some(err) = source {
+                write!(f, ": {err}")?;
+                source = err.source();
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -237,6 +268,10 @@ impl PythonDownloadRequest {
             }
         }
         if let Some(version) = &self.version {
+            // The managed Python distributions do not include free-threaded builds.
+            if version.is_free_threaded() {
+                return false;
+            }
             if !version.matches_major_minor_patch(key.major, key.minor, key.patch) {
                 return false;
             }
@@ -400,15 +435,19 @@ impl ManagedPythonDownload {
         self.sha256
     }
 
-    /// Download and extract
-    #[instrument(skip(client, parent_path, reporter), fields(download = % self.key()))]
+    /// Download and extract the distribution.
+    ///
+    /// Each of the given mirrors is tried in order before falling back to the upstream URL. A
+    /// mirror is expected to replicate the layout of the upstream release downloads, i.e., the
+    /// path following [`UPSTREAM_URL`].
+    #[instrument(skip(client, parent_path, mirrors, reporter), fields(download = % self.key()))]
     pub async fn fetch(
         &self,
         client: &uv_client::BaseClient,
         parent_path: &Path,
+        mirrors: &[String],
         reporter: Option<&dyn Reporter>,
     ) -> Result<DownloadResult, Error> {
-        let url = Url::parse(self.url)?;
         let path = parent_path.join(self.key().to_string());
 
         // If it already exists, return it
@@ -416,59 +455,91 @@ impl ManagedPythonDownload {
             return Ok(DownloadResult::AlreadyAvailable(path));
         }
 
-        let filename = url.path_segments().unwrap().last().unwrap();
-        let response = client.get(url.clone()).send().await?;
+        let upstream = Url::parse(self.url)?;
+        if mirrors.is_empty() {
+            return self
+                .fetch_from(client, &upstream, parent_path, path, reporter)
+                .await;
+        }
+
+        let mut urls = mirrors
+            .iter()
+            .filter_map(|mirror| self.mirror_url(mirror).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+        urls.push(upstream);
+
+        let mut failures = Vec::with_capacity(urls.len());
+        for url in urls {
+            match self
+                .fetch_from(client, &url, parent_path, path.clone(), reporter)
+                .await
+            {
+                Ok(result) => return Ok(result),
+                Err(err) => {
+                    debug!("Failed to download {} from {url}: {err}", self.key);
+                    failures.push((url, err));
+                }
+            }
+        }
 
-        // Ensure the request was successful.
-        response.error_for_status_ref()?;
+        Err(Error::MirrorsExhausted {
+            installation: self.key.to_string(),
+            failures: DownloadFailures(failures),
+        })
+    }
 
-        let size = response.content_length();
-        let progress = reporter
-            .as_ref()
-            .map(|reporter| (reporter, reporter.on_download_start(&self.key, size)));
+    /// Return the URL for this distribution on the given mirror, if the distribution is hosted
+    /// upstream.
+    fn mirror_url(&self, mirror: &str) -> Result<Option<Url>, Error> {
+        let Some(suffix) = self.url.strip_prefix(UPSTREAM_URL) else {
+            return Ok(None);
+        };
+        let url = format!("{}{suffix}", mirror.trim_end_matches('/'));
+        Url::parse(&url)
+            .map(Some)
+            .map_err(|err| Error::InvalidMirror(mirror.to_string(), err))
+    }
 
-        // Download and extract into a temporary directory.
-        let temp_dir = tempfile::tempdir_in(parent_path).map_err(Error::DownloadDirError)?;
+    /// Download the distribution from the given URL, verify its hash, and extract it to `path`.
+    async fn fetch_from(
+        &self,
+        client: &uv_client::BaseClient,
+        url: &Url,
+        parent_path: &Path,
+        path: PathBuf,
+        reporter: Option<&dyn Reporter>,
+    ) -> Result<DownloadResult, Error> {
+        let filename = url.path_segments().unwrap().last().unwrap();
+
+        // Download the archive into a temporary directory.
+        let download_dir = tempfile::tempdir_in(parent_path).map_err(Error::DownloadDirError)?;
+        let archive = download_dir.path().join(filename);
 
         debug!(
             "Downloading {url} to temporary location {}",
-            temp_dir.path().display()
+            archive.display()
         );
+        self.download_archive(client, url, &archive, reporter)
+            .await?;
 
-        let stream = response
-            .bytes_stream()
-            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
-            .into_async_read();
+        // Extract into a temporary directory.
+        let temp_dir = tempfile::tempdir_in(parent_path).map_err(Error::DownloadDirError)?;
+
+        debug!("Extracting {filename}");
 
+        let reader = fs_err::tokio::File::open(&archive).await?;
         let mut hashers = self
             .sha256
             .into_iter()
             .map(|_| Hasher::from(HashAlgorithm::Sha256))
             .collect::<Vec<_>>();
-        let mut hasher = uv_extract::hash::HashReader::new(stream.compat(), &mut hashers);
-
-        debug!("Extracting {filename}");
-
-        match progress {
-            Some((&reporter, progress)) => {
-                let mut reader = ProgressReader::new(&mut hasher, progress, reporter);
-                uv_extract::stream::archive(&mut reader, filename, temp_dir.path())
-                    .await
-                    .map_err(|err| Error::ExtractError(filename.to_string(), err))?;
-            }
-            None => {
-                uv_extract::stream::archive(&mut hasher, filename, temp_dir.path())
-                    .await
-                    .map_err(|err| Error::ExtractError(filename.to_string(), err))?;
-            }
-        };
+        let mut hasher = uv_extract::hash::HashReader::new(reader, &mut hashers);
+        uv_extract::stream::archive(&mut hasher, filename, temp_dir.path())
+            .await
+            .map_err(|err| Error::ExtractError(filename.to_string(), err))?;
 
         hasher.finish().await.map_err(Error::HashExhaustion)?;
 
-        if let Some((&reporter, progress)) = progress {
-            reporter.on_progress(&self.key, progress);
-        }
-
         // Check the hash
         if let Some(expected) = self.sha256 {
             let actual = HashDigest::from(hashers.pop().unwrap()).digest;
@@ -505,6 +576,85 @@ impl ManagedPythonDownload {
         Ok(DownloadResult::Fetched(path))
     }
 
+    /// Download the archive at the given URL to `target`.
+    ///
+    /// If the connection is interrupted and the server supports range requests, the download is
+    /// resumed from the last received byte.
+    async fn download_archive(
+        &self,
+        client: &uv_client::BaseClient,
+        url: &Url,
+        target: &Path,
+        reporter: Option<&dyn Reporter>,
+    ) -> Result<(), Error> {
+        let mut file = fs_err::tokio::File::create(target).await?;
+        let mut downloaded = 0u64;
+        let mut attempts = 0;
+        let mut progress = None;
+
+        loop {
+            let mut request = client.get(url.clone());
+            if downloaded > 0 {
+                request = request.header(RANGE, format!("bytes={downloaded}-"));
+            }
+            let response = request.send().await?;
+
+            // Ensure the request was successful.
+            response.error_for_status_ref()?;
+
+            // If the server ignored the range request, start over.
+            if downloaded > 0 && response.status() != StatusCode::PARTIAL_CONTENT {
+                debug!("Server does not support range requests; restarting download of {url}");
+                file = fs_err::tokio::File::create(target).await?;
+                downloaded = 0;
+            }
+
+            let resumable = response.status() == StatusCode::PARTIAL_CONTENT
+                || response
+                    .headers()
+                    .get(ACCEPT_RANGES)
+                    .is_some_and(|value| value.as_bytes() == b"bytes");
+
+            if progress.is_none() {
+                let size = response.content_length();
+                progress = reporter
+                    .map(|reporter| (reporter, reporter.on_download_start(&self.key, size)));
+            }
+
+            let mut stream = response.bytes_stream();
+            let interrupted = loop {
+                match stream.try_next().await {
+                    Ok(Some(chunk)) => {
+                        file.write_all(&chunk).await?;
+                        downloaded += chunk.len() as u64;
+                        if let Some((reporter, id)) = progress {
+                            reporter.on_download_progress(id, chunk.len() as u64);
+                        }
+                    }
+                    Ok(None) => break None,
+                    Err(err) => break Some(err),
+                }
+            };
+
+            let Some(err) = interrupted else {
+                break;
+            };
+            if !resumable || attempts >= MAX_RESUME_ATTEMPTS {
+                return Err(err.into());
+            }
+            attempts += 1;
+            debug!("Download of {url} interrupted after {downloaded} bytes ({err}); resuming");
+        }
+
+        file.flush().await?;
+
+        if let Some((reporter, id)) = progress {
+            reporter.on_progress(&self.key, id);
+        }
+
+        Ok(())
+    }
+
     pub fn python_version(&self) -> PythonVersion {
         self.key.version()
     }
@@ -539,39 +689,3 @@ pub trait Reporter: Send + Sync {
     fn on_download_progress(&self, id: usize, inc: u64);
     fn on_download_complete(&self);
 }
-
-/// An asynchronous reader that reports progress as bytes are read.
-struct ProgressReader<'a, R> {
-    reader: R,
-    index: usize,
-    reporter: &'a dyn Reporter,
-}
-
-impl<'a, R> ProgressReader<'a, R> {
-    /// Create a new [`ProgressReader`] that wraps another reader.
-    fn new(reader: R, index: usize, reporter: &'a dyn Reporter) -> Self {
-        Self {
-            reader,
-            index,
-            reporter,
-        }
-    }
-}
-
-impl<R> AsyncRead for ProgressReader<'_, R>
-where
-    R: AsyncRead + Unpin,
-{
-    fn poll_read(
-        mut self: Pin<&mut Self>,
-        cx: &mut Context<'_>,
-        buf: &mut ReadBuf<'_>,
-    ) -> Poll<io::Result<()>> {
-        Pin::new(&mut self.as_mut().reader)
-            .poll_read(cx, buf)
-            .map_ok(|()| {
-                self.reporter
-                    .on_download_progress(self.index, buf.filled().len() as u64);
-            })
-    }
-}
@@ -82,6 +82,7 @@ impl PythonInstallation {
         environments: EnvironmentPreference,
         preference: PythonPreference,
         python_fetch: PythonFetch,
+        python_install_mirror: &[String],
         client_builder: &BaseClientBuilder<'a>,
         cache: &Cache,
         reporter: Option<&dyn Reporter>,
@@ -91,7 +92,14 @@ impl PythonInstallation {
         // Perform a fetch aggressively if managed Python is preferred
         if matches!(preference, PythonPreference::Managed) && python_fetch.is_automatic() {
             if let Some(request) = PythonDownloadRequest::from_request(&request) {
-                return Self::fetch(request.fill(), client_builder, cache, reporter).await;
+                return Self::fetch(
+                    request.fill(),
+                    python_install_mirror,
+                    client_builder,
+                    cache,
+                    reporter,
+                )
+                .await;
             }
         }
 
@@ -106,7 +114,15 @@ impl PythonInstallation {
             {
                 if let Some(request) = PythonDownloadRequest::from_request(&request) {
                     debug!("Requested Python not found, checking for available download...");
-                    match Self::fetch(request.fill(), client_builder, cache, reporter).await {
+                    match Self::fetch(
+                        request.fill(),
+                        python_install_mirror,
+                        client_builder,
+                        cache,
+                        reporter,
+                    )
+                    .await
+                    {
                         Ok(installation) => Ok(installation),
                         Err(Error::Download(downloads::Error::NoDownloadFound(_))) => {
                             Err(Error::MissingPython(err))
@@ -122,8 +138,11 @@ impl PythonInstallation {
     }
 
     /// Download and install the requested installation.
+    ///
+    /// The given mirrors are tried in order before falling back to the upstream source.
     pub async fn fetch<'a>(
         request: PythonDownloadRequest,
+        python_install_mirror: &[String],
         client_builder: &BaseClientBuilder<'a>,
         cache: &Cache,
         reporter: Option<&dyn Reporter>,
@@ -136,7 +155,9 @@ impl PythonInstallation {
         let client = client_builder.build();
 
         info!("Fetching requested Python...");
-        let result = download.fetch(&client, installations_dir, reporter).await?;
+        let result = download
+            .fetch(&client, installations_dir, python_install_mirror, reporter)
+            .await?;
 
         let path = match result {
             DownloadResult::AlreadyAvailable(path) => path,
@@ -14,6 +14,7 @@ pub use crate::prefix::Prefix;
 pub use crate::python_version::PythonVersion;
 pub use crate::target::Target;
 pub use crate::version_files::{
+    request_from_ancestor_version_file, request_from_nearest_version_file,
     request_from_version_file, requests_from_version_file, write_version_file,
     PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME,
 };
@@ -198,6 +199,18 @@ mod tests {
             version: &PythonVersion,
             implementation: ImplementationName,
             system: bool,
+        ) -> Result<()> {
+            Self::create_mock_interpreter_with_gil(path, version, implementation, system, false)
+        }
+
+        /// Create a fake Python interpreter executable, as in [`TestContext::create_mock_interpreter`],
+        /// for either a standard or a free-threaded (GIL-disabled) build.
+        fn create_mock_interpreter_with_gil(
+            path: &Path,
+            version: &PythonVersion,
+            implementation: ImplementationName,
+            system: bool,
+            gil_disabled: bool,
         ) -> Result<()> {
             let json = indoc! {r##"
                     {
@@ -247,7 +260,7 @@ mod tests {
                             "scripts": "bin"
                         },
                         "pointer_size": "64",
-                        "gil_disabled": true
+                        "gil_disabled": {GIL_DISABLED}
                     }
                 "##};
 
@@ -264,7 +277,8 @@ mod tests {
                 )
                 .replace("{FULL_VERSION}", &version.to_string())
                 .replace("{VERSION}", &version.without_patch().to_string())
-                .replace("{IMPLEMENTATION}", (&implementation).into());
+                .replace("{IMPLEMENTATION}", (&implementation).into())
+                .replace("{GIL_DISABLED}", &gil_disabled.to_string());
 
             fs_err::create_dir_all(path.parent().unwrap())?;
             fs_err::write(
@@ -829,6 +843,81 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_python_version_free_threaded() -> Result<()> {
+        let mut context = TestContext::new()?;
+        let paths = context.new_search_path_directories(&["standard", "free-threaded"])?;
+        TestContext::create_mock_interpreter_with_gil(
+            &paths[0].join("python3.13"),
+            &PythonVersion::from_str("3.13.0").unwrap(),
+            ImplementationName::CPython,
+            true,
+            false,
+        )?;
+        TestContext::create_mock_interpreter_with_gil(
+            &paths[1].join("python3.13t"),
+            &PythonVersion::from_str("3.13.1").unwrap(),
+            ImplementationName::CPython,
+            true,
+            true,
+        )?;
+
+        // A free-threaded request should skip the standard build earlier in the search path
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.13t"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+            )
+        })??;
+        assert_eq!(
+            &python.interpreter().python_full_version().to_string(),
+            "3.13.1",
+            "We should find the free-threaded interpreter for the request"
+        );
+        assert!(python.interpreter().gil_disabled());
+
+        // A standard request should accept the first interpreter
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.13"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+            )
+        })??;
+        assert_eq!(
+            &python.interpreter().python_full_version().to_string(),
+            "3.13.0",
+            "We should find the first interpreter for the request"
+        );
+        assert!(!python.interpreter().gil_disabled());
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_python_version_free_threaded_no_match() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_versions(&["3.12.3", "3.13.0"])?;
+
+        let result = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.13t"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+            )
+        })?;
+        assert!(
+            matches!(result, Err(PythonNotFound { .. })),
+            "We should not accept a standard build for a free-threaded request; got {result:?}"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_best_python_version_patch_exact() -> Result<()> {
         let mut context = TestContext::new()?;
@@ -1,6 +1,10 @@
+use std::path::Path;
+
 use fs_err as fs;
 use tracing::debug;
 
+use uv_fs::Simplified;
+
 use crate::PythonRequest;
 
 /// The file name for Python version pins.
@@ -14,14 +18,14 @@ pub static PYTHON_VERSIONS_FILENAME: &str = ".python-versions";
 /// Prefers `.python-versions` then `.python-version`.
 /// If only one Python version is desired, use [`request_from_version_files`] which prefers the `.python-version` file.
 pub async fn requests_from_version_file() -> Result<Option<Vec<PythonRequest>>, std::io::Error> {
-    if let Some(versions) = read_versions_file().await? {
+    if let Some(versions) = read_versions_file(Path::new("")).await? {
         Ok(Some(
             versions
                 .into_iter()
                 .map(|version| PythonRequest::parse(&version))
                 .collect(),
         ))
-    } else if let Some(version) = read_version_file().await? {
+    } else if let Some(version) = read_version_file(Path::new("")).await? {
         Ok(Some(vec![PythonRequest::parse(&version)]))
     } else {
         Ok(None)
@@ -33,9 +37,60 @@ pub async fn requests_from_version_file() -> Result<Option<Vec<PythonRequest>>,
 /// Prefers `.python-version` then the first entry of `.python-versions`.
 /// If multiple Python versions are desired, use [`requests_from_version_files`] instead.
 pub async fn request_from_version_file() -> Result<Option<PythonRequest>, std::io::Error> {
-    if let Some(version) = read_version_file().await? {
+    request_from_version_file_in(Path::new("")).await
+}
+
+/// Read a [`PythonRequest`] from the nearest version file, searching from `directory` up to (and
+/// including) `root`.
+///
+/// The search never leaves `root` (e.g., the workspace root). If `directory` is not contained in
+/// `root`, only `root` itself is searched.
+pub async fn request_from_nearest_version_file(
+    directory: &Path,
+    root: &Path,
+) -> Result<Option<PythonRequest>, std::io::Error> {
+    let mut directories = directory
+        .ancestors()
+        .take_while(|ancestor| ancestor.starts_with(root))
+        .peekable();
+    if directories.peek().is_none() {
+        return request_from_version_file_in(root).await;
+    }
+    for directory in directories {
+        if let Some(request) = request_from_version_file_in(directory).await? {
+            return Ok(Some(request));
+        }
+    }
+    Ok(None)
+}
+
+/// Read a [`PythonRequest`] from the nearest version file above `directory`, searching each of its
+/// ancestors up to the filesystem root.
+///
+/// `directory` itself is not searched; use [`request_from_nearest_version_file`] to search within
+/// a root.
+pub async fn request_from_ancestor_version_file(
+    directory: &Path,
+) -> Result<Option<PythonRequest>, std::io::Error> {
+    for ancestor in directory.ancestors().skip(1) {
+        if let Some(request) = request_from_version_file_in(ancestor).await? {
+            debug!(
+                "Using version file from ancestor directory: `{}`",
+                ancestor.user_display()
+            );
+            return Ok(Some(request));
+        }
+    }
+    Ok(None)
+}
+
+/// Read a [`PythonRequest`] from a version file in the given directory, if present.
+async fn request_from_version_file_in(
+    directory: &Path,
+) -> Result<Option<PythonRequest>, std::io::Error> {
+    if let Some(version) = read_version_file(directory).await? {
         Ok(Some(PythonRequest::parse(&version)))
-    } else if let Some(versions) = read_versions_file().await? {
+    } else if let Some(versions) = read_versions_file(directory).await? {
         Ok(versions
             .into_iter()
             .next()
@@ -52,10 +107,11 @@ pub async fn write_version_file(version: &str) -> Result<(), std::io::Error> {
     fs::tokio::write(PYTHON_VERSION_FILENAME, format!("{version}\n")).await
 }
 
-async fn read_versions_file() -> Result<Option<Vec<String>>, std::io::Error> {
-    match fs::tokio::read_to_string(PYTHON_VERSIONS_FILENAME).await {
+async fn read_versions_file(directory: &Path) -> Result<Option<Vec<String>>, std::io::Error> {
+    let path = directory.join(PYTHON_VERSIONS_FILENAME);
+    match fs::tokio::read_to_string(&path).await {
         Ok(content) => {
-            debug!("Reading requests from `{PYTHON_VERSIONS_FILENAME}`");
+            debug!("Reading requests from `{}`", path.display());
             Ok(Some(content.lines().map(ToString::to_string).collect()))
         }
         Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
@@ -63,10 +119,11 @@ async fn read_versions_file() -> Result<Option<Vec<String>>, std::io::Error> {
     }
 }
 
-async fn read_version_file() -> Result<Option<String>, std::io::Error> {
-    match fs::tokio::read_to_string(PYTHON_VERSION_FILENAME).await {
+async fn read_version_file(directory: &Path) -> Result<Option<String>, std::io::Error> {
+    let path = directory.join(PYTHON_VERSION_FILENAME);
+    match fs::tokio::read_to_string(&path).await {
         Ok(content) => {
-            debug!("Reading requests from `{PYTHON_VERSION_FILENAME}`");
+            debug!("Reading requests from `{}`", path.display());
             Ok(content.lines().next().map(ToString::to_string))
         }
         Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
@@ -28,6 +28,10 @@ pub struct PyVenvConfiguration {
     pub(crate) virtualenv: bool,
     /// If the uv package was used to create the virtual environment.
     pub(crate) uv: bool,
+    /// The Python version of the virtual environment, e.g., `3.12.1`.
+    pub(crate) version: Option<String>,
+    /// If the virtual environment was created with seed packages.
+    pub(crate) seed: bool,
 }
 
 #[derive(Debug, Error)]
@@ -136,6 +140,8 @@ impl PyVenvConfiguration {
     pub fn parse(cfg: impl AsRef<Path>) -> Result<Self, Error> {
         let mut virtualenv = false;
         let mut uv = false;
+        let mut version = None;
+        let mut seed = false;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -143,7 +149,7 @@ impl PyVenvConfiguration {
         let content = fs::read_to_string(&cfg)
             .map_err(|err| Error::ParsePyVenvCfg(cfg.as_ref().to_path_buf(), err))?;
         for line in content.lines() {
-            let Some((key, _value)) = line.split_once('=') else {
+            let Some((key, value)) = line.split_once('=') else {
                 continue;
             };
             match key.trim() {
@@ -153,11 +159,33 @@ impl PyVenvConfiguration {
                 "uv" => {
                     uv = true;
                 }
+                "seed" => {
+                    seed = value.trim().eq_ignore_ascii_case("true");
+                }
+                // `venv` writes `version`, while `virtualenv` and uv write `version_info` (which,
+                // for `virtualenv`, includes the release level, as in `3.12.1.final.0`).
+                "version" | "version_info" => {
+                    version = Some(
+                        value
+                            .trim()
+                            .split('.')
+                            .take_while(|part| {
+                                !part.is_empty() && part.chars().all(|c| c.is_ascii_digit())
+                            })
+                            .collect::<Vec<_>>()
+                            .join("."),
+                    );
+                }
                 _ => {}
             }
         }
 
-        Ok(Self { virtualenv, uv })
+        Ok(Self {
+            virtualenv,
+            uv,
+            version,
+            seed,
+        })
     }
 
     /// Returns true if the virtual environment was created with the `virtualenv` package.
@@ -169,4 +197,25 @@ impl PyVenvConfiguration {
     pub fn is_uv(&self) -> bool {
         self.uv
     }
+
+    /// Returns true if the virtual environment was created with seed packages.
+    pub fn is_seeded(&self) -> bool {
+        self.seed
+    }
+
+    /// Returns the Python version of the virtual environment, if known.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Returns the name of the tool that created the virtual environment, if known.
+    pub fn creator(&self) -> Option<&'static str> {
+        if self.uv {
+            Some("uv")
+        } else if self.virtualenv {
+            Some("virtualenv")
+        } else {
+            None
+        }
+    }
 }
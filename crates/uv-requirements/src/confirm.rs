@@ -5,7 +5,7 @@ use console::{style, Key, Term};
 ///
 /// This is a slimmed-down version of `dialoguer::Confirm`, with the post-confirmation report
 /// enabled.
-pub(crate) fn confirm(message: &str, term: &Term, default: bool) -> Result<bool> {
+pub fn confirm(message: &str, term: &Term, default: bool) -> Result<bool> {
     // Set the Ctrl-C handler to exit the process.
     let result = ctrlc::set_handler(move || {
         let term = Term::stderr();
@@ -21,6 +21,8 @@ pub enum LookaheadError {
     Download(BuiltDist, #[source] uv_distribution::Error),
     #[error("Failed to download and build: `{0}`")]
     DownloadAndBuild(SourceDist, #[source] uv_distribution::Error),
+    #[error("Failed to fetch metadata for: `{0}`")]
+    Metadata(Dist),
     #[error(transparent)]
     UnsupportedUrl(#[from] distribution_types::Error),
 }
@@ -153,46 +155,45 @@ impl<'a, Context: BuildContext> LookaheadResolver<'a, Context> {
             return Ok(None);
         };
 
-        // Fetch the metadata for the distribution.
-        let metadata = {
-            let id = dist.version_id();
-            if let Some(archive) =
-                self.index
-                    .distributions()
-                    .get(&id)
-                    .as_deref()
-                    .and_then(|response| {
-                        if let MetadataResponse::Found(archive, ..) = response {
-                            Some(archive)
-                        } else {
-                            None
-                        }
-                    })
+        // Fetch the metadata for the distribution. If another requirement (e.g., from a different
+        // workspace member) already registered the same distribution, wait for its in-flight
+        // request rather than issuing a duplicate one.
+        let id = dist.version_id();
+        let metadata = if self.index.distributions().register(id.clone()) {
+            // Run the PEP 517 build process to extract metadata from the source distribution.
+            let archive = match self
+                .database
+                .get_or_build_wheel_metadata(&dist, self.hasher.get(&dist))
+                .await
             {
-                // If the metadata is already in the index, return it.
-                archive.metadata.clone()
-            } else {
-                // Run the PEP 517 build process to extract metadata from the source distribution.
-                let archive = self
-                    .database
-                    .get_or_build_wheel_metadata(&dist, self.hasher.get(&dist))
-                    .await
-                    .map_err(|err| match &dist {
+                Ok(archive) => archive,
+                Err(err) => {
+                    // Release the request, so that any waiting requests don't hang.
+                    self.index.distributions().cancel(&id);
+                    return Err(match &dist {
                         Dist::Built(built) => LookaheadError::Download(built.clone(), err),
                         Dist::Source(source) => {
                             LookaheadError::DownloadAndBuild(source.clone(), err)
                         }
-                    })?;
+                    });
+                }
+            };
 
-                let metadata = archive.metadata.clone();
+            let metadata = archive.metadata.clone();
 
-                // Insert the metadata into the index.
-                self.index
-                    .distributions()
-                    .done(id, Arc::new(MetadataResponse::Found(archive)));
+            // Insert the metadata into the index, notifying any waiting requests.
+            self.index
+                .distributions()
+                .done(id, Arc::new(MetadataResponse::Found(archive)));
 
-                metadata
-            }
+            metadata
+        } else {
+            // If the request failed, the error is reported by the request that registered it.
+            let response = self.index.distributions().wait(&id).await;
+            let Some(MetadataResponse::Found(archive)) = response.as_deref() else {
+                return Err(LookaheadError::Metadata(dist));
+            };
+            archive.metadata.clone()
         };
 
         // Respect recursive extras by propagating the source extras to the dependencies.
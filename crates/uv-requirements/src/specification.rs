@@ -318,6 +318,15 @@ impl RequirementsSpecification {
         Ok(UnresolvedRequirementSpecification::from(requirement))
     }
 
+    /// Parse an individual editable requirement, which must refer to a local directory.
+    pub fn parse_editable(name: &str) -> Result<UnresolvedRequirementSpecification> {
+        let requirement = RequirementsTxtRequirement::parse(name, std::env::current_dir()?, true)
+            .with_context(|| format!("Failed to parse: `{name}`"))?;
+        Ok(UnresolvedRequirementSpecification::from(
+            requirement.into_editable()?,
+        ))
+    }
+
     /// Read the requirements from a set of sources.
     pub async fn from_simple_sources(
         requirements: &[RequirementsSource],
@@ -7,7 +7,7 @@ use configparser::ini::Ini;
 use futures::{stream::FuturesOrdered, TryStreamExt};
 use serde::Deserialize;
 use tracing::debug;
-use url::Host;
+use url::{Host, Url};
 
 use distribution_filename::{SourceDistFilename, WheelFilename};
 use distribution_types::{
@@ -32,6 +32,9 @@ pub enum NamedRequirementsError {
 
     #[error(transparent)]
     WheelFilename(#[from] distribution_filename::WheelFilenameError),
+
+    #[error("Failed to fetch metadata for: `{0}`")]
+    Metadata(Url),
 }
 
 /// Like [`RequirementsSpecification`], but with concrete names for all requirements.
@@ -72,6 +75,10 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
     }
 
     /// Resolve any unnamed requirements in the specification.
+    ///
+    /// Requirements are resolved concurrently, sharing the [`DistributionDatabase`] (and its
+    /// client), which bounds the number of concurrent downloads and builds. Named requirements
+    /// are returned as-is, without any network access. The order of the requirements is preserved.
     pub async fn resolve(self) -> Result<Vec<Requirement>, NamedRequirementsError> {
         let Self {
             requirements,
@@ -274,38 +281,37 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
             }),
         };
 
-        // Fetch the metadata for the distribution.
-        let name = {
-            let id = VersionId::from_url(source.url());
-            if let Some(archive) = index
-                .distributions()
-                .get(&id)
-                .as_deref()
-                .and_then(|response| {
-                    if let MetadataResponse::Found(archive) = response {
-                        Some(archive)
-                    } else {
-                        None
-                    }
-                })
-            {
-                // If the metadata is already in the index, return it.
-                archive.metadata.name.clone()
-            } else {
-                // Run the PEP 517 build process to extract metadata from the source distribution.
-                let hashes = hasher.get_url(source.url());
-                let source = BuildableSource::Url(source);
-                let archive = database.build_wheel_metadata(&source, hashes).await?;
+        // Fetch the metadata for the distribution. If the same distribution was already registered
+        // by a concurrent request, wait for it rather than building the metadata twice.
+        let id = VersionId::from_url(source.url());
+        let name = if index.distributions().register(id.clone()) {
+            // Run the PEP 517 build process to extract metadata from the source distribution.
+            let hashes = hasher.get_url(source.url());
+            let source = BuildableSource::Url(source);
+            let archive = match database.build_wheel_metadata(&source, hashes).await {
+                Ok(archive) => archive,
+                Err(err) => {
+                    // Release the request, so that any waiting requests don't hang.
+                    index.distributions().cancel(&id);
+                    return Err(err.into());
+                }
+            };
 
-                let name = archive.metadata.name.clone();
+            let name = archive.metadata.name.clone();
 
-                // Insert the metadata into the index.
-                index
-                    .distributions()
-                    .done(id, Arc::new(MetadataResponse::Found(archive)));
+            // Insert the metadata into the index, notifying any waiting requests.
+            index
+                .distributions()
+                .done(id, Arc::new(MetadataResponse::Found(archive)));
 
-                name
-            }
+            name
+        } else {
+            // If the request failed, the error is reported by the request that registered it.
+            let response = index.distributions().wait(&id).await;
+            let Some(MetadataResponse::Found(archive)) = response.as_deref() else {
+                return Err(NamedRequirementsError::Metadata(source.url().clone()));
+            };
+            archive.metadata.name.clone()
         };
 
         Ok(pep508_rs::Requirement {
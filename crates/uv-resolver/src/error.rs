@@ -140,6 +140,30 @@ impl NoSolutionError {
         }
     }
 
+    /// Returns the names of the packages that couldn't be fetched because network access is
+    /// disabled, and they weren't found in the cache.
+    pub fn offline_packages(&self) -> Vec<&PackageName> {
+        let mut packages = self
+            .unavailable_packages
+            .iter()
+            .filter(|(_, reason)| matches!(reason, UnavailablePackage::Offline))
+            .map(|(name, _)| name)
+            .chain(
+                self.incomplete_packages
+                    .iter()
+                    .filter(|(_, versions)| {
+                        versions
+                            .values()
+                            .any(|reason| matches!(reason, IncompletePackage::Offline))
+                    })
+                    .map(|(name, _)| name),
+            )
+            .collect::<Vec<_>>();
+        packages.sort_unstable();
+        packages.dedup();
+        packages
+    }
+
     pub(crate) fn new(
         error: pubgrub::error::NoSolutionError<UvDependencyProvider>,
         available_versions: FxHashMap<PubGrubPackage, BTreeSet<Version>>,
@@ -1,6 +1,9 @@
 use std::str::FromStr;
 
 use chrono::{DateTime, Days, NaiveDate, NaiveTime, Utc};
+use rustc_hash::FxHashMap;
+
+use distribution_types::IndexUrl;
 
 /// A timestamp that excludes files newer than it.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
@@ -52,6 +55,40 @@ impl std::fmt::Display for ExcludeNewer {
     }
 }
 
+/// Per-index overrides for [`ExcludeNewer`], keyed by index URL.
+///
+/// Indexes without an override fall back to the global [`ExcludeNewer`] timestamp, if any.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExcludeNewerIndex(FxHashMap<IndexUrl, ExcludeNewer>);
+
+impl ExcludeNewerIndex {
+    /// Returns the timestamp for the given index, if one was provided.
+    pub fn get(&self, index: &IndexUrl) -> Option<ExcludeNewer> {
+        self.0.get(index).copied()
+    }
+
+    /// Merge two sets of overrides, preferring the entries in `self`.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        for (index, exclude_newer) in other.0 {
+            self.0.entry(index).or_insert(exclude_newer);
+        }
+        self
+    }
+
+    /// Returns `true` if no overrides were provided.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<(IndexUrl, ExcludeNewer)> for ExcludeNewerIndex {
+    fn from_iter<T: IntoIterator<Item = (IndexUrl, ExcludeNewer)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl schemars::JsonSchema for ExcludeNewer {
     fn schema_name() -> String {
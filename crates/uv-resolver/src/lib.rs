@@ -1,9 +1,9 @@
 pub use dependency_mode::DependencyMode;
 pub use error::ResolveError;
-pub use exclude_newer::ExcludeNewer;
+pub use exclude_newer::{ExcludeNewer, ExcludeNewerIndex};
 pub use exclusions::Exclusions;
 pub use flat_index::FlatIndex;
-pub use lock::{Lock, LockError};
+pub use lock::{Lock, LockError, RequirementsTxtExport};
 pub use manifest::Manifest;
 pub use options::{Options, OptionsBuilder};
 pub use preferences::{Preference, PreferenceError, Preferences};
@@ -25,7 +25,7 @@ use distribution_types::{
     RemoteSource, Resolution, ResolvedDist, SourceDistCompatibility, ToUrlError, UrlString,
     VersionId, WheelCompatibility,
 };
-use pep440_rs::{Version, VersionSpecifier};
+use pep440_rs::{Version, VersionSpecifier, VersionSpecifiers};
 use pep508_rs::{
     ExtraOperator, MarkerEnvironment, MarkerExpression, MarkerTree, VerbatimUrl, VerbatimUrlError,
 };
@@ -33,7 +33,9 @@ use platform_tags::{TagCompatibility, TagPriority, Tags};
 use pypi_types::{
     HashDigest, ParsedArchiveUrl, ParsedGitUrl, ParsedUrl, Requirement, RequirementSource,
 };
-use uv_configuration::{ExtrasSpecification, Upgrade};
+use uv_configuration::{
+    DevSpecification, EditableMode, ExtrasSpecification, TargetTriple, Upgrade,
+};
 use uv_distribution::{ArchiveMetadata, Metadata};
 use uv_git::{GitReference, GitSha, RepositoryReference, ResolvedRepositoryReference};
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -46,6 +48,10 @@ use crate::{
     ResolutionMode, VersionMap, VersionsResponse,
 };
 
+pub use crate::lock::requirements_txt::RequirementsTxtExport;
+
+mod requirements_txt;
+
 /// The current version of the lock file format.
 const VERSION: u32 = 1;
 
@@ -56,6 +62,10 @@ pub struct Lock {
     distributions: Vec<Distribution>,
     /// The range of supported Python versions.
     requires_python: Option<RequiresPython>,
+    /// The Python range provided via `--python-range` to narrow the workspace `requires-python`.
+    python_range: Option<VersionSpecifiers>,
+    /// The platforms provided via `--python-platform` to restrict the locked platforms.
+    python_platforms: Vec<TargetTriple>,
     /// The [`ResolutionMode`] used to generate this lock.
     resolution_mode: ResolutionMode,
     /// The [`PreReleaseMode`] used to generate this lock.
@@ -151,11 +161,13 @@ impl Lock {
 
         let distributions = locked_dists.into_values().collect();
         let requires_python = graph.requires_python.clone();
-        let options = graph.options;
+        let options = &graph.options;
         let lock = Self::new(
             VERSION,
             distributions,
             requires_python,
+            None,
+            vec![],
             options.resolution_mode,
             options.prerelease_mode,
             options.exclude_newer,
@@ -168,6 +180,8 @@ impl Lock {
         version: u32,
         mut distributions: Vec<Distribution>,
         requires_python: Option<RequiresPython>,
+        python_range: Option<VersionSpecifiers>,
+        python_platforms: Vec<TargetTriple>,
         resolution_mode: ResolutionMode,
         prerelease_mode: PreReleaseMode,
         exclude_newer: Option<ExcludeNewer>,
@@ -326,6 +340,8 @@ impl Lock {
             version,
             distributions,
             requires_python,
+            python_range,
+            python_platforms,
             resolution_mode,
             prerelease_mode,
             exclude_newer,
@@ -338,6 +354,22 @@ impl Lock {
         &self.distributions
     }
 
+    /// Returns the names of the [`Distribution`] entries that depend on the given package, whether
+    /// as a production, optional, or development dependency.
+    pub fn dependents(&self, name: &PackageName) -> Vec<&PackageName> {
+        self.distributions
+            .iter()
+            .filter(|dist| {
+                dist.dependencies
+                    .iter()
+                    .chain(dist.optional_dependencies.values().flatten())
+                    .chain(dist.dev_dependencies.values().flatten())
+                    .any(|dep| dep.distribution_id.name == *name)
+            })
+            .map(Distribution::name)
+            .collect()
+    }
+
     /// Returns the owned [`Distribution`] entries in this lock.
     pub fn into_distributions(self) -> Vec<Distribution> {
         self.distributions
@@ -348,6 +380,30 @@ impl Lock {
         self.requires_python.as_ref()
     }
 
+    /// Returns the Python range used to narrow the workspace `requires-python`, if any.
+    pub fn python_range(&self) -> Option<&VersionSpecifiers> {
+        self.python_range.as_ref()
+    }
+
+    /// Record the Python range used to narrow the workspace `requires-python`.
+    #[must_use]
+    pub fn with_python_range(mut self, python_range: Option<VersionSpecifiers>) -> Self {
+        self.python_range = python_range;
+        self
+    }
+
+    /// Returns the platforms to which the lock is restricted, if any.
+    pub fn python_platforms(&self) -> &[TargetTriple] {
+        &self.python_platforms
+    }
+
+    /// Record the platforms to which the lock is restricted.
+    #[must_use]
+    pub fn with_python_platforms(mut self, python_platforms: Vec<TargetTriple>) -> Self {
+        self.python_platforms = python_platforms;
+        self
+    }
+
     /// Returns the resolution mode used to generate this lock.
     pub fn resolution_mode(&self) -> ResolutionMode {
         self.resolution_mode
@@ -363,6 +419,17 @@ impl Lock {
         self.exclude_newer
     }
 
+    /// Returns the URLs of the registries from which the locked distributions were resolved.
+    pub fn registries(&self) -> BTreeSet<&Url> {
+        self.distributions
+            .iter()
+            .filter_map(|dist| match dist.id.source {
+                Source::Registry(ref url) => Some(url),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Convert the [`Lock`] to a [`Resolution`] using the given marker environment, tags, and root.
     pub fn to_resolution(
         &self,
@@ -370,10 +437,63 @@ impl Lock {
         marker_env: &MarkerEnvironment,
         tags: &Tags,
         extras: &ExtrasSpecification,
-        dev: &[GroupName],
+        dev: &DevSpecification,
+        editable: EditableMode,
     ) -> Result<Resolution, LockError> {
+        let mut map = BTreeMap::default();
+        let mut hashes = BTreeMap::default();
+        let (dists, _) = self.walk(project, marker_env, extras, dev);
+        for dist in dists {
+            map.insert(
+                dist.id.name.clone(),
+                ResolvedDist::Installable(dist.to_dist(
+                    project.workspace().install_path(),
+                    tags,
+                    editable,
+                )?),
+            );
+            hashes.insert(dist.id.name.clone(), dist.hashes());
+        }
+        let diagnostics = vec![];
+        Ok(Resolution::new(map, hashes, diagnostics))
+    }
+
+    /// Returns the names of the [`Distribution`] entries that depend on the given package, limited
+    /// to the dependencies that would be installed for the given marker environment, extras, and
+    /// dependency groups (i.e., as in [`Lock::to_resolution`]).
+    pub fn installed_dependents(
+        &self,
+        project: &VirtualProject,
+        marker_env: &MarkerEnvironment,
+        extras: &ExtrasSpecification,
+        dev: &DevSpecification,
+        name: &PackageName,
+    ) -> Vec<&PackageName> {
+        let (_, edges) = self.walk(project, marker_env, extras, dev);
+        edges
+            .into_iter()
+            .filter(|(_, dependency)| dependency.id.name == *name)
+            .map(|(dependent, _)| dependent.name())
+            .unique()
+            .collect()
+    }
+
+    /// Walk the [`Distribution`] entries that would be installed for the given marker environment,
+    /// extras, and dependency groups.
+    ///
+    /// Returns the distributions, along with the `(dependent, dependency)` edges through which
+    /// they were reached.
+    fn walk(
+        &self,
+        project: &VirtualProject,
+        marker_env: &MarkerEnvironment,
+        extras: &ExtrasSpecification,
+        dev: &DevSpecification,
+    ) -> (Vec<&Distribution>, Vec<(&Distribution, &Distribution)>) {
         let mut queue: VecDeque<(&Distribution, Option<&ExtraName>)> = VecDeque::new();
         let mut seen = FxHashSet::default();
+        let mut dists = Vec::new();
+        let mut edges = Vec::new();
 
         // Add the workspace packages to the queue.
         for root_name in project.packages() {
@@ -382,38 +502,61 @@ impl Lock {
                 .expect("found too many distributions matching root")
                 .expect("could not find root");
 
-            // Add the base package.
-            queue.push_back((root, None));
-
-            // Add any extras.
-            match extras {
-                ExtrasSpecification::None => {}
-                ExtrasSpecification::All => {
-                    for extra in root.optional_dependencies.keys() {
-                        queue.push_back((root, Some(extra)));
+            if dev.only() {
+                // Add the base package itself, but omit its dependencies; instead, treat each of
+                // its dependency groups as a separate root.
+                dists.push(root);
+            } else {
+                // Add the base package.
+                queue.push_back((root, None));
+
+                // Add any extras.
+                match extras {
+                    ExtrasSpecification::None => {}
+                    ExtrasSpecification::All => {
+                        for extra in root.optional_dependencies.keys() {
+                            queue.push_back((root, Some(extra)));
+                        }
+                    }
+                    ExtrasSpecification::Some(extras) => {
+                        for extra in extras {
+                            queue.push_back((root, Some(extra)));
+                        }
                     }
                 }
-                ExtrasSpecification::Some(extras) => {
-                    for extra in extras {
-                        queue.push_back((root, Some(extra)));
+            }
+
+            // Add the root's dependency groups. Groups are scoped to the workspace member that
+            // declares them, so the groups of any members reached transitively are omitted.
+            for dep in dev
+                .groups()
+                .flat_map(|group| root.dev_dependencies.get(group).into_iter().flatten())
+            {
+                if dep
+                    .marker
+                    .as_ref()
+                    .map_or(true, |marker| marker.evaluate(marker_env, &[]))
+                {
+                    let dep_dist = self.find_by_id(&dep.distribution_id);
+                    edges.push((root, dep_dist));
+                    if seen.insert((&dep.distribution_id, None)) {
+                        queue.push_back((dep_dist, None));
+                    }
+                    for extra in &dep.extra {
+                        if seen.insert((&dep.distribution_id, Some(extra))) {
+                            queue.push_back((dep_dist, Some(extra)));
+                        }
                     }
                 }
             }
         }
 
-        let mut map = BTreeMap::default();
-        let mut hashes = BTreeMap::default();
         while let Some((dist, extra)) = queue.pop_front() {
-            let deps =
-                if let Some(extra) = extra {
-                    Either::Left(dist.optional_dependencies.get(extra).into_iter().flatten())
-                } else {
-                    Either::Right(dist.dependencies.iter().chain(
-                        dev.iter().flat_map(|group| {
-                            dist.dev_dependencies.get(group).into_iter().flatten()
-                        }),
-                    ))
-                };
+            let deps = if let Some(extra) = extra {
+                Either::Left(dist.optional_dependencies.get(extra).into_iter().flatten())
+            } else {
+                Either::Right(dist.dependencies.iter())
+            };
             for dep in deps {
                 if dep
                     .marker
@@ -421,6 +564,7 @@ impl Lock {
                     .map_or(true, |marker| marker.evaluate(marker_env, &[]))
                 {
                     let dep_dist = self.find_by_id(&dep.distribution_id);
+                    edges.push((dist, dep_dist));
                     if seen.insert((&dep.distribution_id, None)) {
                         queue.push_back((dep_dist, None));
                     }
@@ -431,14 +575,10 @@ impl Lock {
                     }
                 }
             }
-            map.insert(
-                dist.id.name.clone(),
-                ResolvedDist::Installable(dist.to_dist(project.workspace().install_path(), tags)?),
-            );
-            hashes.insert(dist.id.name.clone(), dist.hashes());
+            dists.push(dist);
         }
-        let diagnostics = vec![];
-        Ok(Resolution::new(map, hashes, diagnostics))
+
+        (dists, edges)
     }
 
     /// Returns the TOML representation of this lock file.
@@ -451,6 +591,17 @@ impl Lock {
         if let Some(ref requires_python) = self.requires_python {
             doc.insert("requires-python", value(requires_python.to_string()));
         }
+        if let Some(ref python_range) = self.python_range {
+            doc.insert("python-range", value(python_range.to_string()));
+        }
+        if !self.python_platforms.is_empty() {
+            let python_platforms = self
+                .python_platforms
+                .iter()
+                .map(|platform| platform.as_str())
+                .collect::<Array>();
+            doc.insert("python-platforms", value(python_platforms));
+        }
 
         // Write the settings that were used to generate the resolution.
         // This enables us to invalidate the lockfile if the user changes
@@ -575,6 +726,10 @@ struct LockWire {
     distributions: Vec<DistributionWire>,
     requires_python: Option<RequiresPython>,
     #[serde(default)]
+    python_range: Option<VersionSpecifiers>,
+    #[serde(default)]
+    python_platforms: Vec<TargetTriple>,
+    #[serde(default)]
     resolution_mode: ResolutionMode,
     #[serde(default)]
     prerelease_mode: PreReleaseMode,
@@ -592,6 +747,8 @@ impl From<Lock> for LockWire {
                 .map(DistributionWire::from)
                 .collect(),
             requires_python: lock.requires_python,
+            python_range: lock.python_range,
+            python_platforms: lock.python_platforms,
             resolution_mode: lock.resolution_mode,
             prerelease_mode: lock.prerelease_mode,
             exclude_newer: lock.exclude_newer,
@@ -629,6 +786,8 @@ impl TryFrom<LockWire> for Lock {
             wire.version,
             distributions,
             wire.requires_python,
+            wire.python_range,
+            wire.python_platforms,
             wire.resolution_mode,
             wire.prerelease_mode,
             wire.exclude_newer,
@@ -702,7 +861,12 @@ impl Distribution {
     }
 
     /// Convert the [`Distribution`] to a [`Dist`] that can be used in installation.
-    fn to_dist(&self, workspace_root: &Path, tags: &Tags) -> Result<Dist, LockError> {
+    fn to_dist(
+        &self,
+        workspace_root: &Path,
+        tags: &Tags,
+        editable: EditableMode,
+    ) -> Result<Dist, LockError> {
         if let Some(best_wheel_index) = self.find_best_wheel(tags) {
             return match &self.id.source {
                 Source::Registry(url) => {
@@ -760,7 +924,13 @@ impl Distribution {
             };
         }
 
-        if let Some(sdist) = self.to_source_dist(workspace_root)? {
+        if let Some(mut sdist) = self.to_source_dist(workspace_root)? {
+            // If editables are disabled, build and install editable sources as regular wheels.
+            if editable == EditableMode::NonEditable {
+                if let distribution_types::SourceDist::Directory(ref mut dir_dist) = sdist {
+                    dir_dist.editable = false;
+                }
+            }
             return Ok(Dist::Source(sdist));
         }
 
@@ -1080,6 +1250,16 @@ impl Distribution {
         &self.id.name
     }
 
+    /// Returns the [`Version`] of the distribution.
+    pub fn version(&self) -> &Version {
+        &self.id.version
+    }
+
+    /// Returns the extras for which the [`Distribution`] has locked optional dependencies.
+    pub fn provides_extras(&self) -> impl Iterator<Item = &ExtraName> {
+        self.optional_dependencies.keys()
+    }
+
     /// Returns a [`VersionId`] for this package that can be used for resolution.
     pub fn version_id(&self, workspace_root: &Path) -> Result<VersionId, LockError> {
         match &self.id.source {
@@ -1091,6 +1271,11 @@ impl Distribution {
         }
     }
 
+    /// Returns `true` if the distribution is a direct URL dependency without any locked hashes.
+    pub fn is_unhashed_url(&self) -> bool {
+        matches!(self.id.source, Source::Direct(..)) && self.hashes().is_empty()
+    }
+
     /// Returns all the hashes associated with this [`Distribution`].
     fn hashes(&self) -> Vec<HashDigest> {
         let mut hashes = Vec::new();
@@ -0,0 +1,319 @@
+use std::collections::VecDeque;
+use std::fmt::Formatter;
+use std::path::{Path, PathBuf};
+
+use either::Either;
+use petgraph::algo::greedy_feedback_arc_set;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::{EdgeRef, Topo};
+use petgraph::{Direction, Graph};
+use rustc_hash::{FxHashMap, FxHashSet};
+use url::Url;
+
+use pep440_rs::Version;
+use pep508_rs::{MarkerEnvironment, MarkerTree};
+use pypi_types::{ParsedArchiveUrl, ParsedGitUrl};
+use uv_configuration::ExtrasSpecification;
+use uv_git::GitReference;
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_workspace::VirtualProject;
+
+use crate::lock::{serialize_path_with_dot, Distribution, Lock, LockError, Source};
+use crate::marker;
+
+type LockGraph<'lock> = Graph<&'lock Distribution, Option<MarkerTree>>;
+
+/// An export of a [`Lock`] that renders in `requirements.txt` format.
+#[derive(Debug)]
+pub struct RequirementsTxtExport<'lock> {
+    nodes: Vec<Node<'lock>>,
+    hashes: bool,
+}
+
+impl<'lock> RequirementsTxtExport<'lock> {
+    /// Construct a [`RequirementsTxtExport`] from the given [`Lock`], including the workspace
+    /// members of the given project, along with the requested extras and development dependency
+    /// groups.
+    pub fn from_lock(
+        lock: &'lock Lock,
+        project: &VirtualProject,
+        extras: &ExtrasSpecification,
+        dev: &[GroupName],
+        hashes: bool,
+    ) -> Result<Self, LockError> {
+        let mut petgraph = LockGraph::default();
+        let mut inverse = FxHashMap::default();
+        let mut roots = FxHashSet::default();
+
+        let mut queue: VecDeque<(&Distribution, Option<&ExtraName>)> = VecDeque::new();
+        let mut seen = FxHashSet::default();
+
+        // Add the workspace packages to the queue.
+        for root_name in project.packages() {
+            let root = lock
+                .find_by_name(root_name)
+                .expect("found too many distributions matching root")
+                .expect("could not find root");
+
+            // Add the base package, unless it was already added as a development dependency of
+            // another root.
+            let index = *inverse
+                .entry(&root.id)
+                .or_insert_with(|| petgraph.add_node(root));
+            roots.insert(index);
+            if seen.insert((&root.id, None)) {
+                queue.push_back((root, None));
+            }
+
+            // Add any extras.
+            match extras {
+                ExtrasSpecification::None => {}
+                ExtrasSpecification::All => {
+                    for extra in root.optional_dependencies.keys() {
+                        seen.insert((&root.id, Some(extra)));
+                        queue.push_back((root, Some(extra)));
+                    }
+                }
+                ExtrasSpecification::Some(extras) => {
+                    for extra in extras {
+                        seen.insert((&root.id, Some(extra)));
+                        queue.push_back((root, Some(extra)));
+                    }
+                }
+            }
+
+            // Add the root's development dependencies. Groups are scoped to the workspace member
+            // that declares them, so the groups of any members reached transitively are omitted.
+            for dep in dev
+                .iter()
+                .flat_map(|group| root.dev_dependencies.get(group).into_iter().flatten())
+            {
+                let dep_dist = lock.find_by_id(&dep.distribution_id);
+
+                // Add the dependency to the graph, along with an edge from the root.
+                let dep_index = *inverse
+                    .entry(&dep.distribution_id)
+                    .or_insert_with(|| petgraph.add_node(dep_dist));
+                petgraph.add_edge(index, dep_index, dep.marker.clone());
+
+                // Push its dependencies on the queue.
+                if seen.insert((&dep.distribution_id, None)) {
+                    queue.push_back((dep_dist, None));
+                }
+                for extra in &dep.extra {
+                    if seen.insert((&dep.distribution_id, Some(extra))) {
+                        queue.push_back((dep_dist, Some(extra)));
+                    }
+                }
+            }
+        }
+
+        while let Some((dist, extra)) = queue.pop_front() {
+            let index = inverse[&dist.id];
+
+            let deps = if let Some(extra) = extra {
+                Either::Left(dist.optional_dependencies.get(extra).into_iter().flatten())
+            } else {
+                Either::Right(dist.dependencies.iter())
+            };
+
+            for dep in deps {
+                let dep_dist = lock.find_by_id(&dep.distribution_id);
+
+                // Add the dependency to the graph, along with an edge from its dependent.
+                let dep_index = *inverse
+                    .entry(&dep.distribution_id)
+                    .or_insert_with(|| petgraph.add_node(dep_dist));
+                petgraph.add_edge(index, dep_index, dep.marker.clone());
+
+                // Push its dependencies on the queue.
+                if seen.insert((&dep.distribution_id, None)) {
+                    queue.push_back((dep_dist, None));
+                }
+                for extra in &dep.extra {
+                    if seen.insert((&dep.distribution_id, Some(extra))) {
+                        queue.push_back((dep_dist, Some(extra)));
+                    }
+                }
+            }
+        }
+
+        // Propagate markers across the graph, and sort the nodes for deterministic output.
+        let mut nodes = propagate_markers(petgraph, &roots);
+        nodes.sort_unstable_by(|a, b| {
+            (&a.distribution.id.name, &a.distribution.id.version)
+                .cmp(&(&b.distribution.id.name, &b.distribution.id.version))
+        });
+
+        Ok(Self { nodes, hashes })
+    }
+
+    /// Omit any packages that aren't required in the given environment, i.e., those whose markers
+    /// don't apply to it.
+    #[must_use]
+    pub fn with_markers(mut self, markers: &MarkerEnvironment) -> Self {
+        self.nodes.retain(|node| {
+            node.marker
+                .as_ref()
+                .map_or(true, |marker| marker.evaluate(markers, &[]))
+        });
+        self
+    }
+
+    /// Omit the given packages (e.g., the workspace members) from the export, retaining their
+    /// dependencies.
+    #[must_use]
+    pub fn without_packages<'a>(
+        mut self,
+        packages: impl IntoIterator<Item = &'a PackageName>,
+    ) -> Self {
+        let packages = packages.into_iter().collect::<FxHashSet<_>>();
+        self.nodes
+            .retain(|node| !packages.contains(&node.distribution.id.name));
+        self
+    }
+
+    /// Returns an iterator over the name and version of each exported package.
+    pub fn packages(&self) -> impl Iterator<Item = (&PackageName, &Version)> {
+        self.nodes
+            .iter()
+            .map(|node| (&node.distribution.id.name, &node.distribution.id.version))
+    }
+}
+
+impl std::fmt::Display for RequirementsTxtExport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for Node {
+            distribution,
+            marker,
+        } in &self.nodes
+        {
+            let name = &distribution.id.name;
+            match &distribution.id.source {
+                Source::Registry(_) => {
+                    write!(f, "{name}=={}", distribution.id.version)?;
+                }
+                Source::Git(url, git) => {
+                    // Reconstruct the PEP 508-compatible URL from the `GitSource`.
+                    let git_url =
+                        uv_git::GitUrl::new(url.clone(), GitReference::from(git.kind.clone()))
+                            .with_precise(git.precise);
+                    let url = Url::from(ParsedGitUrl {
+                        url: git_url,
+                        subdirectory: git.subdirectory.as_ref().map(PathBuf::from),
+                    });
+                    write!(f, "{name} @ {url}")?;
+                }
+                Source::Direct(url, direct) => {
+                    let url = Url::from(ParsedArchiveUrl {
+                        url: url.clone(),
+                        subdirectory: direct.subdirectory.as_ref().map(PathBuf::from),
+                    });
+                    write!(f, "{name} @ {url}")?;
+                }
+                Source::Path(path) | Source::Directory(path) => {
+                    write!(f, "{}", anchor(path))?;
+                }
+                Source::Editable(path) => {
+                    write!(f, "-e {}", anchor(path))?;
+                }
+            }
+
+            if let Some(marker) = marker {
+                write!(f, " ; {marker}")?;
+            }
+
+            if self.hashes {
+                for hash in distribution.hashes() {
+                    write!(f, " \\\n    --hash={hash}")?;
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A node in the exported graph, along with the markers under which it should be installed.
+#[derive(Debug)]
+struct Node<'lock> {
+    distribution: &'lock Distribution,
+    marker: Option<MarkerTree>,
+}
+
+/// Propagate the [`MarkerTree`] qualifiers across the graph, returning each node along with its
+/// combined marker.
+///
+/// Workspace members (the roots of the graph) are always included, and so are never qualified.
+fn propagate_markers<'lock>(
+    mut graph: LockGraph<'lock>,
+    roots: &FxHashSet<NodeIndex>,
+) -> Vec<Node<'lock>> {
+    // Remove any cycles. By absorption, it should be fine to ignore cycles (see the analogous
+    // routine for `DisplayResolutionGraph`).
+    let mut fas = greedy_feedback_arc_set(&graph)
+        .map(|edge| edge.id())
+        .collect::<Vec<_>>();
+    fas.sort_unstable();
+    for edge_id in fas.into_iter().rev() {
+        graph.remove_edge(edge_id);
+    }
+
+    let mut nodes = Vec::with_capacity(graph.node_count());
+
+    let mut topo = Topo::new(&graph);
+    while let Some(index) = topo.next(&graph) {
+        let marker_tree: Option<MarkerTree> = if roots.contains(&index) {
+            None
+        } else {
+            // Fold over the edges to combine the marker trees. If any edge is `None`, then
+            // the combined marker tree is `None`.
+            let mut edges = graph.edges_directed(index, Direction::Incoming);
+            edges
+                .next()
+                .and_then(|edge| graph.edge_weight(edge.id()).cloned().flatten())
+                .and_then(|initial| {
+                    edges.try_fold(initial, |mut acc, edge| {
+                        acc.or(graph.edge_weight(edge.id())?.clone()?);
+                        Some(acc)
+                    })
+                })
+        };
+
+        // Propagate the marker tree to all downstream nodes.
+        if let Some(marker_tree) = marker_tree.as_ref() {
+            let mut walker = graph
+                .neighbors_directed(index, Direction::Outgoing)
+                .detach();
+            while let Some((outgoing, _)) = walker.next(&graph) {
+                if let Some(weight) = graph.edge_weight_mut(outgoing) {
+                    if let Some(weight) = weight {
+                        weight.and(marker_tree.clone());
+                    } else {
+                        *weight = Some(marker_tree.clone());
+                    }
+                }
+            }
+        }
+
+        nodes.push(Node {
+            distribution: graph[index],
+            marker: marker_tree.and_then(|marker| marker::normalize(marker, None)),
+        });
+    }
+
+    nodes
+}
+
+/// Format a path from the lockfile (relative to the workspace root) such that `pip` recognizes it
+/// as a local path, rather than a package name.
+fn anchor(path: &Path) -> String {
+    let path = serialize_path_with_dot(path);
+    if path.starts_with('.') || Path::new(path.as_ref()).is_absolute() {
+        path.into_owned()
+    } else {
+        format!("./{path}")
+    }
+}
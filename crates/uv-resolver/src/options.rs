@@ -1,15 +1,18 @@
 use uv_configuration::IndexStrategy;
+use uv_normalize::PackageName;
 
-use crate::{DependencyMode, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use crate::{DependencyMode, ExcludeNewer, ExcludeNewerIndex, PreReleaseMode, ResolutionMode};
 
 /// Options for resolving a manifest.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
     pub prerelease_mode: PreReleaseMode,
     pub dependency_mode: DependencyMode,
     pub exclude_newer: Option<ExcludeNewer>,
+    pub exclude_newer_index: ExcludeNewerIndex,
     pub index_strategy: IndexStrategy,
+    pub exclude_dependencies: Vec<PackageName>,
 }
 
 /// Builder for [`Options`].
@@ -19,7 +22,9 @@ pub struct OptionsBuilder {
     prerelease_mode: PreReleaseMode,
     dependency_mode: DependencyMode,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_index: ExcludeNewerIndex,
     index_strategy: IndexStrategy,
+    exclude_dependencies: Vec<PackageName>,
 }
 
 impl OptionsBuilder {
@@ -56,6 +61,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the per-index exclusion dates, which take precedence over the global exclusion date.
+    #[must_use]
+    pub fn exclude_newer_index(mut self, exclude_newer_index: ExcludeNewerIndex) -> Self {
+        self.exclude_newer_index = exclude_newer_index;
+        self
+    }
+
     /// Sets the index strategy.
     #[must_use]
     pub fn index_strategy(mut self, index_strategy: IndexStrategy) -> Self {
@@ -63,6 +75,14 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the dependencies to omit from the root requirements (e.g., the direct dependencies of
+    /// the workspace members, when locking).
+    #[must_use]
+    pub fn exclude_dependencies(mut self, exclude_dependencies: Vec<PackageName>) -> Self {
+        self.exclude_dependencies = exclude_dependencies;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -70,7 +90,9 @@ impl OptionsBuilder {
             prerelease_mode: self.prerelease_mode,
             dependency_mode: self.dependency_mode,
             exclude_newer: self.exclude_newer,
+            exclude_newer_index: self.exclude_newer_index,
             index_strategy: self.index_strategy,
+            exclude_dependencies: self.exclude_dependencies,
         }
     }
 }
@@ -89,6 +89,37 @@ impl RequiresPython {
         Ok(Some(Self { specifiers, bound }))
     }
 
+    /// Returns a [`RequiresPython`] to express the intersection with the given version specifiers,
+    /// or `None` if the two are disjoint.
+    ///
+    /// For example, given `>=3.8` and `>=3.10`, this would return `>=3.10`.
+    pub fn intersection(
+        &self,
+        specifiers: &VersionSpecifiers,
+    ) -> Result<Option<Self>, RequiresPythonError> {
+        // Convert to PubGrub ranges and perform an intersection.
+        let range = Range::from(crate::pubgrub::PubGrubSpecifier::from_release_specifiers(
+            &self.specifiers,
+        )?)
+        .intersection(
+            &crate::pubgrub::PubGrubSpecifier::from_release_specifiers(specifiers)?.into(),
+        );
+
+        // Extract the lower bound.
+        let Some((lower, _)) = range.iter().next() else {
+            return Ok(None);
+        };
+        let bound = RequiresPythonBound(lower.clone());
+
+        // Convert back to PEP 440 specifiers.
+        let specifiers = range
+            .iter()
+            .flat_map(VersionSpecifier::from_bounds)
+            .collect();
+
+        Ok(Some(Self { specifiers, bound }))
+    }
+
     /// Narrow the [`RequiresPython`] to the given version, if it's stricter (i.e., greater) than
     /// the current target.
     pub fn narrow(&self, target: &RequiresPythonBound) -> Option<Self> {
@@ -458,4 +489,32 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn requires_python_intersection() {
+        let version_specifiers = VersionSpecifiers::from_str(">=3.8").unwrap();
+        let requires_python = RequiresPython::union(std::iter::once(&version_specifiers))
+            .unwrap()
+            .unwrap();
+
+        // A narrower range is retained.
+        let narrowed = requires_python
+            .intersection(&VersionSpecifiers::from_str(">=3.10").unwrap())
+            .unwrap()
+            .unwrap();
+        assert_eq!(narrowed.to_string(), ">=3.10");
+
+        // A wider range leaves the original range unchanged.
+        let widened = requires_python
+            .intersection(&VersionSpecifiers::from_str(">=3.7").unwrap())
+            .unwrap()
+            .unwrap();
+        assert_eq!(widened.to_string(), ">=3.8");
+
+        // A disjoint range produces no intersection.
+        let disjoint = requires_python
+            .intersection(&VersionSpecifiers::from_str("<3.8").unwrap())
+            .unwrap();
+        assert!(disjoint.is_none());
+    }
 }
@@ -462,6 +462,12 @@ fn combine_extras(graph: &IntermediatePetGraph) -> RequirementsTxtGraph {
         let source = inverse[&source_node.version_id()];
         let target = inverse[&target_node.version_id()];
 
+        // Omit self-referential extras (e.g., `foo[all]` depending on `foo[bar]`), which are
+        // combined into a single node.
+        if source == target {
+            continue;
+        }
+
         // If either the existing marker or new marker is `None`, then the dependency is
         // included unconditionally, and so the combined marker should be `None`.
         if let Some(edge) = next
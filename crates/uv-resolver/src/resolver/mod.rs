@@ -170,6 +170,7 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             ),
             hasher,
             options.exclude_newer,
+            options.exclude_newer_index.clone(),
             build_context.build_options(),
         );
 
@@ -206,7 +207,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             index: index.clone(),
             git: git.clone(),
             selector: CandidateSelector::for_resolution(
-                options,
+                &options,
                 &manifest,
                 markers.marker_environment(),
             ),
@@ -584,7 +585,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             &self.index,
             &self.git,
             &self.python_requirement,
-            self.options,
+            self.options.clone(),
         )
     }
 
@@ -1296,6 +1297,25 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     requires_python,
                 );
 
+                // Omit any excluded dependencies of the root requirements.
+                let requirements = if !self.options.exclude_dependencies.is_empty()
+                    && self.requirements.iter().any(|req| req.name == *name)
+                {
+                    requirements
+                        .into_iter()
+                        .filter(|req| {
+                            if self.options.exclude_dependencies.contains(&req.name) {
+                                debug!("Excluding dependency of {name}: {req}");
+                                false
+                            } else {
+                                true
+                            }
+                        })
+                        .collect()
+                } else {
+                    requirements
+                };
+
                 let mut dependencies = requirements
                     .iter()
                     .flat_map(|requirement| {
@@ -2236,7 +2256,12 @@ impl ForkState {
                         dev: ref dependency_dev,
                         ..
                     } => {
-                        if self_name.is_some_and(|self_name| self_name == dependency_name) {
+                        // Skip the edge from an extra to its base package, but retain
+                        // self-referential extras (e.g., `foo[all]` depending on `foo[bar]`).
+                        if self_name.is_some_and(|self_name| self_name == dependency_name)
+                            && (dependency_extra.is_none()
+                                || dependency_extra.as_ref() == self_extra)
+                        {
                             continue;
                         }
                         let to_url = self.fork_urls.get(dependency_name);
@@ -2287,7 +2312,9 @@ impl ForkState {
                         marker: ref dependency_marker,
                         ..
                     } => {
-                        if self_name.is_some_and(|self_name| self_name == dependency_name) {
+                        if self_name.is_some_and(|self_name| self_name == dependency_name)
+                            && self_extra == Some(dependency_extra)
+                        {
                             continue;
                         }
                         let to_url = self.fork_urls.get(dependency_name);
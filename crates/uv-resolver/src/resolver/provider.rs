@@ -10,7 +10,7 @@ use uv_types::{BuildContext, HashStrategy};
 use crate::flat_index::FlatIndex;
 use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
-use crate::{ExcludeNewer, RequiresPython};
+use crate::{ExcludeNewer, ExcludeNewerIndex, RequiresPython};
 
 pub type PackageVersionsResult = Result<VersionsResponse, uv_client::Error>;
 pub type WheelMetadataResult = Result<MetadataResponse, uv_distribution::Error>;
@@ -80,6 +80,7 @@ pub struct DefaultResolverProvider<'a, Context: BuildContext> {
     allowed_yanks: AllowedYanks,
     hasher: HashStrategy,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_index: ExcludeNewerIndex,
     build_options: &'a BuildOptions,
 }
 
@@ -93,6 +94,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
         allowed_yanks: AllowedYanks,
         hasher: &'a HashStrategy,
         exclude_newer: Option<ExcludeNewer>,
+        exclude_newer_index: ExcludeNewerIndex,
         build_options: &'a BuildOptions,
     ) -> Self {
         Self {
@@ -103,6 +105,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
             allowed_yanks,
             hasher: hasher.clone(),
             exclude_newer,
+            exclude_newer_index,
             build_options,
         }
     }
@@ -133,7 +136,10 @@ impl<'a, Context: BuildContext> ResolverProvider for DefaultResolverProvider<'a,
                             self.requires_python.as_ref(),
                             &self.allowed_yanks,
                             &self.hasher,
-                            self.exclude_newer.as_ref(),
+                            self.exclude_newer_index
+                                .get(&index)
+                                .or(self.exclude_newer)
+                                .as_ref(),
                             self.flat_index.get(package_name).cloned(),
                             self.build_options,
                         )
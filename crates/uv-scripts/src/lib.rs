@@ -1,5 +1,6 @@
 use std::io;
-use std::path::Path;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 
 use memchr::memmem::Finder;
 use once_cell::sync::Lazy;
@@ -27,6 +28,8 @@ pub enum Pep723Error {
     Utf8(#[from] std::str::Utf8Error),
     #[error(transparent)]
     Toml(#[from] toml::de::Error),
+    #[error("The `# /// script` metadata block is not closed by a `# ///` line")]
+    UnclosedBlock,
 }
 
 /// Read the PEP 723 `script` metadata from a Python file, if it exists.
@@ -52,93 +55,340 @@ pub async fn read_pep723_metadata(
     Ok(Some(metadata))
 }
 
+/// A Python script with PEP 723 metadata, split around its `script` block such that the block can
+/// be rewritten without modifying the rest of the file.
+#[derive(Debug)]
+pub struct Pep723Script {
+    /// The path to the Python script.
+    pub path: PathBuf,
+    /// The parsed [`Pep723Metadata`] from the `script` block.
+    pub metadata: Pep723Metadata,
+    /// The raw TOML content of the `script` block.
+    pub raw: String,
+    /// The content preceding the `script` block.
+    prelude: String,
+    /// The content following the `script` block.
+    postlude: String,
+    /// The line ending used by the script.
+    line_ending: LineEnding,
+}
+
+impl Pep723Script {
+    /// Read the PEP 723 `script` metadata from a Python file, if it exists.
+    ///
+    /// Unlike [`read_pep723_metadata`], returns an error if the block is malformed, as it's
+    /// unsafe to rewrite a block whose extent is unknown.
+    pub async fn read(file: impl AsRef<Path>) -> Result<Option<Self>, Pep723Error> {
+        let contents = fs_err::tokio::read(file.as_ref()).await?;
+
+        let Some(tag) = ScriptTag::parse(&contents)? else {
+            return Ok(None);
+        };
+        let metadata = toml::from_str(&tag.metadata)?;
+
+        Ok(Some(Self {
+            path: file.as_ref().to_path_buf(),
+            metadata,
+            raw: tag.metadata,
+            prelude: tag.prelude,
+            postlude: tag.postlude,
+            line_ending: tag.line_ending,
+        }))
+    }
+
+    /// Initialize an empty `script` block for a Python file that does not yet contain one.
+    ///
+    /// The block is placed after the shebang, if any. The file is not modified until
+    /// [`Pep723Script::write`] is called.
+    pub async fn init(
+        file: impl AsRef<Path>,
+        requires_python: &pep440_rs::VersionSpecifiers,
+    ) -> Result<Self, Pep723Error> {
+        let contents = fs_err::tokio::read(file.as_ref()).await?;
+        let contents = std::str::from_utf8(&contents)?;
+        let line_ending = LineEnding::detect(contents);
+
+        // Preserve the shebang as the first line.
+        let (prelude, body) = if contents.starts_with("#!") {
+            contents.split_at(
+                contents
+                    .find('\n')
+                    .map_or(contents.len(), |index| index + 1),
+            )
+        } else {
+            ("", contents)
+        };
+        let prelude = if prelude.is_empty() || prelude.ends_with('\n') {
+            prelude.to_string()
+        } else {
+            format!("{prelude}{}", line_ending.as_str())
+        };
+
+        // Separate the block from the script body with a blank line.
+        let postlude = if body.is_empty() {
+            String::new()
+        } else {
+            format!("{}{body}", line_ending.as_str())
+        };
+
+        let raw = format!("requires-python = \"{requires_python}\"\ndependencies = []\n");
+        let metadata = toml::from_str(&raw)?;
+
+        Ok(Self {
+            path: file.as_ref().to_path_buf(),
+            metadata,
+            raw,
+            prelude,
+            postlude,
+            line_ending,
+        })
+    }
+
+    /// Replace the `script` block with the given TOML content, leaving the rest of the file as-is.
+    pub async fn write(&self, metadata: &str) -> Result<(), Pep723Error> {
+        let contents = format!(
+            "{}{}{}",
+            self.prelude,
+            serialize_metadata(metadata, self.line_ending),
+            self.postlude
+        );
+        fs_err::tokio::write(&self.path, contents).await?;
+        Ok(())
+    }
+}
+
+/// A Python interpreter requested via a script's shebang, e.g., `#!/usr/bin/env python3.11`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PythonShebang {
+    /// The version suffix of the requested interpreter (e.g., `3.11` in `python3.11`), if any.
+    pub version: Option<String>,
+}
+
+/// Read the Python shebang from a file, if it exists.
+///
+/// Returns `None` if the file does not start with a shebang, or if the shebang does not refer to
+/// a Python interpreter.
+pub fn read_python_shebang(file: impl AsRef<Path>) -> Result<Option<PythonShebang>, io::Error> {
+    let mut contents = Vec::new();
+    match fs_err::File::open(file.as_ref()) {
+        // The shebang is limited to the first line, so avoid reading large (e.g., binary) files.
+        Ok(file) => file.take(512).read_to_end(&mut contents)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    Ok(extract_python_shebang(&contents))
+}
+
+/// Given the contents of a file, extract the Python interpreter requested by its shebang.
+fn extract_python_shebang(contents: &[u8]) -> Option<PythonShebang> {
+    let line = contents.strip_prefix(b"#!")?;
+    let line = line.split(|byte| *byte == b'\n').next()?;
+    let line = std::str::from_utf8(line).ok()?.trim();
+
+    // Find the interpreter, skipping any launcher (e.g., `/usr/bin/env -S`) and its flags.
+    let interpreter = line
+        .split_whitespace()
+        .map(|token| token.rsplit(['/', '\\']).next().unwrap_or(token))
+        .find(|name| name.starts_with("python"))?;
+
+    // Extract the version suffix, e.g., `3.11` from `python3.11`.
+    let suffix = interpreter.strip_prefix("python")?;
+    let suffix = suffix.strip_suffix(".exe").unwrap_or(suffix);
+    if suffix.is_empty() {
+        return Some(PythonShebang { version: None });
+    }
+    if suffix
+        .split('.')
+        .all(|part| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit()))
+    {
+        return Some(PythonShebang {
+            version: Some(suffix.to_string()),
+        });
+    }
+
+    // e.g., `python-config`.
+    None
+}
+
 /// Given the contents of a Python file, extract the `script` metadata block, with leading comment
 /// hashes removed.
 ///
 /// See: <https://peps.python.org/pep-0723/>
 fn extract_script_tag(contents: &[u8]) -> Result<Option<String>, Pep723Error> {
-    // Identify the opening pragma.
-    let Some(index) = FINDER.find(contents) else {
-        return Ok(None);
-    };
-
-    // The opening pragma must be the first line, or immediately preceded by a newline.
-    if !(index == 0 || matches!(contents[index - 1], b'\r' | b'\n')) {
-        return Ok(None);
+    match ScriptTag::parse(contents) {
+        Ok(tag) => Ok(tag.map(|tag| tag.metadata)),
+        // For reads, an unterminated block is treated as if there were no block at all.
+        Err(Pep723Error::UnclosedBlock) => Ok(None),
+        Err(err) => Err(err),
     }
+}
 
-    // Decode as UTF-8.
-    let contents = &contents[index..];
-    let contents = std::str::from_utf8(contents)?;
+/// The line ending used by a Python file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum LineEnding {
+    Lf,
+    CrLf,
+}
 
-    let mut lines = contents.lines();
+impl LineEnding {
+    /// Detect the line ending from the contents of a file, defaulting to `\n`.
+    fn detect(contents: &str) -> Self {
+        if contents.contains("\r\n") {
+            Self::CrLf
+        } else {
+            Self::Lf
+        }
+    }
 
-    // Ensure that the first line is exactly `# /// script`.
-    if !lines.next().is_some_and(|line| line == "# /// script") {
-        return Ok(None);
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
     }
+}
+
+/// A Python file split around its `script` metadata block.
+#[derive(Debug)]
+struct ScriptTag {
+    /// The content preceding the `# /// script` line.
+    prelude: String,
+    /// The TOML content of the block, with leading comment hashes removed.
+    metadata: String,
+    /// The content following the closing `# ///` line.
+    postlude: String,
+    /// The line ending used by the block.
+    line_ending: LineEnding,
+}
 
-    // > Every line between these two lines (# /// TYPE and # ///) MUST be a comment starting
-    // > with #. If there are characters after the # then the first character MUST be a space. The
-    // > embedded content is formed by taking away the first two characters of each line if the
-    // > second character is a space, otherwise just the first character (which means the line
-    // > consists of only a single #).
-    let mut toml = vec![];
-    for line in lines {
-        // Remove the leading `#`.
-        let Some(line) = line.strip_prefix('#') else {
-            break;
+impl ScriptTag {
+    /// Split the contents of a Python file around its `script` metadata block.
+    ///
+    /// Returns `None` if the file does not contain a block, and an error if the block is opened,
+    /// but never closed.
+    fn parse(contents: &[u8]) -> Result<Option<Self>, Pep723Error> {
+        // Identify the opening pragma.
+        let Some(index) = FINDER.find(contents) else {
+            return Ok(None);
         };
 
-        // If the line is empty, continue.
-        if line.is_empty() {
-            toml.push("");
-            continue;
+        // The opening pragma must be the first line, or immediately preceded by a newline.
+        if !(index == 0 || matches!(contents[index - 1], b'\r' | b'\n')) {
+            return Ok(None);
         }
 
-        // Otherwise, the line _must_ start with ` `.
-        let Some(line) = line.strip_prefix(' ') else {
-            break;
+        // Decode as UTF-8.
+        let prelude = std::str::from_utf8(&contents[..index])?;
+        let contents = std::str::from_utf8(&contents[index..])?;
+
+        let mut lines = contents.split_inclusive('\n');
+
+        // Ensure that the first line is exactly `# /// script`.
+        let Some(first) = lines.next() else {
+            return Ok(None);
+        };
+        if first.trim_end_matches(['\r', '\n']) != "# /// script" {
+            return Ok(None);
+        }
+        let line_ending = if first.ends_with("\r\n") {
+            LineEnding::CrLf
+        } else {
+            LineEnding::Lf
         };
-        toml.push(line);
+
+        // > Every line between these two lines (# /// TYPE and # ///) MUST be a comment
+        // > starting with #. If there are characters after the # then the first character MUST
+        // > be a space. The embedded content is formed by taking away the first two characters of
+        // > each line if the second character is a space, otherwise just the first character
+        // > (which means the line consists of only a single #).
+        let mut toml = vec![];
+        let mut offset = first.len();
+
+        // Find the closing `# ///`. The precedence is such that we need to identify the _last_
+        // such line.
+        //
+        // For example, given:
+        // ```python
+        // # /// script
+        // #
+        // # ///
+        // #
+        // # ///
+        // ```
+        //
+        // The latter `///` is the closing pragma
+        let mut closing = None;
+        for line in lines {
+            // Remove the leading `#`.
+            let Some(content) = line.trim_end_matches(['\r', '\n']).strip_prefix('#') else {
+                break;
+            };
+
+            // If the line is empty, continue. Otherwise, the line _must_ start with ` `.
+            let content = if content.is_empty() {
+                content
+            } else if let Some(content) = content.strip_prefix(' ') {
+                content
+            } else {
+                break;
+            };
+
+            offset += line.len();
+            if content == "///" {
+                closing = Some((toml.len(), offset));
+            }
+            toml.push(content);
+        }
+
+        let Some((len, end)) = closing else {
+            return Err(Pep723Error::UnclosedBlock);
+        };
+
+        // Discard the closing `# ///`, along with any lines after it.
+        //
+        // For example, given:
+        // ```python
+        // # /// script
+        // #
+        // # ///
+        // #
+        // #
+        // ```
+        //
+        // We need to discard the last two lines.
+        toml.truncate(len);
+
+        // Join the lines into a single string.
+        let metadata = toml.join("\n") + "\n";
+
+        Ok(Some(Self {
+            prelude: prelude.to_string(),
+            metadata,
+            postlude: contents[end..].to_string(),
+            line_ending,
+        }))
     }
+}
 
-    // Find the closing `# ///`. The precedence is such that we need to identify the _last_ such
-    // line.
-    //
-    // For example, given:
-    // ```python
-    // # /// script
-    // #
-    // # ///
-    // #
-    // # ///
-    // ```
-    //
-    // The latter `///` is the closing pragma
-    let Some(index) = toml.iter().rev().position(|line| *line == "///") else {
-        return Ok(None);
-    };
-    let index = toml.len() - index;
-
-    // Discard any lines after the closing `# ///`.
-    //
-    // For example, given:
-    // ```python
-    // # /// script
-    // #
-    // # ///
-    // #
-    // #
-    // ```
-    //
-    // We need to discard the last two lines.
-    toml.truncate(index - 1);
-
-    // Join the lines into a single string.
-    let toml = toml.join("\n") + "\n";
-
-    Ok(Some(toml))
+/// Serialize TOML content as a `script` metadata block, using the given line ending.
+fn serialize_metadata(metadata: &str, line_ending: LineEnding) -> String {
+    let line_ending = line_ending.as_str();
+
+    let mut output = String::with_capacity(metadata.len() + 32);
+    output.push_str("# /// script");
+    output.push_str(line_ending);
+    for line in metadata.lines() {
+        if line.is_empty() {
+            output.push('#');
+        } else {
+            output.push_str("# ");
+            output.push_str(line);
+        }
+        output.push_str(line_ending);
+    }
+    output.push_str("# ///");
+    output.push_str(line_ending);
+    output
 }
 
 #[cfg(test)]
@@ -280,4 +530,89 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn round_trip_crlf() {
+        let contents = concat!(
+            "#!/usr/bin/env python3\r\n",
+            "# /// script\r\n",
+            "# requires-python = '>=3.11'\r\n",
+            "#\r\n",
+            "# dependencies = []\r\n",
+            "# ///\r\n",
+            "\r\n",
+            "import os\r\n",
+        );
+
+        let tag = super::ScriptTag::parse(contents.as_bytes())
+            .unwrap()
+            .unwrap();
+        assert_eq!(tag.prelude, "#!/usr/bin/env python3\r\n");
+        assert_eq!(
+            tag.metadata,
+            "requires-python = '>=3.11'\n\ndependencies = []\n"
+        );
+        assert_eq!(tag.postlude, "\r\nimport os\r\n");
+        assert_eq!(tag.line_ending, super::LineEnding::CrLf);
+
+        let rewritten = format!(
+            "{}{}{}",
+            tag.prelude,
+            super::serialize_metadata(&tag.metadata, tag.line_ending),
+            tag.postlude
+        );
+        assert_eq!(rewritten, contents);
+    }
+
+    #[test]
+    fn unclosed_block() {
+        let contents = indoc::indoc! {r"
+            # /// script
+            # dependencies = []
+
+            import os
+        "};
+
+        assert!(matches!(
+            super::ScriptTag::parse(contents.as_bytes()),
+            Err(super::Pep723Error::UnclosedBlock)
+        ));
+        assert_eq!(
+            super::extract_script_tag(contents.as_bytes()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn shebang_version() {
+        assert_eq!(
+            super::extract_python_shebang(b"#!/usr/bin/env python3.11\nprint('hello')\n"),
+            Some(super::PythonShebang {
+                version: Some("3.11".to_string())
+            })
+        );
+        assert_eq!(
+            super::extract_python_shebang(b"#!/usr/bin/python3 -u\n"),
+            Some(super::PythonShebang {
+                version: Some("3".to_string())
+            })
+        );
+        assert_eq!(
+            super::extract_python_shebang(b"#!/usr/bin/env -S python\n"),
+            Some(super::PythonShebang { version: None })
+        );
+    }
+
+    #[test]
+    fn shebang_non_python() {
+        assert_eq!(super::extract_python_shebang(b"#!/bin/sh\n"), None);
+        assert_eq!(
+            super::extract_python_shebang(b"#!/usr/bin/env python-config\n"),
+            None
+        );
+        assert_eq!(
+            super::extract_python_shebang(b"import sys\n#!/usr/bin/env python3.11\n"),
+            None
+        );
+    }
 }
@@ -5,7 +5,9 @@ use distribution_types::IndexUrl;
 use install_wheel_rs::linker::LinkMode;
 use uv_configuration::{ConfigSettings, IndexStrategy, KeyringProviderType, TargetTriple};
 use uv_python::{PythonFetch, PythonPreference, PythonVersion};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewer, ExcludeNewerIndex, PreReleaseMode, ResolutionMode,
+};
 
 use crate::{FilesystemOptions, PipOptions};
 
@@ -97,3 +99,14 @@ impl Combine for Option<ConfigSettings> {
         }
     }
 }
+
+impl Combine for Option<ExcludeNewerIndex> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`.
+    fn combine(self, other: Option<ExcludeNewerIndex>) -> Option<ExcludeNewerIndex> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.merge(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
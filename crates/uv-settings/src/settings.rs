@@ -7,12 +7,15 @@ use install_wheel_rs::linker::LinkMode;
 use pep508_rs::Requirement;
 use pypi_types::VerbatimParsedUrl;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier, TargetTriple,
+    AddBounds, ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
+    StrictCategory, TargetTriple,
 };
 use uv_macros::{CombineOptions, OptionsMetadata};
 use uv_normalize::{ExtraName, PackageName};
 use uv_python::{PythonFetch, PythonPreference, PythonVersion};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewer, ExcludeNewerIndex, PreReleaseMode, ResolutionMode,
+};
 
 /// A `pyproject.toml` with an (optional) `[tool.uv]` section.
 #[allow(dead_code)]
@@ -135,6 +138,61 @@ pub struct GlobalOptions {
         possible_values = true
     )]
     pub python_fetch: Option<PythonFetch>,
+    /// Mirror URLs from which to download managed Python installations.
+    ///
+    /// Each mirror is expected to replicate the layout of the upstream
+    /// `https://github.com/indygreg/python-build-standalone/releases/download` URL. Mirrors are
+    /// tried in order, with the upstream URL used as a final fallback; each download is verified
+    /// against the known SHA-256 digest of the distribution.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            python-install-mirror = ["https://mirror.example.com/python-build-standalone"]
+        "#
+    )]
+    pub python_install_mirror: Option<Vec<String>>,
+    /// Whether to disable output buffering in commands invoked via `uv run` and `uv tool run`,
+    /// by setting `PYTHONUNBUFFERED=1` in their environment.
+    ///
+    /// uv itself never buffers the output of the commands it runs.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            unbuffered = true
+        "#
+    )]
+    pub unbuffered: Option<bool>,
+    /// The version bounds to write to the `pyproject.toml` for requirements that are added via
+    /// `uv add` without a version specifier.
+    ///
+    /// The bounds are derived from the locked version of each requirement: `lower` writes
+    /// `>=X.Y.Z`, `exact` writes `==X.Y.Z`, and `caret` writes `>=X.Y.Z,<X+1` (incrementing the
+    /// first non-zero component). By default, no version specifier is written.
+    #[option(
+        default = "\"none\"",
+        value_type = "str",
+        example = r#"
+            add-bounds = "lower"
+        "#,
+        possible_values = true
+    )]
+    pub add_bounds: Option<AddBounds>,
+    /// The categories of warnings to treat as errors in `uv lock`, `uv sync`, and `uv run`.
+    ///
+    /// Supported categories are `yanked` (a yanked version was selected), `missing-interpreter`
+    /// (an existing virtual environment was ignored, as its Python interpreter no longer exists),
+    /// and `unhashed-url` (a direct URL dependency was locked without a hash). Each category
+    /// exits with a distinct exit code. The `--strict` flag enables all categories.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            strict = ["yanked", "unhashed-url"]
+        "#
+    )]
+    pub strict: Option<Vec<StrictCategory>>,
 }
 
 /// Settings relevant to all installer operations.
@@ -150,6 +208,9 @@ pub struct InstallerOptions {
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub config_settings: Option<ConfigSettings>,
+    pub no_build_isolation: Option<bool>,
+    pub no_build_isolation_package: Option<Vec<PackageName>>,
+    pub legacy_setup_py: Option<bool>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
@@ -176,7 +237,11 @@ pub struct ResolverOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PreReleaseMode>,
     pub config_settings: Option<ConfigSettings>,
+    pub no_build_isolation: Option<bool>,
+    pub no_build_isolation_package: Option<Vec<PackageName>>,
+    pub legacy_setup_py: Option<bool>,
     pub exclude_newer: Option<ExcludeNewer>,
+    pub python_platforms: Option<Vec<TargetTriple>>,
     pub link_mode: Option<LinkMode>,
     pub upgrade: Option<bool>,
     pub upgrade_package: Option<Vec<Requirement<VerbatimParsedUrl>>>,
@@ -316,6 +381,40 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub config_settings: Option<ConfigSettings>,
+    /// Disable isolation when building source distributions.
+    ///
+    /// Assumes that build dependencies specified by [PEP 518](https://peps.python.org/pep-0518/)
+    /// are already installed.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            no-build-isolation = true
+        "#
+    )]
+    pub no_build_isolation: Option<bool>,
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the packages' build dependencies specified by
+    /// [PEP 518](https://peps.python.org/pep-0518/) are already installed.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            no-build-isolation-package = ["package1", "package2"]
+        "#
+    )]
+    pub no_build_isolation_package: Option<Vec<PackageName>>,
+    /// Use legacy `setuptools` behavior when building source distributions without a
+    /// `pyproject.toml`.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            legacy-setup-py = true
+        "#
+    )]
+    pub legacy_setup_py: Option<bool>,
     /// Limit candidate packages to those that were uploaded prior to the given date.
     ///
     /// Accepts both [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339.html) timestamps (e.g.,
@@ -328,6 +427,36 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub exclude_newer: Option<ExcludeNewer>,
+    /// Limit candidate packages from specific indexes to those that were uploaded prior to the
+    /// given date, keyed by index URL.
+    ///
+    /// Takes precedence over [`exclude_newer`](#exclude-newer) for the given indexes; any other
+    /// indexes continue to respect the global [`exclude_newer`](#exclude-newer) setting.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            exclude-newer-index = { "https://download.pytorch.org/whl/cpu" = "2024-06-01" }
+        "#
+    )]
+    pub exclude_newer_index: Option<ExcludeNewerIndex>,
+    /// The platforms for which to lock the project, e.g., `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// By default, uv locks the project for all platforms. When set, the lockfile is limited to
+    /// dependencies that are relevant on at least one of the given platforms, based on their
+    /// `sys_platform` and `platform_machine` markers.
+    ///
+    /// The platforms are recorded in the lockfile and reused by subsequent locks, and syncing on a
+    /// platform that isn't covered by the lockfile fails.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            python-platforms = ["x86_64-unknown-linux-gnu", "aarch64-apple-darwin"]
+        "#
+    )]
+    pub python_platforms: Option<Vec<TargetTriple>>,
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
@@ -99,6 +99,8 @@ pub enum StateBucket {
     ManagedPython,
     /// Installed tools.
     Tools,
+    /// Tools running in the background (e.g., via `uv tool run --detach`).
+    ToolRuns,
 }
 
 impl StateBucket {
@@ -106,6 +108,7 @@ impl StateBucket {
         match self {
             Self::ManagedPython => "python",
             Self::Tools => "tools",
+            Self::ToolRuns => "tool-runs",
         }
     }
 }
@@ -8,6 +8,7 @@ use pep508_rs::{InvalidNameError, PackageName};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 use fs_err::File;
 use thiserror::Error;
@@ -16,14 +17,17 @@ use tracing::{debug, warn};
 use install_wheel_rs::read_record_file;
 
 pub use receipt::ToolReceipt;
-pub use tool::{Tool, ToolEntrypoint};
+pub use runs::{ToolRun, ToolRuns};
+pub use tool::{editable_source_mtime, Tool, ToolEntrypoint};
 use uv_cache::Cache;
 use uv_fs::{LockedFile, Simplified};
 use uv_installer::SitePackages;
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_state::{StateBucket, StateStore};
+use uv_warnings::{warn_user, warn_user_once};
 
 mod receipt;
+mod runs;
 mod tool;
 
 #[derive(Error, Debug)]
@@ -34,6 +38,8 @@ pub enum Error {
     ReceiptWrite(PathBuf, #[source] Box<toml::ser::Error>),
     #[error("Failed to read `uv-receipt.toml` at {0}")]
     ReceiptRead(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Failed to read `uv-receipt.toml` at {}: schema version {} is not supported by this version of uv (expected {} or earlier)", .0.display(), .1, receipt::SCHEMA_VERSION)]
+    ReceiptVersion(PathBuf, u32),
     #[error(transparent)]
     VirtualEnvError(#[from] uv_virtualenv::Error),
     #[error("Failed to read package entry points {0}")]
@@ -52,6 +58,42 @@ pub enum Error {
     EnvironmentRead(PathBuf, String),
     #[error("Failed find tool package `{0}` at `{1}`")]
     MissingToolPackage(PackageName, PathBuf),
+    #[error("Timed out after {}s waiting to acquire lock at `{}` (set `UV_TOOL_LOCK_TIMEOUT` to wait longer)", _1.as_secs(), _0.user_display())]
+    LockTimeout(PathBuf, Duration),
+    #[error("Failed to terminate process {0}")]
+    Terminate(u32),
+}
+
+/// The default duration to wait for the tools directory lock, in seconds.
+const DEFAULT_LOCK_TIMEOUT: u64 = 60;
+
+/// The initial delay between attempts to acquire the tools directory lock.
+const MIN_LOCK_BACKOFF: Duration = Duration::from_millis(10);
+
+/// The maximum delay between attempts to acquire the tools directory lock.
+const MAX_LOCK_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Return the duration to wait for the tools directory lock, per `UV_TOOL_LOCK_TIMEOUT`.
+fn lock_timeout() -> Duration {
+    let timeout = std::env::var("UV_TOOL_LOCK_TIMEOUT")
+        .map(|value| {
+            value.parse::<u64>().unwrap_or_else(|_| {
+                // On parse error, warn and use the default timeout
+                warn_user_once!("Ignoring invalid value from environment for `UV_TOOL_LOCK_TIMEOUT`. Expected an integer number of seconds, got \"{value}\".");
+                DEFAULT_LOCK_TIMEOUT
+            })
+        })
+        .unwrap_or(DEFAULT_LOCK_TIMEOUT);
+    Duration::from_secs(timeout)
+}
+
+/// Where the tools directory was determined from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolDirSource {
+    /// The tools directory was provided by the user, i.e., via `UV_TOOL_DIR`.
+    Override,
+    /// The tools directory was derived from the platform's data directory conventions.
+    Default,
 }
 
 /// A collection of uv-managed tools installed on the current system.
@@ -59,12 +101,17 @@ pub enum Error {
 pub struct InstalledTools {
     /// The path to the top-level directory of the tools.
     root: PathBuf,
+    /// Where the top-level directory was determined from.
+    source: ToolDirSource,
 }
 
 impl InstalledTools {
     /// A directory for tools at `root`.
-    fn from_path(root: impl Into<PathBuf>) -> Self {
-        Self { root: root.into() }
+    fn from_path(root: impl Into<PathBuf>, source: ToolDirSource) -> Self {
+        Self {
+            root: root.into(),
+            source,
+        }
     }
 
     /// Create a new [`InstalledTools`] from settings.
@@ -72,18 +119,30 @@ impl InstalledTools {
     /// Prefer, in order:
     ///
     /// 1. The specific tool directory specified by the user, i.e., `UV_TOOL_DIR`
-    /// 2. A directory in the system-appropriate user-level data directory, e.g., `~/.local/uv/tools`
+    /// 2. A directory in the system-appropriate user-level data directory, e.g.,
+    ///    `~/.local/share/uv/tools`
     /// 3. A directory in the local data directory, e.g., `./.uv/tools`
     pub fn from_settings() -> Result<Self, Error> {
-        if let Some(tool_dir) = std::env::var_os("UV_TOOL_DIR") {
-            Ok(Self::from_path(tool_dir))
+        if let Some(tool_dir) = std::env::var_os("UV_TOOL_DIR").filter(|dir| !dir.is_empty()) {
+            Ok(Self::from_path(tool_dir, ToolDirSource::Override))
+        } else if let Some(data_dir) = user_data_dir() {
+            Ok(Self::from_path(
+                data_dir.join("uv").join("tools"),
+                ToolDirSource::Default,
+            ))
         } else {
             Ok(Self::from_path(
                 StateStore::from_settings(None)?.bucket(StateBucket::Tools),
+                ToolDirSource::Default,
             ))
         }
     }
 
+    /// Return where the tools directory was determined from.
+    pub fn source(&self) -> ToolDirSource {
+        self.source
+    }
+
     /// Return the expected directory for a tool with the given [`PackageName`].
     pub fn tool_dir(&self, name: &PackageName) -> PathBuf {
         self.root.join(name.to_string())
@@ -102,21 +161,14 @@ impl InstalledTools {
             let name = directory.file_name().unwrap().to_string_lossy().to_string();
             let name = PackageName::from_str(&name)?;
             let path = directory.join("uv-receipt.toml");
-            let contents = match fs_err::read_to_string(&path) {
-                Ok(contents) => contents,
-                Err(err) if err.kind() == io::ErrorKind::NotFound => {
-                    let err = Error::MissingToolReceipt(name.to_string(), path);
-                    tools.push((name, Err(err)));
-                    continue;
-                }
-                Err(err) => return Err(err.into()),
-            };
-            match ToolReceipt::from_string(contents) {
+            match ToolReceipt::from_path(&path) {
                 Ok(tool_receipt) => tools.push((name, Ok(tool_receipt.tool))),
-                Err(err) => {
-                    let err = Error::ReceiptRead(path, Box::new(err));
+                Err(Error::Io(err)) if err.kind() == io::ErrorKind::NotFound => {
+                    let err = Error::MissingToolReceipt(name.to_string(), path);
                     tools.push((name, Err(err)));
                 }
+                Err(err @ Error::Io(_)) => return Err(err),
+                Err(err) => tools.push((name, Err(err))),
             }
         }
         Ok(tools)
@@ -138,11 +190,44 @@ impl InstalledTools {
     }
 
     /// Lock the tools directory.
+    ///
+    /// Waits for up to `UV_TOOL_LOCK_TIMEOUT` seconds (default: 60) for any other process to
+    /// release the lock.
     pub fn acquire_lock(&self) -> Result<LockedFile, Error> {
-        Ok(LockedFile::acquire(
-            self.root.join(".lock"),
+        self.acquire_lock_with_timeout(lock_timeout())
+    }
+
+    /// Lock the tools directory, waiting for up to the given duration for any other process to
+    /// release the lock.
+    ///
+    /// Retries with exponential backoff, returning [`Error::LockTimeout`] if the lock could not be
+    /// acquired in time.
+    pub fn acquire_lock_with_timeout(&self, timeout: Duration) -> Result<LockedFile, Error> {
+        let path = self.root.join(".lock");
+        if let Some(lock) = LockedFile::try_acquire(&path, self.root.user_display())? {
+            return Ok(lock);
+        }
+
+        warn_user!(
+            "Waiting to acquire lock for {} (lockfile: {})",
             self.root.user_display(),
-        )?)
+            path.user_display(),
+        );
+
+        let start = Instant::now();
+        let mut backoff = MIN_LOCK_BACKOFF;
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return Err(Error::LockTimeout(path, timeout));
+            }
+            std::thread::sleep(backoff.min(timeout - elapsed));
+            backoff = (backoff * 2).min(MAX_LOCK_BACKOFF);
+
+            if let Some(lock) = LockedFile::try_acquire(&path, self.root.user_display())? {
+                return Ok(lock);
+            }
+        }
     }
 
     /// Add a receipt for a tool.
@@ -255,7 +340,8 @@ impl InstalledTools {
         let venv = uv_virtualenv::create_venv(
             &environment_path,
             interpreter,
-            uv_virtualenv::Prompt::None,
+            uv_virtualenv::Prompt::Static(name.to_string()),
+            false,
             false,
             false,
         )?;
@@ -267,6 +353,7 @@ impl InstalledTools {
     pub fn temp() -> Result<Self, Error> {
         Ok(Self::from_path(
             StateStore::temp()?.bucket(StateBucket::Tools),
+            ToolDirSource::Override,
         ))
     }
 
@@ -284,10 +371,20 @@ impl InstalledTools {
 
     /// Initialize the tools directory.
     ///
-    /// Ensures the directory is created.
+    /// Ensures the directory is created, migrating any tools from the legacy tools directory on
+    /// first use.
     pub fn init(self) -> Result<Self, Error> {
         let root = &self.root;
 
+        // If we're using the default directory for the first time, move over any tools that were
+        // installed into the legacy directory.
+        if self.source == ToolDirSource::Default && !root.exists() {
+            let legacy = StateStore::from_settings(None)?.bucket(StateBucket::Tools);
+            if legacy != *root && legacy.is_dir() {
+                self.migrate(&legacy)?;
+            }
+        }
+
         // Create the tools directory, if it doesn't exist.
         fs::create_dir_all(root)?;
 
@@ -309,6 +406,144 @@ impl InstalledTools {
     pub fn root(&self) -> &Path {
         &self.root
     }
+
+    /// Move the tools installed in the `legacy` tools directory into this directory.
+    ///
+    /// Tool environments aren't relocatable: the scripts and launchers in each environment, and the
+    /// executables linked into the executable directory, refer to the environment by absolute
+    /// path. After moving an environment, those references are rewritten to point to the new
+    /// location, and the executables are re-linked.
+    fn migrate(&self, legacy: &Path) -> Result<(), Error> {
+        debug!(
+            "Migrating tools from {} to {}",
+            legacy.user_display(),
+            self.root.user_display()
+        );
+
+        fs::create_dir_all(&self.root)?;
+
+        for source in uv_fs::directories(legacy) {
+            let Some(name) = source.file_name() else {
+                continue;
+            };
+            let target = self.root.join(name);
+            if let Err(err) = fs::rename(&source, &target) {
+                warn!(
+                    "Failed to migrate tool environment from {} to {}: {err}",
+                    source.user_display(),
+                    target.user_display()
+                );
+                continue;
+            }
+            relocate_environment(&source, &target)?;
+        }
+
+        // Remove the legacy directory, if it's now empty (aside from our own metadata).
+        for file in [".lock", ".gitignore"] {
+            match fs::remove_file(legacy.join(file)) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        if let Err(err) = fs::remove_dir(legacy) {
+            debug!(
+                "Failed to remove legacy tools directory {}: {err}",
+                legacy.user_display()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Rewrite the absolute paths that refer to a tool environment that was moved from `source` to
+/// `target`.
+fn relocate_environment(source: &Path, target: &Path) -> Result<(), Error> {
+    let scripts = target.join(if cfg!(windows) { "Scripts" } else { "bin" });
+
+    // Rewrite any text scripts (e.g., shebangs, activation scripts) and Windows launchers in the
+    // environment.
+    let (source_str, target_str) = (source.to_string_lossy(), target.to_string_lossy());
+    for path in uv_fs::files(&scripts) {
+        let contents = fs::read(&path)?;
+
+        // The Windows launchers embed the absolute path to the interpreter.
+        if let Some(python) = install_wheel_rs::windows_launcher_python(&contents) {
+            let Ok(relative) = python.strip_prefix(source) else {
+                continue;
+            };
+            if let Some(launcher) =
+                install_wheel_rs::relocate_windows_launcher(&contents, target.join(relative))
+            {
+                fs::write(&path, launcher)?;
+            }
+            continue;
+        }
+
+        let Ok(contents) = String::from_utf8(contents) else {
+            // Skip any other binary files.
+            continue;
+        };
+        if contents.contains(source_str.as_ref()) {
+            fs::write(
+                &path,
+                contents.replace(source_str.as_ref(), target_str.as_ref()),
+            )?;
+        }
+    }
+
+    // Re-link any executables that were installed from the environment.
+    let receipt = match ToolReceipt::from_path(&target.join("uv-receipt.toml")) {
+        Ok(receipt) => receipt,
+        Err(err) => {
+            warn!(
+                "Failed to read receipt for migrated tool at {}: {err}",
+                target.user_display()
+            );
+            return Ok(());
+        }
+    };
+    for entrypoint in receipt.tool.entrypoints() {
+        let Some(file_name) = entrypoint.install_path.file_name() else {
+            continue;
+        };
+        let executable = scripts.join(file_name);
+        debug!(
+            "Relinking executable `{}` to {}",
+            entrypoint.name,
+            executable.user_display()
+        );
+        #[cfg(unix)]
+        uv_fs::replace_symlink(&executable, &entrypoint.install_path)?;
+        #[cfg(windows)]
+        fs::copy(&executable, &entrypoint.install_path)?;
+    }
+
+    Ok(())
+}
+
+/// Find the user-level data directory.
+///
+/// This follows, in order:
+///
+/// - `$XDG_DATA_HOME`
+/// - `%APPDATA%` on Windows
+/// - `$HOME/Library/Application Support` on macOS
+/// - `$HOME/.local/share` on other platforms
+fn user_data_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_DATA_HOME")
+        .and_then(dirs_sys::is_absolute_path)
+        .or_else(|| {
+            #[cfg(windows)]
+            let data_dir = dirs_sys::known_folder_roaming_app_data();
+            #[cfg(target_os = "macos")]
+            let data_dir =
+                dirs_sys::home_dir().map(|path| path.join("Library").join("Application Support"));
+            #[cfg(all(not(windows), not(target_os = "macos")))]
+            let data_dir = dirs_sys::home_dir().map(|path| path.join(".local").join("share"));
+            data_dir
+        })
 }
 
 /// A uv-managed tool installed on the current system..
@@ -444,3 +679,111 @@ pub fn entrypoint_paths(
 
     Ok(entrypoints)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use fs_err as fs;
+
+    use crate::{Error, InstalledTools, Tool, ToolDirSource, ToolEntrypoint, ToolReceipt};
+
+    /// The name of the directory containing an environment's executables.
+    const SCRIPTS: &str = if cfg!(windows) { "Scripts" } else { "bin" };
+
+    /// Create a tool environment named `foo` in `tools`, with a single executable linked into
+    /// `bin`.
+    fn install_tool(tools: &Path, bin: &Path, executable: &[u8]) -> Result<(), Error> {
+        let scripts = tools.join("foo").join(SCRIPTS);
+        fs::create_dir_all(&scripts)?;
+        fs::write(scripts.join("foo"), executable)?;
+
+        fs::create_dir_all(bin)?;
+        #[cfg(unix)]
+        uv_fs::replace_symlink(scripts.join("foo"), bin.join("foo"))?;
+        #[cfg(windows)]
+        fs::copy(scripts.join("foo"), bin.join("foo"))?;
+
+        let tool = Tool::new(
+            vec![],
+            None,
+            std::iter::once(ToolEntrypoint::new("foo".to_string(), bin.join("foo"))),
+            None,
+            false,
+            None,
+        );
+        fs::write(
+            tools.join("foo").join("uv-receipt.toml"),
+            ToolReceipt::from(tool).to_toml(),
+        )?;
+        fs::write(tools.join(".lock"), "")?;
+        fs::write(tools.join(".gitignore"), "*")?;
+        Ok(())
+    }
+
+    #[test]
+    fn migrate_moves_tools() -> Result<(), Error> {
+        let temp_dir = tempfile::tempdir()?;
+        let legacy = temp_dir.path().join("legacy");
+        let root = temp_dir.path().join("tools");
+        let bin = temp_dir.path().join("bin");
+
+        let shebang = format!(
+            "#!{}\n",
+            legacy.join("foo").join(SCRIPTS).join("python").display()
+        );
+        install_tool(&legacy, &bin, shebang.as_bytes())?;
+
+        InstalledTools::from_path(&root, ToolDirSource::Default).migrate(&legacy)?;
+
+        // The environment was moved, and the legacy directory removed.
+        assert!(!legacy.exists());
+        assert!(root.join("foo").join("uv-receipt.toml").is_file());
+
+        // The script refers to the new location, and the linked executable was updated.
+        let expected = format!(
+            "#!{}\n",
+            root.join("foo").join(SCRIPTS).join("python").display()
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("foo").join(SCRIPTS).join("foo"))?,
+            expected
+        );
+        assert_eq!(fs::read_to_string(bin.join("foo"))?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn migrate_relinks_launchers() -> Result<(), Error> {
+        let temp_dir = tempfile::tempdir()?;
+        let legacy = temp_dir.path().join("legacy");
+        let root = temp_dir.path().join("tools");
+        let bin = temp_dir.path().join("bin");
+
+        // A launcher, as produced by `install-wheel-rs`, that embeds the path to the interpreter.
+        let python = legacy.join("foo").join(SCRIPTS).join("python.exe");
+        let launcher = install_wheel_rs::relocate_windows_launcher(
+            &[b"launcher".as_slice(), &[0, 0, 0, 0], b"UVUV"].concat(),
+            &python,
+        )
+        .unwrap();
+        install_tool(&legacy, &bin, &launcher)?;
+
+        InstalledTools::from_path(&root, ToolDirSource::Default).migrate(&legacy)?;
+
+        // The launcher in the environment, and the linked executable, refer to the new location.
+        let expected = root.join("foo").join(SCRIPTS).join("python.exe");
+        for path in [root.join("foo").join(SCRIPTS).join("foo"), bin.join("foo")] {
+            let launcher = fs::read(&path)?;
+            assert_eq!(
+                install_wheel_rs::windows_launcher_python(&launcher),
+                Some(expected.as_path()),
+                "{}",
+                path.display()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,30 @@
+use std::cmp::Ordering;
 use std::path::Path;
 
 use serde::Deserialize;
+use tracing::debug;
+
+use uv_fs::Simplified;
 
 use crate::Tool;
 
+/// The current version of the `uv-receipt.toml` schema.
+///
+/// Receipts written before the schema was versioned are treated as version `0`.
+pub(crate) const SCHEMA_VERSION: u32 = 1;
+
+/// The migrations between schema versions, where the entry at index `n` upgrades a receipt from
+/// version `n` to version `n + 1`.
+const MIGRATIONS: [fn(&mut toml::Table); SCHEMA_VERSION as usize] = [migrate_v0];
+
 /// A `uv-receipt.toml` file tracking the installation of a tool.
 #[allow(dead_code)]
 #[derive(Debug, Clone, Deserialize)]
 pub struct ToolReceipt {
+    /// The version of the schema used to write the receipt.
+    #[serde(rename = "schema-version")]
+    pub(crate) schema_version: u32,
+
     pub(crate) tool: Tool,
 
     /// The raw unserialized document.
@@ -15,6 +32,13 @@ pub struct ToolReceipt {
     pub(crate) raw: String,
 }
 
+/// The schema version of a `uv-receipt.toml` file, read ahead of the rest of the receipt.
+#[derive(Debug, Deserialize)]
+struct ReceiptVersion {
+    #[serde(rename = "schema-version", default)]
+    schema_version: u32,
+}
+
 impl ToolReceipt {
     /// Parse a [`ToolReceipt`] from a raw TOML string.
     pub(crate) fn from_string(raw: String) -> Result<Self, toml::de::Error> {
@@ -23,11 +47,37 @@ impl ToolReceipt {
     }
 
     ///  Read a [`ToolReceipt`] from the given path.
+    ///
+    /// Receipts written with an older schema version are migrated to the current version, and
+    /// written back to disk.
     pub(crate) fn from_path(path: &Path) -> Result<ToolReceipt, crate::Error> {
-        match fs_err::read_to_string(path) {
-            Ok(contents) => Ok(ToolReceipt::from_string(contents)
-                .map_err(|err| crate::Error::ReceiptRead(path.to_owned(), Box::new(err)))?),
-            Err(err) => Err(err.into()),
+        let raw = fs_err::read_to_string(path)?;
+        let read_err = |err| crate::Error::ReceiptRead(path.to_owned(), Box::new(err));
+
+        let ReceiptVersion { schema_version } = toml::from_str(&raw).map_err(read_err)?;
+        match schema_version.cmp(&SCHEMA_VERSION) {
+            Ordering::Equal => ToolReceipt::from_string(raw).map_err(read_err),
+            Ordering::Greater => Err(crate::Error::ReceiptVersion(
+                path.to_owned(),
+                schema_version,
+            )),
+            Ordering::Less => {
+                debug!(
+                    "Migrating `uv-receipt.toml` at {} from schema version {schema_version} to {SCHEMA_VERSION}",
+                    path.user_display()
+                );
+                let receipt = migrate(toml::from_str(&raw).map_err(read_err)?, schema_version)
+                    .map_err(read_err)?;
+
+                // Persist the migrated receipt, such that the migration only runs once. If the
+                // receipt can't be written (e.g., in a read-only tools directory), the migration
+                // is repeated on the next read.
+                if let Err(err) = fs_err::write(path, receipt.to_toml()) {
+                    debug!("Failed to write migrated `uv-receipt.toml`: {err}");
+                }
+
+                Ok(ToolReceipt { raw, ..receipt })
+            }
         }
     }
 
@@ -36,12 +86,37 @@ impl ToolReceipt {
         // We construct a TOML document manually instead of going through Serde to enable
         // the use of inline tables.
         let mut doc = toml_edit::DocumentMut::new();
+        doc.insert(
+            "schema-version",
+            toml_edit::value(i64::from(self.schema_version)),
+        );
         doc.insert("tool", toml_edit::Item::Table(self.tool.to_toml()));
 
         doc.to_string()
     }
 }
 
+/// Upgrade a receipt written with the given schema version to the current schema, one version at
+/// a time.
+pub(crate) fn migrate(
+    mut old: toml::Table,
+    from_version: u32,
+) -> Result<ToolReceipt, toml::de::Error> {
+    for migration in MIGRATIONS.iter().skip(from_version as usize) {
+        migration(&mut old);
+    }
+    old.insert(
+        "schema-version".to_string(),
+        toml::Value::Integer(i64::from(SCHEMA_VERSION)),
+    );
+    toml::Value::Table(old).try_into()
+}
+
+/// Upgrade a receipt from version `0` to version `1`.
+///
+/// Version `0` receipts predate the `schema-version` field, but are otherwise identical.
+fn migrate_v0(_receipt: &mut toml::Table) {}
+
 // Ignore raw document in comparison.
 impl PartialEq for ToolReceipt {
     fn eq(&self, other: &Self) -> bool {
@@ -54,6 +129,7 @@ impl Eq for ToolReceipt {}
 impl From<Tool> for ToolReceipt {
     fn from(tool: Tool) -> Self {
         ToolReceipt {
+            schema_version: SCHEMA_VERSION,
             tool,
             raw: String::new(),
         }
@@ -0,0 +1,228 @@
+use std::io;
+use std::path::{Path, PathBuf};
+#[cfg(windows)]
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use fs2::FileExt;
+use fs_err as fs;
+use tracing::debug;
+
+#[cfg(unix)]
+use uv_fs::LockedFile;
+use uv_fs::Simplified;
+use uv_state::{StateBucket, StateStore};
+
+use crate::Error;
+
+/// A directory for tools running in the background, as started by `uv tool run --detach`.
+///
+/// Each run is stored in its own subdirectory, which contains the process ID, a lock held by the
+/// process while it's running, and the logs for the process' standard output and error streams.
+#[derive(Debug, Clone)]
+pub struct ToolRuns {
+    root: PathBuf,
+}
+
+impl ToolRuns {
+    /// Create a new [`ToolRuns`] in the user's state directory.
+    pub fn from_settings() -> Result<Self, Error> {
+        Ok(Self {
+            root: StateStore::from_settings(None)?.bucket(StateBucket::ToolRuns),
+        })
+    }
+
+    /// Return the root of the runs directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Initialize the runs directory.
+    pub fn init(self) -> Result<Self, Error> {
+        fs::create_dir_all(&self.root)?;
+        Ok(self)
+    }
+
+    /// Create a directory for a new run of the given executable.
+    pub fn create(&self, name: &str) -> Result<ToolRun, Error> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis())
+            .unwrap_or_default();
+
+        // In the unlikely event of a collision, bump the timestamp until we find a free directory.
+        for offset in 0.. {
+            let root = self.root.join(format!("{name}-{}", timestamp + offset));
+            match fs::create_dir(&root) {
+                Ok(()) => {
+                    debug!("Created run directory at: {}", root.user_display());
+                    return Ok(ToolRun {
+                        root,
+                        name: name.to_string(),
+                    });
+                }
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(err.into()),
+            }
+        }
+        unreachable!()
+    }
+
+    /// Return all runs, in the order in which they were started.
+    pub fn runs(&self) -> Result<Vec<ToolRun>, Error> {
+        let mut runs = Vec::new();
+        let entries = match fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(runs),
+            Err(err) => return Err(err.into()),
+        };
+        for entry in entries {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            let root = entry.path();
+            let Some((name, timestamp)) = entry
+                .file_name()
+                .to_str()
+                .and_then(|dir_name| dir_name.rsplit_once('-'))
+                .and_then(|(name, timestamp)| {
+                    Some((name.to_string(), u128::from_str(timestamp).ok()?))
+                })
+            else {
+                debug!(
+                    "Ignoring unexpected entry in runs directory: {}",
+                    root.user_display()
+                );
+                continue;
+            };
+            runs.push((timestamp, ToolRun { root, name }));
+        }
+        runs.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(runs.into_iter().map(|(_, run)| run).collect())
+    }
+}
+
+/// A tool running in the background.
+#[derive(Debug, Clone)]
+pub struct ToolRun {
+    root: PathBuf,
+    name: String,
+}
+
+impl ToolRun {
+    /// Return the name of the executable.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return the directory for the run.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Return the path to the log for the process' standard output.
+    pub fn stdout(&self) -> PathBuf {
+        self.root.join("stdout.log")
+    }
+
+    /// Return the path to the log for the process' standard error.
+    pub fn stderr(&self) -> PathBuf {
+        self.root.join("stderr.log")
+    }
+
+    /// Return the process ID, if the process was started.
+    pub fn pid(&self) -> Result<Option<u32>, Error> {
+        match fs::read_to_string(self.root.join("pid")) {
+            Ok(contents) => Ok(u32::from_str(contents.trim()).ok()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Record the process ID for the run.
+    pub fn write_pid(&self, pid: u32) -> Result<(), Error> {
+        fs::write(self.root.join("pid"), pid.to_string())?;
+        Ok(())
+    }
+
+    /// Create and lock the file that identifies the process for the run.
+    ///
+    /// The file is intended to be inherited by the process, such that the lock is held for as long
+    /// as the process is running, and a different process that reuses its ID isn't mistaken for it.
+    /// As the lock is shared by every handle to the file, it's released once the last handle is
+    /// closed: the returned file must be closed, rather than unlocked, by the caller.
+    pub fn lock(&self) -> Result<fs::File, Error> {
+        let file = fs::File::create(self.root.join("lock"))?;
+        file.file().try_lock_exclusive()?;
+        Ok(file)
+    }
+
+    /// Returns `true` if the process for the run, with the given ID, is still running.
+    #[cfg(unix)]
+    pub fn is_running(&self, _pid: u32) -> Result<bool, Error> {
+        // The process holds the lock until it exits.
+        let lock = LockedFile::try_acquire(self.root.join("lock"), self.root.user_display())?;
+        Ok(lock.is_none())
+    }
+
+    /// Returns `true` if the process for the run, with the given ID, is still running.
+    #[cfg(windows)]
+    pub fn is_running(&self, pid: u32) -> Result<bool, Error> {
+        // Locks aren't inherited by child processes on Windows, so instead, verify that the
+        // process with the given ID is running the executable for the run.
+        let output = Command::new("tasklist")
+            .args(["/NH", "/FO", "CSV", "/FI", &format!("PID eq {pid}")])
+            .stderr(Stdio::null())
+            .output()?;
+        let name = Path::new(&self.name)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        Ok(String::from_utf8_lossy(&output.stdout).lines().any(|line| {
+            let mut fields = line.split(',').map(|field| field.trim_matches('"'));
+            let image = fields.next().unwrap_or_default().to_lowercase();
+            let image = image.strip_suffix(".exe").unwrap_or(&image);
+            fields.next() == Some(pid.to_string().as_str()) && image == name
+        }))
+    }
+
+    /// Request that the process for the run, with the given ID, terminate.
+    ///
+    /// On Unix, the process is started in a new session, so the signal is sent to its process
+    /// group, including any processes it spawned.
+    #[cfg(unix)]
+    pub fn terminate(&self, pid: u32) -> Result<(), Error> {
+        use nix::errno::Errno;
+        use nix::sys::signal::{killpg, Signal};
+        use nix::unistd::Pid;
+
+        let raw = i32::try_from(pid).map_err(|_| Error::Terminate(pid))?;
+        match killpg(Pid::from_raw(raw), Signal::SIGTERM) {
+            // The process exited in the meantime.
+            Ok(()) | Err(Errno::ESRCH) => Ok(()),
+            Err(_) => Err(Error::Terminate(pid)),
+        }
+    }
+
+    /// Request that the process for the run, with the given ID, terminate.
+    #[cfg(windows)]
+    pub fn terminate(&self, pid: u32) -> Result<(), Error> {
+        let status = Command::new("taskkill")
+            .args(["/T", "/F", "/PID", &pid.to_string()])
+            .stdout(Stdio::null())
+            .status()?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::Terminate(pid))
+        }
+    }
+
+    /// Remove the run directory, including its logs.
+    pub fn remove(self) -> Result<(), Error> {
+        fs::remove_dir_all(&self.root)?;
+        Ok(())
+    }
+}
@@ -1,12 +1,17 @@
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
 
 use path_slash::PathBufExt;
-use pypi_types::VerbatimParsedUrl;
+use pypi_types::{ParsedUrl, VerbatimParsedUrl};
 use serde::Deserialize;
 use toml_edit::value;
 use toml_edit::Array;
 use toml_edit::Table;
 use toml_edit::Value;
+use tracing::debug;
+
+use uv_fs::Simplified;
 
 /// A tool entry.
 #[allow(dead_code)]
@@ -19,6 +24,45 @@ pub struct Tool {
     python: Option<String>,
     /// A mapping of entry point names to their metadata.
     entrypoints: Vec<ToolEntrypoint>,
+    /// The script to run after the tool environment is populated, if any.
+    post_install_script: Option<PathBuf>,
+    /// Whether the tool (i.e., the first requirement) was installed in editable mode from a local
+    /// directory.
+    #[serde(default)]
+    editable: bool,
+    /// For an editable tool, the latest modification time of its project metadata files at
+    /// installation, in milliseconds since the Unix epoch.
+    source_mtime: Option<i64>,
+}
+
+/// The files that define the project metadata of an editable tool.
+const EDITABLE_METADATA_FILES: [&str; 3] = ["pyproject.toml", "setup.py", "setup.cfg"];
+
+/// Return the modification time of the file at the given path, in milliseconds since the Unix
+/// epoch, or `None` if the file doesn't exist.
+fn mtime(path: &Path) -> Result<Option<i64>, io::Error> {
+    match fs_err::metadata(path) {
+        Ok(metadata) => {
+            let millis = metadata
+                .modified()?
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_millis())
+                .unwrap_or_default();
+            Ok(Some(i64::try_from(millis).unwrap_or(i64::MAX)))
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Return the latest modification time of the project metadata files in the given source
+/// directory, in milliseconds since the Unix epoch.
+pub fn editable_source_mtime(source: &Path) -> Result<Option<i64>, io::Error> {
+    let mut latest = None;
+    for file in EDITABLE_METADATA_FILES {
+        latest = latest.max(mtime(&source.join(file))?);
+    }
+    Ok(latest)
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Deserialize)]
@@ -61,6 +105,9 @@ impl Tool {
         requirements: Vec<pep508_rs::Requirement<VerbatimParsedUrl>>,
         python: Option<String>,
         entrypoints: impl Iterator<Item = ToolEntrypoint>,
+        post_install_script: Option<PathBuf>,
+        editable: bool,
+        source_mtime: Option<i64>,
     ) -> Self {
         let mut entrypoints: Vec<_> = entrypoints.collect();
         entrypoints.sort();
@@ -68,6 +115,9 @@ impl Tool {
             requirements,
             python,
             entrypoints,
+            post_install_script,
+            editable,
+            source_mtime,
         }
     }
 
@@ -102,6 +152,22 @@ impl Tool {
             value(entrypoints)
         });
 
+        if let Some(ref post_install_script) = self.post_install_script {
+            table.insert(
+                "post-install-script",
+                // Use cross-platform slashes so the toml string type does not change
+                value(post_install_script.to_slash_lossy().to_string()),
+            );
+        }
+
+        if self.editable {
+            table.insert("editable", value(true));
+        }
+
+        if let Some(source_mtime) = self.source_mtime {
+            table.insert("source-mtime", value(source_mtime));
+        }
+
         table
     }
 
@@ -112,6 +178,66 @@ impl Tool {
     pub fn requirements(&self) -> &[pep508_rs::Requirement<VerbatimParsedUrl>] {
         &self.requirements
     }
+
+    pub fn post_install_script(&self) -> Option<&Path> {
+        self.post_install_script.as_deref()
+    }
+
+    /// The Python requested by the user during installation, if any.
+    pub fn python(&self) -> Option<&str> {
+        self.python.as_deref()
+    }
+
+    /// Whether the tool was installed in editable mode.
+    pub fn editable(&self) -> bool {
+        self.editable
+    }
+
+    /// Returns the local directory from which the tool was installed in editable mode, if any.
+    pub fn editable_path(&self) -> Option<&Path> {
+        if !self.editable {
+            return None;
+        }
+        let requirement = self.requirements.first()?;
+        let Some(pep508_rs::VersionOrUrl::Url(url)) = requirement.version_or_url.as_ref() else {
+            return None;
+        };
+        let ParsedUrl::Directory(directory) = &url.parsed_url else {
+            return None;
+        };
+        Some(&directory.install_path)
+    }
+
+    /// Returns the project metadata files (e.g., `pyproject.toml`) of an editable tool that were
+    /// modified after the tool was installed, per the modification time recorded in the receipt.
+    ///
+    /// Returns an empty list if the tool wasn't installed in editable mode, or if its receipt
+    /// predates the recorded modification time.
+    pub fn stale_editable_files(&self) -> Result<Vec<PathBuf>, io::Error> {
+        let Some(source) = self.editable_path() else {
+            return Ok(Vec::new());
+        };
+        let Some(installed) = self.source_mtime else {
+            debug!(
+                "No modification time recorded for editable source: {}",
+                source.user_display()
+            );
+            return Ok(Vec::new());
+        };
+
+        let mut stale = Vec::new();
+        for file in EDITABLE_METADATA_FILES {
+            let path = source.join(file);
+            if mtime(&path)?.is_some_and(|modified| modified > installed) {
+                debug!(
+                    "Editable source was modified after installation: {}",
+                    path.user_display()
+                );
+                stale.push(path);
+            }
+        }
+        Ok(stale)
+    }
 }
 
 impl ToolEntrypoint {
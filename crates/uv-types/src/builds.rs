@@ -1,3 +1,4 @@
+use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
 
 /// Whether to enforce build isolation when building source distributions.
@@ -6,11 +7,36 @@ pub enum BuildIsolation<'a> {
     #[default]
     Isolated,
     Shared(&'a PythonEnvironment),
+    SharedPackage(&'a PythonEnvironment, &'a [PackageName]),
 }
 
 impl<'a> BuildIsolation<'a> {
-    /// Returns `true` if build isolation is enforced.
-    pub fn is_isolated(&self) -> bool {
-        matches!(self, Self::Isolated)
+    /// Returns `true` if build isolation is enforced for the given package name.
+    pub fn is_isolated(&self, package: Option<&PackageName>) -> bool {
+        match *self {
+            Self::Isolated => true,
+            Self::Shared(_) => false,
+            Self::SharedPackage(_, packages) => {
+                package.map_or(true, |package| !packages.iter().any(|p| p == package))
+            }
+        }
+    }
+
+    /// Returns the shared environment for a given package, if build isolation is not enforced.
+    pub fn shared_environment(
+        &self,
+        package: Option<&PackageName>,
+    ) -> Option<&'a PythonEnvironment> {
+        match *self {
+            Self::Isolated => None,
+            Self::Shared(env) => Some(env),
+            Self::SharedPackage(env, packages) => {
+                if package.is_some_and(|package| packages.iter().any(|p| p == package)) {
+                    Some(env)
+                } else {
+                    None
+                }
+            }
+        }
     }
 }
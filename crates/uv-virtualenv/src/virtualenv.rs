@@ -50,6 +50,7 @@ pub(crate) fn create(
     prompt: Prompt,
     system_site_packages: bool,
     allow_existing: bool,
+    seed: bool,
 ) -> Result<VirtualEnvironment, Error> {
     // Determine the base Python executable; that is, the Python executable that should be
     // considered the "base" for the virtual environment. This is typically the Python executable
@@ -135,7 +136,16 @@ pub(crate) fn create(
             .map(|name| name.to_string_lossy().to_string()),
         Prompt::Static(value) => Some(value),
         Prompt::None => None,
-    };
+    }
+    // Strip any control characters (e.g., newlines), which would corrupt the `pyvenv.cfg` and the
+    // activation scripts.
+    .map(|prompt| {
+        prompt
+            .chars()
+            .filter(|c| !c.is_control())
+            .collect::<String>()
+    })
+    .filter(|prompt| !prompt.is_empty());
 
     // Add the CACHEDIR.TAG.
     cachedir::ensure_tag(&location)?;
@@ -341,6 +351,12 @@ pub(crate) fn create(
         pyvenv_cfg_data.push(("prompt".to_string(), prompt));
     }
 
+    // Record that the environment should include seed packages, such that they can be retained
+    // when syncing.
+    if seed {
+        pyvenv_cfg_data.push(("seed".to_string(), "true".to_string()));
+    }
+
     if cfg!(windows) && interpreter.markers().implementation_name() == "graalpy" {
         pyvenv_cfg_data.push((
             "venvlauncher_command".to_string(),
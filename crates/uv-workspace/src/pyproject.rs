@@ -1,12 +1,14 @@
 //! Reads the following fields from `pyproject.toml`:
 //!
 //! * `project.{dependencies,optional-dependencies}`
+//! * `dependency-groups`
 //! * `tool.uv.sources`
 //! * `tool.uv.workspace`
 //!
 //! Then lowers them into a dependency specification.
 
 use std::ops::Deref;
+use std::path::PathBuf;
 use std::{collections::BTreeMap, mem};
 
 use glob::Pattern;
@@ -18,7 +20,7 @@ use pep440_rs::VersionSpecifiers;
 use pypi_types::{RequirementSource, VerbatimParsedUrl};
 use uv_git::GitReference;
 use uv_macros::OptionsMetadata;
-use uv_normalize::{ExtraName, PackageName};
+use uv_normalize::{ExtraName, GroupName, PackageName};
 
 /// A `pyproject.toml` as specified in PEP 517.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -28,6 +30,11 @@ pub struct PyProjectToml {
     pub project: Option<Project>,
     /// Tool-specific metadata.
     pub tool: Option<Tool>,
+    /// PEP 517 build system configuration.
+    pub build_system: Option<BuildSystem>,
+    /// PEP 735-style dependency groups.
+    pub dependency_groups:
+        Option<BTreeMap<GroupName, Vec<pep508_rs::Requirement<VerbatimParsedUrl>>>>,
     /// The raw unserialized document.
     #[serde(skip)]
     pub(crate) raw: String,
@@ -39,12 +46,27 @@ impl PyProjectToml {
         let pyproject = toml::from_str(&raw)?;
         Ok(PyProjectToml { raw, ..pyproject })
     }
+
+    /// Returns `true` if the project should be built and installed into the environment.
+    ///
+    /// Projects that set `tool.uv.package = false` are never built; only their dependencies are
+    /// installed.
+    pub fn is_package(&self) -> bool {
+        self.tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.package)
+            .unwrap_or(true)
+    }
 }
 
 // Ignore raw document in comparison.
 impl PartialEq for PyProjectToml {
     fn eq(&self, other: &Self) -> bool {
-        self.project.eq(&other.project) && self.tool.eq(&other.tool)
+        self.project.eq(&other.project)
+            && self.tool.eq(&other.tool)
+            && self.build_system.eq(&other.build_system)
+            && self.dependency_groups.eq(&other.dependency_groups)
     }
 }
 
@@ -60,8 +82,24 @@ pub struct Project {
     pub name: PackageName,
     /// The Python versions this project is compatible with.
     pub requires_python: Option<VersionSpecifiers>,
+    /// The dependencies of the project.
+    pub dependencies: Option<Vec<String>>,
     /// The optional dependencies of the project.
     pub optional_dependencies: Option<BTreeMap<ExtraName, Vec<String>>>,
+    /// The console scripts provided by the project, mapping each script name to its entrypoint.
+    pub scripts: Option<BTreeMap<String, String>>,
+}
+
+/// PEP 517 build system configuration (`build-system`).
+///
+/// See <https://packaging.python.org/en/latest/specifications/pyproject-toml>.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct BuildSystem {
+    /// The requirements needed to build the project.
+    pub requires: Option<Vec<String>>,
+    /// The Python object that the build frontend uses to perform the build.
+    pub build_backend: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -88,6 +126,57 @@ pub struct ToolUv {
         "#
     )]
     pub managed: Option<bool>,
+    /// Whether the project should be built and installed into the environment.
+    ///
+    /// If `false`, uv will lock and install the project's dependencies, but will never build or
+    /// install the project itself, as for an application that isn't meant to be packaged. In a
+    /// workspace, the setting applies to the member that declares it.
+    #[option(
+        default = r#"true"#,
+        value_type = "bool",
+        example = r#"
+            package = false
+        "#
+    )]
+    pub package: Option<bool>,
+    /// The path to the project's virtual environment.
+    ///
+    /// Relative paths are resolved against the workspace root. Defaults to `.venv` in the
+    /// workspace root. The `UV_PROJECT_ENVIRONMENT` environment variable takes precedence over
+    /// this setting.
+    #[option(
+        default = r#"".venv""#,
+        value_type = "str",
+        example = r#"
+            environment = "/opt/venv"
+        "#
+    )]
+    pub environment: Option<PathBuf>,
+    /// Whether to install seed packages (`pip`, along with `setuptools` and `wheel` on Python
+    /// 3.11 and earlier) into the project's virtual environment when it's created.
+    ///
+    /// Seed packages are retained by subsequent syncs, even if they aren't project dependencies.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            seed = true
+        "#
+    )]
+    pub seed: Option<bool>,
+    /// The Python implementation required by the project (e.g., `cpython` or `pypy`).
+    ///
+    /// When set, uv will only use interpreters of the given implementation for the project's
+    /// virtual environment, replacing an existing environment that was created with a different
+    /// implementation, even if its Python version satisfies the project's `requires-python`.
+    #[option(
+        default = r#"None"#,
+        value_type = "str",
+        example = r#"
+            python-implementation = "cpython"
+        "#
+    )]
+    pub python_implementation: Option<String>,
     #[cfg_attr(
         feature = "schemars",
         schemars(
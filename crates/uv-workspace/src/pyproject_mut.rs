@@ -16,6 +16,17 @@ use crate::pyproject::{DependencyType, PyProjectToml, Source};
 /// preserving comments and other structure, such as `uv add` and `uv remove`.
 pub struct PyProjectTomlMut {
     doc: DocumentMut,
+    target: DependencyTarget,
+}
+
+/// The document being edited.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DependencyTarget {
+    /// A PEP 723 inline script metadata block, with dependencies in the top-level `dependencies`
+    /// array.
+    Script,
+    /// A `pyproject.toml`, with dependencies in `project.dependencies`.
+    PyProjectToml,
 }
 
 #[derive(Error, Debug)]
@@ -39,6 +50,16 @@ impl PyProjectTomlMut {
     pub fn from_toml(pyproject: &PyProjectToml) -> Result<Self, Error> {
         Ok(Self {
             doc: pyproject.raw.parse().map_err(Box::new)?,
+            target: DependencyTarget::PyProjectToml,
+        })
+    }
+
+    /// Initialize a `PyProjectTomlMut` from the TOML contents of a PEP 723 inline script metadata
+    /// block.
+    pub fn from_script(raw: &str) -> Result<Self, Error> {
+        Ok(Self {
+            doc: raw.parse().map_err(Box::new)?,
+            target: DependencyTarget::Script,
         })
     }
 
@@ -71,23 +92,27 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
-    /// Adds a dependency to `project.dependencies`.
+    /// Adds a dependency to `project.dependencies` (or, for scripts, the top-level
+    /// `dependencies`).
     pub fn add_dependency(
         &mut self,
         req: Requirement,
         source: Option<Source>,
     ) -> Result<(), Error> {
         // Get or create `project.dependencies`.
-        let dependencies = self
-            .doc
-            .entry("project")
-            .or_insert(Item::Table(Table::new()))
-            .as_table_mut()
-            .ok_or(Error::MalformedDependencies)?
-            .entry("dependencies")
-            .or_insert(Item::Value(Value::Array(Array::new())))
-            .as_array_mut()
-            .ok_or(Error::MalformedDependencies)?;
+        let dependencies = match self.target {
+            DependencyTarget::PyProjectToml => self
+                .doc
+                .entry("project")
+                .or_insert(Item::Table(Table::new()))
+                .as_table_mut()
+                .ok_or(Error::MalformedDependencies)?,
+            DependencyTarget::Script => self.doc.as_table_mut(),
+        }
+        .entry("dependencies")
+        .or_insert(Item::Value(Value::Array(Array::new())))
+        .as_array_mut()
+        .ok_or(Error::MalformedDependencies)?;
 
         let name = req.name.clone();
         add_dependency(req, dependencies, source.is_some())?;
@@ -100,11 +125,25 @@ impl PyProjectTomlMut {
     }
 
     /// Adds a development dependency to `tool.uv.dev-dependencies`.
+    ///
+    /// If the `pyproject.toml` defines a PEP 735 `dev` group (i.e., `dependency-groups.dev`), the
+    /// dependency is added there instead.
     pub fn add_dev_dependency(
         &mut self,
         req: Requirement,
         source: Option<Source>,
     ) -> Result<(), Error> {
+        if let Some(dev_group) = self.dev_group_mut()? {
+            let name = req.name.clone();
+            add_dependency(req, dev_group, source.is_some())?;
+
+            if let Some(source) = source {
+                self.add_source(&name, &source)?;
+            }
+
+            return Ok(());
+        }
+
         // Get or create `tool.uv.dev-dependencies`.
         let dev_dependencies = self
             .doc
@@ -150,8 +189,9 @@ impl PyProjectTomlMut {
             .as_table_mut()
             .ok_or(Error::MalformedDependencies)?;
 
+        let key = optional_group_key(optional_dependencies, group);
         let group = optional_dependencies
-            .entry(group.as_ref())
+            .entry(&key)
             .or_insert(Item::Value(Value::Array(Array::new())))
             .as_array_mut()
             .ok_or(Error::MalformedDependencies)?;
@@ -191,12 +231,16 @@ impl PyProjectTomlMut {
     /// Removes all occurrences of dependencies with the given name.
     pub fn remove_dependency(&mut self, req: &PackageName) -> Result<Vec<Requirement>, Error> {
         // Try to get `project.dependencies`.
-        let Some(dependencies) = self
-            .doc
-            .get_mut("project")
-            .map(|project| project.as_table_mut().ok_or(Error::MalformedSources))
-            .transpose()?
-            .and_then(|project| project.get_mut("dependencies"))
+        let table = match self.target {
+            DependencyTarget::PyProjectToml => self
+                .doc
+                .get_mut("project")
+                .map(|project| project.as_table_mut().ok_or(Error::MalformedSources))
+                .transpose()?,
+            DependencyTarget::Script => Some(self.doc.as_table_mut()),
+        };
+        let Some(dependencies) = table
+            .and_then(|table| table.get_mut("dependencies"))
             .map(|dependencies| dependencies.as_array_mut().ok_or(Error::MalformedSources))
             .transpose()?
         else {
@@ -209,10 +253,16 @@ impl PyProjectTomlMut {
         Ok(requirements)
     }
 
-    /// Removes all occurrences of development dependencies with the given name.
+    /// Removes all occurrences of development dependencies with the given name, from both
+    /// `tool.uv.dev-dependencies` and the PEP 735 `dev` group.
     pub fn remove_dev_dependency(&mut self, req: &PackageName) -> Result<Vec<Requirement>, Error> {
+        let mut requirements = self
+            .dev_group_mut()?
+            .map(|dev_group| remove_dependency(req, dev_group))
+            .unwrap_or_default();
+
         // Try to get `tool.uv.dev-dependencies`.
-        let Some(dev_dependencies) = self
+        if let Some(dev_dependencies) = self
             .doc
             .get_mut("tool")
             .map(|tool| tool.as_table_mut().ok_or(Error::MalformedSources))
@@ -223,16 +273,28 @@ impl PyProjectTomlMut {
             .and_then(|tool_uv| tool_uv.get_mut("dev-dependencies"))
             .map(|dependencies| dependencies.as_array_mut().ok_or(Error::MalformedSources))
             .transpose()?
-        else {
-            return Ok(Vec::new());
-        };
+        {
+            requirements.extend(remove_dependency(req, dev_dependencies));
+        }
 
-        let requirements = remove_dependency(req, dev_dependencies);
-        self.remove_source(req)?;
+        if !requirements.is_empty() {
+            self.remove_source(req)?;
+        }
 
         Ok(requirements)
     }
 
+    /// Returns the PEP 735 `dev` group (i.e., `dependency-groups.dev`), if it exists.
+    fn dev_group_mut(&mut self) -> Result<Option<&mut Array>, Error> {
+        self.doc
+            .get_mut("dependency-groups")
+            .map(|groups| groups.as_table_mut().ok_or(Error::MalformedDependencies))
+            .transpose()?
+            .and_then(|groups| groups.get_mut("dev"))
+            .map(|group| group.as_array_mut().ok_or(Error::MalformedDependencies))
+            .transpose()
+    }
+
     /// Removes all occurrences of optional dependencies in the group with the given name.
     pub fn remove_optional_dependency(
         &mut self,
@@ -248,7 +310,10 @@ impl PyProjectTomlMut {
             .and_then(|project| project.get_mut("optional-dependencies"))
             .map(|extras| extras.as_table_mut().ok_or(Error::MalformedSources))
             .transpose()?
-            .and_then(|extras| extras.get_mut(group.as_ref()))
+            .and_then(|extras| {
+                let key = optional_group_key(extras, group);
+                extras.get_mut(&key)
+            })
             .map(|dependencies| dependencies.as_array_mut().ok_or(Error::MalformedSources))
             .transpose()?
         else {
@@ -261,8 +326,49 @@ impl PyProjectTomlMut {
         Ok(requirements)
     }
 
-    // Remove a matching source from `tool.uv.sources`, if it exists.
+    /// Removes the optional dependency group with the given name if it contains no dependencies,
+    /// along with the `project.optional-dependencies` table, if no groups remain.
+    pub fn remove_empty_optional_group(&mut self, group: &ExtraName) -> Result<(), Error> {
+        let Some(project) = self
+            .doc
+            .get_mut("project")
+            .map(|project| project.as_table_mut().ok_or(Error::MalformedDependencies))
+            .transpose()?
+        else {
+            return Ok(());
+        };
+
+        let Some(extras) = project
+            .get_mut("optional-dependencies")
+            .map(|extras| extras.as_table_mut().ok_or(Error::MalformedDependencies))
+            .transpose()?
+        else {
+            return Ok(());
+        };
+
+        let key = optional_group_key(extras, group);
+        if extras
+            .get(&key)
+            .and_then(Item::as_array)
+            .is_some_and(Array::is_empty)
+        {
+            extras.remove(&key);
+        }
+
+        if extras.is_empty() {
+            project.remove("optional-dependencies");
+        }
+
+        Ok(())
+    }
+
+    // Remove a matching source from `tool.uv.sources`, if it exists and the package is no longer
+    // referenced by any other dependency (e.g., a development dependency).
     fn remove_source(&mut self, name: &PackageName) -> Result<(), Error> {
+        if !self.find_dependency(name).is_empty() {
+            return Ok(());
+        }
+
         if let Some(sources) = self
             .doc
             .get_mut("tool")
@@ -275,7 +381,8 @@ impl PyProjectTomlMut {
             .map(|sources| sources.as_table_mut().ok_or(Error::MalformedSources))
             .transpose()?
         {
-            sources.remove(name.as_ref());
+            let key = source_key(sources, name);
+            sources.remove(&key);
         }
 
         Ok(())
@@ -284,11 +391,20 @@ impl PyProjectTomlMut {
     /// Returns all the places in this `pyproject.toml` that contain a dependency with the given
     /// name.
     ///
-    /// This method searches `project.dependencies`, `tool.uv.dev-dependencies`, and
-    /// `tool.uv.optional-dependencies`.
+    /// This method searches `project.dependencies`, `tool.uv.dev-dependencies`,
+    /// `dependency-groups.dev`, and `tool.uv.optional-dependencies`.
     pub fn find_dependency(&self, name: &PackageName) -> Vec<DependencyType> {
         let mut types = Vec::new();
 
+        if self.target == DependencyTarget::Script {
+            if let Some(dependencies) = self.doc.get("dependencies").and_then(Item::as_array) {
+                if !find_dependencies(name, dependencies).is_empty() {
+                    types.push(DependencyType::Production);
+                }
+            }
+            return types;
+        }
+
         if let Some(project) = self.doc.get("project").and_then(Item::as_table) {
             // Check `project.dependencies`.
             if let Some(dependencies) = project.get("dependencies").and_then(Item::as_array) {
@@ -332,6 +448,21 @@ impl PyProjectTomlMut {
             }
         }
 
+        // Check `dependency-groups.dev`.
+        if let Some(dev_group) = self
+            .doc
+            .get("dependency-groups")
+            .and_then(Item::as_table)
+            .and_then(|groups| groups.get("dev"))
+            .and_then(Item::as_array)
+        {
+            if !find_dependencies(name, dev_group).is_empty()
+                && !types.iter().any(|ty| matches!(ty, DependencyType::Dev))
+            {
+                types.push(DependencyType::Dev);
+            }
+        }
+
         types
     }
 }
@@ -343,16 +474,38 @@ fn implicit() -> Item {
     Item::Table(table)
 }
 
+/// Returns the key of the optional dependency group with the given name, matching existing groups
+/// by their normalized name (e.g., `Docs` for `docs`).
+fn optional_group_key(extras: &Table, group: &ExtraName) -> String {
+    extras
+        .iter()
+        .map(|(key, _)| key)
+        .find(|key| ExtraName::from_str(key).is_ok_and(|extra| extra == *group))
+        .unwrap_or(group.as_ref())
+        .to_string()
+}
+
+/// Returns the key of the source for the given package, matching existing entries by their
+/// normalized name (e.g., `Flask` for `flask`).
+fn source_key(sources: &Table, name: &PackageName) -> String {
+    sources
+        .iter()
+        .map(|(key, _)| key)
+        .find(|key| PackageName::from_str(key).is_ok_and(|package| package == *name))
+        .unwrap_or(name.as_ref())
+        .to_string()
+}
+
 /// Adds a dependency to the given `deps` array.
 pub fn add_dependency(req: Requirement, deps: &mut Array, has_source: bool) -> Result<(), Error> {
     // Find matching dependencies.
     let mut to_replace = find_dependencies(&req.name, deps);
     match to_replace.as_slice() {
-        [] => deps.push(req.to_string()),
+        [] => deps.push(requirement_to_string(&req)),
         [_] => {
             let (i, mut old_req) = to_replace.remove(0);
             update_requirement(&mut old_req, req, has_source);
-            deps.replace(i, old_req.to_string());
+            deps.replace(i, requirement_to_string(&old_req));
         }
         // Cannot perform ambiguous updates.
         _ => return Err(Error::Ambiguous),
@@ -429,7 +582,18 @@ fn add_source(req: &PackageName, source: &Source, sources: &mut Table) -> Result
         .unwrap();
     let table = mem::take(doc.as_table_mut()).into_inline_table();
 
-    sources.insert(req.as_ref(), Item::Value(Value::InlineTable(table)));
+    let key = source_key(sources, req);
+    match sources.get_mut(&key) {
+        // Update an existing entry in place, retaining any surrounding comments.
+        Some(Item::Value(existing)) => {
+            let decor = existing.decor().clone();
+            *existing = Value::InlineTable(table);
+            *existing.decor_mut() = decor;
+        }
+        _ => {
+            sources.insert(&key, Item::Value(Value::InlineTable(table)));
+        }
+    }
 
     Ok(())
 }
@@ -444,6 +608,28 @@ fn try_parse_requirement(req: &str) -> Option<Requirement> {
     Requirement::from_str(req).ok()
 }
 
+/// Format a requirement for the `pyproject.toml`.
+///
+/// Unlike [`Requirement::to_string`], URLs that reference environment variables are written as
+/// given (e.g., `https://${HOST}/...`), rather than in their expanded form.
+fn requirement_to_string(req: &Requirement) -> String {
+    let Some(VersionOrUrl::Url(url)) = &req.version_or_url else {
+        return req.to_string();
+    };
+    let Some(templated) = url.templated() else {
+        return req.to_string();
+    };
+
+    let mut unversioned = req.clone();
+    unversioned.version_or_url = None;
+    unversioned.marker = None;
+
+    match &req.marker {
+        Some(marker) => format!("{unversioned} @ {templated} ; {marker}"),
+        None => format!("{unversioned} @ {templated}"),
+    }
+}
+
 /// Reformats a TOML array to multi line while trying to preserve all comments
 /// and move them around. This also formats the array to have a trailing comma.
 fn reformat_array_multiline(deps: &mut Array) {
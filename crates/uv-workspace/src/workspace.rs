@@ -4,6 +4,7 @@ use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use either::Either;
+use futures::{StreamExt, TryStreamExt};
 use glob::{glob, GlobError, PatternError};
 use rustc_hash::FxHashSet;
 use tracing::{debug, trace, warn};
@@ -16,6 +17,9 @@ use uv_warnings::warn_user;
 
 use crate::pyproject::{Project, PyProjectToml, Source, ToolUvWorkspace};
 
+/// The number of workspace member `pyproject.toml` files to read concurrently.
+const MEMBER_CONCURRENCY: usize = 32;
+
 #[derive(thiserror::Error, Debug)]
 pub enum WorkspaceError {
     // Workspace structure errors.
@@ -62,6 +66,15 @@ pub struct Workspace {
     ///
     /// This table is overridden by the project sources.
     sources: BTreeMap<PackageName, Source>,
+    /// The `tool.uv.environment` setting from the workspace `pyproject.toml`, if any.
+    #[cfg_attr(test, serde(skip))]
+    environment: Option<PathBuf>,
+    /// The `tool.uv.seed` setting from the workspace `pyproject.toml`, if any.
+    #[cfg_attr(test, serde(skip))]
+    seed: bool,
+    /// The `tool.uv.python-implementation` setting from the workspace `pyproject.toml`, if any.
+    #[cfg_attr(test, serde(skip))]
+    python_implementation: Option<String>,
 }
 
 impl Workspace {
@@ -309,8 +322,29 @@ impl Workspace {
     }
 
     /// The path to the workspace virtual environment.
+    ///
+    /// The path is read from `UV_PROJECT_ENVIRONMENT`, then from `tool.uv.environment`, and
+    /// otherwise defaults to `.venv`. Relative paths are resolved against the workspace root.
     pub fn venv(&self) -> PathBuf {
-        self.install_path.join(".venv")
+        let environment = std::env::var_os("UV_PROJECT_ENVIRONMENT")
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| self.environment.clone());
+        match environment {
+            Some(environment) => self.install_path.join(environment),
+            None => self.install_path.join(".venv"),
+        }
+    }
+
+    /// Whether to install seed packages into the workspace virtual environment, per
+    /// `tool.uv.seed`.
+    pub fn seed(&self) -> bool {
+        self.seed
+    }
+
+    /// The Python implementation required by the workspace, per `tool.uv.python-implementation`.
+    pub fn python_implementation(&self) -> Option<&str> {
+        self.python_implementation.as_deref()
     }
 
     /// The members of the workspace.
@@ -377,7 +411,8 @@ impl Workspace {
             workspace_members.insert(root_member.project.name.clone(), root_member);
         }
 
-        // Add all other workspace members.
+        // Collect all other workspace members.
+        let mut member_roots = Vec::new();
         for member_glob in workspace_definition.members.unwrap_or_default() {
             let absolute_glob = workspace_root
                 .simplified()
@@ -395,54 +430,71 @@ impl Workspace {
                 let member_root = absolutize_path(&member_root)
                     .map_err(WorkspaceError::Normalize)?
                     .to_path_buf();
+                member_roots.push(member_root);
+            }
+        }
 
+        // Read the member `pyproject.toml` files concurrently, since large workspaces may contain
+        // many members. The results are processed in order, to keep discovery deterministic.
+        let member_pyprojects = futures::stream::iter(member_roots)
+            .map(|member_root| async move {
                 trace!("Processing workspace member {}", member_root.user_display());
 
-                // Read the member `pyproject.toml`.
                 let pyproject_path = member_root.join("pyproject.toml");
                 let contents = fs_err::tokio::read_to_string(&pyproject_path).await?;
                 let pyproject_toml = PyProjectToml::from_string(contents)
                     .map_err(|err| WorkspaceError::Toml(pyproject_path, Box::new(err)))?;
+                Ok::<_, WorkspaceError>((member_root, pyproject_toml))
+            })
+            .buffered(MEMBER_CONCURRENCY)
+            .try_collect::<Vec<_>>()
+            .await?;
 
-                // Check if the current project is explicitly marked as unmanaged.
-                if pyproject_toml
-                    .tool
-                    .as_ref()
-                    .and_then(|tool| tool.uv.as_ref())
-                    .and_then(|uv| uv.managed)
-                    == Some(false)
-                {
-                    debug!(
-                        "Project `{}` is marked as unmanaged; omitting from workspace members",
-                        pyproject_toml.project.as_ref().unwrap().name
-                    );
-                    continue;
-                }
-
-                // Extract the package name.
-                let Some(project) = pyproject_toml.project.clone() else {
-                    return Err(WorkspaceError::MissingProject(member_root));
-                };
-
+        // Add all other workspace members.
+        for (member_root, pyproject_toml) in member_pyprojects {
+            // Check if the current project is explicitly marked as unmanaged.
+            if pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.managed)
+                == Some(false)
+            {
                 debug!(
-                    "Adding discovered workspace member: {}",
-                    member_root.simplified_display()
-                );
-                workspace_members.insert(
-                    project.name.clone(),
-                    WorkspaceMember {
-                        root: member_root.clone(),
-                        project,
-                        pyproject_toml,
-                    },
+                    "Project `{}` is marked as unmanaged; omitting from workspace members",
+                    pyproject_toml.project.as_ref().unwrap().name
                 );
+                continue;
             }
+
+            // Extract the package name.
+            let Some(project) = pyproject_toml.project.clone() else {
+                return Err(WorkspaceError::MissingProject(member_root));
+            };
+
+            debug!(
+                "Adding discovered workspace member: {}",
+                member_root.simplified_display()
+            );
+            workspace_members.insert(
+                project.name.clone(),
+                WorkspaceMember {
+                    root: member_root.clone(),
+                    project,
+                    pyproject_toml,
+                },
+            );
         }
-        let workspace_sources = workspace_pyproject_toml
-            .tool
-            .and_then(|tool| tool.uv)
-            .and_then(|uv| uv.sources)
+        let workspace_uv = workspace_pyproject_toml.tool.and_then(|tool| tool.uv);
+        let workspace_environment = workspace_uv.as_ref().and_then(|uv| uv.environment.clone());
+        let workspace_seed = workspace_uv
+            .as_ref()
+            .and_then(|uv| uv.seed)
             .unwrap_or_default();
+        let workspace_python_implementation = workspace_uv
+            .as_ref()
+            .and_then(|uv| uv.python_implementation.clone());
+        let workspace_sources = workspace_uv.and_then(|uv| uv.sources).unwrap_or_default();
 
         check_nested_workspaces(&workspace_root, stop_discovery_at);
 
@@ -451,6 +503,9 @@ impl Workspace {
             lock_path,
             packages: workspace_members,
             sources: workspace_sources,
+            environment: workspace_environment,
+            seed: workspace_seed,
+            python_implementation: workspace_python_implementation,
         })
     }
 }
@@ -684,6 +739,22 @@ impl ProjectWorkspace {
         &self.workspace().packages[&self.project_name]
     }
 
+    /// Replace the `pyproject.toml` of the current project, e.g., after it was modified on disk.
+    ///
+    /// Returns `None` if the `pyproject.toml` is missing a `[project]` table.
+    #[must_use]
+    pub fn with_pyproject_toml(self, pyproject_toml: PyProjectToml) -> Option<Self> {
+        let mut workspace = self.workspace;
+        let member = workspace.packages.get_mut(&self.project_name)?;
+        member.project = pyproject_toml.project.clone()?;
+        member.pyproject_toml = pyproject_toml;
+        Some(Self {
+            project_root: self.project_root,
+            project_name: self.project_name,
+            workspace,
+        })
+    }
+
     /// Find the workspace for a project.
     pub async fn from_project(
         install_path: &Path,
@@ -753,6 +824,22 @@ impl ProjectWorkspace {
                     // There may be package sources, but we don't need to duplicate them into the
                     // workspace sources.
                     sources: BTreeMap::default(),
+                    environment: project_pyproject_toml
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.environment.clone()),
+                    seed: project_pyproject_toml
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.seed)
+                        .unwrap_or_default(),
+                    python_implementation: project_pyproject_toml
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.python_implementation.clone()),
                 },
             });
         };
@@ -1145,7 +1232,11 @@ mod tests {
                 "project": {
                   "name": "bird-feeder",
                   "requires-python": ">=3.12",
-                  "optional-dependencies": null
+                  "dependencies": [
+                    "anyio>=4.3.0,<5"
+                  ],
+                  "optional-dependencies": null,
+                  "scripts": null
                 },
                 "pyproject_toml": "[PYPROJECT_TOML]"
               }
@@ -1181,7 +1272,11 @@ mod tests {
                     "project": {
                       "name": "bird-feeder",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "anyio>=4.3.0,<5"
+                      ],
+                      "optional-dependencies": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   }
@@ -1216,7 +1311,12 @@ mod tests {
                     "project": {
                       "name": "albatross",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "bird-feeder",
+                        "tqdm>=4,<5"
+                      ],
+                      "optional-dependencies": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   },
@@ -1225,7 +1325,12 @@ mod tests {
                     "project": {
                       "name": "bird-feeder",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "anyio>=4.3.0,<5",
+                        "seeds"
+                      ],
+                      "optional-dependencies": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   },
@@ -1234,7 +1339,11 @@ mod tests {
                     "project": {
                       "name": "seeds",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "idna==3.6"
+                      ],
+                      "optional-dependencies": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   }
@@ -1275,7 +1384,12 @@ mod tests {
                     "project": {
                       "name": "albatross",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "bird-feeder",
+                        "tqdm>=4,<5"
+                      ],
+                      "optional-dependencies": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   },
@@ -1284,7 +1398,12 @@ mod tests {
                     "project": {
                       "name": "bird-feeder",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "anyio>=4.3.0,<5",
+                        "seeds"
+                      ],
+                      "optional-dependencies": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   },
@@ -1293,7 +1412,11 @@ mod tests {
                     "project": {
                       "name": "seeds",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "idna==3.6"
+                      ],
+                      "optional-dependencies": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   }
@@ -1328,7 +1451,11 @@ mod tests {
                     "project": {
                       "name": "albatross",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "tqdm>=4,<5"
+                      ],
+                      "optional-dependencies": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   }
@@ -0,0 +1,120 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::{Cache, PackageUsage};
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Show the size of the cache, and the cached packages that use the most space.
+pub(crate) fn cache_info(
+    json: bool,
+    top: usize,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let usage = cache
+        .usage()
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+
+    // Count the distinct packages, across all cached versions.
+    let mut names = usage
+        .packages
+        .iter()
+        .map(|package| &package.name)
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+    let package_count = names.len();
+
+    if json {
+        let info = Info {
+            total_size_bytes: usage.total_bytes,
+            package_count,
+            packages: usage.packages.iter().take(top).map(Entry::from).collect(),
+        };
+        let output = serde_json::to_string(&info)?;
+        writeln!(printer.stdout(), "{output}")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stdout(),
+        "Cache directory: {}",
+        cache.root().user_display().cyan()
+    )?;
+    writeln!(
+        printer.stdout(),
+        "Total size: {}",
+        format_bytes(usage.total_bytes).green()
+    )?;
+    writeln!(printer.stdout(), "Cached packages: {package_count}")?;
+
+    if top > 0 && !usage.packages.is_empty() {
+        writeln!(printer.stdout(), "Largest entries:")?;
+        for package in usage.packages.iter().take(top) {
+            writeln!(
+                printer.stdout(),
+                "  {} {}",
+                format!("{}=={}", package.name, package.version).bold(),
+                format!("({})", format_bytes(package.size_bytes)).dimmed()
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Format a byte count for display, e.g., `512B` or `1.2MiB`.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
+
+/// The cache information, as emitted by `uv cache info --json`.
+#[derive(Debug, Serialize)]
+struct Info {
+    total_size_bytes: u64,
+    package_count: usize,
+    packages: Vec<Entry>,
+}
+
+/// A cached package version in the JSON output.
+#[derive(Debug, Serialize)]
+struct Entry {
+    name: String,
+    version: String,
+    size_bytes: u64,
+    last_accessed: Option<String>,
+}
+
+impl From<&PackageUsage> for Entry {
+    fn from(package: &PackageUsage) -> Self {
+        Self {
+            name: package.name.to_string(),
+            version: package.version.to_string(),
+            size_bytes: package.size_bytes,
+            last_accessed: package
+                .last_accessed
+                .map(|time| DateTime::<Utc>::from(time).to_rfc3339_opts(SecondsFormat::Secs, true)),
+        }
+    }
+}
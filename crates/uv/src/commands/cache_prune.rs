@@ -3,14 +3,21 @@ use std::fmt::Write;
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 
+use pep440_rs::Version;
 use uv_cache::Cache;
 use uv_fs::Simplified;
+use uv_normalize::PackageName;
 
 use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
 
-/// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+/// Prune all unreachable objects from the cache, or those linked to a specific package.
+pub(crate) fn cache_prune(
+    package: Option<&PackageName>,
+    version: Option<&Version>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -20,6 +27,10 @@ pub(crate) fn cache_prune(cache: &Cache, printer: Printer) -> Result<ExitStatus>
         return Ok(ExitStatus::Success);
     }
 
+    if let Some(package) = package {
+        return cache_prune_package(package, version, cache, printer);
+    }
+
     writeln!(
         printer.stderr(),
         "Pruning cache at: {}",
@@ -64,3 +75,81 @@ pub(crate) fn cache_prune(cache: &Cache, printer: Printer) -> Result<ExitStatus>
 
     Ok(ExitStatus::Success)
 }
+
+/// Remove the cache entries for a single package, optionally limited to a specific version.
+fn cache_prune_package(
+    package: &PackageName,
+    version: Option<&Version>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let target = if let Some(version) = version {
+        format!("{package}=={version}")
+    } else {
+        package.to_string()
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Pruning {} from cache at: {}",
+        target.cyan(),
+        cache.root().user_display().cyan()
+    )?;
+
+    let summary = if let Some(version) = version {
+        cache.remove_version(package, version)
+    } else {
+        cache.remove(package)
+    }
+    .with_context(|| format!("Failed to prune `{target}` from cache"))?;
+
+    // Write a summary of the number of files and directories removed.
+    match (summary.num_files, summary.num_dirs) {
+        (0, 0) => {
+            write!(
+                printer.stderr(),
+                "No cache entries found for {}",
+                target.cyan()
+            )?;
+        }
+        (0, 1) => {
+            write!(
+                printer.stderr(),
+                "Removed 1 directory for {}",
+                target.cyan()
+            )?;
+        }
+        (0, num_dirs_removed) => {
+            write!(
+                printer.stderr(),
+                "Removed {num_dirs_removed} directories for {}",
+                target.cyan()
+            )?;
+        }
+        (1, _) => {
+            write!(printer.stderr(), "Removed 1 file for {}", target.cyan())?;
+        }
+        (num_files_removed, _) => {
+            write!(
+                printer.stderr(),
+                "Removed {num_files_removed} files for {}",
+                target.cyan()
+            )?;
+        }
+    }
+
+    // If any, write a summary of the total byte count removed.
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({})", bytes.green())?;
+    }
+
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
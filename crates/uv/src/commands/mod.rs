@@ -1,11 +1,12 @@
+use std::sync::Arc;
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
-use anyhow::Context;
 use owo_colors::OwoColorize;
 
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_info::cache_info;
 pub(crate) use cache_prune::cache_prune;
 use distribution_types::InstalledMetadata;
 pub(crate) use help::help;
@@ -19,12 +20,15 @@ pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
 pub(crate) use project::add::add;
+pub(crate) use project::export::export;
 pub(crate) use project::init::init;
 pub(crate) use project::lock::lock;
+pub(crate) use project::publish::publish;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::run;
 pub(crate) use project::sync::sync;
 pub(crate) use project::tree::tree;
+pub(crate) use project::ProjectError;
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
 pub(crate) use python::install::install as python_install;
@@ -36,11 +40,14 @@ pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
 pub(crate) use tool::install::install as tool_install;
 pub(crate) use tool::list::list as tool_list;
+pub(crate) use tool::ps::ps as tool_ps;
 pub(crate) use tool::run::run as tool_run;
 pub(crate) use tool::run::ToolRunCommand;
+pub(crate) use tool::stop::stop as tool_stop;
 pub(crate) use tool::uninstall::uninstall as tool_uninstall;
 pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 use uv_cache::Cache;
+use uv_configuration::Concurrency;
 use uv_fs::Simplified;
 use uv_git::GitResolver;
 use uv_installer::compile_tree;
@@ -48,19 +55,23 @@ use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
 use uv_resolver::InMemoryIndex;
 use uv_types::InFlight;
+use uv_warnings::warn_user;
 pub(crate) use venv::venv;
 pub(crate) use version::version;
 
+use crate::commands::reporters::CompileReporter;
 use crate::printer::Printer;
 
 mod cache_clean;
 mod cache_dir;
+mod cache_info;
 mod cache_prune;
 mod help;
 pub(crate) mod pip;
 mod project;
 mod python;
 pub(crate) mod reporters;
+pub(crate) mod strict;
 mod tool;
 
 #[cfg(feature = "self-update")]
@@ -78,6 +89,31 @@ pub(crate) enum ExitStatus {
 
     /// The command failed with an unexpected error.
     Error,
+
+    /// The command failed because the requested executable could not be found.
+    ///
+    /// Mirrors the `127` exit code used by shells, to distinguish a missing executable from one
+    /// that ran and failed.
+    NotFound,
+
+    /// The command ran a child process, which exited with the given code.
+    ExitCode(i32),
+
+    /// The command ran a child process, which was terminated after exceeding its timeout.
+    ///
+    /// Mirrors the `124` exit code used by `timeout`.
+    TimedOut,
+}
+
+impl From<std::process::ExitStatus> for ExitStatus {
+    fn from(status: std::process::ExitStatus) -> Self {
+        match status.code() {
+            Some(0) => Self::Success,
+            // If the process was terminated by a signal, there's no exit code to propagate.
+            Some(code) => Self::ExitCode(code),
+            None => Self::Failure,
+        }
+    }
 }
 
 impl From<ExitStatus> for ExitCode {
@@ -86,6 +122,10 @@ impl From<ExitStatus> for ExitCode {
             ExitStatus::Success => Self::from(0),
             ExitStatus::Failure => Self::from(1),
             ExitStatus::Error => Self::from(2),
+            ExitStatus::NotFound => Self::from(127),
+            // Exit codes outside of the portable range can't be represented; fall back to failure.
+            ExitStatus::ExitCode(code) => Self::from(u8::try_from(code).unwrap_or(1)),
+            ExitStatus::TimedOut => Self::from(124),
         }
     }
 }
@@ -131,22 +171,36 @@ pub(super) struct DryRunEvent<T: Display> {
 /// initial run of any subsequent executions.
 ///
 /// See the `--compile` option on `pip sync` and `pip install`.
+///
+/// Compilation is an optimization, so failures are reported as warnings rather than errors.
 pub(super) async fn compile_bytecode(
     venv: &PythonEnvironment,
+    concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
     let mut files = 0;
     for site_packages in venv.site_packages() {
-        files += compile_tree(&site_packages, venv.python_executable(), cache.root())
-            .await
-            .with_context(|| {
-                format!(
-                    "Failed to bytecode-compile Python file in: {}",
+        let reporter: Arc<dyn uv_installer::CompileReporter> =
+            Arc::new(CompileReporter::from(printer));
+        match compile_tree(
+            &site_packages,
+            venv.python_executable(),
+            concurrency.installs,
+            cache.root(),
+            Some(reporter),
+        )
+        .await
+        {
+            Ok(compiled) => files += compiled,
+            Err(err) => {
+                warn_user!(
+                    "Failed to bytecode-compile Python files in `{}`: {err}",
                     site_packages.user_display()
-                )
-            })?;
+                );
+            }
+        }
     }
     let s = if files == 1 { "" } else { "s" };
     writeln!(
@@ -22,7 +22,7 @@ use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
     BuildOptions, Concurrency, Constraints, ExtrasSpecification, Overrides, PreviewMode, Reinstall,
-    Upgrade,
+    StrictCategory, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -42,6 +42,7 @@ use uv_types::{HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
 
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
+use crate::commands::strict::{self, StrictViolation};
 use crate::commands::{compile_bytecode, elapsed, ChangeEvent, ChangeEventKind, DryRunEvent};
 use crate::printer::Printer;
 
@@ -293,6 +294,22 @@ pub(crate) enum Modifications {
     Exact,
 }
 
+/// A summary of the changes made (or, in a dry run, that would be made) to a Python environment.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Changelog {
+    /// The number of packages that were installed.
+    pub(crate) installed: usize,
+    /// The number of packages that were uninstalled, including those that were reinstalled.
+    pub(crate) uninstalled: usize,
+}
+
+impl Changelog {
+    /// Returns `true` if the environment was left unchanged.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.installed == 0 && self.uninstalled == 0
+    }
+}
+
 /// Install a set of requirements into the current environment.
 pub(crate) async fn install(
     resolution: &Resolution,
@@ -314,7 +331,7 @@ pub(crate) async fn install(
     dry_run: bool,
     printer: Printer,
     preview: PreviewMode,
-) -> Result<(), Error> {
+) -> Result<Changelog, Error> {
     let start = std::time::Instant::now();
 
     // Extract the requirements from the resolution.
@@ -365,7 +382,7 @@ pub(crate) async fn install(
             )
             .dimmed()
         )?;
-        return Ok(());
+        return Ok(Changelog::default());
     }
 
     // Map any registry-based requirements back to those returned by the resolver.
@@ -484,13 +501,18 @@ pub(crate) async fn install(
     }
 
     if compile {
-        compile_bytecode(venv, cache, printer).await?;
+        compile_bytecode(venv, concurrency, cache, printer).await?;
     }
 
+    let changelog = Changelog {
+        installed: wheels.len(),
+        uninstalled: extraneous.len() + reinstalls.len(),
+    };
+
     // Notify the user of any environment modifications.
     report_modifications(wheels, reinstalls, extraneous, printer)?;
 
-    Ok(())
+    Ok(changelog)
 }
 
 /// Report on the results of a dry-run installation.
@@ -500,7 +522,7 @@ fn report_dry_run(
     modifications: Modifications,
     start: std::time::Instant,
     printer: Printer,
-) -> Result<(), Error> {
+) -> Result<Changelog, Error> {
     let Plan {
         cached,
         remote,
@@ -528,7 +550,7 @@ fn report_dry_run(
             .dimmed()
         )?;
         writeln!(printer.stderr(), "Would make no changes")?;
-        return Ok(());
+        return Ok(Changelog::default());
     }
 
     // Map any registry-based requirements back to those returned by the resolver.
@@ -589,9 +611,15 @@ fn report_dry_run(
         )?;
     }
 
+    let changelog = Changelog {
+        installed: installs,
+        uninstalled: extraneous.len() + reinstalls.len(),
+    };
+
     // TDOO(charlie): DRY this up with `report_modifications`. The types don't quite line up.
-    for event in reinstalls
+    for event in extraneous
         .into_iter()
+        .chain(reinstalls)
         .map(|distribution| DryRunEvent {
             name: distribution.name().clone(),
             version: distribution.installed_version().to_string(),
@@ -631,7 +659,7 @@ fn report_dry_run(
         }
     }
 
-    Ok(())
+    Ok(changelog)
 }
 
 /// Report on any modifications to the Python environment.
@@ -690,6 +718,9 @@ pub(crate) fn diagnose_resolution(
     printer: Printer,
 ) -> Result<(), Error> {
     for diagnostic in diagnostics {
+        if matches!(diagnostic, ResolutionDiagnostic::YankedVersion { .. }) {
+            strict::check(StrictCategory::Yanked, diagnostic.message())?;
+        }
         writeln!(
             printer.stderr(),
             "{}{} {}",
@@ -754,4 +785,7 @@ pub(crate) enum Error {
 
     #[error(transparent)]
     PubGrubSpecifier(#[from] uv_resolver::PubGrubSpecifierError),
+
+    #[error(transparent)]
+    Strict(#[from] StrictViolation),
 }
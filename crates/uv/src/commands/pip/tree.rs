@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::Write;
 
 use anyhow::Result;
@@ -8,7 +9,7 @@ use tracing::debug;
 
 use distribution_types::{Diagnostic, Name};
 use pep508_rs::MarkerEnvironment;
-use pypi_types::RequirementSource;
+use pypi_types::{License, RequirementSource};
 use uv_cache::Cache;
 use uv_distribution::Metadata;
 use uv_fs::Simplified;
@@ -24,7 +25,7 @@ use crate::printer::Printer;
 /// Display the installed packages in the current environment as a dependency tree.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) fn pip_tree(
-    depth: u8,
+    depth: Option<usize>,
     prune: Vec<PackageName>,
     package: Vec<PackageName>,
     no_dedupe: bool,
@@ -62,7 +63,7 @@ pub(crate) fn pip_tree(
 
     // Render the tree.
     let rendered_tree = DisplayDependencyGraph::new(
-        depth.into(),
+        depth,
         prune,
         package,
         no_dedupe,
@@ -76,14 +77,7 @@ pub(crate) fn pip_tree(
 
     writeln!(printer.stdout(), "{rendered_tree}")?;
 
-    if rendered_tree.contains("(*)") {
-        let message = if no_dedupe {
-            "(*) Package tree is a cycle and cannot be shown".italic()
-        } else {
-            "(*) Package tree already displayed".italic()
-        };
-        writeln!(printer.stdout(), "{message}")?;
-    }
+    write_tree_legend(&rendered_tree, printer)?;
 
     // Validate that the environment is consistent.
     if strict {
@@ -101,11 +95,30 @@ pub(crate) fn pip_tree(
     Ok(ExitStatus::Success)
 }
 
+/// Explain the markers used in the rendered tree, if any.
+pub(crate) fn write_tree_legend(rendered_tree: &str, printer: Printer) -> Result<()> {
+    if rendered_tree.contains("(*)") {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            "(*) Package tree already displayed".italic()
+        )?;
+    }
+    if rendered_tree.contains("(cycle)") {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            "(cycle) Package tree is a cycle and cannot be shown".italic()
+        )?;
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 pub(crate) struct DisplayDependencyGraph {
     packages: IndexMap<PackageName, Vec<Metadata>>,
-    /// Maximum display depth of the dependency tree
-    depth: usize,
+    /// Maximum display depth of the dependency tree, if any.
+    depth: Option<usize>,
     /// Prune the given packages from the display of the dependency tree.
     prune: Vec<PackageName>,
     /// Display only the specified packages.
@@ -118,12 +131,14 @@ pub(crate) struct DisplayDependencyGraph {
     requirements: FxHashMap<PackageName, Vec<PackageName>>,
     /// Map from requirement package name-to-parent-to-dependency metadata.
     dependencies: FxHashMap<PackageName, FxHashMap<PackageName, Dependency>>,
+    /// Map from package name to its license, if licenses should be displayed.
+    licenses: Option<BTreeMap<PackageName, License>>,
 }
 
 impl DisplayDependencyGraph {
     /// Create a new [`DisplayDependencyGraph`] for the set of installed distributions.
     pub(crate) fn new(
-        depth: usize,
+        depth: Option<usize>,
         prune: Vec<PackageName>,
         package: Vec<PackageName>,
         no_dedupe: bool,
@@ -180,9 +195,19 @@ impl DisplayDependencyGraph {
             no_dedupe,
             requirements,
             dependencies,
+            licenses: None,
         }
     }
 
+    /// Annotate each package with its license.
+    ///
+    /// Packages that are missing from the map are displayed with an unknown license.
+    #[must_use]
+    pub(crate) fn with_licenses(mut self, licenses: BTreeMap<PackageName, License>) -> Self {
+        self.licenses = Some(licenses);
+        self
+    }
+
     /// Perform a depth-first traversal of the given distribution and its dependencies.
     fn visit<'env>(
         &'env self,
@@ -190,14 +215,23 @@ impl DisplayDependencyGraph {
         visited: &mut FxHashMap<&'env PackageName, Vec<PackageName>>,
         path: &mut Vec<&'env PackageName>,
     ) -> Vec<String> {
-        // Short-circuit if the current path is longer than the provided depth.
-        if path.len() > self.depth {
-            return Vec::new();
-        }
-
         let package_name = &metadata.name;
         let mut line = format!("{} v{}", package_name, metadata.version);
 
+        // If licenses were requested, annotate the package, noting when the license was inferred
+        // from the (less reliable) classifiers.
+        if let Some(licenses) = self.licenses.as_ref() {
+            match licenses.get(package_name) {
+                Some(license @ License::Classifiers(_)) => {
+                    line.push_str(&format!(" ({license}, from classifiers)"));
+                }
+                Some(license) if !license.is_unknown() => {
+                    line.push_str(&format!(" ({license})"));
+                }
+                _ => line.push_str(" (unknown license)"),
+            }
+        }
+
         // If the current package is not top-level (i.e., it has a parent), include the specifiers.
         if let Some(last) = path.last().copied() {
             if let Some(dependency) = self
@@ -211,14 +245,19 @@ impl DisplayDependencyGraph {
         }
 
         // Skip the traversal if:
-        // 1. The package is in the current traversal path (i.e., a dependency cycle).
-        // 2. The package has been visited and de-duplication is enabled (default).
+        // 1. The package is in the current traversal path (i.e., a dependency cycle), marked with
+        //    `(cycle)`, even if de-duplication is disabled.
+        // 2. The package has been visited and de-duplication is enabled (default), marked with
+        //    `(*)`.
         if let Some(requirements) = visited.get(package_name) {
-            if !self.no_dedupe || path.contains(&package_name) {
+            if path.contains(&package_name) {
+                return vec![format!("{line} (cycle)")];
+            }
+            if !self.no_dedupe {
                 return if requirements.is_empty() {
                     vec![line]
                 } else {
-                    vec![format!("{} (*)", line)]
+                    vec![format!("{line} (*)")]
                 };
             }
         }
@@ -237,6 +276,16 @@ impl DisplayDependencyGraph {
 
         let mut lines = vec![line];
 
+        // If the current package is at the maximum depth, elide its dependencies. The package is
+        // intentionally omitted from `visited`, such that it can be expanded if it appears again
+        // at a shallower depth.
+        if self.depth.is_some_and(|depth| path.len() >= depth) {
+            if !requirements.is_empty() {
+                lines.push("└── ...".to_string());
+            }
+            return lines;
+        }
+
         // Keep track of the dependency path to avoid cycles.
         visited.insert(package_name, requirements.clone());
         path.push(package_name);
@@ -1,28 +1,52 @@
-use anyhow::{Context, Result};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
-use pep508_rs::ExtraName;
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use distribution_types::{DistributionMetadata, Name, Resolution, VersionOrUrlRef};
+use pep440_rs::{Version, VersionSpecifier, VersionSpecifiers};
+use pep508_rs::{ExtraName, VersionOrUrl};
+use pypi_types::{Requirement, RequirementSource};
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
-use uv_configuration::{Concurrency, ExtrasSpecification, PreviewMode, SetupPyStrategy};
+use uv_client::{
+    BaseClientBuilder, Connectivity, FlatIndexClient, OwnedArchive, RegistryClient,
+    RegistryClientBuilder,
+};
+use uv_configuration::{
+    AddBounds, Concurrency, DevSpecification, EditableMode, ExtrasSpecification, InstallOptions,
+    PreviewMode,
+};
 use uv_dispatch::BuildDispatch;
-use uv_distribution::DistributionDatabase;
+use uv_distribution::{DistributionDatabase, DEV_DEPENDENCIES};
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
-use uv_python::{PythonFetch, PythonPreference, PythonRequest};
+use uv_python::{
+    request_from_version_file, EnvironmentPreference, Interpreter, PythonFetch, PythonInstallation,
+    PythonPreference, PythonRequest, VersionRequest,
+};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSource, RequirementsSpecification};
 use uv_resolver::FlatIndex;
+use uv_scripts::{Pep723Metadata, Pep723Script};
 use uv_types::{BuildIsolation, HashStrategy};
-use uv_warnings::warn_user_once;
-use uv_workspace::pyproject::{DependencyType, Source, SourceError};
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject::{DependencyType, PyProjectToml, Source, SourceError};
 use uv_workspace::pyproject_mut::PyProjectTomlMut;
 use uv_workspace::{ProjectWorkspace, VirtualProject, Workspace};
 
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::resolution_environment;
-use crate::commands::reporters::ResolverReporter;
-use crate::commands::{project, ExitStatus, SharedState};
+use crate::commands::project::{ProjectEnvironment, ProjectError};
+use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
+use crate::commands::{pip, project, ExitStatus, SharedState};
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
 
+/// The maximum number of versions to try when searching for a version that resolves.
+const NEAREST_VERSIONS: usize = 5;
+
 /// Add one or more packages to the project requirements.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn add(
@@ -31,16 +55,21 @@ pub(crate) async fn add(
     requirements: Vec<RequirementsSource>,
     editable: Option<bool>,
     dependency_type: DependencyType,
+    git: Option<String>,
+    path: Option<PathBuf>,
     raw_sources: bool,
     rev: Option<String>,
     tag: Option<String>,
     branch: Option<String>,
     extras: Vec<ExtraName>,
+    bounds: AddBounds,
     package: Option<PackageName>,
     python: Option<String>,
+    script: Option<PathBuf>,
     settings: ResolverInstallerSettings,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     preview: PreviewMode,
     connectivity: Connectivity,
     concurrency: Concurrency,
@@ -52,34 +81,117 @@ pub(crate) async fn add(
         warn_user_once!("`uv add` is experimental and may change without warning");
     }
 
-    // Find the project in the workspace.
-    let project = if let Some(package) = package {
-        Workspace::discover(&std::env::current_dir()?, None)
-            .await?
-            .with_current_project(package.clone())
-            .with_context(|| format!("Package `{package}` not found in workspace"))?
-    } else {
-        ProjectWorkspace::discover(&std::env::current_dir()?, None).await?
-    };
-
-    // Discover or create the virtual environment.
-    let venv = project::get_or_init_environment(
-        project.workspace(),
-        python.as_deref().map(PythonRequest::parse),
-        python_preference,
-        python_fetch,
-        connectivity,
-        native_tls,
-        cache,
-        printer,
-    )
-    .await?;
-
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
         .keyring(settings.keyring_provider);
 
+    let target = if let Some(script) = script {
+        // Read the existing inline metadata, if any, refusing to edit a malformed block.
+        let existing = Pep723Script::read(&script).await.with_context(|| {
+            format!(
+                "Failed to read inline script metadata from: `{}`",
+                script.user_display()
+            )
+        })?;
+
+        // (1) Explicit request from user
+        let python_request = if let Some(request) = python.as_deref() {
+            Some(PythonRequest::parse(request))
+            // (2) Request from `.python-version`
+        } else if let Some(request) = request_from_version_file().await? {
+            Some(request)
+            // (3) `Requires-Python` in the script metadata
+        } else {
+            existing
+                .as_ref()
+                .and_then(|script| script.metadata.requires_python.clone())
+                .map(|requires_python| {
+                    PythonRequest::Version(VersionRequest::Range(requires_python))
+                })
+        };
+
+        let reporter = PythonDownloadReporter::single(printer);
+        let interpreter = PythonInstallation::find_or_fetch(
+            python_request,
+            EnvironmentPreference::Any,
+            python_preference,
+            python_fetch,
+            python_install_mirror,
+            &client_builder,
+            cache,
+            Some(&reporter),
+        )
+        .await?
+        .into_interpreter();
+
+        // If the script doesn't contain a metadata block yet, create one, requiring the minor
+        // version of the interpreter.
+        let script = if let Some(script) = existing {
+            script
+        } else {
+            let requires_python = VersionSpecifiers::from(
+                VersionSpecifier::greater_than_equal_version(interpreter.python_minor_version()),
+            );
+            Pep723Script::init(&script, &requires_python).await?
+        };
+
+        Target::Script(script, Box::new(interpreter))
+    } else {
+        // Find the project in the workspace.
+        let project = if let Some(package) = package {
+            Workspace::discover(&std::env::current_dir()?, None)
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?
+        } else {
+            ProjectWorkspace::discover(&std::env::current_dir()?, None).await?
+        };
+
+        // Discover or create the virtual environment.
+        let venv = project::get_or_init_environment(
+            project.workspace(),
+            python.as_deref().map(PythonRequest::parse),
+            false,
+            None,
+            false,
+            python_preference,
+            python_fetch,
+            python_install_mirror,
+            connectivity,
+            native_tls,
+            false,
+            cache,
+            printer,
+        )
+        .await?;
+
+        Target::Project(project, venv)
+    };
+
+    // Attach the `--git` or `--path` source to the requirement, if provided.
+    let requirements = if let Some(git) = git {
+        let url = if git.starts_with("git+") {
+            git
+        } else {
+            format!("git+{git}")
+        };
+        vec![requirement_with_source(requirements, &url)?]
+    } else if let Some(path) = path {
+        // Ensure that relative paths aren't mistaken for package names (e.g., `--path foo`).
+        let path = if path.is_absolute() || path.starts_with(".") || path.starts_with("..") {
+            path
+        } else {
+            Path::new(".").join(path)
+        };
+        vec![requirement_with_source(
+            requirements,
+            &path.to_string_lossy(),
+        )?]
+    } else {
+        requirements
+    };
+
     // Read the requirements.
     let RequirementsSpecification { requirements, .. } =
         RequirementsSpecification::from_sources(&requirements, &[], &[], &client_builder).await?;
@@ -89,19 +201,26 @@ pub(crate) async fn add(
     let python_version = None;
     let python_platform = None;
     let hasher = HashStrategy::default();
-    let setup_py = SetupPyStrategy::default();
-    let build_isolation = BuildIsolation::default();
+
+    // Determine whether to enable build isolation. Scripts don't have an environment to share.
+    let build_isolation = match &target {
+        Target::Project(_, venv) if settings.no_build_isolation => BuildIsolation::Shared(venv),
+        Target::Project(_, venv) if !settings.no_build_isolation_package.is_empty() => {
+            BuildIsolation::SharedPackage(venv, &settings.no_build_isolation_package)
+        }
+        _ => BuildIsolation::Isolated,
+    };
 
     // Determine the environment for the resolution.
     let (tags, markers) =
-        resolution_environment(python_version, python_platform, venv.interpreter())?;
+        resolution_environment(python_version, python_platform, target.interpreter())?;
 
     // Initialize the registry client.
     let client = RegistryClientBuilder::from(client_builder)
         .index_urls(settings.index_locations.index_urls())
         .index_strategy(settings.index_strategy)
         .markers(&markers)
-        .platform(venv.interpreter().platform())
+        .platform(target.interpreter().platform())
         .build();
 
     // Initialize any shared state.
@@ -118,14 +237,14 @@ pub(crate) async fn add(
     let build_dispatch = BuildDispatch::new(
         &client,
         cache,
-        venv.interpreter(),
+        target.interpreter(),
         &settings.index_locations,
         &flat_index,
         &state.index,
         &state.git,
         &state.in_flight,
         settings.index_strategy,
-        setup_py,
+        settings.setup_py,
         &settings.config_setting,
         build_isolation,
         settings.link_mode,
@@ -146,8 +265,14 @@ pub(crate) async fn add(
     .resolve()
     .await?;
 
-    // Add the requirements to the `pyproject.toml`.
-    let mut pyproject = PyProjectTomlMut::from_toml(project.current_project().pyproject_toml())?;
+    // Add the requirements to the `pyproject.toml` (or the script's metadata block).
+    let mut pyproject = match &target {
+        Target::Script(script, _) => PyProjectTomlMut::from_script(&script.raw)?,
+        Target::Project(project, _) => {
+            PyProjectTomlMut::from_toml(project.current_project().pyproject_toml())?
+        }
+    };
+    let mut added = Vec::with_capacity(requirements.len());
     for mut req in requirements {
         // Add the specified extras.
         req.extras.extend(extras.iter().cloned());
@@ -157,8 +282,22 @@ pub(crate) async fn add(
         let (req, source) = if raw_sources {
             // Use the PEP 508 requirement directly.
             (pep508_rs::Requirement::from(req), None)
+        } else if matches!(target, Target::Script(..)) {
+            // Scripts don't support `tool.uv.sources`, so use the PEP 508 requirement directly.
+            (pep508_rs::Requirement::from(req), None)
+        } else if let Some(templated) = templated_url(&req.source) {
+            // Sources are written in their expanded form, so retain the PEP 508 requirement to
+            // avoid persisting the values of any environment variables.
+            debug!(
+                "Using PEP 508 requirement for `{}`, as its URL references environment variables: `{templated}`",
+                req.name
+            );
+            (pep508_rs::Requirement::from(req), None)
         } else {
             // Otherwise, try to construct the source.
+            let Target::Project(project, _) = &target else {
+                unreachable!("scripts use PEP 508 requirements")
+            };
             let workspace = project.workspace().packages().contains_key(&req.name);
             let result = Source::from_requirement(
                 &req.name,
@@ -173,7 +312,7 @@ pub(crate) async fn add(
             let source = match result {
                 Ok(source) => source,
                 Err(SourceError::UnresolvedReference(rev)) => {
-                    anyhow::bail!("Cannot resolve Git reference `{rev}` for requirement `{}`. Specify the reference with one of `--tag`, `--branch`, or `--rev`, or use the `--raw-sources` flag.", req.name)
+                    bail!("Cannot resolve Git reference `{rev}` for requirement `{}`. Specify the reference with one of `--tag`, `--branch`, or `--rev`, or use the `--raw-sources` flag.", req.name)
                 }
                 Err(err) => return Err(err.into()),
             };
@@ -185,24 +324,61 @@ pub(crate) async fn add(
             (req, source)
         };
 
-        match dependency_type {
-            DependencyType::Production => {
-                pyproject.add_dependency(req, source)?;
-            }
-            DependencyType::Dev => {
-                pyproject.add_dev_dependency(req, source)?;
-            }
-            DependencyType::Optional(ref group) => {
-                pyproject.add_optional_dependency(req, group, source)?;
-            }
+        // Avoid duplicating a production dependency as a development dependency.
+        if matches!(dependency_type, DependencyType::Dev)
+            && pyproject
+                .find_dependency(&req.name)
+                .iter()
+                .any(|ty| matches!(ty, DependencyType::Production))
+        {
+            warn_user!(
+                "`{}` is already a production dependency; skipping",
+                req.name
+            );
+            continue;
         }
+
+        add_requirement(
+            &mut pyproject,
+            &dependency_type,
+            req.clone(),
+            source.clone(),
+        )?;
+        added.push((req, source));
     }
 
-    // Save the modified `pyproject.toml`.
-    fs_err::write(
-        project.current_project().root().join("pyproject.toml"),
-        pyproject.to_string(),
-    )?;
+    let (project, venv) = match target {
+        Target::Script(script, interpreter) => {
+            return add_script(
+                script,
+                &interpreter,
+                &pyproject,
+                &added,
+                frozen,
+                bounds,
+                &settings,
+                preview,
+                connectivity,
+                concurrency,
+                native_tls,
+                cache,
+                printer,
+            )
+            .await;
+        }
+        Target::Project(project, venv) => (project, venv),
+    };
+
+    // Save the modified `pyproject.toml`, retaining the original contents.
+    let pyproject_path = project.current_project().root().join("pyproject.toml");
+    let existing = fs_err::read_to_string(&pyproject_path)?;
+    fs_err::write(&pyproject_path, pyproject.to_string())?;
+
+    // Update the project to reflect the modified `pyproject.toml`, such that the lock includes any
+    // new optional dependency groups.
+    let project = project
+        .with_pyproject_toml(PyProjectToml::from_string(pyproject.to_string())?)
+        .context("The `pyproject.toml` is missing a `[project]` table")?;
 
     // If `--frozen`, exit early. There's no reason to lock and sync, and we don't need a `uv.lock`
     // to exist at all.
@@ -214,10 +390,13 @@ pub(crate) async fn add(
     let state = SharedState::default();
 
     // Lock and sync the environment, if necessary.
-    let lock = project::lock::do_safe_lock(
+    let lock = match project::lock::do_safe_lock(
         locked,
         frozen,
+        false,
         project.workspace(),
+        None,
+        None,
         venv.interpreter(),
         settings.as_ref().into(),
         &state,
@@ -228,20 +407,126 @@ pub(crate) async fn add(
         cache,
         printer,
     )
-    .await?;
+    .await
+    {
+        Ok(lock) => lock,
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::NoSolution(err),
+        ))) => {
+            fs_err::write(&pyproject_path, &existing)?;
+
+            let report = miette::Report::msg(format!("{err}")).context(err.header());
+            anstream::eprint!("{report:?}");
+
+            // If a single versioned requirement was added, suggest the nearest version that
+            // resolves. The search requires additional resolutions, so skip it if the user
+            // requested minimal output, or if the index can't be queried.
+            if let [(req, source)] = added.as_slice() {
+                if printer != Printer::Quiet && !matches!(connectivity, Connectivity::Offline) {
+                    match nearest_version(
+                        req,
+                        source.as_ref(),
+                        &dependency_type,
+                        &project,
+                        &pyproject_path,
+                        &existing,
+                        venv.interpreter(),
+                        &client,
+                        &settings,
+                        preview,
+                        connectivity,
+                        concurrency,
+                        native_tls,
+                        cache,
+                    )
+                    .await
+                    {
+                        Ok(Some(version)) => {
+                            writeln!(
+                                printer.stderr(),
+                                "{}: `{}` is the nearest version that resolves; try `{}`",
+                                "hint".bold().cyan(),
+                                format!("{}=={version}", req.name).green(),
+                                format!("uv add {}=={version}", req.name).green(),
+                            )?;
+                        }
+                        Ok(None) => {}
+                        Err(err) => debug!("Failed to find a satisfiable version: {err}"),
+                    }
+                }
+            }
+
+            return Ok(ExitStatus::Failure);
+        }
+        Err(err) => {
+            // If locking fails (e.g., under `--locked`, if the lockfile would change), restore the
+            // original `pyproject.toml`, rather than leaving it out-of-sync with the lockfile.
+            fs_err::write(&pyproject_path, existing)?;
+            return Err(err.into());
+        }
+    };
+
+    // Write the configured bounds for any registry requirements that were added without a version
+    // specifier, based on their locked versions.
+    if bounds != AddBounds::None {
+        let mut modified = false;
+        for (req, source) in &added {
+            let unconstrained = match &req.version_or_url {
+                None => true,
+                Some(VersionOrUrl::VersionSpecifier(specifiers)) => specifiers.is_empty(),
+                Some(VersionOrUrl::Url(_)) => false,
+            };
+            if source.is_some() || !unconstrained {
+                continue;
+            }
+
+            let versions = lock
+                .distributions()
+                .iter()
+                .filter(|dist| dist.name() == &req.name)
+                .map(|dist| dist.version())
+                .unique()
+                .collect::<Vec<_>>();
+            let [version] = versions.as_slice() else {
+                debug!(
+                    "Skipping bounds for `{}`, which is locked at {} versions",
+                    req.name,
+                    versions.len()
+                );
+                continue;
+            };
+
+            let Some(specifiers) = bounded_specifiers(bounds, version) else {
+                continue;
+            };
+            let mut req = req.clone();
+            req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
+            add_requirement(&mut pyproject, &dependency_type, req, None)?;
+            modified = true;
+        }
+
+        if modified {
+            fs_err::write(&pyproject_path, pyproject.to_string())?;
+        }
+    }
 
     // Perform a full sync, because we don't know what exactly is affected by the removal.
     // TODO(ibraheem): Should we accept CLI overrides for this? Should we even sync here?
     let extras = ExtrasSpecification::All;
-    let dev = true;
+    let dev = DevSpecification::include(vec![DEV_DEPENDENCIES.clone()]);
 
     project::sync::do_sync(
         &VirtualProject::Project(project),
         &venv,
         &lock,
         extras,
-        dev,
+        &dev,
+        EditableMode::default(),
+        &InstallOptions::default(),
         Modifications::Sufficient,
+        false,
+        false,
+        false,
         settings.as_ref().into(),
         &state,
         preview,
@@ -255,3 +540,291 @@ pub(crate) async fn add(
 
     Ok(ExitStatus::Success)
 }
+
+/// The target of `uv add`: either a project, or a PEP 723 script.
+enum Target {
+    /// A PEP 723 script, along with the interpreter used to resolve its dependencies.
+    Script(Pep723Script, Box<Interpreter>),
+    /// A project in a workspace, along with its virtual environment.
+    Project(ProjectWorkspace, ProjectEnvironment),
+}
+
+impl Target {
+    /// Returns the [`Interpreter`] for the target.
+    fn interpreter(&self) -> &Interpreter {
+        match self {
+            Self::Script(_, interpreter) => interpreter,
+            Self::Project(_, venv) => venv.interpreter(),
+        }
+    }
+}
+
+/// Write the requirements added to a PEP 723 script.
+///
+/// Unless `--frozen`, the script's requirements are resolved first, to ensure that they remain
+/// satisfiable and to determine the configured bounds for any unconstrained requirements. The
+/// script is left untouched if the resolution fails.
+#[allow(clippy::too_many_arguments)]
+async fn add_script(
+    script: Pep723Script,
+    interpreter: &Interpreter,
+    pyproject: &PyProjectTomlMut,
+    added: &[(pep508_rs::Requirement, Option<Source>)],
+    frozen: bool,
+    bounds: AddBounds,
+    settings: &ResolverInstallerSettings,
+    preview: PreviewMode,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut content = pyproject.to_string();
+
+    if !frozen {
+        let metadata = toml::from_str::<Pep723Metadata>(&content)?;
+        let requirements = metadata
+            .dependencies
+            .into_iter()
+            .map(Requirement::from)
+            .collect();
+        let spec = RequirementsSpecification::from_requirements(requirements);
+
+        // Resolve the script requirements, to validate the edit.
+        let graph = project::resolve_environment(
+            interpreter,
+            spec,
+            settings.as_ref().into(),
+            &SharedState::default(),
+            preview,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await?;
+        let resolution = Resolution::from(graph);
+
+        // Write the configured bounds for any registry requirements that were added without a
+        // version specifier, based on their resolved versions.
+        if bounds != AddBounds::None {
+            let mut pyproject = PyProjectTomlMut::from_script(&content)?;
+            for (req, _) in added {
+                let unconstrained = match &req.version_or_url {
+                    None => true,
+                    Some(VersionOrUrl::VersionSpecifier(specifiers)) => specifiers.is_empty(),
+                    Some(VersionOrUrl::Url(_)) => false,
+                };
+                if !unconstrained {
+                    continue;
+                }
+
+                let Some(version) = resolution
+                    .distributions()
+                    .filter(|dist| dist.name() == &req.name)
+                    .find_map(|dist| match dist.version_or_url() {
+                        VersionOrUrlRef::Version(version) => Some(version.clone()),
+                        VersionOrUrlRef::Url(_) => None,
+                    })
+                else {
+                    continue;
+                };
+
+                let Some(specifiers) = bounded_specifiers(bounds, &version) else {
+                    continue;
+                };
+                let mut req = req.clone();
+                req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
+                pyproject.add_dependency(req, None)?;
+            }
+            content = pyproject.to_string();
+        }
+    }
+
+    script.write(&content).await?;
+
+    writeln!(
+        printer.stderr(),
+        "Updated `{}`",
+        script.path.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Add a requirement to the `pyproject.toml`, as the given [`DependencyType`].
+fn add_requirement(
+    pyproject: &mut PyProjectTomlMut,
+    dependency_type: &DependencyType,
+    req: pep508_rs::Requirement,
+    source: Option<Source>,
+) -> Result<()> {
+    match dependency_type {
+        DependencyType::Production => {
+            pyproject.add_dependency(req, source)?;
+        }
+        DependencyType::Dev => {
+            pyproject.add_dev_dependency(req, source)?;
+        }
+        DependencyType::Optional(group) => {
+            pyproject.add_optional_dependency(req, group, source)?;
+        }
+    }
+    Ok(())
+}
+
+/// Find the nearest available version of a requirement that resolves with the rest of the
+/// project, if any.
+///
+/// At most [`NEAREST_VERSIONS`] candidates are tried, preferring the versions immediately below
+/// the requested version. Of those that resolve, the latest is returned. The `pyproject.toml` is
+/// restored to its original contents before returning.
+#[allow(clippy::too_many_arguments)]
+async fn nearest_version(
+    req: &pep508_rs::Requirement,
+    source: Option<&Source>,
+    dependency_type: &DependencyType,
+    project: &ProjectWorkspace,
+    pyproject_path: &Path,
+    existing: &str,
+    interpreter: &Interpreter,
+    client: &RegistryClient,
+    settings: &ResolverInstallerSettings,
+    preview: PreviewMode,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+) -> Result<Option<Version>> {
+    let Some(VersionOrUrl::VersionSpecifier(specifiers)) = req.version_or_url.as_ref() else {
+        return Ok(None);
+    };
+    let Some(requested) = specifiers.iter().map(VersionSpecifier::version).max() else {
+        return Ok(None);
+    };
+
+    // Determine the available versions from the first index that provides the package.
+    let Some((_, archive)) = client.simple(&req.name).await?.into_iter().next() else {
+        return Ok(None);
+    };
+    let versions = OwnedArchive::deserialize(&archive)
+        .into_iter()
+        .map(|datum| datum.version)
+        .filter(|version| !specifiers.contains(version))
+        .filter(|version| requested.any_prerelease() || !version.any_prerelease())
+        .collect::<Vec<_>>();
+
+    // Select the versions closest to the requested version, then try them from newest to oldest.
+    let split = versions.partition_point(|version| version < requested);
+    let mut candidates = versions[..split]
+        .iter()
+        .rev()
+        .interleave(&versions[split..])
+        .take(NEAREST_VERSIONS)
+        .cloned()
+        .collect::<Vec<_>>();
+    candidates.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut result = Ok(None);
+    for version in candidates {
+        debug!("Trying `{}=={version}`", req.name);
+
+        let mut req = req.clone();
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
+            VersionSpecifier::equals_version(version.clone()),
+        )));
+        let mut pyproject =
+            PyProjectTomlMut::from_toml(project.current_project().pyproject_toml())?;
+        add_requirement(&mut pyproject, dependency_type, req, source.cloned())?;
+        fs_err::write(pyproject_path, pyproject.to_string())?;
+
+        let lock = project::lock::do_lock(
+            project.workspace(),
+            interpreter,
+            None,
+            None,
+            &[],
+            settings.as_ref().into(),
+            &SharedState::default(),
+            preview,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            Printer::Quiet,
+        )
+        .await;
+
+        match lock {
+            Ok(_) => {
+                result = Ok(Some(version));
+                break;
+            }
+            Err(ProjectError::Operation(pip::operations::Error::Resolve(_))) => {}
+            Err(err) => {
+                result = Err(err.into());
+                break;
+            }
+        }
+    }
+
+    fs_err::write(pyproject_path, existing)?;
+
+    result
+}
+
+/// Returns the version specifiers to write for a requirement locked at the given version, according
+/// to the [`AddBounds`].
+fn bounded_specifiers(bounds: AddBounds, version: &Version) -> Option<VersionSpecifiers> {
+    // Local version identifiers are only permitted in `==` specifiers.
+    let lower = version.clone().without_local();
+    let specifiers = match bounds {
+        AddBounds::None => return None,
+        AddBounds::Lower => vec![VersionSpecifier::greater_than_equal_version(lower)],
+        AddBounds::Exact => vec![VersionSpecifier::equals_version(version.clone())],
+        AddBounds::Caret => {
+            // Increment the first non-zero component of the release (e.g., `<2` for `1.2.3`, or
+            // `<0.3` for `0.2.3`).
+            let release = version.release();
+            let index = release
+                .iter()
+                .position(|part| *part != 0)
+                .unwrap_or(release.len() - 1);
+            let mut upper = release[..=index].to_vec();
+            upper[index] += 1;
+            vec![
+                VersionSpecifier::greater_than_equal_version(lower),
+                VersionSpecifier::less_than_version(Version::new(upper)),
+            ]
+        }
+    };
+    Some(specifiers.into_iter().collect())
+}
+
+/// Attach the given URL or path to the requirement provided alongside `--git` or `--path`.
+///
+/// If no requirement was provided, the source is returned as an unnamed requirement, such that
+/// the package name is inferred from the source itself.
+fn requirement_with_source(
+    requirements: Vec<RequirementsSource>,
+    source: &str,
+) -> Result<RequirementsSource> {
+    match requirements.as_slice() {
+        [] => Ok(RequirementsSource::Package(source.to_string())),
+        [RequirementsSource::Package(name)] => {
+            Ok(RequirementsSource::Package(format!("{name} @ {source}")))
+        }
+        _ => bail!("`--git` and `--path` can only be used to add a single package"),
+    }
+}
+
+/// Return the URL as given by the user, if the requirement source references environment
+/// variables.
+fn templated_url(source: &RequirementSource) -> Option<&str> {
+    match source {
+        RequirementSource::Url { url, .. } | RequirementSource::Git { url, .. } => url.templated(),
+        _ => None,
+    }
+}
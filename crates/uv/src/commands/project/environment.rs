@@ -2,15 +2,17 @@ use itertools::Itertools;
 use tracing::debug;
 
 use cache_key::digest;
-use distribution_types::Resolution;
+use distribution_types::{Name, Resolution};
 use pypi_types::Requirement;
-use uv_cache::{Cache, CacheBucket};
+use uv_cache::stats::CacheEvent;
+use uv_cache::{Cache, CacheBucket, CacheEntry};
 use uv_client::Connectivity;
 use uv_configuration::{Concurrency, PreviewMode};
 use uv_fs::{LockedFile, Simplified};
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_requirements::RequirementsSpecification;
 
+use crate::commands::pip::operations::Modifications;
 use crate::commands::project::{resolve_environment, sync_environment};
 use crate::commands::SharedState;
 use crate::printer::Printer;
@@ -87,9 +89,15 @@ impl CachedEnvironment {
                 .as_bytes(),
         );
 
-        // Hash the interpreter based on its path.
+        // Hash the interpreter based on its path, implementation, and version, such that the
+        // environment is rebuilt if (e.g.) the interpreter at a given path is upgraded in-place.
         // TODO(charlie): Come up with a robust hash for the interpreter.
-        let interpreter_hash = digest(&interpreter.sys_executable());
+        let python = format!(
+            "{} {}",
+            interpreter.implementation_name(),
+            interpreter.python_full_version()
+        );
+        let interpreter_hash = digest(&(interpreter.sys_executable(), &python));
 
         // Search in the content-addressed cache.
         let cache_entry = cache.entry(CacheBucket::Environments, interpreter_hash, resolution_hash);
@@ -101,21 +109,50 @@ impl CachedEnvironment {
             cache_entry.dir().user_display(),
         )?;
 
-        // If the receipt exists, return the environment.
+        // If the receipt exists, return the environment, unless the refresh policy requires that
+        // it be rebuilt (e.g., a mutable Git reference may have moved without changing the
+        // resolution). Environments created during this invocation are always reused.
+        //
+        // The receipt records the Python implementation and version with which the environment
+        // was created; environments created with a different Python version are rebuilt.
         let ok = cache_entry.path().join(".ok");
-        if ok.is_file() {
-            debug!(
-                "Found existing cached environment at: `{}`",
-                cache_entry.path().display()
-            );
-            return Ok(Self(PythonEnvironment::from_root(
-                cache_entry.path(),
-                cache,
-            )?));
+        let refresh = resolution
+            .distributions()
+            .any(|dist| cache.must_revalidate(dist.name()))
+            && !cache.is_fresh(&CacheEntry::from_path(&ok))?;
+        let receipt = match fs_err::tokio::read_to_string(&ok).await {
+            Ok(receipt) => Some(receipt),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err.into()),
+        };
+        if let Some(receipt) = receipt {
+            if refresh {
+                debug!(
+                    "Refreshing cached environment at: `{}`",
+                    cache_entry.path().display()
+                );
+            } else if receipt.trim() != python {
+                debug!(
+                    "Cached environment at `{}` was created with a different Python (`{}`); rebuilding",
+                    cache_entry.path().display(),
+                    receipt.trim()
+                );
+            } else {
+                uv_cache::stats::record(CacheEvent::EnvironmentHit);
+                debug!(
+                    "Found existing cached environment at: `{}` (`{python}`)",
+                    cache_entry.path().display()
+                );
+                return Ok(Self(PythonEnvironment::from_root(
+                    cache_entry.path(),
+                    cache,
+                )?));
+            }
         }
 
+        uv_cache::stats::record(CacheEvent::EnvironmentMiss);
         debug!(
-            "Creating cached environment at: `{}`",
+            "Creating cached environment at: `{}` (`{python}`)",
             cache_entry.path().display()
         );
 
@@ -125,13 +162,18 @@ impl CachedEnvironment {
             uv_virtualenv::Prompt::None,
             false,
             false,
+            false,
         )?;
 
         // TODO(charlie): Rather than passing all the arguments to `sync_environment`, return a
         // struct that lets us "continue" from `resolve_environment`.
+        // The environment isn't atomically swapped, since it's only used once the receipt is
+        // written.
         let venv = sync_environment(
             venv,
             &resolution,
+            Modifications::Exact,
+            false,
             settings.as_ref().into(),
             state,
             preview,
@@ -144,7 +186,7 @@ impl CachedEnvironment {
         .await?;
 
         // Create the receipt, to indicate to future readers that the environment is complete.
-        fs_err::tokio::File::create(ok).await?;
+        fs_err::tokio::write(ok, python).await?;
 
         Ok(Self(venv))
     }
@@ -0,0 +1,356 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anstream::eprint;
+use anyhow::{bail, Context, Result};
+use clap::CommandFactory;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use pypi_types::License;
+use uv_cache::Cache;
+use uv_cli::Cli;
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, ExportFormat, ExtrasSpecification, PreviewMode, TargetTriple};
+use uv_distribution::DEV_DEPENDENCIES;
+use uv_fs::Simplified;
+use uv_python::{Interpreter, PythonFetch, PythonPreference, PythonRequest, PythonVersion};
+use uv_resolver::RequirementsTxtExport;
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::{VirtualProject, Workspace};
+
+use crate::commands::project::lock::do_safe_lock;
+use crate::commands::project::{installed_licenses, FoundInterpreter, ProjectError, SharedState};
+use crate::commands::{pip, ExitStatus};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Export the project's `uv.lock` in an alternate format.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn export(
+    format: ExportFormat,
+    base_image: Option<String>,
+    extras: ExtrasSpecification,
+    hashes: bool,
+    dev: bool,
+    no_emit_project: bool,
+    output_file: Option<PathBuf>,
+    python_platform: Option<TargetTriple>,
+    python_version: Option<PythonVersion>,
+    locked: bool,
+    frozen: bool,
+    lockfile: Option<PathBuf>,
+    python: Option<String>,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    python_fetch: PythonFetch,
+    python_install_mirror: &[String],
+    preview: PreviewMode,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv export` is experimental and may change without warning");
+    }
+
+    // Identify the project.
+    let project = VirtualProject::discover(&std::env::current_dir()?, None).await?;
+
+    // Find an interpreter for the project.
+    let interpreter = FoundInterpreter::discover(
+        project.workspace(),
+        python.as_deref().map(PythonRequest::parse),
+        false,
+        python_preference,
+        python_fetch,
+        python_install_mirror,
+        connectivity,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter();
+
+    // Lock the project.
+    let lock = match do_safe_lock(
+        locked,
+        frozen,
+        false,
+        project.workspace(),
+        lockfile.as_deref(),
+        None,
+        &interpreter,
+        settings.as_ref(),
+        &SharedState::default(),
+        preview,
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(lock) => lock,
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::NoSolution(err),
+        ))) => {
+            let report = miette::Report::msg(format!("{err}")).context(err.header());
+            eprint!("{report:?}");
+            return Ok(ExitStatus::Failure);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Include the development dependencies, unless omitted.
+    let dev = if dev {
+        vec![DEV_DEPENDENCIES.clone()]
+    } else {
+        vec![]
+    };
+
+    // Determine the markers for the target environment, if one was provided. Otherwise, every
+    // package in the lockfile is exported, qualified by its markers.
+    let markers = (python_platform.is_some() || python_version.is_some()).then(|| {
+        let mut markers = interpreter.markers().clone();
+        if let Some(python_platform) = python_platform {
+            markers = python_platform.markers(&markers);
+        }
+        if let Some(python_version) = python_version {
+            markers = python_version.markers(&markers);
+        }
+        markers
+    });
+
+    // Omit the workspace members, if requested.
+    let members = if no_emit_project {
+        project.workspace().packages().keys().collect::<Vec<_>>()
+    } else {
+        vec![]
+    };
+
+    // Generate the export.
+    let mut output = String::new();
+    match format {
+        ExportFormat::RequirementsTxt => {
+            let mut export =
+                RequirementsTxtExport::from_lock(&lock, &project, &extras, &dev, hashes)?
+                    .without_packages(members);
+            if let Some(markers) = markers.as_ref() {
+                export = export.with_markers(markers);
+            }
+            writeln!(
+                output,
+                "{}",
+                "# This file was autogenerated via `uv export`.".green()
+            )?;
+            write!(output, "{export}")?;
+        }
+        ExportFormat::Dockerfile => {
+            let dockerfile = dockerfile(
+                project.workspace(),
+                &interpreter,
+                base_image.as_deref(),
+                lockfile.as_deref(),
+                &extras,
+                !dev.is_empty(),
+            )?;
+            writeln!(
+                output,
+                "{}",
+                "# This file was autogenerated via `uv export`.".green()
+            )?;
+            write!(output, "{dockerfile}")?;
+        }
+        ExportFormat::LicenseCsv => {
+            let mut export =
+                RequirementsTxtExport::from_lock(&lock, &project, &extras, &dev, false)?
+                    .without_packages(members);
+            if let Some(markers) = markers.as_ref() {
+                export = export.with_markers(markers);
+            }
+            let licenses = match installed_licenses(project.workspace(), cache)? {
+                Some(licenses) => licenses,
+                None => {
+                    warn_user!(
+                        "No project environment found; run `uv sync` to install the packages and read their licenses"
+                    );
+                    BTreeMap::default()
+                }
+            };
+
+            let mut unlicensed = Vec::new();
+            writeln!(output, "name,version,license,source")?;
+            for (name, version) in export.packages() {
+                let license = licenses.get(name).cloned().unwrap_or(License::Unknown);
+                if license.is_unknown() {
+                    unlicensed.push(name);
+                }
+                writeln!(
+                    output,
+                    "{name},{version},{},{}",
+                    csv_field(&license.to_string()),
+                    license.source().unwrap_or_default()
+                )?;
+            }
+
+            if !unlicensed.is_empty() {
+                warn_user!(
+                    "No license detected for: {}",
+                    unlicensed.iter().map(|name| format!("`{name}`")).join(", ")
+                );
+            }
+        }
+    }
+
+    // Write the export to the output file, if requested, and to stdout.
+    if let Some(output_file) = output_file.as_deref() {
+        fs_err::write(
+            output_file,
+            anstream::adapter::strip_str(&output).to_string(),
+        )?;
+    }
+    write!(printer.stdout(), "{output}")?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Quote a field for inclusion in a CSV file, if necessary.
+fn csv_field(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\n']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// The `uv sync` flags that may be referenced by the generated `Dockerfile`.
+const DOCKERFILE_SYNC_FLAGS: &[&str] = &[
+    "frozen",
+    "no-install-workspace",
+    "lockfile",
+    "extra",
+    "all-extras",
+    "no-dev",
+];
+
+/// Generate a `Dockerfile` for the workspace.
+///
+/// The workspace manifests and lockfile are copied first, such that the dependencies can be
+/// installed in their own layer; the workspace members are installed after the rest of the source
+/// is copied.
+fn dockerfile(
+    workspace: &Workspace,
+    interpreter: &Interpreter,
+    base_image: Option<&str>,
+    lockfile: Option<&Path>,
+    extras: &ExtrasSpecification,
+    dev: bool,
+) -> Result<String> {
+    // Ensure that the generated commands only use flags that `uv sync` supports.
+    let cli = Cli::command();
+    let sync = cli
+        .find_subcommand("sync")
+        .context("Failed to find the `uv sync` command")?;
+    for flag in DOCKERFILE_SYNC_FLAGS {
+        if !sync
+            .get_arguments()
+            .any(|arg| arg.get_long() == Some(*flag))
+        {
+            bail!("`uv sync` does not support `--{flag}`, which is required by the `Dockerfile`");
+        }
+    }
+
+    // Collect the manifests for the workspace root and each of its members.
+    let lockfile = lockfile.unwrap_or(Path::new("uv.lock"));
+    let mut root = vec!["pyproject.toml".to_string(), docker_path(lockfile)];
+    if workspace.install_path().join(".python-version").is_file() {
+        root.push(".python-version".to_string());
+    }
+    let mut members = workspace
+        .packages()
+        .values()
+        .filter(|member| member.root() != workspace.install_path())
+        .map(|member| {
+            let path = member
+                .root()
+                .strip_prefix(workspace.install_path())
+                .with_context(|| {
+                    format!(
+                        "Workspace member `{}` is outside of the workspace root",
+                        member.root().user_display()
+                    )
+                })?;
+            Ok(docker_path(path))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    members.sort();
+
+    // Determine the arguments to `uv sync`.
+    let mut args = vec!["--frozen".to_string()];
+    if lockfile != Path::new("uv.lock") {
+        args.push(format!("--lockfile {}", docker_path(lockfile)));
+    }
+    match extras {
+        ExtrasSpecification::None => {}
+        ExtrasSpecification::All => args.push("--all-extras".to_string()),
+        ExtrasSpecification::Some(extras) => {
+            args.extend(extras.iter().map(|extra| format!("--extra {extra}")));
+        }
+    }
+    if !dev {
+        args.push("--no-dev".to_string());
+    }
+    let args = args.join(" ");
+
+    let base_image = base_image.map_or_else(
+        || {
+            format!(
+                "python:{}.{}-slim",
+                interpreter.python_major(),
+                interpreter.python_minor()
+            )
+        },
+        ToString::to_string,
+    );
+
+    let mut dockerfile = String::new();
+    writeln!(dockerfile, "FROM {base_image}")?;
+    writeln!(
+        dockerfile,
+        "COPY --from=ghcr.io/astral-sh/uv:{} /uv /bin/uv",
+        env!("CARGO_PKG_VERSION")
+    )?;
+    writeln!(dockerfile)?;
+    writeln!(dockerfile, "WORKDIR /app")?;
+    writeln!(dockerfile, "ENV PATH=\"/app/.venv/bin:$PATH\"")?;
+    writeln!(dockerfile)?;
+    writeln!(
+        dockerfile,
+        "# Install the dependencies, without the workspace members."
+    )?;
+    writeln!(dockerfile, "COPY {} ./", root.join(" "))?;
+    for member in &members {
+        writeln!(dockerfile, "COPY {member}/pyproject.toml {member}/")?;
+    }
+    writeln!(dockerfile, "RUN uv sync {args} --no-install-workspace")?;
+    writeln!(dockerfile)?;
+    writeln!(dockerfile, "# Install the workspace members.")?;
+    writeln!(dockerfile, "COPY . .")?;
+    writeln!(dockerfile, "RUN uv sync {args}")?;
+
+    Ok(dockerfile)
+}
+
+/// Format a relative path for use in a `Dockerfile`, which expects forward slashes.
+fn docker_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .join("/")
+}
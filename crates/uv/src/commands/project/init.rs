@@ -1,11 +1,22 @@
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
+use tracing::debug;
+use url::Url;
+
 use pep508_rs::PackageName;
+use uv_cache::{Cache, CacheBucket};
+use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::PreviewMode;
 use uv_fs::Simplified;
+use uv_git::{GitSource, GitUrl, CHECKOUT_READY_LOCK};
+use uv_python::{
+    request_from_version_file, EnvironmentPreference, PythonInstallation, PythonPreference,
+    PythonRequest,
+};
 use uv_warnings::warn_user_once;
 use uv_workspace::pyproject_mut::PyProjectTomlMut;
 use uv_workspace::{ProjectWorkspace, WorkspaceError};
@@ -14,13 +25,19 @@ use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
 /// Add one or more packages to the project requirements.
-#[allow(clippy::single_match_else)]
+#[allow(clippy::single_match_else, clippy::fn_params_excessive_bools)]
 pub(crate) async fn init(
     explicit_path: Option<String>,
     name: Option<PackageName>,
     no_readme: bool,
+    template: Option<String>,
+    package: bool,
     isolated: bool,
     preview: PreviewMode,
+    python_preference: PythonPreference,
+    connectivity: Connectivity,
+    native_tls: bool,
+    cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     if preview.is_disabled() {
@@ -58,9 +75,20 @@ pub(crate) async fn init(
         );
     }
 
+    let template = template
+        .as_deref()
+        .map(InitTemplate::from_str)
+        .transpose()?
+        .unwrap_or_default();
+
+    if !package && template != InitTemplate::App {
+        anyhow::bail!(
+            "`--no-package` is only supported for applications; pass `--app` to create one"
+        );
+    }
+
     // Create the directory for the project.
-    let src_dir = path.join("src").join(&*name.as_dist_info_name());
-    fs_err::create_dir_all(&src_dir)?;
+    fs_err::create_dir_all(&path)?;
 
     // Canonicalize the path to the project.
     let path = path.canonicalize()?;
@@ -76,39 +104,31 @@ pub(crate) async fn init(
         }
     };
 
-    // Create the `pyproject.toml`.
-    let pyproject = indoc::formatdoc! {r#"
-        [project]
-        name = "{name}"
-        version = "0.1.0"
-        description = "Add your description here"{readme}
-        dependencies = []
-
-        [tool.uv]
-        dev-dependencies = []
-        "#,
-        readme = if no_readme { "" } else { "\nreadme = \"README.md\"" },
-    };
-
-    fs_err::write(path.join("pyproject.toml"), pyproject)?;
-
-    // Create `src/{name}/__init__.py` if it does not already exist.
-    let init_py = src_dir.join("__init__.py");
-    if !init_py.try_exists()? {
-        fs_err::write(
-            init_py,
-            indoc::formatdoc! {r#"
-            def hello() -> str:
-                return "Hello from {name}!"
-            "#},
-        )?;
-    }
-
-    // Create the `README.md` if it does not already exist.
-    if !no_readme {
-        let readme = path.join("README.md");
-        if !readme.exists() {
-            fs_err::write(readme, String::new())?;
+    match template {
+        InitTemplate::Lib => init_builtin(&path, &name, no_readme, false, true)?,
+        InitTemplate::App => init_builtin(&path, &name, no_readme, true, package)?,
+        InitTemplate::Path(template) => {
+            init_template(&path, &name, &template, &[], python_preference, cache).await?;
+        }
+        InitTemplate::Git(git) => {
+            let client = BaseClientBuilder::new()
+                .connectivity(connectivity)
+                .native_tls(native_tls)
+                .build();
+            let source = GitSource::new(git, client.client(), cache.bucket(CacheBucket::Git));
+            let fetch = tokio::task::spawn_blocking(move || source.fetch())
+                .await?
+                .context("Failed to fetch the project template")?;
+            // Omit the marker that uv writes to the root of a completed checkout.
+            init_template(
+                &path,
+                &name,
+                fetch.path(),
+                &[CHECKOUT_READY_LOCK],
+                python_preference,
+                cache,
+            )
+            .await?;
         }
     }
 
@@ -159,3 +179,258 @@ pub(crate) async fn init(
 
     Ok(ExitStatus::Success)
 }
+
+/// The template from which a project is initialized.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+enum InitTemplate {
+    /// The built-in library template.
+    #[default]
+    Lib,
+    /// The built-in application template, which adds a `__main__.py` and a script entry point.
+    App,
+    /// A template directory on the local filesystem.
+    Path(PathBuf),
+    /// A template in a Git repository.
+    Git(GitUrl),
+}
+
+impl FromStr for InitTemplate {
+    type Err = anyhow::Error;
+
+    fn from_str(template: &str) -> Result<Self> {
+        match template {
+            "lib" => Ok(Self::Lib),
+            "app" => Ok(Self::App),
+            _ => {
+                if let Some(url) = template.strip_prefix("git+") {
+                    let url = Url::parse(url)
+                        .with_context(|| format!("Invalid template URL: `{template}`"))?;
+                    let git = GitUrl::try_from(url)
+                        .with_context(|| format!("Invalid template URL: `{template}`"))?;
+                    Ok(Self::Git(git))
+                } else {
+                    let path = PathBuf::from(template);
+                    if !path.is_dir() {
+                        anyhow::bail!(
+                            "Template `{template}` is not a built-in template (`lib` or `app`), a Git URL, or a directory"
+                        );
+                    }
+                    Ok(Self::Path(path))
+                }
+            }
+        }
+    }
+}
+
+/// Initialize a project from one of the built-in templates.
+///
+/// If `package` is `false`, the project is created with `tool.uv.package = false`, and its code is
+/// placed in a `main.py` in the project root, rather than in a package in `src`.
+#[allow(clippy::fn_params_excessive_bools)]
+fn init_builtin(
+    path: &Path,
+    name: &PackageName,
+    no_readme: bool,
+    app: bool,
+    package: bool,
+) -> Result<()> {
+    let module = name.as_dist_info_name();
+
+    // Create the `pyproject.toml`.
+    let pyproject = indoc::formatdoc! {r#"
+        [project]
+        name = "{name}"
+        version = "0.1.0"
+        description = "Add your description here"{readme}
+        dependencies = []
+        {scripts}
+        [tool.uv]{package}
+        dev-dependencies = []
+        "#,
+        readme = if no_readme { "" } else { "\nreadme = \"README.md\"" },
+        scripts = if app && package {
+            format!("\n[project.scripts]\n{name} = \"{module}.__main__:main\"\n")
+        } else {
+            String::new()
+        },
+        package = if package { "" } else { "\npackage = false" },
+    };
+
+    fs_err::write(path.join("pyproject.toml"), pyproject)?;
+
+    let main = indoc::formatdoc! {r#"
+        def main() -> None:
+            print("Hello from {name}!")
+
+
+        if __name__ == "__main__":
+            main()
+        "#};
+
+    if package {
+        // Create `src/{name}/__init__.py` if it does not already exist.
+        let src_dir = path.join("src").join(&*module);
+        fs_err::create_dir_all(&src_dir)?;
+
+        let init_py = src_dir.join("__init__.py");
+        if !init_py.try_exists()? {
+            fs_err::write(
+                init_py,
+                indoc::formatdoc! {r#"
+                def hello() -> str:
+                    return "Hello from {name}!"
+                "#},
+            )?;
+        }
+
+        // Create `src/{name}/__main__.py` if it does not already exist.
+        if app {
+            let main_py = src_dir.join("__main__.py");
+            if !main_py.try_exists()? {
+                fs_err::write(main_py, main)?;
+            }
+        }
+    } else {
+        // Create `main.py` if it does not already exist.
+        let main_py = path.join("main.py");
+        if !main_py.try_exists()? {
+            fs_err::write(main_py, main)?;
+        }
+    }
+
+    // Create the `README.md` if it does not already exist.
+    if !no_readme {
+        let readme = path.join("README.md");
+        if !readme.exists() {
+            fs_err::write(readme, String::new())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Initialize a project by rendering the files in a template directory.
+///
+/// The variables `{{project_name}}`, `{{module_name}}`, `{{python_version}}`, and `{{author}}`
+/// are substituted in both file names and (UTF-8) file contents. Existing files are left as-is, as
+/// are any files in `exclude`, relative to the template root.
+async fn init_template(
+    path: &Path,
+    name: &PackageName,
+    template: &Path,
+    exclude: &[&str],
+    python_preference: PythonPreference,
+    cache: &Cache,
+) -> Result<()> {
+    // Read the template files.
+    let mut files = Vec::new();
+    read_template_dir(template, template, &mut files)?;
+    files.retain(|(relative, _)| !exclude.iter().any(|exclude| relative == Path::new(exclude)));
+
+    if !files
+        .iter()
+        .any(|(relative, _)| relative == Path::new("pyproject.toml"))
+    {
+        anyhow::bail!(
+            "Template at `{}` does not contain a `pyproject.toml`",
+            template.user_display()
+        );
+    }
+
+    // Only compute the variables that require discovery if the template uses them.
+    let uses = |variable: &str| {
+        files.iter().any(|(relative, contents)| {
+            relative.to_string_lossy().contains(variable)
+                || std::str::from_utf8(contents).is_ok_and(|contents| contents.contains(variable))
+        })
+    };
+
+    let python_version = if uses("{{python_version}}") {
+        let request = request_from_version_file()
+            .await?
+            .unwrap_or(PythonRequest::Any);
+        let interpreter = PythonInstallation::find(
+            &request,
+            EnvironmentPreference::OnlySystem,
+            python_preference,
+            cache,
+        )?
+        .into_interpreter();
+        format!(
+            "{}.{}",
+            interpreter.python_major(),
+            interpreter.python_minor()
+        )
+    } else {
+        String::new()
+    };
+
+    let author = if uses("{{author}}") {
+        git_author().unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    let module = name.as_dist_info_name();
+    let variables = [
+        ("{{project_name}}", name.as_str()),
+        ("{{module_name}}", &*module),
+        ("{{python_version}}", python_version.as_str()),
+        ("{{author}}", author.as_str()),
+    ];
+    let render = |text: &str| {
+        variables
+            .iter()
+            .fold(text.to_string(), |text, (variable, value)| {
+                text.replace(variable, value)
+            })
+    };
+
+    // Write the rendered files.
+    for (relative, contents) in files {
+        let target = path.join(render(&relative.to_string_lossy()));
+        if target.try_exists()? {
+            debug!("Skipping existing file: `{}`", target.user_display());
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        match String::from_utf8(contents) {
+            Ok(contents) => fs_err::write(&target, render(&contents))?,
+            Err(err) => fs_err::write(&target, err.into_bytes())?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Recursively read the files in a template directory, skipping any `.git` directory.
+fn read_template_dir(root: &Path, dir: &Path, files: &mut Vec<(PathBuf, Vec<u8>)>) -> Result<()> {
+    for entry in fs_err::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != ".git" {
+                read_template_dir(root, &path, files)?;
+            }
+        } else {
+            let relative = path.strip_prefix(root)?.to_path_buf();
+            files.push((relative, fs_err::read(&path)?));
+        }
+    }
+    Ok(())
+}
+
+/// Read the author name from the Git configuration, if available.
+fn git_author() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["config", "--get", "user.name"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let author = String::from_utf8(output.stdout).ok()?;
+    Some(author.trim().to_string())
+}
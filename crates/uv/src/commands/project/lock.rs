@@ -1,33 +1,40 @@
 #![allow(clippy::single_match_else)]
 
-use std::collections::BTreeSet;
-use std::{fmt::Write, path::Path};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
 use anstream::eprint;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
-use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
 
 use distribution_types::{Diagnostic, UnresolvedRequirementSpecification, VersionId};
-use pep440_rs::Version;
+use pep440_rs::{Version, VersionSpecifiers};
+use pep508_rs::MarkerTree;
 use uv_cache::Cache;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
-use uv_configuration::{Concurrency, ExtrasSpecification, PreviewMode, Reinstall, SetupPyStrategy};
+use uv_configuration::{
+    Concurrency, ExtrasSpecification, PreviewMode, Reinstall, StrictCategory, TargetTriple, Upgrade,
+};
 use uv_dispatch::BuildDispatch;
-use uv_distribution::DEV_DEPENDENCIES;
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
 use uv_normalize::PackageName;
-use uv_python::{Interpreter, PythonFetch, PythonPreference, PythonRequest};
+use uv_python::{Interpreter, PythonEnvironment, PythonFetch, PythonPreference, PythonRequest};
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_resolver::{
     FlatIndex, Lock, OptionsBuilder, PythonRequirement, RequiresPython, ResolverMarkers,
 };
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject_mut::PyProjectTomlMut;
 use uv_workspace::Workspace;
 
-use crate::commands::project::{find_requires_python, FoundInterpreter, ProjectError, SharedState};
-use crate::commands::{pip, ExitStatus};
+use crate::commands::project::{
+    dependency_groups, find_requires_python, FoundInterpreter, ProjectError, SharedState,
+};
+use crate::commands::{pip, strict, ExitStatus};
 use crate::printer::Printer;
 use crate::settings::{ResolverSettings, ResolverSettingsRef};
 
@@ -35,11 +42,18 @@ use crate::settings::{ResolverSettings, ResolverSettingsRef};
 pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
+    lockfile: Option<PathBuf>,
+    python_range: Option<VersionSpecifiers>,
+    exclude_packages: Vec<PackageName>,
+    write: bool,
+    check: bool,
+    dry_run: bool,
     python: Option<String>,
     settings: ResolverSettings,
     preview: PreviewMode,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
@@ -57,8 +71,10 @@ pub(crate) async fn lock(
     let interpreter = FoundInterpreter::discover(
         &workspace,
         python.as_deref().map(PythonRequest::parse),
+        false,
         python_preference,
         python_fetch,
+        python_install_mirror,
         connectivity,
         native_tls,
         cache,
@@ -67,11 +83,53 @@ pub(crate) async fn lock(
     .await?
     .into_interpreter();
 
+    // If any packages are excluded, resolve without them, leaving the lockfile unchanged.
+    if !exclude_packages.is_empty() {
+        return lock_excluding(
+            &workspace,
+            lockfile.as_deref(),
+            python_range.as_ref(),
+            &exclude_packages,
+            write,
+            &interpreter,
+            settings.as_ref(),
+            preview,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await;
+    }
+
+    // If checking the lockfile (or performing a dry run), report the changes without writing.
+    if check || dry_run {
+        return lock_check(
+            &workspace,
+            lockfile.as_deref(),
+            python_range.as_ref(),
+            check,
+            &interpreter,
+            settings.as_ref(),
+            preview,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await;
+    }
+
     // Perform the lock operation.
     match do_safe_lock(
         locked,
         frozen,
+        false,
         &workspace,
+        lockfile.as_deref(),
+        python_range.as_ref(),
         &interpreter,
         settings.as_ref(),
         &SharedState::default(),
@@ -96,11 +154,186 @@ pub(crate) async fn lock(
     }
 }
 
+/// Resolve the project without the given direct dependencies, to help debug resolution failures.
+///
+/// Reports whether the resolution succeeds and, if so, the versions of the remaining packages. The
+/// lockfile is only written if `write` is set.
+async fn lock_excluding(
+    workspace: &Workspace,
+    lockfile: Option<&Path>,
+    python_range: Option<&VersionSpecifiers>,
+    exclude_packages: &[PackageName],
+    write: bool,
+    interpreter: &Interpreter,
+    settings: ResolverSettingsRef<'_>,
+    preview: PreviewMode,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    // Warn if an excluded package isn't a direct dependency of any workspace member.
+    for name in exclude_packages {
+        let mut found = false;
+        for member in workspace.packages().values() {
+            let pyproject = PyProjectTomlMut::from_toml(member.pyproject_toml())?;
+            if !pyproject.find_dependency(name).is_empty() {
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            warn_user!("`{name}` is not a direct dependency of any workspace member");
+        }
+    }
+
+    let lockfile = lockfile_path(workspace, lockfile);
+    let existing = read(&lockfile).await?;
+
+    let lock = match do_lock(
+        workspace,
+        interpreter,
+        existing.as_ref(),
+        python_range,
+        exclude_packages,
+        settings,
+        &SharedState::default(),
+        preview,
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(lock) => lock,
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::NoSolution(err),
+        ))) => {
+            let report = miette::Report::msg(format!("{err}")).context(err.header());
+            eprint!("{report:?}");
+            return Ok(ExitStatus::Failure);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Report the versions of the remaining packages.
+    for distribution in lock.distributions() {
+        if workspace.packages().contains_key(distribution.name()) {
+            continue;
+        }
+        writeln!(
+            printer.stdout(),
+            "{}=={}",
+            distribution.name(),
+            distribution.version()
+        )?;
+    }
+
+    if write {
+        commit(&lock, &lockfile).await?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "The lockfile was left unchanged; use `--write` to update it"
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Resolve the project and report the changes that would be made to the lockfile, without
+/// writing it.
+///
+/// If `check` is set, exits with a failure status if the lockfile would change.
+async fn lock_check(
+    workspace: &Workspace,
+    lockfile: Option<&Path>,
+    python_range: Option<&VersionSpecifiers>,
+    check: bool,
+    interpreter: &Interpreter,
+    settings: ResolverSettingsRef<'_>,
+    preview: PreviewMode,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let lockfile = lockfile_path(workspace, lockfile);
+    let existing = read(&lockfile).await?;
+
+    let lock = match do_lock(
+        workspace,
+        interpreter,
+        existing.as_ref(),
+        python_range,
+        &[],
+        settings,
+        &SharedState::default(),
+        preview,
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(lock) => lock,
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::NoSolution(err),
+        ))) => {
+            let report = miette::Report::msg(format!("{err}")).context(err.header());
+            eprint!("{report:?}");
+            return Ok(ExitStatus::Failure);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    if existing.as_ref().is_some_and(|existing| *existing == lock) {
+        writeln!(
+            printer.stderr(),
+            "Lockfile is up-to-date: {}",
+            lockfile.user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Would update lockfile at: {}",
+        lockfile.user_display().cyan()
+    )?;
+
+    // Upgrades are already reported during resolution.
+    report_changes(
+        existing.as_ref(),
+        &lock,
+        workspace.install_path(),
+        settings.upgrade.is_none(),
+        printer,
+    )?;
+
+    if check {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
 /// Perform a lock operation, respecting the `--locked` and `--frozen` parameters.
+///
+/// In a dry run, the lockfile is never written to disk.
 pub(super) async fn do_safe_lock(
     locked: bool,
     frozen: bool,
+    dry_run: bool,
     workspace: &Workspace,
+    lockfile: Option<&Path>,
+    python_range: Option<&VersionSpecifiers>,
     interpreter: &Interpreter,
     settings: ResolverSettingsRef<'_>,
     state: &SharedState,
@@ -111,22 +344,42 @@ pub(super) async fn do_safe_lock(
     cache: &Cache,
     printer: Printer,
 ) -> Result<Lock, ProjectError> {
+    let lockfile = lockfile_path(workspace, lockfile);
+
     if frozen {
         // Read the existing lockfile, but don't attempt to lock the project.
-        read(workspace)
+        let lock = read(&lockfile)
             .await?
-            .ok_or_else(|| ProjectError::MissingLockfile)
+            .ok_or_else(|| ProjectError::MissingLockfile(lockfile.clone()))?;
+
+        // Since we won't re-resolve, refuse to install from a lockfile that doesn't include every
+        // workspace member (e.g., a member was added or renamed after the lockfile was written).
+        if let Some(member) = workspace.packages().keys().find(|member| {
+            !lock
+                .distributions()
+                .iter()
+                .any(|dist| dist.name() == *member)
+        }) {
+            return Err(ProjectError::FrozenLockMissingMember(
+                member.clone(),
+                lockfile,
+            ));
+        }
+
+        Ok(lock)
     } else if locked {
         // Read the existing lockfile.
-        let existing = read(workspace)
+        let existing = read(&lockfile)
             .await?
-            .ok_or_else(|| ProjectError::MissingLockfile)?;
+            .ok_or_else(|| ProjectError::MissingLockfile(lockfile.clone()))?;
 
         // Perform the lock operation, but don't write the lockfile to disk.
         let lock = do_lock(
             workspace,
             interpreter,
             Some(&existing),
+            python_range,
+            &[],
             settings,
             state,
             preview,
@@ -140,19 +393,21 @@ pub(super) async fn do_safe_lock(
 
         // If the locks disagree, return an error.
         if lock != existing {
-            return Err(ProjectError::LockMismatch);
+            return Err(ProjectError::LockMismatch(lockfile));
         }
 
         Ok(lock)
     } else {
         // Read the existing lockfile.
-        let existing = read(workspace).await?;
+        let existing = read(&lockfile).await?;
 
         // Perform the lock operation.
         let lock = do_lock(
             workspace,
             interpreter,
             existing.as_ref(),
+            python_range,
+            &[],
             settings,
             state,
             preview,
@@ -165,7 +420,15 @@ pub(super) async fn do_safe_lock(
         .await?;
 
         if !existing.is_some_and(|existing| existing == lock) {
-            commit(&lock, workspace).await?;
+            if dry_run {
+                writeln!(
+                    printer.stderr(),
+                    "Would update lockfile at: {}",
+                    lockfile.user_display().cyan()
+                )?;
+            } else {
+                commit(&lock, &lockfile).await?;
+            }
         }
 
         Ok(lock)
@@ -177,6 +440,8 @@ pub(super) async fn do_lock(
     workspace: &Workspace,
     interpreter: &Interpreter,
     existing_lock: Option<&Lock>,
+    python_range: Option<&VersionSpecifiers>,
+    exclude_packages: &[PackageName],
     settings: ResolverSettingsRef<'_>,
     state: &SharedState,
     preview: PreviewMode,
@@ -194,7 +459,12 @@ pub(super) async fn do_lock(
         resolution,
         prerelease,
         config_setting,
+        no_build_isolation,
+        no_build_isolation_package,
+        setup_py,
         exclude_newer,
+        exclude_newer_index,
+        python_platforms,
         link_mode,
         upgrade,
         build_options,
@@ -212,7 +482,9 @@ pub(super) async fn do_lock(
         .map(UnresolvedRequirementSpecification::from)
         .collect::<Vec<_>>();
     let constraints = workspace.constraints();
-    let dev = vec![DEV_DEPENDENCIES.clone()];
+    let dev = dependency_groups(workspace.packages().values())
+        .into_iter()
+        .collect::<Vec<_>>();
     let source_trees = vec![];
 
     // Determine the supported Python range. If no range is defined, and warn and default to the
@@ -233,8 +505,65 @@ pub(super) async fn do_lock(
         default
     };
 
+    // If a Python range was provided (or recorded in the existing lockfile), narrow the supported
+    // Python range accordingly. The range is only recorded if it narrows the workspace range.
+    let (requires_python, python_range) = if let Some(python_range) =
+        python_range.or_else(|| existing_lock.and_then(Lock::python_range))
+    {
+        let narrowed = requires_python.intersection(python_range)?.ok_or_else(|| {
+            ProjectError::DisjointPythonRange(python_range.clone(), requires_python.clone())
+        })?;
+        if narrowed == requires_python {
+            (requires_python, None)
+        } else {
+            debug!(
+                "Narrowing `requires-python` to `{narrowed}` for Python range: `{python_range}`"
+            );
+            (narrowed, Some(python_range.clone()))
+        }
+    } else {
+        (requires_python, None)
+    };
+
     let python_requirement = PythonRequirement::from_requires_python(interpreter, &requires_python);
 
+    // If platforms were provided (or recorded in the existing lockfile), restrict the locked
+    // platforms accordingly.
+    let python_platforms = if python_platforms.is_empty() {
+        existing_lock
+            .map(|lock| lock.python_platforms().to_vec())
+            .unwrap_or_default()
+    } else {
+        python_platforms
+            .iter()
+            .copied()
+            .sorted()
+            .dedup()
+            .collect::<Vec<_>>()
+    };
+
+    // By default, lock for all platforms. If specific platforms were requested, restrict the
+    // universal resolution to dependencies that are relevant on at least one of them.
+    let markers = if python_platforms.is_empty() {
+        ResolverMarkers::Universal
+    } else {
+        ResolverMarkers::Fork(MarkerTree::Or(
+            python_platforms
+                .iter()
+                .copied()
+                .map(TargetTriple::marker_tree)
+                .collect(),
+        ))
+    };
+
+    debug!(
+        "Using indexes: {}",
+        index_locations
+            .indexes()
+            .map(ToString::to_string)
+            .join(", ")
+    );
+
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
@@ -250,15 +579,27 @@ pub(super) async fn do_lock(
         .resolution_mode(resolution)
         .prerelease_mode(prerelease)
         .exclude_newer(exclude_newer)
+        .exclude_newer_index(exclude_newer_index.clone())
         .index_strategy(index_strategy)
+        .exclude_dependencies(exclude_packages.to_vec())
         .build();
     let hasher = HashStrategy::Generate;
 
+    // Determine whether to enable build isolation.
+    let environment;
+    let build_isolation = if no_build_isolation {
+        environment = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::Shared(&environment)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        environment = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::SharedPackage(&environment, no_build_isolation_package)
+    };
+
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
-    let build_isolation = BuildIsolation::default();
     let extras = ExtrasSpecification::default();
-    let setup_py = SetupPyStrategy::default();
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
@@ -287,6 +628,23 @@ pub(super) async fn do_lock(
             );
             return false;
         }
+        if lock.python_platforms() != python_platforms {
+            if lock.python_platforms().is_empty() {
+                let _ = writeln!(
+                    printer.stderr(),
+                    "Ignoring existing lockfile due to addition of platforms: `{}`",
+                    python_platforms.iter().join(", ").cyan()
+                );
+            } else {
+                let _ = writeln!(
+                    printer.stderr(),
+                    "Ignoring existing lockfile due to change in platforms: `{}` vs. `{}`",
+                    lock.python_platforms().iter().join(", ").cyan(),
+                    python_platforms.iter().join(", ").cyan()
+                );
+            }
+            return false;
+        }
         match (lock.exclude_newer(), options.exclude_newer) {
             (None, None) => (),
             (Some(existing), Some(provided)) if existing == provided => (),
@@ -316,9 +674,38 @@ pub(super) async fn do_lock(
                 return false;
             }
         }
+        // If the lockfile references an index that is no longer in use (e.g., due to `--index` or
+        // `--default-index`), the locked distributions may not be available.
+        let indexes = index_locations
+            .urls()
+            .map(|url| url.as_str().trim_end_matches('/'))
+            .collect::<BTreeSet<_>>();
+        if let Some(registry) = lock
+            .registries()
+            .into_iter()
+            .find(|registry| !indexes.contains(registry.as_str().trim_end_matches('/')))
+        {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to removal of index: `{}`",
+                registry.cyan()
+            );
+            return false;
+        }
         true
     });
 
+    // Warn if any of the packages marked for upgrade aren't present in the existing lockfile.
+    if let (Upgrade::Packages(packages), Some(lock)) = (upgrade, existing_lock) {
+        for name in packages.keys().sorted() {
+            if !lock.distributions().iter().any(|dist| dist.name() == name) {
+                warn_user!(
+                    "`{name}` was passed to `--upgrade-package`, but is not included in the lockfile"
+                );
+            }
+        }
+    }
+
     // If an existing lockfile exists, build up a set of preferences.
     let LockedRequirements { preferences, git } = existing_lock
         .as_ref()
@@ -332,9 +719,12 @@ pub(super) async fn do_lock(
 
     let start = std::time::Instant::now();
 
-    let requires_python = find_requires_python(workspace)?;
+    // If the workspace defines a `requires-python`, compare against the (possibly narrowed) range.
+    let specified = find_requires_python(workspace)?
+        .is_some()
+        .then_some(&requires_python);
     let existing_lock = existing_lock.filter(|lock| {
-        match (lock.requires_python(), requires_python.as_ref()) {
+        match (lock.requires_python(), specified) {
             // If the Requires-Python bound in the lockfile is weaker or equivalent to the
             // Requires-Python bound in the workspace, we should have the necessary wheels to perform
             // a locked resolution.
@@ -401,14 +791,14 @@ pub(super) async fn do_lock(
                 &Reinstall::default(),
                 upgrade,
                 None,
-                ResolverMarkers::Universal,
+                markers.clone(),
                 python_requirement.clone(),
                 &client,
                 &flat_index,
                 &index,
                 &build_dispatch,
                 concurrency,
-                options,
+                options.clone(),
                 printer,
                 preview,
                 true,
@@ -477,7 +867,7 @@ pub(super) async fn do_lock(
                 &Reinstall::default(),
                 upgrade,
                 None,
-                ResolverMarkers::Universal,
+                markers,
                 python_requirement,
                 &client,
                 &flat_index,
@@ -499,7 +889,21 @@ pub(super) async fn do_lock(
     // Notify the user of any resolution diagnostics.
     pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
-    let new_lock = Lock::from_resolution_graph(&resolution)?;
+    let new_lock = Lock::from_resolution_graph(&resolution)?
+        .with_python_range(python_range)
+        .with_python_platforms(python_platforms);
+
+    // Notify the user of any direct URL dependencies that were locked without a hash.
+    for dist in new_lock.distributions() {
+        if dist.is_unhashed_url() {
+            let message = format!(
+                "`{}` is a direct URL dependency, but was locked without a hash",
+                dist.name()
+            );
+            strict::check(StrictCategory::UnhashedUrl, message.clone())?;
+            warn_user!("{message}");
+        }
+    }
 
     // Notify the user of any dependency updates
     if !upgrade.is_none() {
@@ -511,18 +915,28 @@ pub(super) async fn do_lock(
     Ok(new_lock)
 }
 
+/// Returns the path to the lockfile for the workspace.
+///
+/// Defaults to `uv.lock` in the workspace root; relative paths are resolved against the workspace
+/// root.
+pub(crate) fn lockfile_path(workspace: &Workspace, lockfile: Option<&Path>) -> PathBuf {
+    workspace
+        .install_path()
+        .join(lockfile.unwrap_or_else(|| Path::new("uv.lock")))
+}
+
 /// Write the lockfile to disk.
-pub(crate) async fn commit(lock: &Lock, workspace: &Workspace) -> Result<(), ProjectError> {
+pub(crate) async fn commit(lock: &Lock, path: &Path) -> Result<(), ProjectError> {
     let encoded = lock.to_toml()?;
-    fs_err::tokio::write(workspace.install_path().join("uv.lock"), encoded).await?;
+    fs_err::tokio::write(path, encoded).await?;
     Ok(())
 }
 
-/// Read the lockfile from the workspace.
+/// Read the lockfile at the given path.
 ///
 /// Returns `Ok(None)` if the lockfile does not exist.
-pub(crate) async fn read(workspace: &Workspace) -> Result<Option<Lock>, ProjectError> {
-    match fs_err::tokio::read_to_string(&workspace.install_path().join("uv.lock")).await {
+pub(crate) async fn read(path: &Path) -> Result<Option<Lock>, ProjectError> {
+    match fs_err::tokio::read_to_string(path).await {
         Ok(encoded) => match toml::from_str::<Lock>(&encoded) {
             Ok(lock) => Ok(Some(lock)),
             Err(err) => {
@@ -535,6 +949,25 @@ pub(crate) async fn read(workspace: &Workspace) -> Result<Option<Lock>, ProjectE
     }
 }
 
+/// Returns the locked versions of each package in the lockfile, keyed by name.
+fn locked_versions(lock: &Lock, workspace_root: &Path) -> BTreeMap<PackageName, BTreeSet<Version>> {
+    lock.distributions()
+        .iter()
+        .fold(BTreeMap::new(), |mut acc, distribution| {
+            if let Ok(VersionId::NameVersion(name, version)) =
+                distribution.version_id(workspace_root)
+            {
+                acc.entry(name).or_default().insert(version);
+            }
+            acc
+        })
+}
+
+/// Format a set of versions for display, e.g., `v1.0.0, 2.0.0`.
+fn format_versions(versions: &BTreeSet<Version>) -> String {
+    format!("v{}", versions.iter().join(", "))
+}
+
 /// Reports on the versions that were upgraded in the new lockfile.
 fn report_upgrades(
     existing_lock: &Lock,
@@ -542,51 +975,77 @@ fn report_upgrades(
     workspace_root: &Path,
     printer: Printer,
 ) -> anyhow::Result<()> {
-    let existing_distributions: FxHashMap<PackageName, BTreeSet<Version>> =
-        existing_lock.distributions().iter().fold(
-            FxHashMap::with_capacity_and_hasher(existing_lock.distributions().len(), FxBuildHasher),
-            |mut acc, distribution| {
-                if let Ok(VersionId::NameVersion(name, version)) =
-                    distribution.version_id(workspace_root)
-                {
-                    acc.entry(name).or_default().insert(version);
-                }
-                acc
-            },
-        );
-
-    let new_distribution_names: FxHashMap<PackageName, BTreeSet<Version>> =
-        new_lock.distributions().iter().fold(
-            FxHashMap::with_capacity_and_hasher(new_lock.distributions().len(), FxBuildHasher),
-            |mut acc, distribution| {
-                if let Ok(VersionId::NameVersion(name, version)) =
-                    distribution.version_id(workspace_root)
-                {
-                    acc.entry(name).or_default().insert(version);
-                }
-                acc
-            },
-        );
+    let existing_distributions = locked_versions(existing_lock, workspace_root);
+    let new_distributions = locked_versions(new_lock, workspace_root);
 
-    for (name, new_versions) in new_distribution_names {
+    for (name, new_versions) in new_distributions {
         if let Some(existing_versions) = existing_distributions.get(&name) {
             if new_versions != *existing_versions {
-                let existing_versions = existing_versions
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                let new_versions = new_versions
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<_>>()
-                    .join(", ");
                 writeln!(
                     printer.stderr(),
-                    "{} {name} v{existing_versions} -> v{new_versions}",
-                    "Updating".green().bold()
+                    "{} {name} {} -> {}",
+                    "Updating".green().bold(),
+                    format_versions(existing_versions),
+                    format_versions(&new_versions)
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Reports on the packages that would be added to, removed from, or updated in the lockfile.
+fn report_changes(
+    existing_lock: Option<&Lock>,
+    new_lock: &Lock,
+    workspace_root: &Path,
+    updates: bool,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    let existing_distributions = existing_lock
+        .map(|lock| locked_versions(lock, workspace_root))
+        .unwrap_or_default();
+    let new_distributions = locked_versions(new_lock, workspace_root);
+
+    let names = existing_distributions
+        .keys()
+        .chain(new_distributions.keys())
+        .collect::<BTreeSet<_>>();
+
+    for name in names {
+        match (
+            existing_distributions.get(name),
+            new_distributions.get(name),
+        ) {
+            (None, Some(new_versions)) => {
+                writeln!(
+                    printer.stderr(),
+                    "{} {name} {}",
+                    "Adding".green().bold(),
+                    format_versions(new_versions)
+                )?;
+            }
+            (Some(existing_versions), None) => {
+                writeln!(
+                    printer.stderr(),
+                    "{} {name} {}",
+                    "Removing".red().bold(),
+                    format_versions(existing_versions)
+                )?;
+            }
+            (Some(existing_versions), Some(new_versions))
+                if updates && existing_versions != new_versions =>
+            {
+                writeln!(
+                    printer.stderr(),
+                    "{} {name} {} -> {}",
+                    "Updating".green().bold(),
+                    format_versions(existing_versions),
+                    format_versions(new_versions)
                 )?;
             }
+            _ => {}
         }
     }
 
@@ -1,43 +1,53 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::debug;
 
-use distribution_types::{Resolution, UnresolvedRequirementSpecification};
-use pep440_rs::Version;
-use pypi_types::Requirement;
+use distribution_types::{Name, Resolution, UnresolvedRequirementSpecification};
+use pep440_rs::{Version, VersionSpecifiers};
+use pypi_types::{License, Requirement};
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, ExtrasSpecification, PreviewMode, Reinstall, SetupPyStrategy, Upgrade,
+    Concurrency, DevSpecification, ExtrasSpecification, PreviewMode, Reinstall, StrictCategory,
+    TargetTriple, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
-use uv_distribution::DistributionDatabase;
-use uv_fs::Simplified;
-use uv_installer::{SatisfiesResult, SitePackages};
+use uv_distribution::{DistributionDatabase, DEV_DEPENDENCIES};
+use uv_fs::{absolutize_path, normalize_path, Simplified};
+use uv_installer::{PrepareError, SatisfiesResult, SitePackages};
+use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_python::{
-    request_from_version_file, EnvironmentPreference, Interpreter, PythonEnvironment, PythonFetch,
+    request_from_ancestor_version_file, request_from_nearest_version_file, EnvironmentPreference,
+    ImplementationName, Interpreter, PyVenvConfiguration, PythonEnvironment, PythonFetch,
     PythonInstallation, PythonPreference, PythonRequest, VersionRequest,
 };
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
 use uv_resolver::{
-    FlatIndex, OptionsBuilder, PythonRequirement, RequiresPython, ResolutionGraph, ResolverMarkers,
+    FlatIndex, OptionsBuilder, Preference, PythonRequirement, RequiresPython, ResolutionGraph,
+    ResolverMarkers,
 };
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
-use uv_warnings::warn_user;
-use uv_workspace::Workspace;
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::{Workspace, WorkspaceMember};
 
 use crate::commands::pip::operations::Modifications;
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
+use crate::commands::strict::{self, StrictViolation};
 use crate::commands::{pip, SharedState};
 use crate::printer::Printer;
 use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings, ResolverSettingsRef};
 
 pub(crate) mod add;
 pub(crate) mod environment;
+pub(crate) mod export;
 pub(crate) mod init;
 pub(crate) mod lock;
+pub(crate) mod publish;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
@@ -45,20 +55,63 @@ pub(crate) mod tree;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ProjectError {
-    #[error("The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.")]
-    LockMismatch,
+    #[error("The lockfile at `{}` needs to be updated, but `--locked` was provided.", .0.user_display())]
+    LockMismatch(PathBuf),
 
-    #[error(
-        "Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`."
-    )]
-    MissingLockfile,
+    #[error("Unable to find lockfile at `{}`.", .0.user_display())]
+    MissingLockfile(PathBuf),
+
+    #[error("The lockfile at `{}` does not include workspace member `{}`, but `--frozen` was provided.", .1.user_display(), .0)]
+    FrozenLockMissingMember(PackageName, PathBuf),
 
     #[error("The current Python version ({0}) is not compatible with the locked Python requirement: `{1}`")]
     LockedPythonIncompatibility(Version, RequiresPython),
 
+    #[error("The current Python version ({0}) is not compatible with the locked Python requirement: `{1}` (narrowed by `--python-range {2}`)")]
+    LockedPythonRangeIncompatibility(Version, RequiresPython, VersionSpecifiers),
+
+    #[error("The current platform is not included in the lockfile, which is restricted to: {}", .0.iter().map(|platform| format!("`{platform}`")).join(", "))]
+    LockedPlatformIncompatibility(Vec<TargetTriple>),
+
+    #[error("The requested Python range (`{0}`) does not overlap with the project Python requirement: `{1}`")]
+    DisjointPythonRange(VersionSpecifiers, RequiresPython),
+
     #[error("The requested Python interpreter ({0}) is incompatible with the project Python requirement: `{1}`")]
     RequestedPythonIncompatibility(Version, RequiresPython),
 
+    #[error("The requested Python interpreter ({0}) is incompatible with the project Python implementation: `{1}`")]
+    RequestedImplementationIncompatibility(String, ImplementationName),
+
+    #[error("Invalid `tool.uv.python-implementation` setting: {0}")]
+    InvalidPythonImplementation(String),
+
+    #[error("Package `{0}` was marked for reinstallation, but is not included in the resolved requirements")]
+    ReinstallPackageNotFound(PackageName),
+
+    #[error("Extra `{0}` is not defined in the project's `optional-dependencies` table (available extras: {})", format_extras(.1))]
+    MissingExtra(ExtraName, Vec<ExtraName>),
+
+    #[error("The lockfile does not contain a resolution for extra `{0}`.")]
+    LockMissingExtra(ExtraName),
+
+    #[error("Group `{0}` is not defined in the project's `dependency-groups` table (available groups: {})", format_groups(.1))]
+    MissingGroup(GroupName, Vec<GroupName>),
+
+    #[error("The lockfile does not include packages required in the current environment: {}", .0.iter().map(ToString::to_string).join(", "))]
+    MarkerMismatch(Vec<sync::MarkerGap>),
+
+    #[error("The project environment path `{}` exists, but is not a virtual environment; remove it or choose a different path with `UV_PROJECT_ENVIRONMENT`", .0.user_display())]
+    InvalidProjectEnvironment(PathBuf),
+
+    #[error("Failed to build workspace member `{0}`, which doesn't declare a `[build-system]`")]
+    MissingBuildBackend(PackageName, #[source] pip::operations::Error),
+
+    #[error("Workspace member `{1}` depends on `{0}`, which sets `tool.uv.package = false` and can't be installed")]
+    NonPackageDependency(PackageName, PackageName),
+
+    #[error("Network connectivity is disabled, but the following packages weren't found in the cache: {}", .0.iter().map(|name| format!("`{name}`")).join(", "))]
+    Offline(Vec<PackageName>),
+
     #[error(transparent)]
     Python(#[from] uv_python::Error),
 
@@ -91,6 +144,203 @@ pub(crate) enum ProjectError {
 
     #[error(transparent)]
     RequiresPython(#[from] uv_resolver::RequiresPythonError),
+
+    #[error(transparent)]
+    Strict(#[from] StrictViolation),
+}
+
+/// Format a list of extras for display in an error message.
+fn format_extras(extras: &[ExtraName]) -> String {
+    if extras.is_empty() {
+        "none".to_string()
+    } else {
+        extras.iter().map(|extra| format!("`{extra}`")).join(", ")
+    }
+}
+
+/// Format a list of dependency groups for display in an error message.
+fn format_groups(groups: &[GroupName]) -> String {
+    groups.iter().map(|group| format!("`{group}`")).join(", ")
+}
+
+impl ProjectError {
+    /// Returns a one-line suggestion for how the user might recover from the error, if any.
+    pub(crate) fn hint(&self) -> Option<String> {
+        match self {
+            ProjectError::LockMismatch(_) => {
+                Some("To update the lockfile, run `uv lock`.".to_string())
+            }
+            ProjectError::MissingLockfile(_) => {
+                Some("To create a lockfile, run `uv lock` or `uv sync`.".to_string())
+            }
+            ProjectError::FrozenLockMissingMember(..) => Some(
+                "The lockfile is out of date with the workspace; to update it, run `uv lock`."
+                    .to_string(),
+            ),
+            ProjectError::LockedPythonIncompatibility(_, requires_python) => Some(format!(
+                "Use `--python` to select an interpreter that satisfies `{requires_python}`, or run `uv lock` to update the lockfile."
+            )),
+            ProjectError::LockedPythonRangeIncompatibility(version, requires_python, _) => Some(format!(
+                "Use `--python` to select an interpreter that satisfies `{requires_python}`, or run `uv lock --python-range` with a range that includes `{version}`."
+            )),
+            ProjectError::LockedPlatformIncompatibility(_) => Some(
+                "Run `uv lock --python-platform` with a platform that matches the current environment to include it in the lockfile."
+                    .to_string(),
+            ),
+            ProjectError::DisjointPythonRange(_, requires_python) => Some(format!(
+                "Provide a `--python-range` that overlaps with `{requires_python}`."
+            )),
+            ProjectError::RequestedPythonIncompatibility(version, _) => Some(format!(
+                "Try setting `requires-python` to include `{version}` in your `pyproject.toml`."
+            )),
+            ProjectError::RequestedImplementationIncompatibility(_, implementation) => Some(format!(
+                "Use `--python` to select a {} interpreter, or update `tool.uv.python-implementation` in your `pyproject.toml`.",
+                implementation.pretty()
+            )),
+            ProjectError::InvalidPythonImplementation(_) => Some(
+                "Set `tool.uv.python-implementation` to one of `cpython`, `pypy`, or `graalpy`."
+                    .to_string(),
+            ),
+            ProjectError::ReinstallPackageNotFound(package) => Some(format!(
+                "Remove `{package}` from `--reinstall-package`, or add it to the project's dependencies."
+            )),
+            ProjectError::MissingExtra(extra, _) => Some(format!(
+                "Remove `--extra {extra}`, or define the extra under `[project.optional-dependencies]` in your `pyproject.toml`."
+            )),
+            ProjectError::LockMissingExtra(_) => {
+                Some("To update the lockfile, run `uv lock`.".to_string())
+            }
+            ProjectError::MissingGroup(group, _) => Some(format!(
+                "Define the `{group}` group under `[dependency-groups]` in your `pyproject.toml`."
+            )),
+            ProjectError::MarkerMismatch(_) => Some(
+                "The lockfile may have been created on a different platform; run `uv lock` on this platform to include it."
+                    .to_string(),
+            ),
+            ProjectError::MissingBuildBackend(package, _) => Some(format!(
+                "If `{package}` isn't meant to be built, set `tool.uv.package = false` in its `pyproject.toml` to install only its dependencies; otherwise, declare a `[build-system]`."
+            )),
+            ProjectError::NonPackageDependency(package, _) => Some(format!(
+                "Remove `tool.uv.package = false` from `{package}`'s `pyproject.toml` so that it can be built, or remove the dependency on it."
+            )),
+            ProjectError::Offline(_) => Some(
+                "Run the command once with network access to populate the cache, or remove `--offline` (or `UV_OFFLINE`)."
+                    .to_string(),
+            ),
+            ProjectError::InvalidProjectEnvironment(_)
+            | ProjectError::Python(_)
+            | ProjectError::Virtualenv(_)
+            | ProjectError::HashStrategy(_)
+            | ProjectError::Tags(_)
+            | ProjectError::FlatIndex(_)
+            | ProjectError::Lock(_)
+            | ProjectError::Fmt(_)
+            | ProjectError::Io(_)
+            | ProjectError::Anyhow(_)
+            | ProjectError::Operation(_)
+            | ProjectError::RequiresPython(_)
+            | ProjectError::Strict(_) => None,
+        }
+    }
+
+    /// Convert a resolution or installation error into a [`ProjectError`].
+    ///
+    /// If network access is disabled, and the operation failed because some packages weren't
+    /// found in the cache, returns a [`ProjectError::Offline`] naming those packages.
+    fn from_operation(err: pip::operations::Error, connectivity: Connectivity) -> Self {
+        if connectivity.is_offline() {
+            let packages = offline_packages(&err);
+            if !packages.is_empty() {
+                return ProjectError::Offline(packages);
+            }
+        }
+        ProjectError::Operation(err)
+    }
+
+    /// Convert an installation error into a [`ProjectError`].
+    ///
+    /// If the installation failed to build a workspace member that doesn't declare a
+    /// `[build-system]` (and so fell back to the legacy `setuptools` backend), returns a
+    /// [`ProjectError::MissingBuildBackend`] naming that member.
+    fn from_install(err: pip::operations::Error, workspace: &Workspace) -> Self {
+        let member = build_failures(&err).into_iter().find(|name| {
+            workspace
+                .packages()
+                .get(name)
+                .is_some_and(|member| member.pyproject_toml().build_system.is_none())
+        });
+        match member {
+            Some(member) => ProjectError::MissingBuildBackend(member, err),
+            None => ProjectError::Operation(err),
+        }
+    }
+
+    /// Returns the [`StrictViolation`] underlying the error, if any.
+    pub(crate) fn strict_violation(&self) -> Option<&StrictViolation> {
+        match self {
+            ProjectError::Strict(violation)
+            | ProjectError::Operation(pip::operations::Error::Strict(violation)) => Some(violation),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the names of the packages that couldn't be fetched from the cache, for an operation that
+/// failed without network access.
+fn offline_packages(err: &pip::operations::Error) -> Vec<PackageName> {
+    let mut packages = match err {
+        // e.g., the available versions of a package weren't found in the cache.
+        pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err)) => err
+            .offline_packages()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>(),
+        // e.g., the metadata for a direct URL requirement wasn't found in the cache.
+        pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::Fetch(dist, err)
+            | uv_resolver::ResolveError::Read(dist, err),
+        ) if is_offline(err) => vec![dist.name().clone()],
+        pip::operations::Error::Resolve(uv_resolver::ResolveError::FetchAndBuild(dist, err))
+            if is_offline(err) =>
+        {
+            vec![dist.name().clone()]
+        }
+        // e.g., a package was resolved from the cache, but its wheel wasn't cached.
+        pip::operations::Error::Anyhow(err) => err
+            .chain()
+            .filter_map(|err| err.downcast_ref::<PrepareError>())
+            .filter_map(|err| match err {
+                PrepareError::Fetch(dist, err) if is_offline(err) => Some(dist.name().clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+    packages.sort_unstable();
+    packages.dedup();
+    packages
+}
+
+/// Returns the packages that failed to build during an installation.
+fn build_failures(err: &pip::operations::Error) -> Vec<PackageName> {
+    let pip::operations::Error::Anyhow(err) = err else {
+        return vec![];
+    };
+    err.chain()
+        .filter_map(|err| err.downcast_ref::<PrepareError>())
+        .filter_map(|err| match err {
+            PrepareError::Fetch(
+                dist,
+                uv_distribution::Error::Build(..) | uv_distribution::Error::BuildEditable(..),
+            ) => Some(dist.name().clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns `true` if the distribution error was caused by a lack of network access.
+fn is_offline(err: &uv_distribution::Error) -> bool {
+    matches!(err, uv_distribution::Error::Client(err) if err.is_offline())
 }
 
 /// Compute the `Requires-Python` bound for the [`Workspace`].
@@ -109,12 +359,106 @@ pub(crate) fn find_requires_python(
     }))
 }
 
+/// Return the Python implementation required by the [`Workspace`], per
+/// `tool.uv.python-implementation`.
+pub(crate) fn find_python_implementation(
+    workspace: &Workspace,
+) -> Result<Option<ImplementationName>, ProjectError> {
+    workspace
+        .python_implementation()
+        .map(|implementation| {
+            ImplementationName::from_str(implementation)
+                .map_err(|err| ProjectError::InvalidPythonImplementation(err.to_string()))
+        })
+        .transpose()
+}
+
+/// Return the dependency groups declared by the given workspace members.
+///
+/// The `dev` group, which includes `tool.uv.dev-dependencies`, is always considered declared.
+pub(crate) fn dependency_groups<'a>(
+    members: impl IntoIterator<Item = &'a WorkspaceMember>,
+) -> BTreeSet<GroupName> {
+    std::iter::once(DEV_DEPENDENCIES.clone())
+        .chain(members.into_iter().flat_map(|member| {
+            member
+                .pyproject_toml()
+                .dependency_groups
+                .iter()
+                .flat_map(BTreeMap::keys)
+                .cloned()
+        }))
+        .collect()
+}
+
+/// Return the path to the active virtual environment (per `VIRTUAL_ENV`), if any.
+fn active_environment() -> Option<PathBuf> {
+    std::env::var_os("VIRTUAL_ENV")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Return the path to the virtual environment for the current project.
+///
+/// By default, this is the `.venv` directory at the workspace root. If `active` is set, the active
+/// virtual environment is used instead, if any.
+fn environment_path(workspace: &Workspace, active: bool) -> PathBuf {
+    if active {
+        if let Some(active_venv) = active_environment() {
+            debug!(
+                "Using active virtual environment at: {}",
+                active_venv.user_display()
+            );
+            return active_venv;
+        }
+    }
+    workspace.venv()
+}
+
+/// Returns `true` if the two paths refer to the same location, even if neither exists.
+fn is_same_path(left: &Path, right: &Path) -> bool {
+    if let (Ok(left), Ok(right)) = (fs_err::canonicalize(left), fs_err::canonicalize(right)) {
+        return left == right;
+    }
+    match (absolutize_path(left), absolutize_path(right)) {
+        (Ok(left), Ok(right)) => normalize_path(&left) == normalize_path(&right),
+        _ => false,
+    }
+}
+
 /// Find the virtual environment for the current project.
 fn find_environment(
     workspace: &Workspace,
+    active: bool,
     cache: &Cache,
 ) -> Result<PythonEnvironment, uv_python::Error> {
-    PythonEnvironment::from_root(workspace.venv(), cache)
+    PythonEnvironment::from_root(environment_path(workspace, active), cache)
+}
+
+/// Read the licenses of the packages installed in the project environment.
+///
+/// Licenses are read from the installed distribution metadata, and so don't require network
+/// access. Returns `None` if the project environment doesn't exist.
+pub(crate) fn installed_licenses(
+    workspace: &Workspace,
+    cache: &Cache,
+) -> anyhow::Result<Option<BTreeMap<PackageName, License>>> {
+    let venv = match find_environment(workspace, false, cache) {
+        Ok(venv) => venv,
+        Err(uv_python::Error::MissingEnvironment(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let site_packages = SitePackages::from_environment(&venv)?;
+    let mut licenses = BTreeMap::new();
+    for dist in site_packages.iter() {
+        let license = dist.license().unwrap_or_else(|err| {
+            debug!("Failed to read license for `{}`: {err}", dist.name());
+            License::Unknown
+        });
+        licenses.insert(dist.name().clone(), license);
+    }
+    Ok(Some(licenses))
 }
 
 /// Check if the given interpreter satisfies the project's requirements.
@@ -135,9 +479,88 @@ fn interpreter_meets_requirements(
     }
 }
 
+/// Returns `true` if the interpreter is of the given implementation (e.g., CPython).
+fn interpreter_meets_implementation(
+    interpreter: &Interpreter,
+    implementation: ImplementationName,
+) -> bool {
+    if interpreter
+        .implementation_name()
+        .eq_ignore_ascii_case((&implementation).into())
+    {
+        true
+    } else {
+        debug!(
+            "Interpreter implementation `{}` does not match the project's Python implementation: `{implementation}`",
+            interpreter.implementation_name()
+        );
+        false
+    }
+}
+
+/// Narrow a [`PythonRequest`] to the given implementation, if the request doesn't already
+/// specify one.
+///
+/// For example, a request for Python `>=3.12` with an implementation of `cpython` becomes a
+/// request for `cpython@>=3.12`.
+fn with_implementation(
+    request: Option<PythonRequest>,
+    implementation: Option<ImplementationName>,
+) -> Option<PythonRequest> {
+    let Some(implementation) = implementation else {
+        return request;
+    };
+    match request {
+        None | Some(PythonRequest::Any) => Some(PythonRequest::Implementation(implementation)),
+        Some(PythonRequest::Version(version)) => Some(PythonRequest::ImplementationVersion(
+            implementation,
+            version,
+        )),
+        request => request,
+    }
+}
+
+/// Returns `true` if the interpreter satisfies a patch-level version request in all but the patch
+/// version (e.g., `3.12.4` for a request of `3.12.3`).
+fn matches_except_patch(interpreter: &Interpreter, request: &PythonRequest) -> bool {
+    matches!(
+        request,
+        PythonRequest::Version(VersionRequest::MajorMinorPatch(major, minor, _))
+            | PythonRequest::ImplementationVersion(_, VersionRequest::MajorMinorPatch(major, minor, _))
+            if interpreter.python_major() == *major && interpreter.python_minor() == *minor
+    )
+}
+
+/// The reason an existing project environment could not be used.
+#[derive(Debug)]
+pub(crate) enum IncompatibleEnvironment {
+    /// The environment's interpreter doesn't satisfy the Python request.
+    Request(PythonRequest),
+    /// The environment's interpreter doesn't satisfy the project's `requires-python`.
+    RequiresPython(RequiresPython),
+    /// The environment's interpreter isn't of the project's `python-implementation`.
+    Implementation(ImplementationName),
+}
+
+impl std::fmt::Display for IncompatibleEnvironment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Request(request) => write!(f, "{request} was requested"),
+            Self::RequiresPython(requires_python) => {
+                write!(f, "the project requires Python {requires_python}")
+            }
+            Self::Implementation(implementation) => {
+                write!(f, "the project requires {}", implementation.pretty())
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum FoundInterpreter {
-    Interpreter(Interpreter),
+    /// An interpreter with which to create the project environment, along with the reason that
+    /// the existing environment (if any) could not be used.
+    Interpreter(Interpreter, Option<IncompatibleEnvironment>),
     Environment(PythonEnvironment),
 }
 
@@ -146,55 +569,127 @@ impl FoundInterpreter {
     pub(crate) async fn discover(
         workspace: &Workspace,
         python_request: Option<PythonRequest>,
+        active: bool,
         python_preference: PythonPreference,
         python_fetch: PythonFetch,
+        python_install_mirror: &[String],
         connectivity: Connectivity,
         native_tls: bool,
         cache: &Cache,
         printer: Printer,
     ) -> Result<Self, ProjectError> {
         let requires_python = find_requires_python(workspace)?;
+        let implementation = find_python_implementation(workspace)?;
 
         // (1) Explicit request from user
+        let explicit = python_request.is_some();
         let python_request = if let Some(request) = python_request {
+            debug!("Using explicit Python request: `{request}`");
+            Some(request)
+            // (2) Request from the nearest `.python-version`, up to the workspace root
+        } else if let Some(request) =
+            request_from_nearest_version_file(&std::env::current_dir()?, workspace.install_path())
+                .await?
+        {
+            debug!("Using Python request from version file: `{request}`");
             Some(request)
-            // (2) Request from `.python-version`
-        } else if let Some(request) = request_from_version_file().await? {
+            // (3) Request from the nearest `.python-version` above the workspace root
+        } else if let Some(request) =
+            request_from_ancestor_version_file(workspace.install_path()).await?
+        {
+            debug!("Using Python request from version file: `{request}`");
             Some(request)
-            // (3) `Requires-Python` in `pyproject.toml`
+            // (4) `Requires-Python` in `pyproject.toml`
+        } else if let Some(requires_python) = requires_python.as_ref() {
+            debug!("Using Python request from `requires-python`: `{requires_python}`");
+            Some(PythonRequest::Version(VersionRequest::Range(
+                requires_python.specifiers().clone(),
+            )))
         } else {
-            requires_python
-                .as_ref()
-                .map(RequiresPython::specifiers)
-                .map(|specifiers| PythonRequest::Version(VersionRequest::Range(specifiers.clone())))
+            debug!("No Python request found; any interpreter is acceptable");
+            None
         };
 
+        // Restrict the request to the project's Python implementation, if any.
+        if let Some(implementation) = implementation {
+            debug!("Using Python implementation from `tool.uv.python-implementation`: `{implementation}`");
+        }
+        let python_request = with_implementation(python_request, implementation);
+
         // Read from the virtual environment first.
-        match find_environment(workspace, cache) {
+        let incompatibility = match find_environment(workspace, active, cache) {
             Ok(venv) => {
-                if interpreter_meets_requirements(
+                debug!(
+                    "Found existing virtual environment at `{}` with Python {}",
+                    venv.root().user_display(),
+                    venv.interpreter().python_version()
+                );
+
+                // Tolerate patch-level changes to the environment's interpreter (e.g., after the
+                // base interpreter was upgraded in-place), unless the patch version was explicitly
+                // requested.
+                let meets_request = if interpreter_meets_requirements(
                     venv.interpreter(),
                     python_request.as_ref(),
                     cache,
                 ) {
-                    if let Some(requires_python) = requires_python.as_ref() {
-                        if requires_python.contains(venv.interpreter().python_version()) {
-                            return Ok(Self::Environment(venv));
-                        }
-                        debug!(
-                            "Interpreter does not meet the project's Python requirement: `{requires_python}`"
-                        );
-                    } else {
-                        return Ok(Self::Environment(venv));
-                    }
+                    true
+                } else if !explicit
+                    && python_request
+                        .as_ref()
+                        .is_some_and(|request| matches_except_patch(venv.interpreter(), request))
+                {
+                    debug!(
+                        "Interpreter differs from the request in the patch version only: {}",
+                        venv.interpreter().python_version()
+                    );
+                    true
+                } else {
+                    false
+                };
+
+                if let Some(implementation) = implementation.filter(|implementation| {
+                    !interpreter_meets_implementation(venv.interpreter(), *implementation)
+                }) {
+                    Some(IncompatibleEnvironment::Implementation(implementation))
+                } else if !meets_request {
+                    debug!(
+                        "Ignoring existing virtual environment, which does not meet the request"
+                    );
+                    python_request.clone().map(IncompatibleEnvironment::Request)
+                } else if let Some(requires_python) =
+                    requires_python.as_ref().filter(|requires_python| {
+                        !requires_python.contains(venv.interpreter().python_version())
+                    })
+                {
+                    debug!(
+                        "Interpreter does not meet the project's Python requirement: `{requires_python}`"
+                    );
+                    Some(IncompatibleEnvironment::RequiresPython(
+                        requires_python.clone(),
+                    ))
+                } else {
+                    debug!("Using existing virtual environment");
+                    return Ok(Self::Environment(venv));
                 }
             }
-            Err(uv_python::Error::MissingEnvironment(_)) => {}
+            Err(uv_python::Error::MissingEnvironment(_)) => {
+                debug!("No existing virtual environment found");
+                None
+            }
             Err(uv_python::Error::Query(uv_python::InterpreterError::NotFound(path))) => {
+                strict::check(
+                    StrictCategory::MissingInterpreter,
+                    format!(
+                        "Existing virtual environment is linked to non-existent Python interpreter: {}",
+                        path.user_display()
+                    ),
+                )?;
                 warn_user!(
                     "Ignoring existing virtual environment linked to non-existent Python interpreter: {}",
                     path.user_display().cyan()
                 );
+                None
             }
             Err(err) => return Err(err.into()),
         };
@@ -205,12 +700,19 @@ impl FoundInterpreter {
 
         let reporter = PythonDownloadReporter::single(printer);
 
+        if let Some(request) = python_request.as_ref() {
+            debug!("Searching for a Python interpreter that satisfies the request: `{request}`");
+        } else {
+            debug!("Searching for a default Python interpreter");
+        }
+
         // Locate the Python interpreter to use in the environment
         let interpreter = PythonInstallation::find_or_fetch(
             python_request,
             EnvironmentPreference::OnlySystem,
             python_preference,
             python_fetch,
+            python_install_mirror,
             &client_builder,
             cache,
             Some(&reporter),
@@ -234,34 +736,101 @@ impl FoundInterpreter {
             }
         }
 
-        Ok(Self::Interpreter(interpreter))
+        if let Some(implementation) = implementation {
+            if !interpreter_meets_implementation(&interpreter, implementation) {
+                return Err(ProjectError::RequestedImplementationIncompatibility(
+                    interpreter.implementation_name().to_string(),
+                    implementation,
+                ));
+            }
+        }
+
+        Ok(Self::Interpreter(interpreter, incompatibility))
     }
 
     /// Convert the [`FoundInterpreter`] into an [`Interpreter`].
     pub(crate) fn into_interpreter(self) -> Interpreter {
         match self {
-            FoundInterpreter::Interpreter(interpreter) => interpreter,
+            FoundInterpreter::Interpreter(interpreter, _) => interpreter,
             FoundInterpreter::Environment(venv) => venv.into_interpreter(),
         }
     }
 }
 
+/// The Python environment for a project.
+#[derive(Debug)]
+pub(crate) enum ProjectEnvironment {
+    /// The project's virtual environment, which may have been created or replaced.
+    Project(PythonEnvironment),
+    /// A temporary virtual environment, standing in for the virtual environment that would be
+    /// created during a dry run.
+    DryRun(PythonEnvironment, tempfile::TempDir),
+}
+
+impl ProjectEnvironment {
+    /// Convert the [`ProjectEnvironment`] into a [`PythonEnvironment`].
+    ///
+    /// For a dry run, the temporary environment is removed, so the returned [`PythonEnvironment`]
+    /// should only be used to access its [`Interpreter`].
+    pub(crate) fn into_environment(self) -> PythonEnvironment {
+        match self {
+            ProjectEnvironment::Project(venv) => venv,
+            ProjectEnvironment::DryRun(venv, _) => venv,
+        }
+    }
+}
+
+impl std::ops::Deref for ProjectEnvironment {
+    type Target = PythonEnvironment;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            ProjectEnvironment::Project(venv) => venv,
+            ProjectEnvironment::DryRun(venv, _) => venv,
+        }
+    }
+}
+
 /// Initialize a virtual environment for the current project.
+///
+/// In a dry run, the project's virtual environment is never created or removed; instead, if the
+/// environment would be (re)created, a temporary environment is returned in its place.
 pub(crate) async fn get_or_init_environment(
     workspace: &Workspace,
     python: Option<PythonRequest>,
+    active: bool,
+    prompt: Option<&str>,
+    seed: bool,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     connectivity: Connectivity,
     native_tls: bool,
+    dry_run: bool,
     cache: &Cache,
     printer: Printer,
-) -> Result<PythonEnvironment, ProjectError> {
+) -> Result<ProjectEnvironment, ProjectError> {
+    // Warn if the user appears to expect that the active virtual environment will be used.
+    if !active {
+        if let Some(active_venv) = active_environment() {
+            let venv = workspace.venv();
+            if !is_same_path(&active_venv, &venv) {
+                warn_user_once!(
+                    "`VIRTUAL_ENV={}` does not match the project environment path `{}` and will be ignored; use `--active` to target the active environment instead",
+                    active_venv.user_display().cyan(),
+                    venv.user_display().cyan()
+                );
+            }
+        }
+    }
+
     match FoundInterpreter::discover(
         workspace,
         python,
+        active,
         python_preference,
         python_fetch,
+        python_install_mirror,
         connectivity,
         native_tls,
         cache,
@@ -270,18 +839,92 @@ pub(crate) async fn get_or_init_environment(
     .await?
     {
         // If we found an existing, compatible environment, use it.
-        FoundInterpreter::Environment(environment) => Ok(environment),
+        FoundInterpreter::Environment(environment) => Ok(ProjectEnvironment::Project(environment)),
 
         // Otherwise, create a virtual environment with the discovered interpreter.
-        FoundInterpreter::Interpreter(interpreter) => {
-            let venv = workspace.venv();
+        FoundInterpreter::Interpreter(interpreter, incompatibility) => {
+            let venv = environment_path(workspace, active);
+
+            // Inspect the existing virtual environment (if any), so that we can describe it (and
+            // warn if it doesn't appear to be managed by uv) before removing it. Since the path may
+            // be user-provided, refuse to remove a non-empty directory that isn't a virtual
+            // environment.
+            let existing = if venv.is_dir() {
+                match PyVenvConfiguration::parse(venv.join("pyvenv.cfg")) {
+                    Ok(cfg) => {
+                        if !cfg.is_uv() {
+                            warn_user!(
+                                "The virtual environment at `{}` was not created by uv ({}) and will be replaced",
+                                venv.user_display().cyan(),
+                                cfg.creator()
+                                    .map(|creator| format!("created by {creator}"))
+                                    .unwrap_or_else(|| "unknown creator".to_string())
+                            );
+                        }
+                        Some(cfg)
+                    }
+                    Err(err) => {
+                        debug!("Failed to read `pyvenv.cfg`: {err}");
+                        if fs_err::read_dir(&venv)?.next().is_some() {
+                            return Err(ProjectError::InvalidProjectEnvironment(venv));
+                        }
+                        None
+                    }
+                }
+            } else if venv.exists() {
+                return Err(ProjectError::InvalidProjectEnvironment(venv));
+            } else {
+                None
+            };
+
+            // In a dry run, report the changes, but create a temporary environment instead.
+            if dry_run {
+                if venv.exists() {
+                    writeln!(
+                        printer.stderr(),
+                        "Would remove virtual environment at: {}",
+                        venv.user_display().cyan()
+                    )?;
+                }
+                writeln!(
+                    printer.stderr(),
+                    "Would create virtualenv at: {}",
+                    venv.user_display().cyan()
+                )?;
+
+                let temp_dir = cache.environment()?;
+                let environment = uv_virtualenv::create_venv(
+                    temp_dir.path(),
+                    interpreter,
+                    uv_virtualenv::Prompt::None,
+                    false,
+                    false,
+                    false,
+                )?;
+                return Ok(ProjectEnvironment::DryRun(environment, temp_dir));
+            }
 
             // Remove the existing virtual environment if it doesn't meet the requirements.
             match fs_err::remove_dir_all(&venv) {
                 Ok(()) => {
+                    let details = existing
+                        .as_ref()
+                        .map(|cfg| {
+                            cfg.version()
+                                .map(|version| format!("Python {version}"))
+                                .into_iter()
+                                .chain(cfg.creator().map(|creator| format!("created by {creator}")))
+                                .join(", ")
+                        })
+                        .filter(|details| !details.is_empty())
+                        .map(|details| format!(" ({details})"))
+                        .unwrap_or_default();
+                    let reason = incompatibility
+                        .map(|incompatibility| format!(", since {incompatibility}"))
+                        .unwrap_or_default();
                     writeln!(
                         printer.stderr(),
-                        "Removed virtual environment at: {}",
+                        "Removed virtual environment at: {}{details}{reason}",
                         venv.user_display().cyan()
                     )?;
                 }
@@ -295,18 +938,58 @@ pub(crate) async fn get_or_init_environment(
                 venv.user_display().cyan()
             )?;
 
-            Ok(uv_virtualenv::create_venv(
+            // Default to the name of the workspace root project, or the workspace directory.
+            let prompt = match prompt {
+                Some(prompt) => uv_virtualenv::Prompt::from_args(Some(prompt.to_string())),
+                None => workspace
+                    .packages()
+                    .values()
+                    .find(|member| member.root() == workspace.install_path())
+                    .map(|member| member.project().name.to_string())
+                    .or_else(|| {
+                        workspace
+                            .install_path()
+                            .file_name()
+                            .map(|name| name.to_string_lossy().to_string())
+                    })
+                    .map_or(uv_virtualenv::Prompt::None, uv_virtualenv::Prompt::Static),
+            };
+
+            // Seed the environment if requested, or if the environment being replaced was seeded.
+            let seed = seed
+                || workspace.seed()
+                || existing
+                    .as_ref()
+                    .is_some_and(PyVenvConfiguration::is_seeded);
+
+            Ok(ProjectEnvironment::Project(uv_virtualenv::create_venv(
                 &venv,
                 interpreter,
-                uv_virtualenv::Prompt::None,
+                prompt,
                 false,
                 false,
-            )?)
+                seed,
+            )?))
         }
     }
 }
 
+/// Validate that any packages marked for reinstallation are included in the [`Resolution`].
+fn validate_reinstall(reinstall: &Reinstall, resolution: &Resolution) -> Result<(), ProjectError> {
+    if let Reinstall::Packages(packages) = reinstall {
+        if let Some(package) = packages
+            .iter()
+            .find(|package| !resolution.packages().any(|name| name == *package))
+        {
+            return Err(ProjectError::ReinstallPackageNotFound(package.clone()));
+        }
+    }
+    Ok(())
+}
+
 /// Resolve any [`UnresolvedRequirementSpecification`] into a fully-qualified [`Requirement`].
+///
+/// The requirements are resolved concurrently, up to the download and build concurrency limits.
 pub(crate) async fn resolve_names(
     requirements: Vec<UnresolvedRequirementSpecification>,
     interpreter: &Interpreter,
@@ -327,7 +1010,12 @@ pub(crate) async fn resolve_names(
         resolution: _,
         prerelease: _,
         config_setting,
+        no_build_isolation,
+        no_build_isolation_package,
+        setup_py,
         exclude_newer,
+        exclude_newer_index: _,
+        python_platforms: _,
         link_mode,
         compile_bytecode: _,
         upgrade: _,
@@ -346,11 +1034,21 @@ pub(crate) async fn resolve_names(
         .platform(interpreter.platform())
         .build();
 
+    // Determine whether to enable build isolation.
+    let environment;
+    let build_isolation = if *no_build_isolation {
+        environment = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::Shared(&environment)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        environment = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::SharedPackage(&environment, no_build_isolation_package)
+    };
+
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
-    let build_isolation = BuildIsolation::default();
     let hasher = HashStrategy::default();
-    let setup_py = SetupPyStrategy::default();
     let flat_index = FlatIndex::default();
 
     // Create a build dispatch.
@@ -364,7 +1062,7 @@ pub(crate) async fn resolve_names(
         &state.git,
         &state.in_flight,
         *index_strategy,
-        setup_py,
+        *setup_py,
         config_setting,
         build_isolation,
         *link_mode,
@@ -374,6 +1072,10 @@ pub(crate) async fn resolve_names(
         preview,
     );
 
+    // Deduplicate the requirements, such that repeated requirements are only resolved once. The
+    // order of the requirements is preserved.
+    let requirements = requirements.into_iter().unique().collect::<Vec<_>>();
+
     // Initialize the resolver.
     let resolver = NamedRequirementsResolver::new(
         requirements,
@@ -406,7 +1108,12 @@ pub(crate) async fn resolve_environment<'a>(
         resolution,
         prerelease,
         config_setting,
+        no_build_isolation,
+        no_build_isolation_package,
+        setup_py,
         exclude_newer,
+        exclude_newer_index,
+        python_platforms: _,
         link_mode,
         upgrade: _,
         build_options,
@@ -417,6 +1124,14 @@ pub(crate) async fn resolve_environment<'a>(
     let markers = interpreter.markers();
     let python_requirement = PythonRequirement::from_interpreter(interpreter);
 
+    debug!(
+        "Using indexes: {}",
+        index_locations
+            .indexes()
+            .map(ToString::to_string)
+            .join(", ")
+    );
+
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
@@ -432,17 +1147,28 @@ pub(crate) async fn resolve_environment<'a>(
         .resolution_mode(resolution)
         .prerelease_mode(prerelease)
         .exclude_newer(exclude_newer)
+        .exclude_newer_index(exclude_newer_index.clone())
         .index_strategy(index_strategy)
         .build();
 
+    // Determine whether to enable build isolation.
+    let environment;
+    let build_isolation = if no_build_isolation {
+        environment = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::Shared(&environment)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        environment = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::SharedPackage(&environment, no_build_isolation_package)
+    };
+
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
-    let build_isolation = BuildIsolation::default();
     let dev = Vec::default();
     let extras = ExtrasSpecification::default();
     let hasher = HashStrategy::default();
     let preferences = Vec::default();
-    let setup_py = SetupPyStrategy::default();
 
     // When resolving from an interpreter, we assume an empty environment, so reinstalls and
     // upgrades aren't relevant.
@@ -478,7 +1204,7 @@ pub(crate) async fn resolve_environment<'a>(
     );
 
     // Resolve the requirements.
-    Ok(pip::operations::resolve(
+    pip::operations::resolve(
         spec.requirements,
         spec.constraints,
         spec.overrides,
@@ -504,13 +1230,20 @@ pub(crate) async fn resolve_environment<'a>(
         preview,
         false,
     )
-    .await?)
+    .await
+    .map_err(|err| ProjectError::from_operation(err, connectivity).into())
 }
 
 /// Sync a [`PythonEnvironment`] with a set of resolved requirements.
+///
+/// If `atomic`, a copy of the environment is staged in an adjacent directory before installing,
+/// and swapped back into place if the installation fails, such that the environment is never left
+/// partially synced.
 pub(crate) async fn sync_environment(
     venv: PythonEnvironment,
     resolution: &Resolution,
+    modifications: Modifications,
+    atomic: bool,
     settings: InstallerSettingsRef<'_>,
     state: &SharedState,
     preview: PreviewMode,
@@ -525,6 +1258,9 @@ pub(crate) async fn sync_environment(
         index_strategy,
         keyring_provider,
         config_setting,
+        no_build_isolation,
+        no_build_isolation_package,
+        setup_py,
         exclude_newer,
         link_mode,
         compile_bytecode,
@@ -550,12 +1286,19 @@ pub(crate) async fn sync_environment(
         .platform(interpreter.platform())
         .build();
 
+    // Determine whether to enable build isolation.
+    let build_isolation = if no_build_isolation {
+        BuildIsolation::Shared(&venv)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(&venv, no_build_isolation_package)
+    };
+
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
-    let build_isolation = BuildIsolation::default();
     let dry_run = false;
     let hasher = HashStrategy::default();
-    let setup_py = SetupPyStrategy::default();
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
@@ -585,11 +1328,21 @@ pub(crate) async fn sync_environment(
         preview,
     );
 
+    // Ensure that any packages marked for reinstallation are present.
+    validate_reinstall(reinstall, resolution)?;
+
+    // Stage a copy of the environment, to restore if the installation fails.
+    let staging = if atomic {
+        stage_environment(venv.root())?
+    } else {
+        None
+    };
+
     // Sync the environment.
-    pip::operations::install(
+    let result = pip::operations::install(
         resolution,
         site_packages,
-        Modifications::Exact,
+        modifications,
         reinstall,
         build_options,
         link_mode,
@@ -607,7 +1360,14 @@ pub(crate) async fn sync_environment(
         printer,
         preview,
     )
-    .await?;
+    .await;
+
+    if let Err(err) = result {
+        if let Some(staging) = staging {
+            restore_environment(venv.root(), staging);
+        }
+        return Err(ProjectError::from_operation(err, connectivity).into());
+    }
 
     // Notify the user of any resolution diagnostics.
     pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
@@ -615,10 +1375,76 @@ pub(crate) async fn sync_environment(
     Ok(venv)
 }
 
+/// Stage a copy of the environment at `root` in an adjacent temporary directory.
+///
+/// Returns `None` if the environment can't be atomically swapped back into place (e.g., if it's a
+/// mount point on a different filesystem than its parent), in which case the environment is
+/// synced in-place.
+fn stage_environment(root: &Path) -> anyhow::Result<Option<tempfile::TempDir>> {
+    let Some(parent) = root.parent() else {
+        return Ok(None);
+    };
+    let staging = tempfile::Builder::new()
+        .prefix(".uv-staging-")
+        .tempdir_in(parent)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        if fs_err::metadata(root)?.dev() != fs_err::metadata(staging.path())?.dev() {
+            warn_user!(
+                "The environment at `{}` is on a different filesystem than its parent directory, so it can't be swapped atomically; installing in-place (use `--no-atomic` to silence this warning)",
+                root.user_display()
+            );
+            return Ok(None);
+        }
+    }
+
+    debug!(
+        "Staging environment at `{}` in `{}`",
+        root.user_display(),
+        staging.path().user_display()
+    );
+    uv_fs::copy_dir_all(root, staging.path().join("environment"))?;
+
+    Ok(Some(staging))
+}
+
+/// Swap the staged copy of an environment back into place at `root`, after a failed installation.
+///
+/// The partially-synced environment is moved into the staging directory, such that it's removed
+/// along with it. If the environment can't be restored, a warning is shown instead of an error, so
+/// that the installation error is the one reported; in that case, the staging directory is
+/// retained, such that the previous environment can be recovered manually.
+fn restore_environment(root: &Path, staging: tempfile::TempDir) {
+    debug!(
+        "Restoring environment at `{}` after failed installation",
+        root.user_display()
+    );
+    let result = fs_err::rename(root, staging.path().join("failed"))
+        .and_then(|()| fs_err::rename(staging.path().join("environment"), root));
+    if let Err(err) = result {
+        let staging = staging.into_path();
+        warn_user!(
+            "Failed to restore the environment at `{}` after a failed installation; the previous environment was left at `{}`: {err}",
+            root.user_display(),
+            staging.join("environment").user_display()
+        );
+    }
+}
+
 /// Update a [`PythonEnvironment`] to satisfy a set of [`RequirementsSource`]s.
+///
+/// If `atomic`, the environment is restored if the installation fails, as in
+/// [`sync_environment`].
 pub(crate) async fn update_environment(
     venv: PythonEnvironment,
     spec: RequirementsSpecification,
+    extras: &ExtrasSpecification,
+    dev: &DevSpecification,
+    modifications: Modifications,
+    atomic: bool,
     settings: &ResolverInstallerSettings,
     state: &SharedState,
     preview: PreviewMode,
@@ -635,7 +1461,12 @@ pub(crate) async fn update_environment(
         resolution,
         prerelease,
         config_setting,
+        no_build_isolation,
+        no_build_isolation_package,
+        setup_py,
         exclude_newer,
+        exclude_newer_index,
+        python_platforms: _,
         link_mode,
         compile_bytecode,
         upgrade,
@@ -643,9 +1474,15 @@ pub(crate) async fn update_environment(
         build_options,
     } = settings;
 
-    // Check if the current environment satisfies the requirements
+    // Check if the current environment satisfies the requirements. The selected extras and groups
+    // are applied to the source trees, so a change in either requires a re-resolve.
     let site_packages = SitePackages::from_environment(&venv)?;
-    if spec.source_trees.is_empty() && reinstall.is_none() && upgrade.is_none() {
+    if spec.source_trees.is_empty()
+        && extras.is_empty()
+        && dev.groups().next().is_none()
+        && reinstall.is_none()
+        && upgrade.is_none()
+    {
         match site_packages.satisfies(&spec.requirements, &spec.constraints)? {
             // If the requirements are already satisfied, we're done.
             SatisfiesResult::Fresh {
@@ -673,6 +1510,14 @@ pub(crate) async fn update_environment(
     let markers = venv.interpreter().markers();
     let python_requirement = PythonRequirement::from_interpreter(interpreter);
 
+    debug!(
+        "Using indexes: {}",
+        index_locations
+            .indexes()
+            .map(ToString::to_string)
+            .join(", ")
+    );
+
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
@@ -688,18 +1533,31 @@ pub(crate) async fn update_environment(
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .exclude_newer(*exclude_newer)
+        .exclude_newer_index(exclude_newer_index.clone())
         .index_strategy(*index_strategy)
         .build();
 
+    // Determine whether to enable build isolation.
+    let build_isolation = if *no_build_isolation {
+        BuildIsolation::Shared(&venv)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(&venv, no_build_isolation_package)
+    };
+
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
-    let build_isolation = BuildIsolation::default();
-    let dev = Vec::default();
     let dry_run = false;
-    let extras = ExtrasSpecification::default();
     let hasher = HashStrategy::default();
-    let preferences = Vec::default();
-    let setup_py = SetupPyStrategy::default();
+
+    // Prefer the installed versions, to avoid churning unrelated packages, unless an upgrade was
+    // requested.
+    let preferences = site_packages
+        .iter()
+        .filter(|dist| !upgrade.contains(dist.name()))
+        .map(Preference::from_installed)
+        .collect::<Vec<_>>();
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
@@ -719,7 +1577,7 @@ pub(crate) async fn update_environment(
         &state.git,
         &state.in_flight,
         *index_strategy,
-        setup_py,
+        *setup_py,
         config_setting,
         build_isolation,
         *link_mode,
@@ -734,10 +1592,10 @@ pub(crate) async fn update_environment(
         spec.requirements,
         spec.constraints,
         spec.overrides,
-        dev,
+        dev.groups().cloned().collect(),
         spec.source_trees,
         spec.project,
-        &extras,
+        extras,
         preferences,
         site_packages.clone(),
         &hasher,
@@ -759,14 +1617,24 @@ pub(crate) async fn update_environment(
     .await
     {
         Ok(resolution) => Resolution::from(resolution),
-        Err(err) => return Err(err.into()),
+        Err(err) => return Err(ProjectError::from_operation(err, connectivity).into()),
+    };
+
+    // Ensure that any packages marked for reinstallation are present.
+    validate_reinstall(reinstall, &resolution)?;
+
+    // Stage a copy of the environment, to restore if the installation fails.
+    let staging = if atomic {
+        stage_environment(venv.root())?
+    } else {
+        None
     };
 
     // Sync the environment.
-    pip::operations::install(
+    let result = pip::operations::install(
         &resolution,
         site_packages,
-        Modifications::Exact,
+        modifications,
         reinstall,
         build_options,
         *link_mode,
@@ -784,10 +1652,310 @@ pub(crate) async fn update_environment(
         printer,
         preview,
     )
-    .await?;
+    .await;
+
+    if let Err(err) = result {
+        if let Some(staging) = staging {
+            restore_environment(venv.root(), staging);
+        }
+        return Err(ProjectError::from_operation(err, connectivity).into());
+    }
 
     // Notify the user of any resolution diagnostics.
     pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
     Ok(venv)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use pep440_rs::{Version, VersionSpecifiers};
+    use uv_configuration::TargetTriple;
+    use uv_normalize::{ExtraName, GroupName, PackageName};
+    use uv_python::{ImplementationName, PythonRequest, VersionRequest};
+    use uv_resolver::RequiresPython;
+
+    use crate::commands::pip;
+
+    use super::ProjectError;
+
+    #[test]
+    fn hint_lock_mismatch() {
+        let err = ProjectError::LockMismatch(PathBuf::from("uv.lock"));
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("To update the lockfile, run `uv lock`.")
+        );
+    }
+
+    #[test]
+    fn hint_missing_lockfile() {
+        let err = ProjectError::MissingLockfile(PathBuf::from("uv.lock"));
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("To create a lockfile, run `uv lock` or `uv sync`.")
+        );
+    }
+
+    #[test]
+    fn hint_frozen_lock_missing_member() {
+        let err = ProjectError::FrozenLockMissingMember(
+            PackageName::from_str("child").unwrap(),
+            PathBuf::from("uv.lock"),
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("The lockfile is out of date with the workspace; to update it, run `uv lock`.")
+        );
+    }
+
+    #[test]
+    fn hint_locked_python_incompatibility() {
+        let err = ProjectError::LockedPythonIncompatibility(
+            Version::from_str("3.8.18").unwrap(),
+            RequiresPython::greater_than_equal_version(&Version::from_str("3.12").unwrap()),
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("Use `--python` to select an interpreter that satisfies `>=3.12`, or run `uv lock` to update the lockfile.")
+        );
+    }
+
+    #[test]
+    fn hint_locked_python_range_incompatibility() {
+        let err = ProjectError::LockedPythonRangeIncompatibility(
+            Version::from_str("3.8.18").unwrap(),
+            RequiresPython::greater_than_equal_version(&Version::from_str("3.12").unwrap()),
+            VersionSpecifiers::from_str(">=3.12").unwrap(),
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("Use `--python` to select an interpreter that satisfies `>=3.12`, or run `uv lock --python-range` with a range that includes `3.8.18`.")
+        );
+    }
+
+    #[test]
+    fn hint_locked_platform_incompatibility() {
+        let err = ProjectError::LockedPlatformIncompatibility(vec![
+            TargetTriple::X8664UnknownLinuxGnu,
+            TargetTriple::Aarch64AppleDarwin,
+        ]);
+        assert_eq!(
+            err.to_string(),
+            "The current platform is not included in the lockfile, which is restricted to: `x86_64-unknown-linux-gnu`, `aarch64-apple-darwin`"
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("Run `uv lock --python-platform` with a platform that matches the current environment to include it in the lockfile.")
+        );
+    }
+
+    #[test]
+    fn hint_disjoint_python_range() {
+        let err = ProjectError::DisjointPythonRange(
+            VersionSpecifiers::from_str("<3.8").unwrap(),
+            RequiresPython::greater_than_equal_version(&Version::from_str("3.12").unwrap()),
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("Provide a `--python-range` that overlaps with `>=3.12`.")
+        );
+    }
+
+    #[test]
+    fn hint_requested_python_incompatibility() {
+        let err = ProjectError::RequestedPythonIncompatibility(
+            Version::from_str("3.8.18").unwrap(),
+            RequiresPython::greater_than_equal_version(&Version::from_str("3.12").unwrap()),
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("Try setting `requires-python` to include `3.8.18` in your `pyproject.toml`.")
+        );
+    }
+
+    #[test]
+    fn hint_requested_implementation_incompatibility() {
+        let err = ProjectError::RequestedImplementationIncompatibility(
+            "pypy".to_string(),
+            ImplementationName::CPython,
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("Use `--python` to select a CPython interpreter, or update `tool.uv.python-implementation` in your `pyproject.toml`.")
+        );
+    }
+
+    #[test]
+    fn hint_invalid_python_implementation() {
+        let err = ProjectError::InvalidPythonImplementation(
+            "Unknown Python implementation `jython`".to_string(),
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("Set `tool.uv.python-implementation` to one of `cpython`, `pypy`, or `graalpy`.")
+        );
+    }
+
+    #[test]
+    fn with_implementation() {
+        let version = VersionRequest::from_str(">=3.12").unwrap();
+
+        // A version request is narrowed to the implementation.
+        assert_eq!(
+            super::with_implementation(
+                Some(PythonRequest::Version(version.clone())),
+                Some(ImplementationName::PyPy)
+            ),
+            Some(PythonRequest::ImplementationVersion(
+                ImplementationName::PyPy,
+                version.clone()
+            ))
+        );
+
+        // An absent request becomes a request for the implementation.
+        assert_eq!(
+            super::with_implementation(None, Some(ImplementationName::CPython)),
+            Some(PythonRequest::Implementation(ImplementationName::CPython))
+        );
+
+        // An explicit implementation is left as-is.
+        assert_eq!(
+            super::with_implementation(
+                Some(PythonRequest::ImplementationVersion(
+                    ImplementationName::PyPy,
+                    version.clone()
+                )),
+                Some(ImplementationName::CPython)
+            ),
+            Some(PythonRequest::ImplementationVersion(
+                ImplementationName::PyPy,
+                version.clone()
+            ))
+        );
+
+        // Without an implementation, the request is unchanged.
+        assert_eq!(
+            super::with_implementation(Some(PythonRequest::Version(version.clone())), None),
+            Some(PythonRequest::Version(version))
+        );
+    }
+
+    #[test]
+    fn hint_reinstall_package_not_found() {
+        let err = ProjectError::ReinstallPackageNotFound(PackageName::from_str("foo").unwrap());
+        assert_eq!(
+            err.hint().as_deref(),
+            Some(
+                "Remove `foo` from `--reinstall-package`, or add it to the project's dependencies."
+            )
+        );
+    }
+
+    #[test]
+    fn hint_missing_extra() {
+        let err = ProjectError::MissingExtra(
+            ExtraName::from_str("foo").unwrap(),
+            vec![ExtraName::from_str("bar").unwrap()],
+        );
+        assert_eq!(
+            err.to_string(),
+            "Extra `foo` is not defined in the project's `optional-dependencies` table (available extras: `bar`)"
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("Remove `--extra foo`, or define the extra under `[project.optional-dependencies]` in your `pyproject.toml`.")
+        );
+    }
+
+    #[test]
+    fn hint_lock_missing_extra() {
+        let err = ProjectError::LockMissingExtra(ExtraName::from_str("foo").unwrap());
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("To update the lockfile, run `uv lock`.")
+        );
+    }
+
+    #[test]
+    fn hint_missing_group() {
+        let err = ProjectError::MissingGroup(
+            GroupName::from_str("docs").unwrap(),
+            vec![GroupName::from_str("dev").unwrap()],
+        );
+        assert_eq!(
+            err.to_string(),
+            "Group `docs` is not defined in the project's `dependency-groups` table (available groups: `dev`)"
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("Define the `docs` group under `[dependency-groups]` in your `pyproject.toml`.")
+        );
+    }
+
+    #[test]
+    fn hint_marker_mismatch() {
+        let err = ProjectError::MarkerMismatch(vec![]);
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("The lockfile may have been created on a different platform; run `uv lock` on this platform to include it.")
+        );
+    }
+
+    #[test]
+    fn hint_missing_build_backend() {
+        let err = ProjectError::MissingBuildBackend(
+            PackageName::from_str("app").unwrap(),
+            pip::operations::Error::Anyhow(anyhow::anyhow!("Failed to prepare distributions")),
+        );
+        assert_eq!(
+            err.to_string(),
+            "Failed to build workspace member `app`, which doesn't declare a `[build-system]`"
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("If `app` isn't meant to be built, set `tool.uv.package = false` in its `pyproject.toml` to install only its dependencies; otherwise, declare a `[build-system]`.")
+        );
+    }
+
+    #[test]
+    fn hint_non_package_dependency() {
+        let err = ProjectError::NonPackageDependency(
+            PackageName::from_str("lib").unwrap(),
+            PackageName::from_str("app").unwrap(),
+        );
+        assert_eq!(
+            err.to_string(),
+            "Workspace member `app` depends on `lib`, which sets `tool.uv.package = false` and can't be installed"
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("Remove `tool.uv.package = false` from `lib`'s `pyproject.toml` so that it can be built, or remove the dependency on it.")
+        );
+    }
+
+    #[test]
+    fn hint_offline() {
+        let err = ProjectError::Offline(vec![
+            PackageName::from_str("anyio").unwrap(),
+            PackageName::from_str("idna").unwrap(),
+        ]);
+        assert_eq!(
+            err.to_string(),
+            "Network connectivity is disabled, but the following packages weren't found in the cache: `anyio`, `idna`"
+        );
+        assert_eq!(
+            err.hint().as_deref(),
+            Some("Run the command once with network access to populate the cache, or remove `--offline` (or `UV_OFFLINE`).")
+        );
+    }
+
+    #[test]
+    fn hint_transparent() {
+        let err = ProjectError::Io(std::io::Error::other("error"));
+        assert_eq!(err.hint(), None);
+    }
+}
@@ -0,0 +1,687 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use mailparse::MailHeaderMap;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use sha2::{Digest, Sha256};
+use tracing::debug;
+use url::Url;
+
+use distribution_filename::DistFilename;
+use distribution_types::IndexUrl;
+use pypi_types::SimpleJson;
+use uv_auth::store_credentials_from_url;
+use uv_cache::Cache;
+use uv_client::{BaseClient, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{BuildKind, Concurrency, PreviewMode};
+use uv_dispatch::BuildDispatch;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_python::{PythonFetch, PythonPreference, PythonRequest};
+use uv_resolver::FlatIndex;
+use uv_types::{BuildContext, BuildIsolation, HashStrategy, SourceBuildTrait};
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::{ProjectWorkspace, Workspace};
+
+use crate::commands::project::{FoundInterpreter, SharedState};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings};
+
+/// The upload URL for PyPI.
+const PYPI_UPLOAD_URL: &str = "https://upload.pypi.org/legacy/";
+
+/// The index URL for PyPI.
+const PYPI_SIMPLE_URL: &str = "https://pypi.org/simple/";
+
+/// The core metadata fields to send with each upload, as `(header, form field)` pairs, mirroring
+/// the fields sent by `twine`.
+///
+/// See: <https://packaging.python.org/en/latest/specifications/core-metadata/>
+const METADATA_FIELDS: &[(&str, &str)] = &[
+    ("Summary", "summary"),
+    ("Description-Content-Type", "description_content_type"),
+    ("Keywords", "keywords"),
+    ("Home-page", "home_page"),
+    ("Download-URL", "download_url"),
+    ("Author", "author"),
+    ("Author-email", "author_email"),
+    ("Maintainer", "maintainer"),
+    ("Maintainer-email", "maintainer_email"),
+    ("License", "license"),
+    ("License-Expression", "license_expression"),
+    ("License-File", "license_file"),
+    ("Classifier", "classifiers"),
+    ("Platform", "platform"),
+    ("Supported-Platform", "supported_platform"),
+    ("Requires-Dist", "requires_dist"),
+    ("Requires-Python", "requires_python"),
+    ("Requires-External", "requires_external"),
+    ("Provides-Dist", "provides_dist"),
+    ("Provides-Extra", "provides_extra"),
+    ("Obsoletes-Dist", "obsoletes_dist"),
+    ("Project-URL", "project_urls"),
+    ("Dynamic", "dynamic"),
+];
+
+/// Build the project, and upload its distributions to a registry.
+pub(crate) async fn publish(
+    repository: Option<Url>,
+    username: Option<String>,
+    password: Option<String>,
+    skip_existing: bool,
+    package: Option<PackageName>,
+    python: Option<String>,
+    settings: ResolverInstallerSettings,
+    python_preference: PythonPreference,
+    python_fetch: PythonFetch,
+    python_install_mirror: &[String],
+    preview: PreviewMode,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv publish` is experimental and may change without warning");
+    }
+
+    if connectivity.is_offline() {
+        bail!("Network connectivity is disabled, but `uv publish` requires network access to upload distributions");
+    }
+
+    // Identify the project.
+    let project = if let Some(package) = package {
+        Workspace::discover(&std::env::current_dir()?, None)
+            .await?
+            .with_current_project(package.clone())
+            .with_context(|| format!("Package `{package}` not found in workspace"))?
+    } else {
+        ProjectWorkspace::discover(&std::env::current_dir()?, None).await?
+    };
+
+    // Extract the project settings.
+    let InstallerSettingsRef {
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        config_setting,
+        no_build_isolation,
+        no_build_isolation_package,
+        setup_py,
+        exclude_newer,
+        link_mode,
+        compile_bytecode: _,
+        reinstall: _,
+        build_options,
+    } = settings.as_ref().into();
+
+    // The distributions are built from scratch, without access to the project environment.
+    if no_build_isolation || !no_build_isolation_package.is_empty() {
+        warn_user!("`uv publish` always builds in an isolated environment; ignoring `--no-build-isolation`");
+    }
+
+    // Find an interpreter with which to build the project.
+    let interpreter = FoundInterpreter::discover(
+        project.workspace(),
+        python.as_deref().map(PythonRequest::parse),
+        false,
+        python_preference,
+        python_fetch,
+        python_install_mirror,
+        connectivity,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter();
+
+    // Add all authenticated sources to the cache, such that credentials for a configured index are
+    // reused when uploading to the same registry.
+    for url in index_locations.urls() {
+        store_credentials_from_url(url);
+    }
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .native_tls(native_tls)
+        .connectivity(connectivity)
+        .index_urls(index_locations.index_urls())
+        .index_strategy(index_strategy)
+        .keyring(keyring_provider)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(&client, cache);
+        let entries = client.fetch(index_locations.flat_index()).await?;
+        FlatIndex::from_entries(
+            entries,
+            Some(interpreter.tags()?),
+            &HashStrategy::None,
+            build_options,
+        )
+    };
+
+    // Create a build dispatch.
+    let state = SharedState::default();
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        &interpreter,
+        index_locations,
+        &flat_index,
+        &state.index,
+        &state.git,
+        &state.in_flight,
+        index_strategy,
+        setup_py,
+        config_setting,
+        BuildIsolation::Isolated,
+        link_mode,
+        build_options,
+        exclude_newer,
+        concurrency,
+        preview,
+    );
+
+    // Build the source distribution and the wheel into the project's `dist` directory.
+    let dist_dir = project.project_root().join("dist");
+    fs_err::create_dir_all(&dist_dir)?;
+
+    let version_id = project.project_root().user_display().to_string();
+    let mut artifacts = Vec::new();
+    for build_kind in [BuildKind::Sdist, BuildKind::Wheel] {
+        let builder = build_dispatch
+            .setup_build(project.project_root(), None, &version_id, None, build_kind)
+            .await
+            .with_context(|| format!("Failed to build `{}`", project.project_name()))?;
+        let filename = builder
+            .wheel(&dist_dir)
+            .await
+            .with_context(|| format!("Failed to build `{}`", project.project_name()))?;
+        writeln!(
+            printer.stderr(),
+            "Built {}",
+            dist_dir.join(&filename).user_display().cyan()
+        )?;
+        artifacts.push(dist_dir.join(filename));
+    }
+
+    // Determine the registry to upload to, and the index from which the uploads are served.
+    let (repository, index) = if let Some(repository) = repository {
+        let index = index_url(&repository);
+        (repository, index)
+    } else if let Some(IndexUrl::Url(url)) = index_locations.index() {
+        (upload_url(url.raw()), url.raw().clone())
+    } else {
+        (Url::parse(PYPI_UPLOAD_URL)?, Url::parse(PYPI_SIMPLE_URL)?)
+    };
+    debug!("Uploading to: {repository}");
+
+    // If a password was provided without a username, assume that it's an API token.
+    let credentials = match (username, password) {
+        (None, None) => None,
+        (username, password) => Some((
+            username.unwrap_or_else(|| "__token__".to_string()),
+            password,
+        )),
+    };
+
+    // Upload the distributions.
+    let metadata = core_metadata(&artifacts, project.project_name())?;
+    let base_client = client.uncached_client();
+    let mut uploaded = Vec::new();
+    for artifact in &artifacts {
+        match upload(
+            &base_client,
+            &repository,
+            artifact,
+            project.project_name(),
+            &metadata,
+            credentials.as_ref(),
+            skip_existing,
+        )
+        .await?
+        {
+            UploadOutcome::Uploaded => {
+                writeln!(
+                    printer.stderr(),
+                    "Uploaded {} to {}",
+                    file_name(artifact)?.cyan(),
+                    redacted(&repository).cyan()
+                )?;
+                uploaded.push(file_name(artifact)?);
+            }
+            UploadOutcome::Skipped => {
+                writeln!(
+                    printer.stderr(),
+                    "Skipped {}, which already exists in {}",
+                    file_name(artifact)?.cyan(),
+                    redacted(&repository).cyan()
+                )?;
+            }
+        }
+    }
+
+    // Print the URLs at which the uploaded distributions are available.
+    if !uploaded.is_empty() {
+        match artifact_urls(&base_client, &index, project.project_name()).await {
+            Ok(urls) => {
+                for filename in uploaded {
+                    if let Some(url) = urls.get(filename) {
+                        writeln!(printer.stdout(), "{url}")?;
+                    } else {
+                        warn_user!(
+                            "`{filename}` was uploaded, but is not yet listed in the index at {}",
+                            redacted(&index)
+                        );
+                    }
+                }
+            }
+            Err(err) => {
+                warn_user!(
+                    "Unable to read the uploaded distributions from the index at {}: {err}",
+                    redacted(&index)
+                );
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// The result of uploading a single distribution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum UploadOutcome {
+    /// The distribution was uploaded.
+    Uploaded,
+    /// The distribution already existed in the registry, and was skipped.
+    Skipped,
+}
+
+/// Upload a distribution to a registry, via the legacy upload API.
+///
+/// See: <https://warehouse.pypa.io/api-reference/legacy.html#upload-api>
+async fn upload(
+    client: &BaseClient,
+    repository: &Url,
+    artifact: &Path,
+    package_name: &PackageName,
+    metadata: &CoreMetadata,
+    credentials: Option<&(String, Option<String>)>,
+    skip_existing: bool,
+) -> Result<UploadOutcome> {
+    let filename = file_name(artifact)?;
+    let Some(dist_filename) = DistFilename::try_from_filename(filename, package_name) else {
+        bail!("Unable to determine the distribution type of `{filename}`");
+    };
+    let (filetype, pyversion) = match &dist_filename {
+        DistFilename::SourceDistFilename(_) => ("sdist", "source".to_string()),
+        DistFilename::WheelFilename(wheel) => ("bdist_wheel", wheel.python_tag.join(".")),
+    };
+
+    let contents = fs_err::read(artifact)?;
+    let digest = format!("{:x}", Sha256::digest(&contents));
+
+    let mut form = MultipartForm::new(format!("uv-{digest}"));
+    form.text(":action", "file_upload");
+    form.text("protocol_version", "1");
+    form.text("metadata_version", &metadata.metadata_version);
+    form.text("name", dist_filename.name().as_ref());
+    form.text("version", &dist_filename.version().to_string());
+    form.text("filetype", filetype);
+    form.text("pyversion", &pyversion);
+    form.text("sha256_digest", &digest);
+    for (field, value) in &metadata.fields {
+        form.text(field, value);
+    }
+    if let Some(description) = &metadata.description {
+        form.text("description", description);
+    }
+    form.file("content", filename, &contents);
+
+    let mut request = client
+        .client()
+        .post(repository.clone())
+        .header("Content-Type", form.content_type());
+    if let Some((username, password)) = credentials {
+        request = request.basic_auth(username, password.as_ref());
+    }
+
+    debug!("Uploading `{filename}` to: {}", redacted(repository));
+    let response = request
+        .body(form.finish())
+        .send()
+        .await
+        .with_context(|| format!("Failed to upload `{filename}`"))?;
+
+    let status = response.status();
+    if status.is_success() {
+        return Ok(UploadOutcome::Uploaded);
+    }
+
+    let text = response.text().await.unwrap_or_default();
+    if skip_existing && is_existing(status.as_u16(), &text) {
+        return Ok(UploadOutcome::Skipped);
+    }
+
+    bail!(
+        "Failed to upload `{filename}` to {} ({status}): {}",
+        redacted(repository),
+        text.trim()
+    );
+}
+
+/// Returns `true` if an upload was rejected because the distribution already exists.
+///
+/// Registries disagree on how to report this: some return `409 Conflict` (e.g., `pypiserver` and
+/// `devpi`), while others return `400 Bad Request` (e.g., PyPI) or `403 Forbidden` with a message.
+fn is_existing(status: u16, text: &str) -> bool {
+    match status {
+        409 => true,
+        400 | 403 => {
+            let text = text.to_lowercase();
+            text.contains("already exist")
+                || text.contains("updating asset")
+                || text.contains("overwrite artifact")
+        }
+        _ => false,
+    }
+}
+
+/// Infer the upload URL for an index URL, by stripping a trailing `/simple` path segment, as is
+/// conventional for registries that serve both APIs (e.g., `https://example.com/pypi/simple`
+/// uploads to `https://example.com/pypi`).
+fn upload_url(index_url: &Url) -> Url {
+    let is_simple = index_url
+        .path_segments()
+        .and_then(|segments| segments.filter(|segment| !segment.is_empty()).last())
+        == Some("simple");
+    if !is_simple {
+        return index_url.clone();
+    }
+
+    let mut url = index_url.clone();
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments.pop_if_empty().pop();
+    }
+    url
+}
+
+/// Infer the index URL for an upload URL, from which the uploaded distributions are served.
+///
+/// PyPI-style upload URLs (e.g., `https://test.pypi.org/legacy/`) are served from a sibling
+/// `/simple` path; otherwise, this is the inverse of [`upload_url`] (e.g.,
+/// `https://example.com/pypi` is served from `https://example.com/pypi/simple/`).
+fn index_url(repository: &Url) -> Url {
+    if repository.as_str() == PYPI_UPLOAD_URL {
+        return Url::parse(PYPI_SIMPLE_URL).expect("PyPI index URL is valid");
+    }
+
+    let mut url = repository.clone();
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments.pop_if_empty();
+    }
+    let is_legacy = url
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .is_some_and(|segment| segment == "legacy");
+    if let Ok(mut segments) = url.path_segments_mut() {
+        if is_legacy {
+            segments.pop();
+        }
+        segments.push("simple").push("");
+    }
+    url
+}
+
+/// Read the URLs of a package's distributions from an index, via the JSON Simple API, keyed by
+/// file name.
+///
+/// See: <https://peps.python.org/pep-0691/>
+async fn artifact_urls(
+    client: &BaseClient,
+    index: &Url,
+    package_name: &PackageName,
+) -> Result<FxHashMap<String, Url>> {
+    let mut url = index.clone();
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments.pop_if_empty().push(package_name.as_ref()).push("");
+    }
+
+    debug!("Reading uploaded distributions from: {}", redacted(&url));
+    let response = client
+        .client()
+        .get(url.clone())
+        .header("Accept", "application/vnd.pypi.simple.v1+json")
+        .send()
+        .await?
+        .error_for_status()?;
+    let simple: SimpleJson = serde_json::from_slice(&response.bytes().await?)?;
+
+    let mut urls = FxHashMap::default();
+    for file in simple.files {
+        let file_url = url.join(&file.url)?;
+        urls.insert(file.filename, file_url);
+    }
+    Ok(urls)
+}
+
+/// The core metadata of the built distributions, as sent with each upload.
+#[derive(Debug)]
+struct CoreMetadata {
+    /// The `Metadata-Version` of the distributions.
+    metadata_version: String,
+    /// The remaining metadata fields, as `(form field, value)` pairs, in the order of
+    /// [`METADATA_FIELDS`].
+    fields: Vec<(&'static str, String)>,
+    /// The long description, read from the message body or the `Description` header.
+    description: Option<String>,
+}
+
+impl CoreMetadata {
+    /// Parse the [`CoreMetadata`] from a `METADATA` file.
+    fn parse(content: &[u8]) -> Result<Self> {
+        let (headers, body) = mailparse::parse_headers(content)?;
+
+        let Some(metadata_version) = headers.get_first_value("Metadata-Version") else {
+            bail!("The `METADATA` file is missing a `Metadata-Version`");
+        };
+
+        let fields = METADATA_FIELDS
+            .iter()
+            .flat_map(|(header, field)| {
+                headers
+                    .get_all_values(header)
+                    .into_iter()
+                    .map(|value| (*field, value))
+            })
+            .collect();
+
+        // As of Metadata 2.1, the description may be provided in the message body.
+        let description = Some(String::from_utf8_lossy(&content[body..]).into_owned())
+            .filter(|description| !description.trim().is_empty())
+            .or_else(|| headers.get_first_value("Description"));
+
+        Ok(Self {
+            metadata_version: metadata_version.trim().to_string(),
+            fields,
+            description,
+        })
+    }
+}
+
+/// Read the [`CoreMetadata`] from the built wheel, to include in the upload.
+fn core_metadata(artifacts: &[PathBuf], package_name: &PackageName) -> Result<CoreMetadata> {
+    for artifact in artifacts {
+        let filename = file_name(artifact)?;
+        let Some(DistFilename::WheelFilename(wheel)) =
+            DistFilename::try_from_filename(filename, package_name)
+        else {
+            continue;
+        };
+
+        let mut archive = zip::ZipArchive::new(fs_err::File::open(artifact)?)
+            .with_context(|| format!("Failed to read `{filename}`"))?;
+        let metadata = install_wheel_rs::metadata::read_archive_metadata(&wheel, &mut archive)?;
+        return CoreMetadata::parse(&metadata)
+            .with_context(|| format!("Failed to read the metadata of `{filename}`"));
+    }
+    bail!("Unable to find a wheel from which to read the distribution metadata")
+}
+
+/// Return the file name of a built distribution.
+fn file_name(path: &Path) -> Result<&str> {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .with_context(|| format!("Invalid distribution path: {}", path.user_display()))
+}
+
+/// Return the URL with any credentials removed, for display.
+fn redacted(url: &Url) -> Url {
+    let mut url = url.clone();
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+    url
+}
+
+/// A `multipart/form-data` request body.
+///
+/// See: <https://datatracker.ietf.org/doc/html/rfc7578>
+struct MultipartForm {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl MultipartForm {
+    /// Create an empty form with the given boundary, which must not appear in any of the fields.
+    fn new(boundary: String) -> Self {
+        Self {
+            boundary,
+            body: Vec::new(),
+        }
+    }
+
+    /// Add a text field to the form.
+    fn text(&mut self, name: &str, value: &str) {
+        self.body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n",
+                self.boundary
+            )
+            .as_bytes(),
+        );
+    }
+
+    /// Add a file field to the form.
+    fn file(&mut self, name: &str, filename: &str, contents: &[u8]) {
+        self.body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{name}\"; filename=\"{filename}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+                self.boundary
+            )
+            .as_bytes(),
+        );
+        self.body.extend_from_slice(contents);
+        self.body.extend_from_slice(b"\r\n");
+    }
+
+    /// The `Content-Type` header for the form.
+    fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    /// Terminate the form, returning the request body.
+    fn finish(mut self) -> Vec<u8> {
+        self.body
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::{index_url, is_existing, upload_url, CoreMetadata, MultipartForm};
+
+    #[test]
+    fn multipart_form() {
+        let mut form = MultipartForm::new("boundary".to_string());
+        form.text("name", "project");
+        form.file("content", "project-0.1.0.tar.gz", b"contents");
+        assert_eq!(
+            form.content_type(),
+            "multipart/form-data; boundary=boundary"
+        );
+        assert_eq!(
+            String::from_utf8(form.finish()).unwrap(),
+            "--boundary\r\nContent-Disposition: form-data; name=\"name\"\r\n\r\nproject\r\n--boundary\r\nContent-Disposition: form-data; name=\"content\"; filename=\"project-0.1.0.tar.gz\"\r\nContent-Type: application/octet-stream\r\n\r\ncontents\r\n--boundary--\r\n"
+        );
+    }
+
+    #[test]
+    fn upload_url_strips_simple() {
+        let url = Url::parse("https://example.com/pypi/simple/").unwrap();
+        assert_eq!(upload_url(&url).as_str(), "https://example.com/pypi");
+
+        let url = Url::parse("https://example.com/pypi/simple").unwrap();
+        assert_eq!(upload_url(&url).as_str(), "https://example.com/pypi");
+
+        let url = Url::parse("https://example.com/pypi/").unwrap();
+        assert_eq!(upload_url(&url).as_str(), "https://example.com/pypi/");
+    }
+
+    #[test]
+    fn index_url_from_upload_url() {
+        let url = Url::parse("https://upload.pypi.org/legacy/").unwrap();
+        assert_eq!(index_url(&url).as_str(), "https://pypi.org/simple/");
+
+        let url = Url::parse("https://test.pypi.org/legacy/").unwrap();
+        assert_eq!(index_url(&url).as_str(), "https://test.pypi.org/simple/");
+
+        let url = Url::parse("https://example.com/pypi").unwrap();
+        assert_eq!(index_url(&url).as_str(), "https://example.com/pypi/simple/");
+    }
+
+    #[test]
+    fn core_metadata() {
+        let metadata = CoreMetadata::parse(
+            b"Metadata-Version: 2.1\nName: project\nVersion: 0.1.0\nSummary: A project\nRequires-Python: >=3.8\nRequires-Dist: anyio\nRequires-Dist: iniconfig; extra == 'test'\nProvides-Extra: test\nDescription-Content-Type: text/markdown\n\n# Project\n",
+        )
+        .unwrap();
+        assert_eq!(metadata.metadata_version, "2.1");
+        assert_eq!(
+            metadata.fields,
+            vec![
+                ("summary", "A project".to_string()),
+                ("description_content_type", "text/markdown".to_string()),
+                ("requires_dist", "anyio".to_string()),
+                ("requires_dist", "iniconfig; extra == 'test'".to_string()),
+                ("requires_python", ">=3.8".to_string()),
+                ("provides_extra", "test".to_string()),
+            ]
+        );
+        assert_eq!(metadata.description.as_deref(), Some("# Project\n"));
+
+        assert!(CoreMetadata::parse(b"Name: project\nVersion: 0.1.0\n").is_err());
+    }
+
+    #[test]
+    fn existing_distribution() {
+        assert!(is_existing(409, ""));
+        assert!(is_existing(
+            400,
+            "400 File already exists. See https://pypi.org/help/#file-name-reuse"
+        ));
+        assert!(!is_existing(400, "400 Invalid value for version"));
+        assert!(!is_existing(500, "already exists"));
+    }
+}
@@ -1,12 +1,22 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
 use anyhow::{Context, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
 
 use pep508_rs::PackageName;
 use uv_cache::Cache;
 use uv_client::Connectivity;
-use uv_configuration::{Concurrency, ExtrasSpecification, PreviewMode};
+use uv_configuration::{
+    Concurrency, DevSpecification, EditableMode, ExtrasSpecification, InstallOptions, PreviewMode,
+};
+use uv_distribution::DEV_DEPENDENCIES;
+use uv_fs::Simplified;
 use uv_python::{PythonFetch, PythonPreference, PythonRequest};
+use uv_scripts::Pep723Script;
 use uv_warnings::{warn_user, warn_user_once};
-use uv_workspace::pyproject::DependencyType;
+use uv_workspace::pyproject::{DependencyType, PyProjectToml};
 use uv_workspace::pyproject_mut::PyProjectTomlMut;
 use uv_workspace::{ProjectWorkspace, VirtualProject, Workspace};
 
@@ -19,13 +29,17 @@ use crate::settings::ResolverInstallerSettings;
 pub(crate) async fn remove(
     locked: bool,
     frozen: bool,
+    no_sync: bool,
     requirements: Vec<PackageName>,
     dependency_type: DependencyType,
+    keep_empty: bool,
     package: Option<PackageName>,
     python: Option<String>,
+    script: Option<PathBuf>,
     settings: ResolverInstallerSettings,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     preview: PreviewMode,
     connectivity: Connectivity,
     concurrency: Concurrency,
@@ -37,39 +51,62 @@ pub(crate) async fn remove(
         warn_user_once!("`uv remove` is experimental and may change without warning");
     }
 
-    // Find the project in the workspace.
-    let project = if let Some(package) = package {
-        Workspace::discover(&std::env::current_dir()?, None)
-            .await?
-            .with_current_project(package.clone())
-            .with_context(|| format!("Package `{package}` not found in workspace"))?
+    let target = if let Some(script) = script {
+        // Read the inline metadata, refusing to edit a malformed block.
+        let metadata = Pep723Script::read(&script).await.with_context(|| {
+            format!(
+                "Failed to read inline script metadata from: `{}`",
+                script.user_display()
+            )
+        })?;
+        let Some(script) = metadata else {
+            anyhow::bail!(
+                "`{}` does not contain inline script metadata",
+                script.user_display()
+            );
+        };
+        Target::Script(script)
     } else {
-        ProjectWorkspace::discover(&std::env::current_dir()?, None).await?
+        // Find the project in the workspace.
+        let project = if let Some(package) = package {
+            Workspace::discover(&std::env::current_dir()?, None)
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?
+        } else {
+            ProjectWorkspace::discover(&std::env::current_dir()?, None).await?
+        };
+        Target::Project(project)
     };
 
-    let mut pyproject = PyProjectTomlMut::from_toml(project.current_project().pyproject_toml())?;
-    for req in requirements {
+    let mut pyproject = match &target {
+        Target::Script(script) => PyProjectTomlMut::from_script(&script.raw)?,
+        Target::Project(project) => {
+            PyProjectTomlMut::from_toml(project.current_project().pyproject_toml())?
+        }
+    };
+    for req in &requirements {
         match dependency_type {
             DependencyType::Production => {
-                let deps = pyproject.remove_dependency(&req)?;
+                let deps = pyproject.remove_dependency(req)?;
                 if deps.is_empty() {
-                    warn_if_present(&req, &pyproject);
+                    warn_if_present(req, &pyproject);
                     anyhow::bail!("The dependency `{req}` could not be found in `dependencies`");
                 }
             }
             DependencyType::Dev => {
-                let deps = pyproject.remove_dev_dependency(&req)?;
+                let deps = pyproject.remove_dev_dependency(req)?;
                 if deps.is_empty() {
-                    warn_if_present(&req, &pyproject);
+                    warn_if_present(req, &pyproject);
                     anyhow::bail!(
                         "The dependency `{req}` could not be found in `dev-dependencies`"
                     );
                 }
             }
             DependencyType::Optional(ref group) => {
-                let deps = pyproject.remove_optional_dependency(&req, group)?;
+                let deps = pyproject.remove_optional_dependency(req, group)?;
                 if deps.is_empty() {
-                    warn_if_present(&req, &pyproject);
+                    warn_if_present(req, &pyproject);
                     anyhow::bail!(
                         "The dependency `{req}` could not be found in `optional-dependencies`"
                     );
@@ -78,11 +115,36 @@ pub(crate) async fn remove(
         }
     }
 
-    // Save the modified `pyproject.toml`.
-    fs_err::write(
-        project.current_project().root().join("pyproject.toml"),
-        pyproject.to_string(),
-    )?;
+    // Drop the optional dependency group if it no longer contains any dependencies.
+    if let DependencyType::Optional(ref group) = dependency_type {
+        if !keep_empty {
+            pyproject.remove_empty_optional_group(group)?;
+        }
+    }
+
+    let project = match target {
+        Target::Script(script) => {
+            // Scripts aren't locked or synced, so there's nothing left to do.
+            script.write(&pyproject.to_string()).await?;
+            writeln!(
+                printer.stderr(),
+                "Updated `{}`",
+                script.path.user_display().cyan()
+            )?;
+            return Ok(ExitStatus::Success);
+        }
+        Target::Project(project) => project,
+    };
+
+    // Save the modified `pyproject.toml`, retaining the original contents.
+    let pyproject_path = project.current_project().root().join("pyproject.toml");
+    let existing = fs_err::read_to_string(&pyproject_path)?;
+    fs_err::write(&pyproject_path, pyproject.to_string())?;
+
+    // Update the project to reflect the modified `pyproject.toml`.
+    let project = project
+        .with_pyproject_toml(PyProjectToml::from_string(pyproject.to_string())?)
+        .context("The `pyproject.toml` is missing a `[project]` table")?;
 
     // If `--frozen`, exit early. There's no reason to lock and sync, and we don't need a `uv.lock`
     // to exist at all.
@@ -94,10 +156,15 @@ pub(crate) async fn remove(
     let venv = project::get_or_init_environment(
         project.workspace(),
         python.as_deref().map(PythonRequest::parse),
+        false,
+        None,
+        false,
         python_preference,
         python_fetch,
+        python_install_mirror,
         connectivity,
         native_tls,
+        false,
         cache,
         printer,
     )
@@ -107,10 +174,13 @@ pub(crate) async fn remove(
     let state = SharedState::default();
 
     // Lock and sync the environment, if necessary.
-    let lock = project::lock::do_safe_lock(
+    let lock = match project::lock::do_safe_lock(
         locked,
         frozen,
+        false,
         project.workspace(),
+        None,
+        None,
         venv.interpreter(),
         settings.as_ref().into(),
         &state,
@@ -121,20 +191,56 @@ pub(crate) async fn remove(
         cache,
         printer,
     )
-    .await?;
+    .await
+    {
+        Ok(lock) => lock,
+        Err(err) => {
+            // If locking fails (e.g., under `--locked`, if the lockfile would change), restore the
+            // original `pyproject.toml`, rather than leaving it out-of-sync with the lockfile.
+            fs_err::write(&pyproject_path, existing)?;
+            return Err(err.into());
+        }
+    };
+
+    // If a removed package is still required elsewhere (e.g., by another workspace member, or
+    // transitively by another dependency), it's retained in the lockfile; explain why.
+    for req in &requirements {
+        let dependents = lock.dependents(req);
+        if !dependents.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "Kept `{}` in the lockfile, as it's still required by: {}",
+                req.cyan(),
+                dependents
+                    .iter()
+                    .map(|name| format!("`{}`", name.cyan()))
+                    .join(", ")
+            )?;
+        }
+    }
+
+    // If `--no-sync`, exit early, leaving the environment as-is.
+    if no_sync {
+        return Ok(ExitStatus::Success);
+    }
 
     // Perform a full sync, because we don't know what exactly is affected by the removal.
     // TODO(ibraheem): Should we accept CLI overrides for this? Should we even sync here?
     let extras = ExtrasSpecification::All;
-    let dev = true;
+    let dev = DevSpecification::include(vec![DEV_DEPENDENCIES.clone()]);
 
     project::sync::do_sync(
         &VirtualProject::Project(project),
         &venv,
         &lock,
         extras,
-        dev,
+        &dev,
+        EditableMode::default(),
+        &InstallOptions::default(),
         Modifications::Exact,
+        false,
+        false,
+        false,
         settings.as_ref().into(),
         &state,
         preview,
@@ -170,3 +276,9 @@ fn warn_if_present(name: &PackageName, pyproject: &PyProjectTomlMut) {
         }
     }
 }
+
+/// The target of `uv remove`: either a project, or a PEP 723 script.
+enum Target {
+    Script(Pep723Script),
+    Project(ProjectWorkspace),
+}
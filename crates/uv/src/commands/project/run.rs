@@ -1,9 +1,9 @@
 use std::borrow::Cow;
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tokio::process::Command;
@@ -13,7 +13,9 @@ use pypi_types::Requirement;
 use uv_cache::Cache;
 use uv_cli::ExternalCommand;
 use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::{Concurrency, ExtrasSpecification, PreviewMode};
+use uv_configuration::{
+    Concurrency, DevSpecification, EditableMode, ExtrasSpecification, InstallOptions, PreviewMode,
+};
 use uv_fs::Simplified;
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
@@ -40,15 +42,21 @@ pub(crate) async fn run(
     requirements: Vec<RequirementsSource>,
     locked: bool,
     frozen: bool,
+    lockfile: Option<PathBuf>,
     package: Option<PackageName>,
     extras: ExtrasSpecification,
-    dev: bool,
+    dev: DevSpecification,
+    modifications: Modifications,
+    dry_run: bool,
     python: Option<String>,
+    active: bool,
+    unbuffered: bool,
     settings: ResolverInstallerSettings,
     isolated: bool,
     preview: PreviewMode,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
@@ -62,6 +70,24 @@ pub(crate) async fn run(
     // Parse the input command.
     let command = RunCommand::from(command);
 
+    // If the target is a file that doesn't exist, fail early, rather than attempting to spawn it.
+    if let Some(path) = command.missing_file() {
+        bail!("File not found: `{}`", path.user_display());
+    }
+
+    // Read the Python version requested by the script's shebang, if any (e.g., `3.11` from
+    // `#!/usr/bin/env python3.11`).
+    let shebang_request = if let RunCommand::Python(target, _) = &command {
+        uv_scripts::read_python_shebang(target)?
+            .and_then(|shebang| shebang.version)
+            .map(|version| {
+                debug!("Found Python request in shebang: {version}");
+                PythonRequest::parse(&version)
+            })
+    } else {
+        None
+    };
+
     // Initialize any shared state.
     let state = SharedState::default();
 
@@ -82,11 +108,14 @@ pub(crate) async fn run(
                 // (2) Request from `.python-version`
             } else if let Some(request) = request_from_version_file().await? {
                 Some(request)
-                // (3) `Requires-Python` in `pyproject.toml`
+                // (3) `Requires-Python` in the script metadata
+            } else if let Some(requires_python) = metadata.requires_python {
+                Some(PythonRequest::Version(VersionRequest::Range(
+                    requires_python,
+                )))
+                // (4) The interpreter requested by the script's shebang
             } else {
-                metadata.requires_python.map(|requires_python| {
-                    PythonRequest::Version(VersionRequest::Range(requires_python))
-                })
+                shebang_request.clone()
             };
 
             let client_builder = BaseClientBuilder::new()
@@ -98,6 +127,7 @@ pub(crate) async fn run(
                 EnvironmentPreference::Any,
                 python_preference,
                 python_fetch,
+                python_install_mirror,
                 &client_builder,
                 cache,
                 Some(&reporter),
@@ -159,6 +189,11 @@ pub(crate) async fn run(
         };
 
         let interpreter = if let Some(project) = project {
+            // Within a project, the project's `requires-python` takes precedence over the shebang.
+            if python.is_none() && shebang_request.is_some() {
+                debug!("Ignoring shebang Python request in favor of the project interpreter");
+            }
+
             if let Some(project_name) = project.project_name() {
                 debug!(
                     "Discovered project `{project_name}` at: {}",
@@ -174,10 +209,15 @@ pub(crate) async fn run(
             let venv = project::get_or_init_environment(
                 project.workspace(),
                 python.as_deref().map(PythonRequest::parse),
+                active,
+                None,
+                false,
                 python_preference,
                 python_fetch,
+                python_install_mirror,
                 connectivity,
                 native_tls,
+                dry_run,
                 cache,
                 printer,
             )
@@ -186,7 +226,10 @@ pub(crate) async fn run(
             let lock = match project::lock::do_safe_lock(
                 locked,
                 frozen,
+                dry_run,
                 project.workspace(),
+                lockfile.as_deref(),
+                None,
                 venv.interpreter(),
                 settings.as_ref().into(),
                 &state,
@@ -215,8 +258,13 @@ pub(crate) async fn run(
                 &venv,
                 &lock,
                 extras,
-                dev,
-                Modifications::Sufficient,
+                &dev,
+                EditableMode::default(),
+                &InstallOptions::default(),
+                modifications,
+                false,
+                false,
+                dry_run,
                 settings.as_ref().into(),
                 &state,
                 preview,
@@ -228,7 +276,7 @@ pub(crate) async fn run(
             )
             .await?;
 
-            venv.into_interpreter()
+            venv.into_environment().into_interpreter()
         } else {
             debug!("No project found; searching for Python interpreter");
 
@@ -237,11 +285,15 @@ pub(crate) async fn run(
                 .native_tls(native_tls);
 
             let python = PythonInstallation::find_or_fetch(
-                python.as_deref().map(PythonRequest::parse),
+                python
+                    .as_deref()
+                    .map(PythonRequest::parse)
+                    .or_else(|| shebang_request.clone()),
                 // No opt-in is required for system environments, since we are not mutating it.
                 EnvironmentPreference::Any,
                 python_preference,
                 python_fetch,
+                python_install_mirror,
                 &client_builder,
                 cache,
                 Some(&reporter),
@@ -262,6 +314,11 @@ pub(crate) async fn run(
         );
     }
 
+    // In a dry run, exit without running the command.
+    if dry_run {
+        return Ok(ExitStatus::Success);
+    }
+
     // Read the `--with` requirements.
     let spec = if requirements.is_empty() {
         None
@@ -335,10 +392,14 @@ pub(crate) async fn run(
 
             // Note we force preview on during `uv run` for now since the entire interface is in preview
             PythonInstallation::find_or_fetch(
-                python.as_deref().map(PythonRequest::parse),
+                python
+                    .as_deref()
+                    .map(PythonRequest::parse)
+                    .or_else(|| shebang_request.clone()),
                 EnvironmentPreference::Any,
                 python_preference,
                 python_fetch,
+                python_install_mirror,
                 &client_builder,
                 cache,
                 Some(&reporter),
@@ -360,6 +421,7 @@ pub(crate) async fn run(
             uv_virtualenv::Prompt::None,
             false,
             false,
+            false,
         )?;
 
         if requirements.is_empty() {
@@ -377,9 +439,17 @@ pub(crate) async fn run(
 
             // Install the ephemeral requirements.
             Some(
+                // The project's extras and groups are installed into the base environment, so
+                // they don't apply to the ephemeral requirements. Likewise, `--exact` and
+                // `--inexact` only apply to the base environment: the ephemeral environment was
+                // created above, so it has no extraneous packages to retain.
                 project::update_environment(
                     venv,
                     spec,
+                    &ExtrasSpecification::default(),
+                    &DevSpecification::default(),
+                    Modifications::Exact,
+                    false,
                     &settings,
                     &state,
                     preview,
@@ -443,6 +513,10 @@ pub(crate) async fn run(
     )?;
     process.env("PYTHONPATH", new_python_path);
 
+    if unbuffered {
+        disable_output_buffering(&mut process);
+    }
+
     // Spawn and wait for completion
     // Standard input, output, and error streams are all inherited
     // TODO(zanieb): Throw a nicer error message if the command is not found
@@ -454,13 +528,8 @@ pub(crate) async fn run(
     })?;
     let status = handle.wait().await.context("Child process disappeared")?;
 
-    // Exit based on the result of the command
-    // TODO(zanieb): Do we want to exit with the code of the child process? Probably.
-    if status.success() {
-        Ok(ExitStatus::Success)
-    } else {
-        Ok(ExitStatus::Failure)
-    }
+    // Exit with the code of the child process.
+    Ok(ExitStatus::from(status))
 }
 
 #[derive(Debug)]
@@ -474,6 +543,20 @@ enum RunCommand {
 }
 
 impl RunCommand {
+    /// Return the path to the target, if the command refers to a file that does not exist.
+    fn missing_file(&self) -> Option<&Path> {
+        match self {
+            Self::Python(target, _) => (!target.exists()).then_some(target.as_path()),
+            Self::External(executable, _) => {
+                // Only consider targets that look like paths (e.g., `./script`), rather than
+                // commands to be looked up on the `PATH`.
+                let path = Path::new(executable);
+                (path.components().count() > 1 && !path.exists()).then_some(path)
+            }
+            Self::Empty => None,
+        }
+    }
+
     /// Return the name of the target executable.
     fn executable(&self) -> Cow<'_, OsString> {
         match self {
@@ -516,13 +599,8 @@ impl From<ExternalCommand> for RunCommand {
             return Self::Empty;
         };
 
-        let target_path = PathBuf::from(&target);
-        if target_path
-            .extension()
-            .is_some_and(|ext| ext.eq_ignore_ascii_case("py"))
-            && target_path.exists()
-        {
-            Self::Python(target_path, args.to_vec())
+        if is_python_script(target) {
+            Self::Python(PathBuf::from(target), args.to_vec())
         } else {
             Self::External(
                 target.clone(),
@@ -550,3 +628,36 @@ impl From<&RunCommand> for Command {
         }
     }
 }
+
+/// Returns `true` if the target refers to a Python script, rather than a command or package.
+///
+/// A target is considered a Python script if it has a `.py` extension (whether or not it exists),
+/// or if it's an existing file with a Python shebang (e.g., `#!/usr/bin/env python3`).
+pub(crate) fn is_python_script(target: &OsStr) -> bool {
+    let path = Path::new(target);
+    if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("py"))
+    {
+        return true;
+    }
+    path.is_file()
+        && uv_scripts::read_python_shebang(path)
+            .ok()
+            .flatten()
+            .is_some()
+}
+
+/// Disable output buffering in a Python child process, such that its output is delivered line by
+/// line even when the standard streams aren't attached to a terminal (e.g., when piped).
+///
+/// uv never buffers the inherited streams itself; this only affects the child's own buffering.
+pub(crate) fn disable_output_buffering(process: &mut Command) {
+    process.env("PYTHONUNBUFFERED", "1");
+
+    // When the streams aren't attached to a terminal, Python falls back to the locale encoding,
+    // which may not be able to represent the output. Respect any user-provided encoding.
+    if std::env::var_os("PYTHONIOENCODING").is_none() {
+        process.env("PYTHONIOENCODING", "utf-8");
+    }
+}
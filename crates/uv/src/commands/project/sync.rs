@@ -1,23 +1,37 @@
-use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use distribution_types::{Dist, Name, Resolution, ResolvedDist, SourceDist};
+use pep508_rs::{MarkerEnvironment, MarkerTree, Requirement, VerbatimUrl};
 
 use uv_cache::Cache;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, ExtrasSpecification, HashCheckingMode, PreviewMode, SetupPyStrategy,
+    Concurrency, DevSpecification, EditableMode, ExtrasSpecification, HashCheckingMode,
+    InstallOptions, PreviewMode,
 };
 use uv_dispatch::BuildDispatch;
-use uv_distribution::DEV_DEPENDENCIES;
+use uv_distribution::{DistributionDatabase, DEV_DEPENDENCIES};
+use uv_fs::Simplified;
 use uv_installer::SitePackages;
+use uv_normalize::{ExtraName, PackageName};
 use uv_python::{PythonEnvironment, PythonFetch, PythonPreference, PythonRequest};
 use uv_resolver::{FlatIndex, Lock};
-use uv_types::{BuildIsolation, HashStrategy};
-use uv_warnings::warn_user_once;
-use uv_workspace::VirtualProject;
+use uv_types::{BuildContext, BuildIsolation, HashStrategy};
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::{VirtualProject, Workspace, WorkspaceMember};
 
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{Changelog, Modifications};
 use crate::commands::project::lock::do_safe_lock;
 use crate::commands::project::{ProjectError, SharedState};
-use crate::commands::{pip, project, ExitStatus};
+use crate::commands::{pip, project, venv, ExitStatus};
 use crate::printer::Printer;
 use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings};
 
@@ -26,12 +40,25 @@ use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings};
 pub(crate) async fn sync(
     locked: bool,
     frozen: bool,
+    lockfile: Option<PathBuf>,
     extras: ExtrasSpecification,
-    dev: bool,
+    dev: DevSpecification,
+    editable: EditableMode,
+    install_options: InstallOptions,
     modifications: Modifications,
+    strict_markers: bool,
+    require_hashes: bool,
+    dry_run: bool,
+    check: bool,
+    repair: bool,
+    package: Option<PackageName>,
     python: Option<String>,
+    active: bool,
+    prompt: Option<String>,
+    seed: bool,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     settings: ResolverInstallerSettings,
     preview: PreviewMode,
     connectivity: Connectivity,
@@ -44,17 +71,32 @@ pub(crate) async fn sync(
         warn_user_once!("`uv sync` is experimental and may change without warning");
     }
 
-    // Identify the project
-    let project = VirtualProject::discover(&std::env::current_dir()?, None).await?;
+    // Identify the project.
+    let project = if let Some(package) = package {
+        // Select the given workspace member, e.g., from the root of a virtual workspace.
+        VirtualProject::Project(
+            Workspace::discover(&std::env::current_dir()?, None)
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        )
+    } else {
+        VirtualProject::discover(&std::env::current_dir()?, None).await?
+    };
 
     // Discover or create the virtual environment.
     let venv = project::get_or_init_environment(
         project.workspace(),
         python.as_deref().map(PythonRequest::parse),
+        active,
+        prompt.as_deref(),
+        seed,
         python_preference,
         python_fetch,
+        python_install_mirror,
         connectivity,
         native_tls,
+        dry_run,
         cache,
         printer,
     )
@@ -66,7 +108,10 @@ pub(crate) async fn sync(
     let lock = match do_safe_lock(
         locked,
         frozen,
+        dry_run,
         project.workspace(),
+        lockfile.as_deref(),
+        None,
         venv.interpreter(),
         settings.as_ref().into(),
         &state,
@@ -90,14 +135,45 @@ pub(crate) async fn sync(
         Err(err) => return Err(err.into()),
     };
 
+    // Remove any invalid distributions, such that they can be reinstalled.
+    if repair {
+        let site_packages = SitePackages::from_environment(&venv)?;
+        for dist in site_packages.invalid() {
+            if dry_run {
+                writeln!(
+                    printer.stderr(),
+                    "Would remove invalid distribution at: {}",
+                    dist.path().user_display().cyan()
+                )?;
+            } else {
+                dist.remove().with_context(|| {
+                    format!(
+                        "Failed to remove invalid distribution at: {}",
+                        dist.path().user_display()
+                    )
+                })?;
+                writeln!(
+                    printer.stderr(),
+                    "Removed invalid distribution at: {}",
+                    dist.path().user_display().cyan()
+                )?;
+            }
+        }
+    }
+
     // Perform the sync operation.
-    do_sync(
+    let changelog = do_sync(
         &project,
         &venv,
         &lock,
         extras,
-        dev,
+        &dev,
+        editable,
+        &install_options,
         modifications,
+        strict_markers,
+        require_hashes,
+        dry_run,
         settings.as_ref().into(),
         &state,
         preview,
@@ -109,17 +185,30 @@ pub(crate) async fn sync(
     )
     .await?;
 
+    // If requested, fail if the environment is out of sync with the lockfile.
+    if check && !changelog.is_empty() {
+        return Ok(ExitStatus::Failure);
+    }
+
     Ok(ExitStatus::Success)
 }
 
 /// Sync a lockfile with an environment.
+///
+/// In a dry run, the changes that would be made to the environment are reported, but the
+/// environment itself is left untouched.
 pub(super) async fn do_sync(
     project: &VirtualProject,
     venv: &PythonEnvironment,
     lock: &Lock,
     extras: ExtrasSpecification,
-    dev: bool,
+    dev: &DevSpecification,
+    editable: EditableMode,
+    install_options: &InstallOptions,
     modifications: Modifications,
+    strict_markers: bool,
+    require_hashes: bool,
+    dry_run: bool,
     settings: InstallerSettingsRef<'_>,
     state: &SharedState,
     preview: PreviewMode,
@@ -128,13 +217,16 @@ pub(super) async fn do_sync(
     native_tls: bool,
     cache: &Cache,
     printer: Printer,
-) -> Result<(), ProjectError> {
+) -> Result<Changelog, ProjectError> {
     // Extract the project settings.
     let InstallerSettingsRef {
         index_locations,
         index_strategy,
         keyring_provider,
         config_setting,
+        no_build_isolation,
+        no_build_isolation_package,
+        setup_py,
         exclude_newer,
         link_mode,
         compile_bytecode,
@@ -145,6 +237,14 @@ pub(super) async fn do_sync(
     // Validate that the Python version is supported by the lockfile.
     if let Some(requires_python) = lock.requires_python() {
         if !requires_python.contains(venv.interpreter().python_version()) {
+            // If the range was narrowed with `--python-range`, name it as the source.
+            if let Some(python_range) = lock.python_range() {
+                return Err(ProjectError::LockedPythonRangeIncompatibility(
+                    venv.interpreter().python_version().clone(),
+                    requires_python.clone(),
+                    python_range.clone(),
+                ));
+            }
             return Err(ProjectError::LockedPythonIncompatibility(
                 venv.interpreter().python_version().clone(),
                 requires_python.clone(),
@@ -152,18 +252,80 @@ pub(super) async fn do_sync(
         }
     }
 
-    // Include development dependencies, if requested.
-    let dev = if dev {
-        vec![DEV_DEPENDENCIES.clone()]
-    } else {
-        vec![]
-    };
+    // Validate that the current platform is supported by the lockfile.
+    if !lock.python_platforms().is_empty()
+        && !lock.python_platforms().iter().any(|platform| {
+            platform
+                .marker_tree()
+                .evaluate(venv.interpreter().markers(), &[])
+        })
+    {
+        return Err(ProjectError::LockedPlatformIncompatibility(
+            lock.python_platforms().to_vec(),
+        ));
+    }
+
+    // Validate that the requested extras are defined by the project and present in the lockfile.
+    validate_extras(project, lock, &extras)?;
+
+    // Validate that the requested dependency groups are defined by the project.
+    validate_groups(project, dev)?;
 
     let markers = venv.interpreter().markers();
     let tags = venv.interpreter().tags()?;
 
     // Read the lockfile.
-    let resolution = lock.to_resolution(project, markers, tags, &extras, &dev)?;
+    let resolution = lock.to_resolution(project, markers, tags, &extras, dev, editable)?;
+
+    // Ensure that any packages marked for reinstallation are present.
+    project::validate_reinstall(reinstall, &resolution)?;
+
+    // Retain the complete resolution, to check that it covers the current environment below.
+    let locked = resolution.clone();
+
+    // Omit any packages that were excluded from installation (e.g., via `--no-install-project`).
+    let members = project
+        .workspace()
+        .packages()
+        .keys()
+        .cloned()
+        .collect::<BTreeSet<_>>();
+    let resolution = resolution.filter(|package| {
+        install_options.include_package(package, project.project_name(), &members)
+    });
+
+    // Omit any workspace members that aren't packages (i.e., `tool.uv.package = false`), while
+    // retaining their dependencies. A member that's required by another installed package (via the
+    // selected extras and dependency groups) can't be omitted, since the dependent would be
+    // installed without it.
+    let includes = |name: &PackageName| resolution.packages().any(|package| package == name);
+    let non_packages = project
+        .workspace()
+        .packages()
+        .iter()
+        .filter(|(_, member)| !member.pyproject_toml().is_package())
+        .map(|(name, _)| name)
+        .filter(|name| includes(name))
+        .collect::<Vec<_>>();
+    for name in &non_packages {
+        if let Some(dependent) = lock
+            .installed_dependents(project, markers, &extras, dev, name)
+            .into_iter()
+            .find(|dependent| !non_packages.contains(dependent) && includes(dependent))
+        {
+            return Err(ProjectError::NonPackageDependency(
+                (*name).clone(),
+                dependent.clone(),
+            ));
+        }
+    }
+    let resolution = resolution.filter(|package| {
+        if !non_packages.contains(&package) {
+            return true;
+        }
+        debug!("Omitting `{package}` from resolution due to `tool.uv.package = false`");
+        false
+    });
 
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
@@ -176,11 +338,35 @@ pub(super) async fn do_sync(
         .platform(venv.interpreter().platform())
         .build();
 
-    // TODO(charlie): These are all default values. We should consider whether we want to make them
-    // optional on the downstream APIs.
-    let build_isolation = BuildIsolation::default();
-    let dry_run = false;
-    let setup_py = SetupPyStrategy::default();
+    // Determine whether to enable build isolation.
+    let build_isolation = if no_build_isolation {
+        BuildIsolation::Shared(venv)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(venv, no_build_isolation_package)
+    };
+
+    // With `--require-hashes`, every distribution must have a locked hash, except for local
+    // directories (including editables), which can't be hashed.
+    if require_hashes {
+        for dist in resolution.distributions() {
+            if is_local_directory(dist) {
+                writeln!(
+                    printer.stderr(),
+                    "Skipping hash verification for local directory: {}",
+                    dist.name().cyan()
+                )?;
+            } else if resolution.get_hashes(dist.name()).is_empty() {
+                return Err(ProjectError::HashStrategy(
+                    uv_types::HashStrategyError::MissingHashes(
+                        dist.name().to_string(),
+                        HashCheckingMode::Require,
+                    ),
+                ));
+            }
+        }
+    }
 
     // Extract the hashes from the lockfile.
     let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
@@ -213,10 +399,48 @@ pub(super) async fn do_sync(
         preview,
     );
 
+    // Ensure that the lockfile covers every requirement that applies to the current environment.
+    let database =
+        DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads, preview);
+    let gaps = find_marker_gaps(project, markers, &extras, dev, &locked, &database, &hasher).await;
+    if !gaps.is_empty() {
+        if strict_markers {
+            return Err(ProjectError::MarkerMismatch(gaps));
+        }
+        for gap in gaps {
+            warn_user!(
+                "The lockfile does not include {gap} in the current environment; use `--strict-markers` to fail instead"
+            );
+        }
+    }
+
     let site_packages = SitePackages::from_environment(venv)?;
 
+    // If the environment was seeded, ensure that the seed packages are present and retain them
+    // during the sync.
+    let (resolution, site_packages) = if venv.cfg().is_ok_and(|cfg| cfg.is_seeded()) {
+        let missing = venv::seed_requirements(venv.interpreter())
+            .into_iter()
+            .filter(|requirement| {
+                resolution.get_remote(&requirement.name).is_none()
+                    && site_packages.get_packages(&requirement.name).is_empty()
+            })
+            .collect::<Vec<_>>();
+        let site_packages = if missing.is_empty() || dry_run {
+            site_packages
+        } else {
+            let seed = build_dispatch.resolve(&missing).await?;
+            let installed = build_dispatch.install(&seed, venv).await?;
+            pip::operations::report_modifications(installed, vec![], vec![], printer)?;
+            SitePackages::from_environment(venv)?
+        };
+        (retain_seed(resolution, &site_packages, venv), site_packages)
+    } else {
+        (resolution, site_packages)
+    };
+
     // Sync the environment.
-    pip::operations::install(
+    let changelog = pip::operations::install(
         &resolution,
         site_packages,
         modifications,
@@ -237,7 +461,337 @@ pub(super) async fn do_sync(
         printer,
         preview,
     )
-    .await?;
+    .await
+    .map_err(|err| ProjectError::from_install(err, project.workspace()))?;
+
+    Ok(changelog)
+}
+
+/// Add any installed seed packages to the [`Resolution`], such that they're retained by the sync.
+fn retain_seed(
+    resolution: Resolution,
+    site_packages: &SitePackages,
+    venv: &PythonEnvironment,
+) -> Resolution {
+    let mut packages = resolution
+        .packages()
+        .cloned()
+        .zip(resolution.distributions().cloned())
+        .collect::<BTreeMap<_, _>>();
+    let hashes = packages
+        .keys()
+        .filter(|name| !resolution.get_hashes(name).is_empty())
+        .map(|name| (name.clone(), resolution.get_hashes(name).to_vec()))
+        .collect::<BTreeMap<_, _>>();
+    for requirement in venv::seed_requirements(venv.interpreter()) {
+        if packages.contains_key(&requirement.name) {
+            continue;
+        }
+        if let [dist] = site_packages.get_packages(&requirement.name).as_slice() {
+            packages.insert(requirement.name, ResolvedDist::Installed((*dist).clone()));
+        }
+    }
+    Resolution::new(packages, hashes, resolution.diagnostics().to_vec())
+}
+
+/// Returns `true` if the distribution is built from a local directory (e.g., a workspace member
+/// or an editable), for which there's no archive to hash.
+fn is_local_directory(dist: &ResolvedDist) -> bool {
+    matches!(
+        dist,
+        ResolvedDist::Installable(Dist::Source(SourceDist::Directory(_)))
+    )
+}
+
+/// Validate that the requested extras are defined by the project's workspace members, and that
+/// the lockfile includes a resolution for each of them.
+fn validate_extras(
+    project: &VirtualProject,
+    lock: &Lock,
+    extras: &ExtrasSpecification,
+) -> Result<(), ProjectError> {
+    let ExtrasSpecification::Some(extras) = extras else {
+        return Ok(());
+    };
+
+    for extra in extras {
+        let mut defined = false;
+        for name in project.packages() {
+            let member = &project.workspace().packages()[name];
+            let Some(dependencies) = member
+                .project()
+                .optional_dependencies
+                .as_ref()
+                .and_then(|optional_dependencies| optional_dependencies.get(extra))
+            else {
+                continue;
+            };
+            defined = true;
+
+            // Extras without any dependencies are omitted from the lockfile.
+            if dependencies.is_empty() {
+                continue;
+            }
+
+            let locked = lock
+                .distributions()
+                .iter()
+                .filter(|dist| dist.name() == name)
+                .any(|dist| dist.provides_extras().any(|locked| locked == extra));
+            if !locked {
+                return Err(ProjectError::LockMissingExtra(extra.clone()));
+            }
+        }
+
+        if !defined {
+            let available = project
+                .packages()
+                .filter_map(|name| {
+                    project.workspace().packages()[name]
+                        .project()
+                        .optional_dependencies
+                        .as_ref()
+                })
+                .flat_map(BTreeMap::keys)
+                .cloned()
+                .collect::<BTreeSet<ExtraName>>();
+            return Err(ProjectError::MissingExtra(
+                extra.clone(),
+                available.into_iter().collect(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// A package that's required in the current environment, but missing from the lockfile's solution
+/// for it.
+///
+/// When a requirement's marker is satisfied by the current environment, but wasn't satisfied by
+/// any of the environments that the lockfile was resolved for (e.g., a `platform_machine` that
+/// didn't exist when the project was locked), the lockfile omits the package altogether, and
+/// installing from the lockfile would silently skip it and its dependencies.
+#[derive(Debug)]
+pub(crate) struct MarkerGap {
+    /// The missing package.
+    package: PackageName,
+    /// The package that requires the missing package, i.e., a workspace member or one of its
+    /// (transitive) dependencies.
+    dependent: PackageName,
+    /// The marker under which the package is required, if any.
+    marker: Option<MarkerTree>,
+}
+
+impl std::fmt::Display for MarkerGap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(marker) = &self.marker {
+            write!(
+                f,
+                "`{}` (required by `{}` with marker `{marker}`)",
+                self.package, self.dependent
+            )
+        } else {
+            write!(f, "`{}` (required by `{}`)", self.package, self.dependent)
+        }
+    }
+}
+
+/// Find any requirements that apply to the current environment, but are missing from the
+/// [`Resolution`] derived from the lockfile.
+///
+/// Starting from the workspace members, the resolution is walked along the requirements that
+/// apply to the current environment, such that gaps in the transitive dependencies are detected,
+/// too. The requirements of workspace members are read from their `pyproject.toml`; those of any
+/// other package are read from its metadata. Packages whose metadata can't be retrieved are
+/// skipped, as the installation will fail for them anyway.
+async fn find_marker_gaps(
+    project: &VirtualProject,
+    markers: &MarkerEnvironment,
+    extras: &ExtrasSpecification,
+    dev: &DevSpecification,
+    resolution: &Resolution,
+    database: &DistributionDatabase<'_, BuildDispatch<'_>>,
+    hasher: &HashStrategy,
+) -> Vec<MarkerGap> {
+    let locked = resolution.packages().collect::<FxHashSet<_>>();
+
+    let mut gaps = Vec::new();
+    let mut seen = FxHashSet::default();
+    let mut queue = VecDeque::new();
+
+    // Start from the workspace members, along with the requested extras and groups.
+    for name in project.packages() {
+        let member = &project.workspace().packages()[name];
+        let extras = match extras {
+            ExtrasSpecification::None => vec![],
+            ExtrasSpecification::All => member
+                .project()
+                .optional_dependencies
+                .iter()
+                .flat_map(BTreeMap::keys)
+                .cloned()
+                .collect(),
+            ExtrasSpecification::Some(extras) => extras.clone(),
+        };
+        let mut requirements = if dev.only() {
+            vec![]
+        } else {
+            member_requirements(member, &extras)
+        };
+        for group in dev.groups() {
+            let dev_dependencies = (*group == *DEV_DEPENDENCIES)
+                .then(|| {
+                    member
+                        .pyproject_toml()
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.dev_dependencies.as_ref())
+                })
+                .flatten();
+            let dependency_groups = member
+                .pyproject_toml()
+                .dependency_groups
+                .as_ref()
+                .and_then(|groups| groups.get(group));
+            for requirement in dev_dependencies
+                .into_iter()
+                .chain(dependency_groups)
+                .flatten()
+            {
+                requirements.push(Dependency {
+                    name: requirement.name.clone(),
+                    extras: requirement.extras.clone(),
+                    marker: requirement.marker.clone(),
+                });
+            }
+        }
+        seen.insert(name.clone());
+        queue.push_back((name.clone(), requirements, extras));
+    }
+
+    while let Some((dependent, requirements, extras)) = queue.pop_front() {
+        for requirement in requirements {
+            let applies = requirement
+                .marker
+                .as_ref()
+                .map_or(true, |marker| marker.evaluate(markers, &extras));
+            if !applies {
+                continue;
+            }
+            if resolution
+                .packages()
+                .all(|package| *package != requirement.name)
+            {
+                gaps.push(MarkerGap {
+                    package: requirement.name,
+                    dependent: dependent.clone(),
+                    marker: requirement.marker,
+                });
+                continue;
+            }
+            if !seen.insert(requirement.name.clone()) {
+                continue;
+            }
+
+            // Collect the requirements of the dependency itself.
+            let requirements =
+                if let Some(member) = project.workspace().packages().get(&requirement.name) {
+                    member_requirements(member, &requirement.extras)
+                } else if let Some(dist) = resolution.get_remote(&requirement.name) {
+                    match database
+                        .get_or_build_wheel_metadata(dist, hasher.get(dist))
+                        .await
+                    {
+                        Ok(archive) => archive
+                            .metadata
+                            .requires_dist
+                            .into_iter()
+                            .map(|requirement| Dependency {
+                                name: requirement.name,
+                                extras: requirement.extras,
+                                marker: requirement.marker,
+                            })
+                            .collect(),
+                        Err(err) => {
+                            debug!(
+                            "Failed to read metadata for `{}`; skipping its requirements: {err}",
+                            requirement.name
+                        );
+                            continue;
+                        }
+                    }
+                } else {
+                    // The package is already installed (e.g., a seed package).
+                    continue;
+                };
+            queue.push_back((requirement.name, requirements, requirement.extras));
+        }
+    }
+    gaps
+}
+
+/// A requirement considered by [`find_marker_gaps`], regardless of its source.
+struct Dependency {
+    name: PackageName,
+    extras: Vec<ExtraName>,
+    marker: Option<MarkerTree>,
+}
+
+/// Return the requirements of a workspace member, including those of the given extras.
+fn member_requirements(member: &WorkspaceMember, extras: &[ExtraName]) -> Vec<Dependency> {
+    let parse = |requirement: &String| match Requirement::<VerbatimUrl>::from_str(requirement) {
+        Ok(requirement) => Some(requirement),
+        Err(err) => {
+            debug!("Failed to parse requirement `{requirement}`: {err}");
+            None
+        }
+    };
+
+    let mut requirements = member
+        .project()
+        .dependencies
+        .iter()
+        .flatten()
+        .filter_map(parse)
+        .map(|requirement| Dependency {
+            name: requirement.name,
+            extras: requirement.extras,
+            marker: requirement.marker,
+        })
+        .collect::<Vec<_>>();
+    let optional_dependencies = member.project().optional_dependencies.as_ref();
+    for (extra, dependencies) in optional_dependencies.into_iter().flatten() {
+        if extras.contains(extra) {
+            requirements.extend(dependencies.iter().filter_map(parse).map(|requirement| {
+                Dependency {
+                    name: requirement.name,
+                    extras: requirement.extras,
+                    marker: requirement.marker,
+                }
+            }));
+        }
+    }
+    requirements
+}
+
+/// Validate that the requested dependency groups are defined by the project's workspace members.
+fn validate_groups(project: &VirtualProject, dev: &DevSpecification) -> Result<(), ProjectError> {
+    let available = project::dependency_groups(
+        project
+            .packages()
+            .map(|name| &project.workspace().packages()[name]),
+    );
+
+    for group in dev.names() {
+        if !available.contains(group) {
+            return Err(ProjectError::MissingGroup(
+                group.clone(),
+                available.into_iter().collect(),
+            ));
+        }
+    }
 
     Ok(())
 }
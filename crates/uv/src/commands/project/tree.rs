@@ -1,18 +1,22 @@
 use std::fmt::Write;
+use std::path::PathBuf;
 
 use anyhow::Result;
 use indexmap::IndexMap;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 
 use pep508_rs::PackageName;
+use pypi_types::License;
 use uv_cache::Cache;
 use uv_client::Connectivity;
-use uv_configuration::{Concurrency, PreviewMode};
-use uv_python::{PythonFetch, PythonPreference, PythonRequest};
-use uv_warnings::warn_user_once;
+use uv_configuration::{Concurrency, DevSpecification, PreviewMode, TargetTriple};
+use uv_python::{PythonFetch, PythonPreference, PythonRequest, PythonVersion};
+use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::Workspace;
 
-use crate::commands::pip::tree::DisplayDependencyGraph;
+use crate::commands::pip::tree::{write_tree_legend, DisplayDependencyGraph};
 use crate::commands::project::FoundInterpreter;
 use crate::commands::{project, ExitStatus};
 use crate::printer::Printer;
@@ -25,16 +29,22 @@ use super::SharedState;
 pub(crate) async fn tree(
     locked: bool,
     frozen: bool,
-    depth: u8,
+    lockfile: Option<PathBuf>,
+    depth: Option<usize>,
     prune: Vec<PackageName>,
     package: Vec<PackageName>,
     no_dedupe: bool,
     invert: bool,
     show_version_specifiers: bool,
+    dev: DevSpecification,
     python: Option<String>,
+    python_platform: Option<TargetTriple>,
+    python_version: Option<PythonVersion>,
+    licenses: bool,
     settings: ResolverSettings,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     preview: PreviewMode,
     connectivity: Connectivity,
     concurrency: Concurrency,
@@ -53,8 +63,10 @@ pub(crate) async fn tree(
     let interpreter = FoundInterpreter::discover(
         &workspace,
         python.as_deref().map(PythonRequest::parse),
+        false,
         python_preference,
         python_fetch,
+        python_install_mirror,
         connectivity,
         native_tls,
         cache,
@@ -67,7 +79,10 @@ pub(crate) async fn tree(
     let lock = project::lock::do_safe_lock(
         locked,
         frozen,
+        false,
         &workspace,
+        lockfile.as_deref(),
+        None,
         &interpreter,
         settings.as_ref(),
         &SharedState::default(),
@@ -80,37 +95,134 @@ pub(crate) async fn tree(
     )
     .await?;
 
+    // Development dependencies are displayed for the selected workspace members only (i.e., those
+    // given via `--package`, or all members by default), rather than for any member that's
+    // reached transitively, to match the set that would be installed by `uv sync --package`.
+    let roots: Vec<&PackageName> = if package.is_empty() {
+        workspace.packages().keys().collect()
+    } else {
+        package
+            .iter()
+            .filter(|name| workspace.packages().contains_key(*name))
+            .collect()
+    };
+
     // Read packages from the lockfile.
     let mut packages: IndexMap<_, Vec<_>> = IndexMap::new();
     for dist in lock.into_distributions() {
         let name = dist.name().clone();
-        let metadata = dist.to_metadata(workspace.install_path())?;
+        let mut metadata = dist.to_metadata(workspace.install_path())?;
+        if roots.contains(&&name) {
+            let dev_dependencies = std::mem::take(&mut metadata.dev_dependencies);
+            let requirements = dev
+                .groups()
+                .flat_map(|group| dev_dependencies.get(group).into_iter().flatten())
+                .cloned();
+            if dev.only() {
+                metadata.requires_dist = requirements.collect();
+            } else {
+                metadata.requires_dist.extend(requirements);
+            }
+        }
         packages.entry(name).or_default().push(metadata);
     }
 
+    // Omit any packages that are only reachable through omitted development dependencies.
+    let mut reachable = FxHashSet::default();
+    let mut queue: Vec<&PackageName> = workspace.packages().keys().collect();
+    while let Some(name) = queue.pop() {
+        if !reachable.insert(name.clone()) {
+            continue;
+        }
+        for metadata in packages.get(name).into_iter().flatten() {
+            queue.extend(
+                metadata
+                    .requires_dist
+                    .iter()
+                    .map(|requirement| &requirement.name),
+            );
+        }
+    }
+    packages.retain(|name, _| reachable.contains(name));
+
+    // Determine the markers to use for evaluation. If a platform or Python version was provided,
+    // simulate the target environment, rather than using the current interpreter.
+    let markers = (python_platform.is_some() || python_version.is_some()).then(|| {
+        let mut markers = interpreter.markers().clone();
+        if let Some(python_platform) = python_platform {
+            markers = python_platform.markers(&markers);
+        }
+        if let Some(python_version) = python_version {
+            markers = python_version.markers(&markers);
+        }
+        markers
+    });
+
+    // Read the licenses from the installed packages, if requested.
+    let licenses = if licenses {
+        let licenses = project::installed_licenses(&workspace, cache)?;
+        if licenses.is_none() {
+            warn_user!(
+                "No project environment found; run `uv sync` to install the packages and read their licenses"
+            );
+        }
+        Some(licenses.unwrap_or_default())
+    } else {
+        None
+    };
+
+    // Identify any packages for which no license could be detected, to report them below.
+    let unlicensed = licenses
+        .as_ref()
+        .map(|licenses| {
+            packages
+                .keys()
+                .filter(|name| licenses.get(*name).map_or(true, License::is_unknown))
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
     // Render the tree.
-    let rendered_tree = DisplayDependencyGraph::new(
-        depth.into(),
+    let mut graph = DisplayDependencyGraph::new(
+        depth,
         prune,
         package,
         no_dedupe,
         invert,
         show_version_specifiers,
-        interpreter.markers(),
+        markers.as_ref().unwrap_or(interpreter.markers()),
         packages,
-    )
-    .render()
-    .join("\n");
+    );
+    if let Some(licenses) = licenses {
+        graph = graph.with_licenses(licenses);
+    }
+    let rendered_tree = graph.render().join("\n");
+
+    // Label the tree with the simulated environment, to avoid mistaking it for the local state.
+    if let Some(markers) = markers.as_ref() {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            format!(
+                "Simulated environment: {} ({}), Python {}",
+                markers.sys_platform(),
+                markers.platform_machine(),
+                markers.python_full_version().string
+            )
+            .italic()
+        )?;
+    }
 
     writeln!(printer.stdout(), "{rendered_tree}")?;
 
-    if rendered_tree.contains('*') {
-        let message = if no_dedupe {
-            "(*) Package tree is a cycle and cannot be shown".italic()
-        } else {
-            "(*) Package tree already displayed".italic()
-        };
-        writeln!(printer.stdout(), "{message}")?;
+    write_tree_legend(&rendered_tree, printer)?;
+
+    if !unlicensed.is_empty() {
+        warn_user!(
+            "No license detected for: {}",
+            unlicensed.iter().map(|name| format!("`{name}`")).join(", ")
+        );
     }
 
     Ok(ExitStatus::Success)
@@ -27,6 +27,7 @@ use crate::printer::Printer;
 pub(crate) async fn install(
     targets: Vec<String>,
     reinstall: bool,
+    python_install_mirror: &[String],
     native_tls: bool,
     connectivity: Connectivity,
     preview: PreviewMode,
@@ -150,7 +151,12 @@ pub(crate) async fn install(
             (
                 download.key(),
                 download
-                    .fetch(&client, installations_dir, Some(&reporter))
+                    .fetch(
+                        &client,
+                        installations_dir,
+                        python_install_mirror,
+                        Some(&reporter),
+                    )
                     .await,
             )
         });
@@ -157,11 +157,19 @@ fn pep440_version_from_request(request: &PythonRequest) -> Option<pep440_rs::Ver
         }
     };
 
-    if matches!(version_request, uv_python::VersionRequest::Range(_)) {
-        return None;
+    match version_request {
+        uv_python::VersionRequest::Range(_) => return None,
+        uv_python::VersionRequest::FreeThreaded(major, minor) => {
+            return Some(pep440_rs::Version::new([
+                u64::from(*major),
+                u64::from(*minor),
+            ]));
+        }
+        _ => {}
     }
 
-    // SAFETY: converting `VersionRequest` to `Version` is guaranteed to succeed if not a `Range`.
+    // SAFETY: converting `VersionRequest` to `Version` is guaranteed to succeed if not a `Range`
+    // or a free-threaded request.
     Some(pep440_rs::Version::from_str(&version_request.to_string()).unwrap())
 }
 
@@ -1,4 +1,5 @@
 use std::env;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
@@ -443,6 +444,41 @@ impl uv_installer::InstallReporter for InstallReporter {
     }
 }
 
+#[derive(Debug)]
+pub(crate) struct CompileReporter {
+    progress: ProgressBar,
+}
+
+impl From<Printer> for CompileReporter {
+    fn from(printer: Printer) -> Self {
+        let progress = ProgressBar::with_draw_target(None, printer.target());
+        progress.set_style(
+            ProgressStyle::with_template("{bar:20} [{pos}/{len}] {wide_msg:.dim}").unwrap(),
+        );
+        progress.set_message("Compiling bytecode...");
+        Self { progress }
+    }
+}
+
+impl uv_installer::CompileReporter for CompileReporter {
+    fn on_compile_start(&self, files: usize) {
+        self.progress.set_length(files as u64);
+    }
+
+    fn on_compile_progress(&self, path: &Path) {
+        if let Some(file_name) = path.file_name() {
+            self.progress
+                .set_message(file_name.to_string_lossy().into_owned());
+        }
+        self.progress.inc(1);
+    }
+
+    fn on_compile_complete(&self) {
+        self.progress.set_message("");
+        self.progress.finish_and_clear();
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct PythonDownloadReporter {
     reporter: ProgressReporter,
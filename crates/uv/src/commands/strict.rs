@@ -0,0 +1,44 @@
+use std::sync::RwLock;
+
+use uv_configuration::StrictCategory;
+
+/// The categories of user-facing warnings that should be treated as errors.
+static STRICT: RwLock<Vec<StrictCategory>> = RwLock::new(Vec::new());
+
+/// Treat user-facing warnings in the given categories as errors.
+pub(crate) fn enable(categories: &[StrictCategory]) {
+    if let Ok(mut strict) = STRICT.write() {
+        strict.extend_from_slice(categories);
+    }
+}
+
+/// Returns an error if warnings in the given category should be treated as errors.
+///
+/// Callers are expected to emit the warning as usual if this returns `Ok`.
+pub(crate) fn check(category: StrictCategory, message: String) -> Result<(), StrictViolation> {
+    let enabled = STRICT.read().is_ok_and(|strict| strict.contains(&category));
+    if enabled {
+        Err(StrictViolation { category, message })
+    } else {
+        Ok(())
+    }
+}
+
+/// A user-facing warning that was promoted to an error via `--strict`.
+#[derive(thiserror::Error, Debug)]
+#[error("{message} (`{category}` warnings are treated as errors)")]
+pub(crate) struct StrictViolation {
+    category: StrictCategory,
+    message: String,
+}
+
+impl StrictViolation {
+    /// Returns the exit code for the violation, which is distinct for each category.
+    pub(crate) fn exit_code(&self) -> u8 {
+        match self.category {
+            StrictCategory::Yanked => 3,
+            StrictCategory::MissingInterpreter => 4,
+            StrictCategory::UnhashedUrl => 5,
+        }
+    }
+}
@@ -13,8 +13,12 @@ use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
 
 /// Resolve any [`UnnamedRequirements`].
+///
+/// If `editable` is set, the first requirement is parsed as an editable, and so must refer to a
+/// local directory.
 pub(super) async fn resolve_requirements(
     requirements: impl Iterator<Item = &str>,
+    editable: bool,
     interpreter: &Interpreter,
     settings: &ResolverInstallerSettings,
     state: &SharedState,
@@ -28,8 +32,12 @@ pub(super) async fn resolve_requirements(
     // Parse the requirements.
     let requirements = {
         let mut parsed = vec![];
-        for requirement in requirements {
-            parsed.push(RequirementsSpecification::parse_package(requirement)?);
+        for (index, requirement) in requirements.enumerate() {
+            if editable && index == 0 {
+                parsed.push(RequirementsSpecification::parse_editable(requirement)?);
+            } else {
+                parsed.push(RequirementsSpecification::parse_package(requirement)?);
+            }
         }
         parsed
     };
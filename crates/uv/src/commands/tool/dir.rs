@@ -1,10 +1,10 @@
-use anstream::println;
+use anstream::{eprintln, println};
 use anyhow::Context;
 use owo_colors::OwoColorize;
 
 use uv_configuration::PreviewMode;
 use uv_fs::Simplified;
-use uv_tool::{find_executable_directory, InstalledTools};
+use uv_tool::{find_executable_directory, InstalledTools, ToolDirSource};
 use uv_warnings::warn_user_once;
 
 /// Show the tool directory.
@@ -20,6 +20,9 @@ pub(crate) fn dir(bin: bool, preview: PreviewMode) -> anyhow::Result<()> {
         let installed_tools =
             InstalledTools::from_settings().context("Failed to initialize tools settings")?;
         println!("{}", installed_tools.root().simplified_display().cyan());
+        if installed_tools.source() == ToolDirSource::Override {
+            eprintln!("Using tool directory from `{}`", "UV_TOOL_DIR".green());
+        }
     }
 
     Ok(())
@@ -1,6 +1,7 @@
 use std::collections::BTreeSet;
 use std::ffi::OsString;
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use anyhow::{bail, Context, Result};
@@ -9,10 +10,10 @@ use owo_colors::OwoColorize;
 use tracing::{debug, warn};
 
 use distribution_types::Name;
-use pypi_types::Requirement;
+use pypi_types::{Requirement, RequirementSource};
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::{Concurrency, PreviewMode};
+use uv_configuration::{Concurrency, DevSpecification, ExtrasSpecification, PreviewMode};
 #[cfg(unix)]
 use uv_fs::replace_symlink;
 use uv_fs::Simplified;
@@ -24,9 +25,13 @@ use uv_python::{
 };
 use uv_requirements::RequirementsSpecification;
 use uv_shell::Shell;
-use uv_tool::{entrypoint_paths, find_executable_directory, InstalledTools, Tool, ToolEntrypoint};
+use uv_tool::{
+    editable_source_mtime, entrypoint_paths, find_executable_directory, InstalledTools, Tool,
+    ToolEntrypoint,
+};
 use uv_warnings::{warn_user, warn_user_once};
 
+use crate::commands::pip::operations::Modifications;
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::tool::common::resolve_requirements;
 use crate::commands::{
@@ -43,11 +48,15 @@ pub(crate) async fn install(
     from: Option<String>,
     python: Option<String>,
     with: Vec<String>,
+    editable: bool,
     force: bool,
+    no_atomic: bool,
+    post_install_script: Option<PathBuf>,
     settings: ResolverInstallerSettings,
     preview: PreviewMode,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
@@ -73,6 +82,7 @@ pub(crate) async fn install(
         EnvironmentPreference::OnlySystem,
         python_preference,
         python_fetch,
+        python_install_mirror,
         &client_builder,
         cache,
         Some(&reporter),
@@ -83,80 +93,75 @@ pub(crate) async fn install(
     // Initialize any shared state.
     let state = SharedState::default();
 
-    // Resolve the `from` requirement.
-    let from = if let Some(from) = from {
-        // Parse the positional name. If the user provided more than a package name, it's an error
-        // (e.g., `uv install foo==1.0 --from foo`).
+    // Parse the positional name. If the user provided more than a package name alongside `--from`,
+    // it's an error (e.g., `uv install foo==1.0 --from foo`).
+    let package_name = if let Some(from) = from.as_ref() {
         let Ok(package) = PackageName::from_str(&package) else {
             bail!("Package requirement (`{from}`) provided with `--from` conflicts with install request (`{package}`)")
         };
+        Some(package)
+    } else {
+        None
+    };
 
-        let from_requirement = resolve_requirements(
-            std::iter::once(from.as_str()),
-            &interpreter,
-            &settings,
-            &state,
-            preview,
-            connectivity,
-            concurrency,
-            native_tls,
-            cache,
-            printer,
-        )
-        .await?
-        .pop()
-        .unwrap();
+    // An editable tool must be a Python project, i.e., a directory with a `pyproject.toml` or a
+    // `setup.py`.
+    if editable {
+        let source = Path::new(from.as_deref().unwrap_or(package.as_str()));
+        if source.is_dir()
+            && !source.join("pyproject.toml").is_file()
+            && !source.join("setup.py").is_file()
+        {
+            bail!(
+                "Tool directory `{}` does not contain a `pyproject.toml` or `setup.py`",
+                source.user_display()
+            );
+        }
+    }
 
-        // Check if the positional name conflicts with `--from`.
-        if from_requirement.name != package {
+    // Resolve the `from` (or package) and `with` requirements in a single batch. Any repeated
+    // requirements are only resolved (and included) once.
+    let requirements = resolve_requirements(
+        std::iter::once(from.as_deref().unwrap_or(package.as_str()))
+            .chain(with.iter().map(String::as_str)),
+        editable,
+        &interpreter,
+        &settings,
+        &state,
+        preview,
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
+
+    // The `from` requirement is always first.
+    let from = requirements[0].clone();
+
+    // Check if the positional name conflicts with `--from`.
+    if let Some(package) = package_name {
+        if from.name != package {
             // Determine if it's an entirely different package (e.g., `uv install foo --from bar`).
             bail!(
                 "Package name (`{}`) provided with `--from` does not match install request (`{}`)",
-                from_requirement.name,
+                from.name,
                 package
             );
         }
+    }
 
-        from_requirement
-    } else {
-        resolve_requirements(
-            std::iter::once(package.as_str()),
-            &interpreter,
-            &settings,
-            &state,
-            preview,
-            connectivity,
-            concurrency,
-            native_tls,
-            cache,
-            printer,
-        )
-        .await?
-        .pop()
-        .unwrap()
-    };
-
-    // Combine the `from` and `with` requirements.
-    let requirements = {
-        let mut requirements = Vec::with_capacity(1 + with.len());
-        requirements.push(from.clone());
-        requirements.extend(
-            resolve_requirements(
-                with.iter().map(String::as_str),
-                &interpreter,
-                &settings,
-                &state,
-                preview,
-                connectivity,
-                concurrency,
-                native_tls,
-                cache,
-                printer,
-            )
-            .await?,
-        );
-        requirements
-    };
+    // Store the post-install script as an absolute path, such that it can be re-run from any
+    // directory.
+    let post_install_script = post_install_script
+        .map(|path| {
+            if !path.is_file() {
+                bail!("Post-install script not found: `{}`", path.user_display());
+            }
+            Ok(uv_fs::absolutize_path(&path)?.into_owned())
+        })
+        .transpose()?;
 
     let installed_tools = InstalledTools::from_settings()?.init()?;
     let _lock = installed_tools.acquire_lock()?;
@@ -210,13 +215,14 @@ pub(crate) async fn install(
     // If the requested and receipt requirements are the same...
     if existing_environment.is_some() {
         if let Some(tool_receipt) = existing_tool_receipt.as_ref() {
-            let receipt = tool_receipt
-                .requirements()
-                .iter()
-                .cloned()
-                .map(Requirement::from)
-                .collect::<Vec<_>>();
-            if requirements == receipt {
+            let receipt = receipt_requirements(tool_receipt);
+            let script = post_install_script
+                .as_deref()
+                .or_else(|| tool_receipt.post_install_script());
+            // An editable tool whose project metadata changed since it was installed is
+            // reinstalled, e.g., to pick up new dependencies.
+            let stale = !tool_receipt.stale_editable_files()?.is_empty();
+            if requirements == receipt && tool_receipt.post_install_script() == script && !stale {
                 // And the user didn't request a reinstall or upgrade...
                 if !force && settings.reinstall.is_none() && settings.upgrade.is_none() {
                     // We're done.
@@ -227,6 +233,14 @@ pub(crate) async fn install(
         }
     }
 
+    // If no post-install script was provided, re-use the script from the existing receipt.
+    let post_install_script = post_install_script.or_else(|| {
+        existing_tool_receipt
+            .as_ref()
+            .and_then(Tool::post_install_script)
+            .map(Path::to_path_buf)
+    });
+
     // Resolve the requirements.
     let state = SharedState::default();
     let spec = RequirementsSpecification::from_requirements(requirements.clone());
@@ -235,10 +249,15 @@ pub(crate) async fn install(
     // This lets us confirm the environment is valid before removing an existing install. However,
     // entrypoints always contain an absolute path to the relevant Python interpreter, which would
     // be invalidated by moving the environment.
+    let created = existing_environment.is_none();
     let environment = if let Some(environment) = existing_environment {
         update_environment(
             environment,
             spec,
+            &ExtrasSpecification::default(),
+            &DevSpecification::default(),
+            Modifications::Exact,
+            !no_atomic,
             &settings,
             &state,
             preview,
@@ -272,6 +291,8 @@ pub(crate) async fn install(
         sync_environment(
             environment,
             &resolution.into(),
+            Modifications::Exact,
+            !no_atomic,
             settings.as_ref().into(),
             &state,
             preview,
@@ -284,6 +305,17 @@ pub(crate) async fn install(
         .await?
     };
 
+    // Run the post-install script, if any, prior to installing any executables.
+    if let Some(script) = post_install_script.as_deref() {
+        if let Err(err) = run_post_install_script(script, &environment, printer).await {
+            // Clean up the environment, if we just created it.
+            if created {
+                installed_tools.remove_environment(&from.name)?;
+            }
+            return Err(err);
+        }
+    }
+
     let site_packages = SitePackages::from_environment(&environment)?;
     let installed = site_packages.get_packages(&from.name);
     let Some(installed_dist) = installed.first().copied() else {
@@ -393,6 +425,15 @@ pub(crate) async fn install(
             .join(", ")
     )?;
 
+    // For an editable tool, record when its project metadata was last modified, such that later
+    // changes to it can be detected.
+    let source_mtime = match &from.source {
+        RequirementSource::Directory { install_path, .. } if editable => {
+            editable_source_mtime(install_path)?
+        }
+        _ => None,
+    };
+
     debug!("Adding receipt for tool `{}`", from.name);
     let tool = Tool::new(
         requirements
@@ -403,6 +444,9 @@ pub(crate) async fn install(
         target_entry_points
             .into_iter()
             .map(|(name, _, target_path)| ToolEntrypoint::new(name, target_path)),
+        post_install_script,
+        editable,
+        source_mtime,
     );
     installed_tools.add_tool_receipt(&from.name, tool)?;
 
@@ -441,6 +485,63 @@ pub(crate) async fn install(
     Ok(ExitStatus::Success)
 }
 
+/// Returns the requirements recorded in a tool receipt.
+///
+/// The editable flag of the tool's own requirement isn't preserved when the requirement is
+/// serialized, so it's restored from the receipt.
+fn receipt_requirements(tool: &Tool) -> Vec<Requirement> {
+    let mut requirements = tool
+        .requirements()
+        .iter()
+        .cloned()
+        .map(Requirement::from)
+        .collect::<Vec<_>>();
+    if tool.editable() {
+        if let Some(Requirement {
+            source: RequirementSource::Directory { editable, .. },
+            ..
+        }) = requirements.first_mut()
+        {
+            *editable = true;
+        }
+    }
+    requirements
+}
+
+/// Run a post-install script with the interpreter of the tool environment.
+async fn run_post_install_script(
+    script: &Path,
+    environment: &PythonEnvironment,
+    printer: Printer,
+) -> Result<()> {
+    writeln!(
+        printer.stderr(),
+        "Running post-install script: {}",
+        script.user_display().cyan()
+    )?;
+
+    let status = tokio::process::Command::new(environment.python_executable())
+        .arg(script)
+        .env("VIRTUAL_ENV", environment.root().as_os_str())
+        .status()
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to run post-install script: `{}`",
+                script.user_display()
+            )
+        })?;
+
+    if !status.success() {
+        bail!(
+            "Post-install script `{}` failed with {status}",
+            script.user_display()
+        );
+    }
+
+    Ok(())
+}
+
 /// Displays a hint if an executable matching the package name can be found in a dependency of the package.
 fn hint_executable_from_dependency(
     from: &Requirement,
@@ -23,7 +23,7 @@ pub(crate) async fn list(
         warn_user_once!("`uv tool list` is experimental and may change without warning");
     }
 
-    let installed_tools = InstalledTools::from_settings()?;
+    let installed_tools = InstalledTools::from_settings()?.init()?;
     let _lock = match installed_tools.acquire_lock() {
         Ok(lock) => lock,
         Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
@@ -2,6 +2,8 @@ mod common;
 pub(crate) mod dir;
 pub(crate) mod install;
 pub(crate) mod list;
+pub(crate) mod ps;
 pub(crate) mod run;
+pub(crate) mod stop;
 pub(crate) mod uninstall;
 pub(crate) mod update_shell;
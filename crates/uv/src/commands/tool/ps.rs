@@ -0,0 +1,54 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_configuration::PreviewMode;
+use uv_fs::Simplified;
+use uv_tool::ToolRuns;
+use uv_warnings::warn_user_once;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List tools running in the background.
+pub(crate) async fn ps(preview: PreviewMode, printer: Printer) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv tool ps` is experimental and may change without warning");
+    }
+
+    let runs = ToolRuns::from_settings()?.runs()?;
+    if runs.is_empty() {
+        writeln!(printer.stderr(), "No tools running in the background")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for run in runs {
+        let Some(pid) = run.pid()? else {
+            continue;
+        };
+        let status = if run.is_running(pid)? {
+            "running".green().to_string()
+        } else {
+            "exited".red().to_string()
+        };
+        writeln!(
+            printer.stdout(),
+            "{} {} ({status})",
+            pid.to_string().bold(),
+            run.name(),
+        )?;
+        writeln!(
+            printer.stdout(),
+            "- {}",
+            run.stdout().simplified_display().cyan()
+        )?;
+        writeln!(
+            printer.stdout(),
+            "- {}",
+            run.stderr().simplified_display().cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
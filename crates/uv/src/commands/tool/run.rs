@@ -1,38 +1,53 @@
-use std::ffi::OsString;
+use std::collections::{HashMap, HashSet};
+use std::ffi::{OsStr, OsString};
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
 use std::str::FromStr;
+use std::time::Duration;
 use std::{borrow::Cow, fmt::Display};
 
 use anyhow::{bail, Context, Result};
+use console::Term;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use pypi_types::Requirement;
-use tokio::process::Command;
+use tokio::process::{Child, Command};
 use tracing::{debug, warn};
 
 use distribution_types::{Name, UnresolvedRequirementSpecification};
-use pep440_rs::Version;
+use pep440_rs::{Version, VersionSpecifiers};
 use uv_cache::Cache;
 use uv_cli::ExternalCommand;
 use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::{Concurrency, PreviewMode};
+use uv_configuration::{Concurrency, DevSpecification, ExtrasSpecification, PreviewMode};
+use uv_fs::Simplified;
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
 use uv_python::{
     EnvironmentPreference, PythonEnvironment, PythonFetch, PythonInstallation, PythonPreference,
     PythonRequest,
 };
-use uv_tool::{entrypoint_paths, InstalledTools};
+use uv_requirements::RequirementsSource;
+use uv_tool::{entrypoint_paths, InstalledTools, ToolRun, ToolRuns};
 use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject::PyProjectToml;
 
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::environment::CachedEnvironment;
+use crate::commands::project::run::{disable_output_buffering, is_python_script};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::tool::common::resolve_requirements;
-use crate::commands::{project::environment::CachedEnvironment, tool::common::matching_packages};
-use crate::commands::{ExitStatus, SharedState};
+use crate::commands::{self, project, ExitStatus, SharedState};
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
 
+/// The amount of time a detached process must remain running to be considered started.
+const DETACH_STARTUP_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// The amount of time a process is given to exit after `SIGTERM`, before it's killed.
+const TERMINATE_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
 /// The user-facing command used to invoke a tool run.
 pub(crate) enum ToolRunCommand {
     /// via the `uvx` alias
@@ -52,9 +67,19 @@ impl Display for ToolRunCommand {
 
 /// Run a command.
 pub(crate) async fn run(
-    command: ExternalCommand,
+    command: Option<ExternalCommand>,
     from: Option<String>,
     with: Vec<String>,
+    working_dir: Option<PathBuf>,
+    detach: bool,
+    timeout: Option<Duration>,
+    unbuffered: bool,
+    env: Vec<(String, String)>,
+    env_file: Option<PathBuf>,
+    no_inherit_env: bool,
+    propagate_pythonpath: bool,
+    list_entrypoints: bool,
+    yes: bool,
     python: Option<String>,
     settings: ResolverInstallerSettings,
     invocation_source: ToolRunCommand,
@@ -62,6 +87,7 @@ pub(crate) async fn run(
     preview: PreviewMode,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
@@ -72,27 +98,137 @@ pub(crate) async fn run(
         warn_user_once!("`{invocation_source}` is experimental and may change without warning");
     }
 
+    // If requested, list the executables provided by the package, rather than running a command.
+    if list_entrypoints {
+        if command.is_some() {
+            bail!("`--list-entrypoints` cannot be used with a command");
+        }
+        let Some(from) = from else {
+            bail!("`--list-entrypoints` requires `--from`");
+        };
+
+        let (from, environment) = get_or_create_environment(
+            &from,
+            &with,
+            python.as_deref(),
+            &settings,
+            isolated,
+            yes,
+            preview,
+            python_preference,
+            python_fetch,
+            python_install_mirror,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await?;
+
+        for entrypoint in executables(&from.name, &environment)? {
+            writeln!(printer.stdout(), "{entrypoint}")?;
+        }
+
+        return Ok(ExitStatus::Success);
+    }
+
+    let Some(command) = command else {
+        return Err(anyhow::anyhow!("No tool command provided"));
+    };
     let (target, args) = command.split();
     let Some(target) = target else {
         return Err(anyhow::anyhow!("No tool command provided"));
     };
 
+    // If the target is a Python script, execute it in an isolated environment (honoring any
+    // inline script metadata), rather than treating it as a package requirement.
+    if from.is_none() && is_python_script(target) {
+        if working_dir.is_some() {
+            bail!("`--working-dir` is not supported when running a Python script");
+        }
+        if detach {
+            bail!("`--detach` is not supported when running a Python script");
+        }
+        if !env.is_empty() || env_file.is_some() || no_inherit_env {
+            bail!("`--env`, `--env-file`, and `--no-inherit-env` are not supported when running a Python script");
+        }
+
+        debug!("Running Python script: {}", target.to_string_lossy());
+        let requirements = with
+            .into_iter()
+            .map(RequirementsSource::from_package)
+            .collect();
+        return project::run::run(
+            command,
+            requirements,
+            false,
+            false,
+            None,
+            None,
+            ExtrasSpecification::None,
+            DevSpecification::default(),
+            Modifications::Sufficient,
+            false,
+            python,
+            false,
+            unbuffered,
+            settings,
+            true,
+            preview,
+            python_preference,
+            python_fetch,
+            python_install_mirror,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await;
+    }
+
+    // Read the environment file before doing any work, such that malformed files fail fast.
+    let env_file = env_file
+        .as_deref()
+        .map(read_env_file)
+        .transpose()?
+        .unwrap_or_default();
+
+    // Validate the working directory before doing any work.
+    if let Some(working_dir) = working_dir.as_deref() {
+        if !working_dir.exists() {
+            bail!(
+                "Working directory does not exist: `{}`",
+                working_dir.user_display()
+            );
+        }
+        if !working_dir.is_dir() {
+            bail!(
+                "Working directory is not a directory: `{}`",
+                working_dir.user_display()
+            );
+        }
+    }
+
     let (target, from) = if let Some(from) = from {
-        (Cow::Borrowed(target), Cow::Owned(from))
+        (Some(Cow::Borrowed(target)), Cow::Owned(from))
     } else {
         parse_target(target)?
     };
 
     // Get or create a compatible environment in which to execute the tool.
-    let (from, environment) = get_or_create_environment(
+    let (mut from, mut environment) = get_or_create_environment(
         &from,
         &with,
         python.as_deref(),
         &settings,
         isolated,
+        yes,
         preview,
         python_preference,
         python_fetch,
+        python_install_mirror,
         connectivity,
         concurrency,
         native_tls,
@@ -101,34 +237,108 @@ pub(crate) async fn run(
     )
     .await?;
 
-    // TODO(zanieb): Determine the executable command via the package entry points
-    let executable = target;
+    // If the target is a source (e.g., a Git repository), determine the executable from the
+    // package's entry points.
+    let executable = match target {
+        Some(target) => target,
+        None => {
+            let executable = default_executable(&from.name, &environment)?;
+            debug!(
+                "Using executable `{executable}` from package `{}`",
+                from.name
+            );
+            Cow::Owned(OsString::from(executable))
+        }
+    };
+
+    // We check if the provided command is not part of the executables for the `from` package.
+    // If the command is found in other packages, we warn the user about the correct package to use,
+    // or, if a single dependency provides it, offer to run the command from that dependency.
+    if let Some(package) = warn_executable_not_provided_by_package(
+        &executable.to_string_lossy(),
+        &from.name,
+        &environment,
+        &invocation_source,
+        yes,
+    )? {
+        writeln!(
+            printer.stderr(),
+            "Running `{}` from `{}`",
+            executable.to_string_lossy().cyan(),
+            package.cyan()
+        )?;
+        (from, environment) = get_or_create_environment(
+            package.as_ref(),
+            &with,
+            python.as_deref(),
+            &settings,
+            isolated,
+            yes,
+            preview,
+            python_preference,
+            python_fetch,
+            python_install_mirror,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await?;
+    }
 
     // Construct the command
     let mut process = Command::new(executable.as_ref());
     process.args(args);
 
-    // Construct the `PATH` environment variable.
-    let new_path = std::env::join_paths(
-        std::iter::once(environment.scripts().to_path_buf()).chain(
-            std::env::var_os("PATH")
-                .as_ref()
-                .iter()
-                .flat_map(std::env::split_paths),
-        ),
+    // If requested, start from an empty environment, such that only the variables set below are
+    // visible to the command.
+    if no_inherit_env {
+        process.env_clear();
+
+        // Windows requires `SYSTEMROOT` to initialize core facilities (e.g., sockets).
+        #[cfg(windows)]
+        if let Some(system_root) = std::env::var_os("SYSTEMROOT") {
+            process.env("SYSTEMROOT", system_root);
+        }
+    }
+
+    // Run the command in the requested directory, if any.
+    if let Some(working_dir) = working_dir.as_deref() {
+        process.current_dir(working_dir);
+    }
+
+    // Construct the `PATH` environment variable, with the environment's scripts taking precedence.
+    let new_path = prepend_paths(
+        std::iter::once(environment.scripts().to_path_buf()),
+        std::env::var_os("PATH").as_deref(),
     )?;
     process.env("PATH", new_path);
 
-    // Construct the `PYTHONPATH` environment variable.
-    let new_python_path = std::env::join_paths(
-        environment.site_packages().map(PathBuf::from).chain(
-            std::env::var_os("PYTHONPATH")
-                .as_ref()
-                .iter()
-                .flat_map(std::env::split_paths),
-        ),
-    )?;
-    process.env("PYTHONPATH", new_python_path);
+    // If requested, construct the `PYTHONPATH` environment variable, with the environment's site
+    // packages taking precedence. Otherwise, leave `PYTHONPATH` untouched, such that any Python
+    // processes spawned by the tool don't see its packages.
+    //
+    // Python doesn't process `.pth` files in `PYTHONPATH` entries, so the paths they reference
+    // (e.g., the source directories of editable installs) are added explicitly.
+    if propagate_pythonpath {
+        let new_python_path = prepend_paths(
+            environment
+                .site_packages()
+                .flat_map(|site_packages| with_pth_paths(&site_packages)),
+            std::env::var_os("PYTHONPATH").as_deref(),
+        )?;
+        process.env("PYTHONPATH", new_python_path);
+    }
+
+    if unbuffered {
+        disable_output_buffering(&mut process);
+    }
+
+    // Apply any user-provided environment variables, which take precedence over the defaults.
+    for (key, value) in env_file.into_iter().chain(env) {
+        process.env(key, value);
+    }
 
     // Spawn and wait for completion
     // Standard input, output, and error streams are all inherited
@@ -140,16 +350,61 @@ pub(crate) async fn run(
         args.iter().map(|arg| arg.to_string_lossy()).join(" ")
     );
 
-    // We check if the provided command is not part of the executables for the `from` package.
-    // If the command is found in other packages, we warn the user about the correct package to use.
-    warn_executable_not_provided_by_package(
-        &executable.to_string_lossy(),
-        &from.name,
-        &environment,
-        &invocation_source,
-    );
+    // If requested, detach the process from the terminal, redirecting its output to log files.
+    let (spawned, run) = if detach {
+        let run = ToolRuns::from_settings()?
+            .init()?
+            .create(&executable.to_string_lossy())?;
+        process.stdin(Stdio::null());
+        process.stdout(fs_err::File::create(run.stdout())?.into_parts().0);
+        process.stderr(fs_err::File::create(run.stderr())?.into_parts().0);
+        process.kill_on_drop(false);
+
+        // Start the process in a new session, such that it isn't terminated with the terminal, and
+        // let it inherit the run's lock, such that `uv tool stop` can verify its identity.
+        #[cfg(unix)]
+        let lock = {
+            use std::os::fd::AsRawFd;
+
+            use nix::fcntl::{fcntl, FcntlArg, FdFlag};
+
+            let lock = run.lock()?;
+            let fd = lock.file().as_raw_fd();
+            // SAFETY: `setsid` and `fcntl` are async-signal-safe, and don't allocate.
+            #[allow(unsafe_code)]
+            unsafe {
+                process.pre_exec(move || {
+                    nix::unistd::setsid()?;
+                    fcntl(fd, FcntlArg::F_SETFD(FdFlag::empty()))?;
+                    Ok(())
+                });
+            }
+            lock
+        };
+        #[cfg(windows)]
+        {
+            const DETACHED_PROCESS: u32 = 0x0000_0008;
+            const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
+            process.creation_flags(DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP);
+        }
+
+        let spawned = process.spawn();
+
+        // Close our handle to the lock, leaving the process as its only holder.
+        #[cfg(unix)]
+        drop(lock);
 
-    let mut handle = match process.spawn() {
+        if spawned.is_err() {
+            run.remove()?;
+            (spawned, None)
+        } else {
+            (spawned, Some(run))
+        }
+    } else {
+        (process.spawn(), None)
+    };
+
+    let mut handle = match spawned {
         Ok(handle) => Ok(handle),
         Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
             match get_entrypoints(&from.name, &environment) {
@@ -169,7 +424,7 @@ pub(crate) async fn run(
                             writeln!(printer.stdout(), "- {}", name.cyan())?;
                         }
                     }
-                    return Ok(ExitStatus::Failure);
+                    return Ok(ExitStatus::NotFound);
                 }
                 Err(err) => {
                     warn!("Failed to get entrypoints for `{from}`: {err}");
@@ -181,14 +436,96 @@ pub(crate) async fn run(
     }
     .with_context(|| format!("Failed to spawn: `{}`", executable.to_string_lossy()))?;
 
-    let status = handle.wait().await.context("Child process disappeared")?;
+    if let Some(run) = run {
+        return detached(handle, &run, printer).await;
+    }
 
-    // Exit based on the result of the command
-    // TODO(zanieb): Do we want to exit with the code of the child process? Probably.
-    if status.success() {
-        Ok(ExitStatus::Success)
+    let status = if let Some(timeout) = timeout {
+        tokio::select! {
+            status = handle.wait() => status.context("Child process disappeared")?,
+            () = tokio::time::sleep(timeout) => {
+                terminate(&mut handle).await?;
+                writeln!(
+                    printer.stderr(),
+                    "`{}` timed out after {}s",
+                    executable.to_string_lossy().red(),
+                    timeout.as_secs()
+                )?;
+                return Ok(ExitStatus::TimedOut);
+            }
+        }
     } else {
-        Ok(ExitStatus::Failure)
+        handle.wait().await.context("Child process disappeared")?
+    };
+
+    // Exit with the code of the child process.
+    Ok(ExitStatus::from(status))
+}
+
+/// Terminate a child process, and wait for it to exit.
+///
+/// On Unix, the process is sent `SIGTERM`, and killed if it doesn't exit within
+/// [`TERMINATE_GRACE_PERIOD`]. Elsewhere, the process is killed immediately.
+async fn terminate(handle: &mut Child) -> Result<()> {
+    #[cfg(unix)]
+    if let Some(pid) = handle.id().and_then(|pid| i32::try_from(pid).ok()) {
+        use nix::sys::signal::{kill, Signal};
+        use nix::unistd::Pid;
+
+        debug!("Sending SIGTERM to process {pid}");
+        if kill(Pid::from_raw(pid), Signal::SIGTERM).is_ok()
+            && tokio::time::timeout(TERMINATE_GRACE_PERIOD, handle.wait())
+                .await
+                .is_ok()
+        {
+            return Ok(());
+        }
+    }
+
+    debug!("Killing child process");
+    handle
+        .kill()
+        .await
+        .context("Failed to kill child process")?;
+    Ok(())
+}
+
+/// Confirm that a detached process started successfully, and report its process ID.
+///
+/// Processes that exit within [`DETACH_STARTUP_TIMEOUT`] are considered to have failed to start.
+async fn detached(mut handle: Child, run: &ToolRun, printer: Printer) -> Result<ExitStatus> {
+    let Some(pid) = handle.id() else {
+        bail!("Failed to determine the process ID of `{}`", run.name());
+    };
+    run.write_pid(pid)?;
+
+    match tokio::time::timeout(DETACH_STARTUP_TIMEOUT, handle.wait()).await {
+        Ok(status) => {
+            let status = status.context("Child process disappeared")?;
+            writeln!(
+                printer.stderr(),
+                "`{}` exited during startup ({status}); see the log at: {}",
+                run.name().red(),
+                run.stderr().user_display().cyan()
+            )?;
+            Ok(ExitStatus::Failure)
+        }
+        Err(_) => {
+            writeln!(printer.stdout(), "{pid}")?;
+            writeln!(
+                printer.stderr(),
+                "Started `{}` in the background (pid {})",
+                run.name().green(),
+                pid.to_string().cyan()
+            )?;
+            writeln!(
+                printer.stderr(),
+                "Logging output to: {} and {}",
+                run.stdout().user_display().cyan(),
+                run.stderr().user_display().cyan()
+            )?;
+            Ok(ExitStatus::Success)
+        }
     }
 }
 
@@ -211,60 +548,168 @@ fn get_entrypoints(
     )?)
 }
 
+/// Return the names of the executables provided by the specified package, without any platform
+/// suffix, in sorted order.
+fn executables(from: &PackageName, environment: &PythonEnvironment) -> Result<Vec<String>> {
+    Ok(get_entrypoints(from, environment)?
+        .into_iter()
+        .map(
+            |(name, _)| match name.strip_suffix(std::env::consts::EXE_SUFFIX) {
+                Some(stripped) => stripped.to_string(),
+                None => name,
+            },
+        )
+        .sorted()
+        .dedup()
+        .collect())
+}
+
+/// Determine the executable to run for a package installed from a source, like a Git repository,
+/// based on its entry points.
+///
+/// If the package provides multiple entry points, the one matching the package name is preferred.
+fn default_executable(from: &PackageName, environment: &PythonEnvironment) -> Result<String> {
+    let entrypoints = executables(from, environment)?;
+
+    if let Some(entrypoint) = entrypoints
+        .iter()
+        .find(|entrypoint| PackageName::from_str(entrypoint).is_ok_and(|name| name == *from))
+    {
+        return Ok(entrypoint.clone());
+    }
+
+    match entrypoints.as_slice() {
+        [] => bail!("Package `{from}` does not provide any executables"),
+        [entrypoint] => Ok(entrypoint.clone()),
+        _ => bail!(
+            "Package `{from}` provides multiple executables ({}); use `--from` to select one",
+            entrypoints
+                .iter()
+                .map(|entrypoint| format!("`{entrypoint}`"))
+                .join(", ")
+        ),
+    }
+}
+
+/// Return the entry points for all packages installed in the environment, as a map from
+/// executable name to every package that provides it, in sorted order.
+fn get_all_entrypoints(
+    environment: &PythonEnvironment,
+) -> Result<HashMap<String, Vec<PackageName>>> {
+    let site_packages = SitePackages::from_environment(environment)?;
+
+    let mut entrypoints = HashMap::new();
+    for installed_dist in site_packages.iter() {
+        for (name, _) in
+            entrypoint_paths(environment, installed_dist.name(), installed_dist.version())?
+        {
+            let name = match name.strip_suffix(std::env::consts::EXE_SUFFIX) {
+                Some(stripped) => stripped.to_string(),
+                None => name,
+            };
+            entrypoints
+                .entry(name)
+                .or_insert_with(Vec::new)
+                .push(installed_dist.name().clone());
+        }
+    }
+
+    // Sort the providers, such that the result doesn't depend on the order of `site-packages`.
+    for packages in entrypoints.values_mut() {
+        packages.sort_unstable();
+        packages.dedup();
+    }
+
+    Ok(entrypoints)
+}
+
 /// Display a warning if an executable is not provided by package.
 ///
-/// If found in a dependency of the requested package instead of the requested package itself, we will hint to use that instead.
+/// If found in a dependency of the requested package instead of the requested package itself, we
+/// will hint to use that instead. If a single dependency provides the executable and the user
+/// confirms (or passed `--yes`), that dependency is returned, such that the command can be run
+/// from it.
 fn warn_executable_not_provided_by_package(
     executable: &str,
     from_package: &PackageName,
     environment: &PythonEnvironment,
     invocation_source: &ToolRunCommand,
-) {
-    if let Ok(packages) = matching_packages(executable, environment) {
-        if !packages
-            .iter()
-            .any(|package| package.name() == from_package)
-        {
-            match packages.as_slice() {
-                [] => {
-                    warn_user!(
-                        "An executable named `{}` is not provided by package `{}`.",
-                        executable.cyan(),
-                        from_package.red()
-                    );
-                }
-                [package] => {
-                    let suggested_command = format!(
-                        "{invocation_source} --from {} {}",
-                        package.name(),
-                        executable
-                    );
-                    warn_user!(
-                        "An executable named `{}` is not provided by package `{}` but is available via the dependency `{}`. Consider using `{}` instead.",
-                        executable.cyan(),
-                        from_package.cyan(),
-                        package.name().cyan(),
-                        suggested_command.green()
-                    );
-                }
-                packages => {
-                    let suggested_command = format!("{invocation_source} --from PKG {executable}");
-                    let provided_by = packages
-                        .iter()
-                        .map(distribution_types::Name::name)
-                        .map(|name| format!("- {}", name.cyan()))
-                        .join("\n");
-                    warn_user!(
-                        "An executable named `{}` is not provided by package `{}` but is available via the following dependencies:\n- {}\nConsider using `{}` instead.",
-                        executable.cyan(),
-                        from_package.cyan(),
-                        provided_by,
-                        suggested_command.green(),
-                    );
+    yes: bool,
+) -> Result<Option<PackageName>> {
+    let Ok(entrypoints) = get_all_entrypoints(environment) else {
+        return Ok(None);
+    };
+
+    match entrypoints.get(executable).map(Vec::as_slice) {
+        Some(packages) if packages.contains(from_package) => {}
+        Some([package]) => {
+            if yes {
+                return Ok(Some(package.clone()));
+            }
+
+            let term = Term::stderr();
+            if term.is_term() {
+                let prompt = format!(
+                    "An executable named `{executable}` is not provided by package `{from_package}` but is available via the dependency `{package}`. Run it from `{package}`?"
+                );
+                if uv_requirements::confirm(&prompt, &term, true)? {
+                    return Ok(Some(package.clone()));
                 }
             }
+
+            let suggested_command = format!("{invocation_source} --from {package} {executable}");
+            warn_user!(
+                "An executable named `{}` is not provided by package `{}` but is available via the dependency `{}`. Consider using `{}` instead.",
+                executable.cyan(),
+                from_package.cyan(),
+                package.cyan(),
+                suggested_command.green()
+            );
+        }
+        Some(packages) => {
+            let suggested_command = format!("{invocation_source} --from PKG {executable}");
+            let provided_by = packages
+                .iter()
+                .map(|package| format!("- {}", package.cyan()))
+                .join("\n");
+            warn_user!(
+                "An executable named `{}` is not provided by package `{}` but is available via the following dependencies:\n{}\nConsider using `{}` instead.",
+                executable.cyan(),
+                from_package.cyan(),
+                provided_by,
+                suggested_command.green(),
+            );
+        }
+        None => {
+            warn_user!(
+                "An executable named `{}` is not provided by package `{}`.",
+                executable.cyan(),
+                from_package.red()
+            );
         }
     }
+
+    Ok(None)
+}
+
+/// Returns `true` if the user confirms (or passed `--yes`) that an editable tool should be
+/// reinstalled, as its project metadata file `file` changed since the tool was installed.
+/// Prompt the user to reinstall an editable tool whose project metadata has changed.
+fn confirm_reinstall(name: &PackageName, file: &Path, yes: bool) -> Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+
+    let term = Term::stderr();
+    if !term.is_term() {
+        return Ok(false);
+    }
+
+    let prompt = format!(
+        "`{name}` was installed in editable mode, but `{}` has changed since. Reinstall it?",
+        file.user_display()
+    );
+    uv_requirements::confirm(&prompt, &term, true)
 }
 
 /// Get or create a [`PythonEnvironment`] in which to run the specified tools.
@@ -277,9 +722,11 @@ async fn get_or_create_environment(
     python: Option<&str>,
     settings: &ResolverInstallerSettings,
     isolated: bool,
+    yes: bool,
     preview: PreviewMode,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
@@ -300,6 +747,7 @@ async fn get_or_create_environment(
         EnvironmentPreference::OnlySystem,
         python_preference,
         python_fetch,
+        python_install_mirror,
         &client_builder,
         cache,
         Some(&reporter),
@@ -310,51 +758,30 @@ async fn get_or_create_environment(
     // Initialize any shared state.
     let state = SharedState::default();
 
-    // Resolve the `from` requirement.
-    let from = {
-        resolve_requirements(
-            std::iter::once(from),
-            &interpreter,
-            settings,
-            &state,
-            preview,
-            connectivity,
-            concurrency,
-            native_tls,
-            cache,
-            printer,
-        )
-        .await?
-        .pop()
-        .unwrap()
-    };
+    // Resolve the `from` and `with` requirements in a single batch. Any repeated requirements
+    // (e.g., `uvx --with foo foo`) are only resolved (and included) once.
+    let requirements = resolve_requirements(
+        std::iter::once(from).chain(with.iter().map(String::as_str)),
+        false,
+        &interpreter,
+        settings,
+        &state,
+        preview,
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
 
-    // Combine the `from` and `with` requirements.
-    let requirements = {
-        let mut requirements = Vec::with_capacity(1 + with.len());
-        requirements.push(from.clone());
-        requirements.extend(
-            resolve_requirements(
-                with.iter().map(String::as_str),
-                &interpreter,
-                settings,
-                &state,
-                preview,
-                connectivity,
-                concurrency,
-                native_tls,
-                cache,
-                printer,
-            )
-            .await?,
-        );
-        requirements
-    };
+    // The `from` requirement is always first.
+    let from = requirements[0].clone();
 
     // Check if the tool is already installed in a compatible environment.
     if !isolated {
         let installed_tools = InstalledTools::from_settings()?.init()?;
-        let _lock = installed_tools.acquire_lock()?;
+        let lock = installed_tools.acquire_lock()?;
 
         let existing_environment =
             installed_tools
@@ -379,6 +806,65 @@ async fn get_or_create_environment(
                 site_packages.satisfies(&requirements, &constraints),
                 Ok(SatisfiesResult::Fresh { .. })
             ) {
+                // If the tool was installed in editable mode, and its project metadata has since
+                // changed (e.g., to add a dependency), offer to reinstall it.
+                if let Ok(Some(tool)) = installed_tools.get_tool_receipt(&from.name) {
+                    if let Some(path) = tool.editable_path() {
+                        let stale = tool.stale_editable_files().unwrap_or_default();
+                        if let Some(file) = stale.first() {
+                            if confirm_reinstall(&from.name, file, yes)? {
+                                // Release the lock, which is acquired again by the installation.
+                                drop(lock);
+
+                                let status = Box::pin(commands::tool_install(
+                                    path.to_string_lossy().to_string(),
+                                    None,
+                                    tool.python().map(ToString::to_string),
+                                    tool.requirements()
+                                        .iter()
+                                        .skip(1)
+                                        .map(ToString::to_string)
+                                        .collect(),
+                                    true,
+                                    false,
+                                    false,
+                                    None,
+                                    settings.clone(),
+                                    preview,
+                                    python_preference,
+                                    python_fetch,
+                                    python_install_mirror,
+                                    connectivity,
+                                    concurrency,
+                                    native_tls,
+                                    cache,
+                                    printer,
+                                ))
+                                .await?;
+                                if !matches!(status, ExitStatus::Success) {
+                                    bail!("Failed to reinstall `{}`", from.name);
+                                }
+
+                                let Some(environment) =
+                                    installed_tools.get_environment(&from.name, cache)?
+                                else {
+                                    bail!("Failed to find the reinstalled tool `{}`", from.name);
+                                };
+                                debug!("Using reinstalled tool `{}`", from.name);
+                                return Ok((from, environment));
+                            }
+
+                            warn_user!(
+                                "`{}` was installed in editable mode, but `{}` has changed since; run `{}` to reinstall it",
+                                from.name,
+                                file.user_display(),
+                                format!("uv tool install --editable {}", path.user_display())
+                                    .green()
+                            );
+                        }
+                    }
+                }
+
                 debug!("Using existing tool `{}`", from.name);
                 return Ok((from, environment));
             }
@@ -406,37 +892,460 @@ async fn get_or_create_environment(
 }
 
 /// Parse a target into a command name and a requirement.
-fn parse_target(target: &OsString) -> Result<(Cow<OsString>, Cow<str>)> {
+fn parse_target(target: &OsString) -> Result<(Option<Cow<OsString>>, Cow<str>)> {
     let Some(target_str) = target.to_str() else {
         return Err(anyhow::anyhow!("Tool command could not be parsed as UTF-8 string. Use `--from` to specify the package name."));
     };
 
+    // e.g. `git+https://github.com/psf/black@main`, install the tool from the repository (at the
+    // given reference, if any) and determine the executable from its entry points
+    if target_str.starts_with("git+") {
+        debug!("Using Git repository as tool source: `{target_str}`");
+        return Ok((None, Cow::Borrowed(target_str)));
+    }
+
+    // e.g. `./path/to/tool`, install the tool from the local directory
+    if is_local_path(target_str) {
+        let path = Path::new(target_str);
+        if !path.exists() {
+            bail!("Tool directory does not exist: `{}`", path.user_display());
+        }
+        if path.is_dir() {
+            let executable = local_directory_script(path)?;
+            debug!(
+                "Using executable `{executable}` from local directory: `{}`",
+                path.user_display()
+            );
+            return Ok((
+                Some(Cow::Owned(OsString::from(executable))),
+                Cow::Borrowed(target_str),
+            ));
+        }
+
+        // e.g. `./dist/tool-0.1.0-py3-none-any.whl`, install the tool from the archive and
+        // determine the executable from its entry points
+        debug!(
+            "Using local archive as tool source: `{}`",
+            path.user_display()
+        );
+        return Ok((None, Cow::Borrowed(target_str)));
+    }
+
     // e.g. uv, no special handling
     let Some((name, version)) = target_str.split_once('@') else {
-        return Ok((Cow::Borrowed(target), Cow::Borrowed(target_str)));
+        return Ok((Some(Cow::Borrowed(target)), Cow::Borrowed(target_str)));
     };
 
     // e.g. `uv@`, warn and treat the whole thing as the command
     if version.is_empty() {
         debug!("Ignoring empty version request in command");
-        return Ok((Cow::Borrowed(target), Cow::Borrowed(target_str)));
+        return Ok((Some(Cow::Borrowed(target)), Cow::Borrowed(target_str)));
     }
 
-    // e.g. ignore `git+https://github.com/uv/uv.git@main`
+    // e.g. `uv/tool@main`, treat the whole thing as the command
     if PackageName::from_str(name).is_err() {
         debug!("Ignoring non-package name `{name}` in command");
-        return Ok((Cow::Borrowed(target), Cow::Borrowed(target_str)));
+        return Ok((Some(Cow::Borrowed(target)), Cow::Borrowed(target_str)));
     }
 
     // e.g. `uv@0.1.0`, convert to `uv==0.1.0`
     if let Ok(version) = Version::from_str(version) {
         return Ok((
-            Cow::Owned(OsString::from(name)),
+            Some(Cow::Owned(OsString::from(name))),
             Cow::Owned(format!("{name}=={version}")),
         ));
     }
 
+    // e.g. `uv@>=0.2,<0.3`, convert to `uv>=0.2, <0.3`
+    if let Ok(specifiers) = VersionSpecifiers::from_str(version) {
+        if !specifiers.is_empty() {
+            return Ok((
+                Some(Cow::Owned(OsString::from(name))),
+                Cow::Owned(format!("{name}{specifiers}")),
+            ));
+        }
+    }
+
     // e.g. `uv@invalid`, warn and treat the whole thing as the command
     debug!("Ignoring invalid version request `{version}` in command");
-    Ok((Cow::Borrowed(target), Cow::Borrowed(target_str)))
+    Ok((Some(Cow::Borrowed(target)), Cow::Borrowed(target_str)))
+}
+
+/// Return the given `site-packages` directory, followed by the directories referenced by the
+/// `.pth` files within it.
+///
+/// Mirrors the processing in Python's `site` module: `.pth` files are read in alphabetical order,
+/// blank lines, comments, and `import` lines are skipped, and relative entries are resolved
+/// against the `site-packages` directory. Entries that don't exist are ignored.
+fn with_pth_paths(site_packages: &Path) -> Vec<PathBuf> {
+    let mut paths = vec![site_packages.to_path_buf()];
+
+    let Ok(entries) = fs_err::read_dir(site_packages) else {
+        return paths;
+    };
+    let pth_files = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "pth"))
+        .filter(|path| {
+            !path
+                .file_name()
+                .and_then(OsStr::to_str)
+                .is_some_and(|name| name.starts_with('.'))
+        })
+        .sorted();
+
+    for pth_file in pth_files {
+        let Ok(contents) = fs_err::read_to_string(&pth_file) else {
+            debug!("Failed to read `.pth` file: {}", pth_file.user_display());
+            continue;
+        };
+        for line in contents.lines() {
+            let line = line.trim_end();
+            if line.is_empty()
+                || line.starts_with('#')
+                || line.starts_with("import ")
+                || line.starts_with("import\t")
+            {
+                continue;
+            }
+            let path = site_packages.join(line);
+            if path.exists() {
+                paths.push(path);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Prepend the given paths to an existing `PATH`-like variable.
+///
+/// Empty segments are dropped, as are duplicate paths (keeping the first occurrence), such that
+/// nested invocations (e.g., `uvx` within `uvx`) don't accumulate entries.
+fn prepend_paths(
+    paths: impl IntoIterator<Item = PathBuf>,
+    existing: Option<&OsStr>,
+) -> Result<OsString, std::env::JoinPathsError> {
+    let mut seen = HashSet::new();
+    std::env::join_paths(
+        paths
+            .into_iter()
+            .chain(existing.into_iter().flat_map(std::env::split_paths))
+            .filter(|path| !path.as_os_str().is_empty())
+            .filter(|path| seen.insert(path.clone())),
+    )
+}
+
+/// Read the environment variables from a dotenv-format file.
+///
+/// Each line must be a `KEY=VALUE` assignment, optionally prefixed with `export`; blank lines and
+/// comments are ignored. Values may be wrapped in single or double quotes.
+fn read_env_file(path: &Path) -> Result<Vec<(String, String)>> {
+    let contents = fs_err::read_to_string(path)?;
+    let mut vars = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let assignment = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = assignment.split_once('=') else {
+            bail!(
+                "Invalid line {} in `{}`: expected `KEY=VALUE`, found `{line}`",
+                index + 1,
+                path.user_display()
+            );
+        };
+        let key = key.trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            bail!(
+                "Invalid line {} in `{}`: expected `KEY=VALUE`, found `{line}`",
+                index + 1,
+                path.user_display()
+            );
+        }
+        let value = value.trim();
+        let value = ['"', '\'']
+            .into_iter()
+            .find_map(|quote| {
+                value
+                    .strip_prefix(quote)
+                    .and_then(|value| value.strip_suffix(quote))
+            })
+            .unwrap_or(value);
+        vars.push((key.to_string(), value.to_string()));
+    }
+    Ok(vars)
+}
+
+/// Returns `true` if the target looks like a path on the local filesystem (e.g., `./tool`), rather
+/// than a command or package name.
+fn is_local_path(target: &str) -> bool {
+    if matches!(target, "." | "..")
+        || target.starts_with("./")
+        || target.starts_with("../")
+        || target.starts_with('/')
+    {
+        return true;
+    }
+    if cfg!(windows) {
+        // e.g. `.\tool`, `..\tool`, or `C:\tool`
+        if target.starts_with(".\\") || target.starts_with("..\\") {
+            return true;
+        }
+        let mut chars = target.chars();
+        if let (Some(drive), Some(':'), Some('\\' | '/')) =
+            (chars.next(), chars.next(), chars.next())
+        {
+            return drive.is_ascii_alphabetic();
+        }
+    }
+    false
+}
+
+/// Determine the executable to run for a tool in a local directory, based on the scripts declared
+/// in its `pyproject.toml`.
+///
+/// If the project declares multiple scripts, the script matching the project name is preferred.
+fn local_directory_script(path: &Path) -> Result<String> {
+    let pyproject_toml = path.join("pyproject.toml");
+    let contents = match fs_err::read_to_string(&pyproject_toml) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            bail!(
+                "Tool directory `{}` does not contain a `pyproject.toml`",
+                path.user_display()
+            );
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let pyproject_toml = PyProjectToml::from_string(contents)
+        .with_context(|| format!("Failed to parse: `{}`", pyproject_toml.user_display()))?;
+    let Some(project) = pyproject_toml.project else {
+        bail!(
+            "Tool directory `{}` does not declare a `[project]` in its `pyproject.toml`",
+            path.user_display()
+        );
+    };
+    let scripts = project.scripts.unwrap_or_default();
+
+    // Prefer the script that matches the project name.
+    if let Some(script) = scripts
+        .keys()
+        .find(|script| PackageName::from_str(script).is_ok_and(|name| name == project.name))
+    {
+        return Ok(script.clone());
+    }
+
+    match scripts.len() {
+        0 => bail!(
+            "Package `{}` at `{}` does not provide any scripts in `[project.scripts]`",
+            project.name,
+            path.user_display()
+        ),
+        1 => Ok(scripts.into_keys().next().unwrap()),
+        _ => {
+            bail!(
+            "Package `{}` at `{}` provides multiple scripts ({}); use `--from {} <script>` to select one",
+            project.name,
+            path.user_display(),
+            scripts.keys().map(|script| format!("`{script}`")).join(", "),
+            path.user_display()
+        )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+    use std::path::PathBuf;
+
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+
+    use super::{parse_target, prepend_paths, read_env_file, with_pth_paths};
+
+    /// Parse the target, returning the executable and requirement.
+    fn parse(target: &str) -> (String, String) {
+        let (executable, requirement) = parse_target(&OsString::from(target)).unwrap();
+        (
+            executable
+                .expect("target should determine the executable")
+                .to_string_lossy()
+                .into_owned(),
+            requirement.into_owned(),
+        )
+    }
+
+    #[test]
+    fn parse_target_name() {
+        assert_eq!(parse("ruff"), ("ruff".to_string(), "ruff".to_string()));
+    }
+
+    #[test]
+    fn parse_target_exact_version() {
+        assert_eq!(
+            parse("ruff@0.4.0"),
+            ("ruff".to_string(), "ruff==0.4.0".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_target_range() {
+        assert_eq!(
+            parse("ruff@>=0.4,<0.5"),
+            ("ruff".to_string(), "ruff>=0.4, <0.5".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_target_compatible_release() {
+        assert_eq!(
+            parse("ruff@~=0.4.0"),
+            ("ruff".to_string(), "ruff~=0.4.0".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_target_pre_release() {
+        assert_eq!(
+            parse("ruff@>=0.5.0a1"),
+            ("ruff".to_string(), "ruff>=0.5.0a1".to_string())
+        );
+        assert_eq!(
+            parse("ruff@0.5.0rc1"),
+            ("ruff".to_string(), "ruff==0.5.0rc1".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_target_empty_version() {
+        assert_eq!(parse("ruff@"), ("ruff@".to_string(), "ruff@".to_string()));
+    }
+
+    #[test]
+    fn parse_target_invalid_version() {
+        assert_eq!(
+            parse("ruff@invalid"),
+            ("ruff@invalid".to_string(), "ruff@invalid".to_string())
+        );
+        assert_eq!(
+            parse("ruff@>=invalid"),
+            ("ruff@>=invalid".to_string(), "ruff@>=invalid".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_target_git_url() {
+        for target in [
+            "git+https://github.com/astral-sh/ruff",
+            "git+https://github.com/astral-sh/ruff.git@main",
+            "git+https://github.com/astral-sh/ruff@v0.5.0",
+        ] {
+            let (executable, requirement) = parse_target(&OsString::from(target)).unwrap();
+            assert_eq!(executable, None);
+            assert_eq!(requirement, target);
+        }
+    }
+
+    #[test]
+    fn env_file() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let env_file = temp_dir.child(".env");
+        env_file.write_str(indoc::indoc! {r#"
+            # A comment.
+            FOO=bar
+
+            export BAZ = "qux quux"
+            EMPTY=
+            URL='https://example.com/?a=b'
+        "#})?;
+
+        assert_eq!(
+            read_env_file(env_file.path())?,
+            vec![
+                ("FOO".to_string(), "bar".to_string()),
+                ("BAZ".to_string(), "qux quux".to_string()),
+                ("EMPTY".to_string(), String::new()),
+                ("URL".to_string(), "https://example.com/?a=b".to_string()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn env_file_malformed() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let env_file = temp_dir.child(".env");
+        env_file.write_str("FOO=bar\nBAZ\n")?;
+
+        let err = read_env_file(env_file.path()).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid line 2 in `"), "{err}");
+        assert!(
+            err.to_string()
+                .ends_with("expected `KEY=VALUE`, found `BAZ`"),
+            "{err}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_pth_paths_expands_entries() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let site_packages = temp_dir.child("site-packages");
+        site_packages.create_dir_all()?;
+        let editable = temp_dir.child("plugin").child("src");
+        editable.create_dir_all()?;
+
+        site_packages
+            .child("_plugin.pth")
+            .write_str(&format!("{}\n", editable.path().display()))?;
+        site_packages
+            .child("relative.pth")
+            .write_str(indoc::indoc! {r"
+            # A comment.
+            import sys
+            ../plugin
+            missing
+        "})?;
+        site_packages
+            .child("plugin.txt")
+            .write_str(&format!("{}\n", temp_dir.path().display()))?;
+
+        assert_eq!(
+            with_pth_paths(site_packages.path()),
+            vec![
+                site_packages.path().to_path_buf(),
+                editable.path().to_path_buf(),
+                site_packages.path().join("../plugin"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn prepend_paths_deduplicates() -> Result<()> {
+        let existing = std::env::join_paths(["/usr/bin", "/env/bin", "", "/bin", "/usr/bin"])?;
+        let path = prepend_paths([PathBuf::from("/env/bin")], Some(existing.as_os_str()))?;
+        assert_eq!(
+            std::env::split_paths(&path).collect::<Vec<_>>(),
+            ["/env/bin", "/usr/bin", "/bin"]
+                .into_iter()
+                .map(PathBuf::from)
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn prepend_paths_without_existing() -> Result<()> {
+        let path = prepend_paths([PathBuf::from("/env/bin")], None)?;
+        assert_eq!(path, OsString::from("/env/bin"));
+        Ok(())
+    }
 }
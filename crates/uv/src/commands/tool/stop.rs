@@ -0,0 +1,61 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use uv_configuration::PreviewMode;
+use uv_tool::ToolRuns;
+use uv_warnings::warn_user_once;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Stop a tool running in the background.
+pub(crate) async fn stop(
+    target: String,
+    preview: PreviewMode,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv tool stop` is experimental and may change without warning");
+    }
+
+    // Match the target against either the process ID or the executable name.
+    let target_pid = u32::from_str(&target).ok();
+    let mut matches = Vec::new();
+    for run in ToolRuns::from_settings()?.runs()? {
+        let pid = run.pid()?;
+        if target_pid.is_some_and(|target_pid| pid == Some(target_pid)) || run.name() == target {
+            matches.push((run, pid));
+        }
+    }
+
+    if matches.is_empty() {
+        bail!("No background tool found matching `{target}`");
+    }
+
+    for (run, pid) in matches {
+        if let Some(pid) = pid {
+            if run.is_running(pid)? {
+                run.terminate(pid)?;
+                writeln!(
+                    printer.stderr(),
+                    "Stopped `{}` (pid {})",
+                    run.name().green(),
+                    pid.to_string().cyan()
+                )?;
+            } else {
+                writeln!(
+                    printer.stderr(),
+                    "`{}` (pid {}) already exited",
+                    run.name().green(),
+                    pid.to_string().cyan()
+                )?;
+            }
+        }
+        run.remove()?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -22,7 +22,7 @@ use uv_configuration::{
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
 use uv_python::{
-    request_from_version_file, EnvironmentPreference, PythonFetch, PythonInstallation,
+    request_from_version_file, EnvironmentPreference, Interpreter, PythonFetch, PythonInstallation,
     PythonPreference, PythonRequest,
 };
 use uv_resolver::{ExcludeNewer, FlatIndex};
@@ -40,6 +40,7 @@ pub(crate) async fn venv(
     python_request: Option<&str>,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     link_mode: LinkMode,
     index_locations: &IndexLocations,
     index_strategy: IndexStrategy,
@@ -69,6 +70,7 @@ pub(crate) async fn venv(
         preview,
         python_preference,
         python_fetch,
+        python_install_mirror,
         allow_existing,
         exclude_newer,
         native_tls,
@@ -120,6 +122,7 @@ async fn venv_impl(
     preview: PreviewMode,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
+    python_install_mirror: &[String],
     allow_existing: bool,
     exclude_newer: Option<ExcludeNewer>,
     native_tls: bool,
@@ -145,6 +148,7 @@ async fn venv_impl(
         EnvironmentPreference::OnlySystem,
         python_preference,
         python_fetch,
+        python_install_mirror,
         &client_builder,
         cache,
         Some(&reporter),
@@ -181,6 +185,7 @@ async fn venv_impl(
         prompt,
         system_site_packages,
         allow_existing,
+        seed,
     )
     .map_err(VenvError::Creation)?;
 
@@ -247,18 +252,7 @@ async fn venv_impl(
         );
 
         // Resolve the seed packages.
-        let requirements = if interpreter.python_tuple() < (3, 12) {
-            // Only include `setuptools` and `wheel` on Python <3.12
-            vec![
-                Requirement::from(pep508_rs::Requirement::from_str("pip").unwrap()),
-                Requirement::from(pep508_rs::Requirement::from_str("setuptools").unwrap()),
-                Requirement::from(pep508_rs::Requirement::from_str("wheel").unwrap()),
-            ]
-        } else {
-            vec![Requirement::from(
-                pep508_rs::Requirement::from_str("pip").unwrap(),
-            )]
-        };
+        let requirements = seed_requirements(interpreter);
 
         // Resolve and install the requirements.
         //
@@ -342,3 +336,19 @@ fn shlex_windows(executable: impl AsRef<Path>, shell: Shell) -> String {
         executable
     }
 }
+
+/// The seed packages to install into a virtual environment.
+pub(crate) fn seed_requirements(interpreter: &Interpreter) -> Vec<Requirement> {
+    if interpreter.python_tuple() < (3, 12) {
+        // Only include `setuptools` and `wheel` on Python <3.12
+        vec![
+            Requirement::from(pep508_rs::Requirement::from_str("pip").unwrap()),
+            Requirement::from(pep508_rs::Requirement::from_str("setuptools").unwrap()),
+            Requirement::from(pep508_rs::Requirement::from_str("wheel").unwrap()),
+        ]
+    } else {
+        vec![Requirement::from(
+            pep508_rs::Requirement::from_str("pip").unwrap(),
+        )]
+    }
+}
@@ -26,7 +26,8 @@ use uv_requirements::RequirementsSource;
 use uv_settings::{Combine, FilesystemOptions};
 use uv_workspace::Workspace;
 
-use crate::commands::{ExitStatus, ToolRunCommand};
+use crate::commands::strict::StrictViolation;
+use crate::commands::{ExitStatus, ProjectError, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
     CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
@@ -154,10 +155,15 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
     show_settings!(globals, false);
     show_settings!(cache_settings, false);
 
+    // Collect cache statistics, if requested.
+    if !globals.quiet && (cache_settings.cache_stats || globals.verbose > 1) {
+        uv_cache::stats::enable();
+    }
+
     // Configure the cache.
     let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
 
-    match *cli.command {
+    let result = match *cli.command {
         Commands::Help(args) => commands::help(
             args.command.unwrap_or_default().as_slice(),
             printer,
@@ -537,14 +543,28 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             commands::cache_clean(&args.package, &cache, printer)
         }
         Commands::Cache(CacheNamespace {
-            command: CacheCommand::Prune,
-        }) => commands::cache_prune(&cache, printer),
+            command: CacheCommand::Prune(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_prune(
+                args.package.as_ref(),
+                args.version.as_ref(),
+                &cache,
+                printer,
+            )
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => {
             commands::cache_dir(&cache);
             Ok(ExitStatus::Success)
         }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Info(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_info(args.json, args.top, &cache, printer)
+        }
         Commands::Venv(args) => {
             args.compat_args.validate()?;
 
@@ -569,6 +589,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.python.as_deref(),
                 globals.python_preference,
                 globals.python_fetch,
+                &globals.python_install_mirror,
                 args.settings.link_mode,
                 &args.settings.index_locations,
                 args.settings.index_strategy,
@@ -621,6 +642,16 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.command,
                 args.from,
                 args.with,
+                args.working_dir,
+                args.detach,
+                args.timeout,
+                args.unbuffered,
+                args.env,
+                args.env_file,
+                args.no_inherit_env,
+                args.propagate_pythonpath,
+                args.list_entrypoints,
+                args.yes,
                 args.python,
                 args.settings,
                 invocation_source,
@@ -628,6 +659,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 globals.preview,
                 globals.python_preference,
                 globals.python_fetch,
+                &globals.python_install_mirror,
                 globals.connectivity,
                 Concurrency::default(),
                 globals.native_tls,
@@ -651,11 +683,15 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.from,
                 args.python,
                 args.with,
+                args.editable,
                 args.force,
+                args.no_atomic,
+                args.post_install_script,
                 args.settings,
                 globals.preview,
                 globals.python_preference,
                 globals.python_fetch,
+                &globals.python_install_mirror,
                 globals.connectivity,
                 Concurrency::default(),
                 globals.native_tls,
@@ -685,6 +721,18 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
 
             commands::tool_uninstall(args.name, globals.preview, printer).await
         }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Ps,
+        }) => commands::tool_ps(globals.preview, printer).await,
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Stop(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolStopSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::tool_stop(args.target, globals.preview, printer).await
+        }
         Commands::Tool(ToolNamespace {
             command: ToolCommand::UpdateShell,
         }) => {
@@ -736,6 +784,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             commands::python_install(
                 args.targets,
                 args.reinstall,
+                &globals.python_install_mirror,
                 globals.native_tls,
                 globals.connectivity,
                 globals.preview,
@@ -798,7 +847,14 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             commands::python_dir(globals.preview)?;
             Ok(ExitStatus::Success)
         }
+    };
+
+    // Print a summary of the cache statistics, if enabled.
+    if let Some(summary) = uv_cache::stats::summary() {
+        writeln!(printer.stderr(), "{summary}")?;
     }
+
+    result
 }
 
 /// Run a [`ProjectCommand`].
@@ -830,12 +886,21 @@ async fn run_project(
             let args = settings::InitSettings::resolve(args, filesystem);
             show_settings!(args);
 
+            // Initialize the cache.
+            let cache = cache.init()?;
+
             commands::init(
                 args.path,
                 args.name,
                 args.no_readme,
+                args.template,
+                args.package,
                 globals.isolated,
                 globals.preview,
+                globals.python_preference,
+                globals.connectivity,
+                globals.native_tls,
+                &cache,
                 printer,
             )
             .await
@@ -845,6 +910,9 @@ async fn run_project(
             let args = settings::RunSettings::resolve(args, filesystem);
             show_settings!(args);
 
+            // Promote the selected warnings to errors.
+            commands::strict::enable(&args.strict);
+
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(args.refresh);
 
@@ -859,15 +927,21 @@ async fn run_project(
                 requirements,
                 args.locked,
                 args.frozen,
+                args.lockfile,
                 args.package,
                 args.extras,
                 args.dev,
+                args.modifications,
+                args.dry_run,
                 args.python,
+                args.active,
+                args.unbuffered,
                 args.settings,
                 globals.isolated,
                 globals.preview,
                 globals.python_preference,
                 globals.python_fetch,
+                &globals.python_install_mirror,
                 globals.connectivity,
                 Concurrency::default(),
                 globals.native_tls,
@@ -881,18 +955,34 @@ async fn run_project(
             let args = settings::SyncSettings::resolve(args, filesystem);
             show_settings!(args);
 
+            // Promote the selected warnings to errors.
+            commands::strict::enable(&args.strict);
+
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(args.refresh);
 
             commands::sync(
                 args.locked,
                 args.frozen,
+                args.lockfile,
                 args.extras,
                 args.dev,
+                args.editable,
+                args.install_options,
                 args.modifications,
+                args.strict_markers,
+                args.require_hashes,
+                args.dry_run,
+                args.check,
+                args.repair,
+                args.package,
                 args.python,
+                args.active,
+                args.prompt,
+                args.seed,
                 globals.python_preference,
                 globals.python_fetch,
+                &globals.python_install_mirror,
                 args.settings,
                 globals.preview,
                 globals.connectivity,
@@ -908,17 +998,62 @@ async fn run_project(
             let args = settings::LockSettings::resolve(args, filesystem);
             show_settings!(args);
 
+            // Promote the selected warnings to errors.
+            commands::strict::enable(&args.strict);
+
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(args.refresh);
 
             commands::lock(
                 args.locked,
                 args.frozen,
+                args.lockfile,
+                args.python_range,
+                args.exclude_package,
+                args.write,
+                args.check,
+                args.dry_run,
                 args.python,
                 args.settings,
                 globals.preview,
                 globals.python_preference,
                 globals.python_fetch,
+                &globals.python_install_mirror,
+                globals.connectivity,
+                Concurrency::default(),
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        ProjectCommand::Export(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ExportSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(args.refresh);
+
+            commands::export(
+                args.format,
+                args.base_image,
+                args.extras,
+                args.hashes,
+                args.dev,
+                args.no_emit_project,
+                args.output_file,
+                args.python_platform,
+                args.python_version,
+                args.locked,
+                args.frozen,
+                args.lockfile,
+                args.python,
+                args.settings,
+                globals.python_preference,
+                globals.python_fetch,
+                &globals.python_install_mirror,
+                globals.preview,
                 globals.connectivity,
                 Concurrency::default(),
                 globals.native_tls,
@@ -941,16 +1076,21 @@ async fn run_project(
                 args.requirements,
                 args.editable,
                 args.dependency_type,
+                args.git,
+                args.path,
                 args.raw_sources,
                 args.rev,
                 args.tag,
                 args.branch,
                 args.extras,
+                args.bounds,
                 args.package,
                 args.python,
+                args.script,
                 args.settings,
                 globals.python_preference,
                 globals.python_fetch,
+                &globals.python_install_mirror,
                 globals.preview,
                 globals.connectivity,
                 Concurrency::default(),
@@ -971,13 +1111,17 @@ async fn run_project(
             commands::remove(
                 args.locked,
                 args.frozen,
+                args.no_sync,
                 args.requirements,
                 args.dependency_type,
+                args.keep_empty,
                 args.package,
                 args.python,
+                args.script,
                 args.settings,
                 globals.python_preference,
                 globals.python_fetch,
+                &globals.python_install_mirror,
                 globals.preview,
                 globals.connectivity,
                 Concurrency::default(),
@@ -998,16 +1142,50 @@ async fn run_project(
             commands::tree(
                 args.locked,
                 args.frozen,
+                args.lockfile,
                 args.depth,
                 args.prune,
                 args.package,
                 args.no_dedupe,
                 args.invert,
                 args.show_version_specifiers,
+                args.dev,
                 args.python,
+                args.python_platform,
+                args.python_version,
+                args.licenses,
                 args.resolver,
                 globals.python_preference,
                 globals.python_fetch,
+                &globals.python_install_mirror,
+                globals.preview,
+                globals.connectivity,
+                Concurrency::default(),
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        ProjectCommand::Publish(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PublishSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(args.refresh);
+
+            commands::publish(
+                args.repository,
+                args.username,
+                args.password,
+                args.skip_existing,
+                args.package,
+                args.python,
+                args.settings,
+                globals.python_preference,
+                globals.python_fetch,
+                &globals.python_install_mirror,
                 globals.preview,
                 globals.connectivity,
                 Concurrency::default(),
@@ -1143,6 +1321,24 @@ where
             for err in causes {
                 eprintln!("  {}: {}", "Caused by".red().bold(), err);
             }
+            if let Some(hint) = err
+                .chain()
+                .find_map(|err| err.downcast_ref::<ProjectError>())
+                .and_then(ProjectError::hint)
+            {
+                eprintln!("{}: {}", "hint".bold().cyan(), hint.dimmed());
+            }
+
+            // Use a distinct exit code for warnings that were promoted to errors via `--strict`.
+            if let Some(violation) = err.chain().find_map(|err| {
+                err.downcast_ref::<StrictViolation>().or_else(|| {
+                    err.downcast_ref::<ProjectError>()
+                        .and_then(ProjectError::strict_violation)
+                })
+            }) {
+                return ExitCode::from(violation.exit_code());
+            }
+
             ExitStatus::Error.into()
         }
     }
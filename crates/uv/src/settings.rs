@@ -3,30 +3,40 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
+use std::time::Duration;
+
+use url::Url;
 
 use distribution_types::IndexLocations;
 use install_wheel_rs::linker::LinkMode;
+use pep440_rs::VersionSpecifiers;
 use pep508_rs::{ExtraName, RequirementOrigin};
 use pypi_types::Requirement;
 use uv_cache::{CacheArgs, Refresh};
 use uv_cli::options::{flag, resolver_installer_options, resolver_options};
 use uv_cli::{
-    AddArgs, ColorChoice, Commands, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs,
-    Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs, ToolDirArgs,
-    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
+    AddArgs, ColorChoice, Commands, ExportArgs, ExternalCommand, GlobalArgs, InitArgs, ListFormat,
+    LockArgs, Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs,
+    PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs, PublishArgs, PythonFindArgs,
+    PythonInstallArgs, PythonListArgs, PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs,
+    SyncArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolStopArgs,
+    ToolUninstallArgs, TreeArgs, VenvArgs,
 };
 use uv_client::Connectivity;
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, ExtrasSpecification, HashCheckingMode,
-    IndexStrategy, KeyringProviderType, NoBinary, NoBuild, PreviewMode, Reinstall, SetupPyStrategy,
-    TargetTriple, Upgrade,
+    AddBounds, BuildOptions, Concurrency, ConfigSettings, DevSpecification, EditableMode,
+    ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
+    KeyringProviderType, NoBinary, NoBuild, PackageNameSpecifier, PreviewMode, Reinstall,
+    SetupPyStrategy, StrictCategory, TargetTriple, Upgrade,
 };
+use uv_distribution::DEV_DEPENDENCIES;
 use uv_normalize::PackageName;
 use uv_python::{Prefix, PythonFetch, PythonPreference, PythonVersion, Target};
 use uv_requirements::RequirementsSource;
-use uv_resolver::{AnnotationStyle, DependencyMode, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerIndex, PreReleaseMode,
+    ResolutionMode,
+};
 use uv_settings::{
     Combine, FilesystemOptions, Options, PipOptions, ResolverInstallerOptions, ResolverOptions,
 };
@@ -48,6 +58,7 @@ pub(crate) struct GlobalSettings {
     pub(crate) preview: PreviewMode,
     pub(crate) python_preference: PythonPreference,
     pub(crate) python_fetch: PythonFetch,
+    pub(crate) python_install_mirror: Vec<String>,
     pub(crate) no_progress: bool,
 }
 
@@ -119,6 +130,13 @@ impl GlobalSettings {
                 .python_fetch
                 .combine(workspace.and_then(|workspace| workspace.globals.python_fetch))
                 .unwrap_or_default(),
+            python_install_mirror: args
+                .python_install_mirror
+                .clone()
+                .combine(
+                    workspace.and_then(|workspace| workspace.globals.python_install_mirror.clone()),
+                )
+                .unwrap_or_default(),
             no_progress: args.no_progress,
         }
     }
@@ -130,6 +148,7 @@ impl GlobalSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) cache_stats: bool,
 }
 
 impl CacheSettings {
@@ -143,6 +162,7 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            cache_stats: args.cache_stats,
         }
     }
 }
@@ -154,6 +174,8 @@ pub(crate) struct InitSettings {
     pub(crate) path: Option<String>,
     pub(crate) name: Option<PackageName>,
     pub(crate) no_readme: bool,
+    pub(crate) template: Option<String>,
+    pub(crate) package: bool,
 }
 
 impl InitSettings {
@@ -164,12 +186,21 @@ impl InitSettings {
             path,
             name,
             no_readme,
+            template,
+            app,
+            no_package,
         } = args;
 
         Self {
             path,
             name,
             no_readme,
+            template: if app {
+                Some("app".to_string())
+            } else {
+                template
+            },
+            package: !no_package,
         }
     }
 }
@@ -180,12 +211,18 @@ impl InitSettings {
 pub(crate) struct RunSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
+    pub(crate) strict: Vec<StrictCategory>,
+    pub(crate) lockfile: Option<PathBuf>,
     pub(crate) extras: ExtrasSpecification,
-    pub(crate) dev: bool,
+    pub(crate) dev: DevSpecification,
+    pub(crate) modifications: Modifications,
+    pub(crate) dry_run: bool,
     pub(crate) command: ExternalCommand,
     pub(crate) with: Vec<String>,
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
+    pub(crate) active: bool,
+    pub(crate) unbuffered: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
 }
@@ -197,11 +234,19 @@ impl RunSettings {
         let RunArgs {
             locked,
             frozen,
+            strict,
+            no_strict,
+            lockfile,
             extra,
             all_extras,
             no_all_extras,
             dev,
             no_dev,
+            group,
+            only_group,
+            exact,
+            inexact,
+            dry_run,
             command,
             with,
             installer,
@@ -209,20 +254,47 @@ impl RunSettings {
             refresh,
             package,
             python,
+            active,
+            unbuffered,
+            no_unbuffered,
         } = args;
 
+        let modifications = if flag(exact, inexact).unwrap_or(false) {
+            Modifications::Exact
+        } else {
+            Modifications::Sufficient
+        };
+
         Self {
             locked,
             frozen,
+            strict: strict_categories(strict, no_strict, filesystem.as_ref()),
+            lockfile,
             extras: ExtrasSpecification::from_args(
                 flag(all_extras, no_all_extras).unwrap_or_default(),
                 extra.unwrap_or_default(),
             ),
-            dev: flag(dev, no_dev).unwrap_or(true),
+            dev: DevSpecification::from_args(
+                flag(dev, no_dev).unwrap_or(true),
+                group,
+                only_group,
+                Vec::new(),
+                &DEV_DEPENDENCIES,
+            ),
+            modifications,
+            dry_run,
             command,
             with,
             package,
             python,
+            active,
+            unbuffered: flag(unbuffered, no_unbuffered)
+                .combine(
+                    filesystem
+                        .as_ref()
+                        .and_then(|filesystem| filesystem.globals.unbuffered),
+                )
+                .unwrap_or(false),
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
@@ -236,9 +308,19 @@ impl RunSettings {
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct ToolRunSettings {
-    pub(crate) command: ExternalCommand,
+    pub(crate) command: Option<ExternalCommand>,
     pub(crate) from: Option<String>,
     pub(crate) with: Vec<String>,
+    pub(crate) working_dir: Option<PathBuf>,
+    pub(crate) detach: bool,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) unbuffered: bool,
+    pub(crate) env: Vec<(String, String)>,
+    pub(crate) env_file: Option<PathBuf>,
+    pub(crate) no_inherit_env: bool,
+    pub(crate) propagate_pythonpath: bool,
+    pub(crate) list_entrypoints: bool,
+    pub(crate) yes: bool,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
@@ -252,6 +334,17 @@ impl ToolRunSettings {
             command,
             from,
             with,
+            working_dir,
+            detach,
+            timeout,
+            unbuffered,
+            no_unbuffered,
+            env,
+            env_file,
+            no_inherit_env,
+            propagate_pythonpath,
+            list_entrypoints,
+            yes,
             installer,
             build,
             refresh,
@@ -262,6 +355,22 @@ impl ToolRunSettings {
             command,
             from,
             with,
+            working_dir,
+            detach,
+            timeout: timeout.map(Duration::from_secs),
+            unbuffered: flag(unbuffered, no_unbuffered)
+                .combine(
+                    filesystem
+                        .as_ref()
+                        .and_then(|filesystem| filesystem.globals.unbuffered),
+                )
+                .unwrap_or(false),
+            env,
+            env_file,
+            no_inherit_env,
+            propagate_pythonpath,
+            list_entrypoints,
+            yes,
             python,
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
@@ -279,10 +388,13 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) package: String,
     pub(crate) from: Option<String>,
     pub(crate) with: Vec<String>,
+    pub(crate) editable: bool,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
+    pub(crate) no_atomic: bool,
+    pub(crate) post_install_script: Option<PathBuf>,
 }
 
 impl ToolInstallSettings {
@@ -293,8 +405,11 @@ impl ToolInstallSettings {
             package,
             from,
             with,
+            editable,
             installer,
             force,
+            no_atomic,
+            post_install_script,
             build,
             refresh,
             python,
@@ -304,8 +419,11 @@ impl ToolInstallSettings {
             package,
             from,
             with,
+            editable,
             python,
             force,
+            no_atomic,
+            post_install_script,
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
@@ -351,6 +469,23 @@ impl ToolUninstallSettings {
     }
 }
 
+/// The resolved settings to use for a `tool stop` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ToolStopSettings {
+    pub(crate) target: String,
+}
+
+impl ToolStopSettings {
+    /// Resolve the [`ToolStopSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolStopArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolStopArgs { target } = args;
+
+        Self { target }
+    }
+}
+
 /// The resolved settings to use for a `tool dir` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -494,10 +629,23 @@ impl PythonPinSettings {
 pub(crate) struct SyncSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
+    pub(crate) strict: Vec<StrictCategory>,
+    pub(crate) lockfile: Option<PathBuf>,
     pub(crate) extras: ExtrasSpecification,
-    pub(crate) dev: bool,
+    pub(crate) dev: DevSpecification,
+    pub(crate) editable: EditableMode,
+    pub(crate) install_options: InstallOptions,
     pub(crate) modifications: Modifications,
+    pub(crate) strict_markers: bool,
+    pub(crate) require_hashes: bool,
+    pub(crate) dry_run: bool,
+    pub(crate) check: bool,
+    pub(crate) repair: bool,
+    pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
+    pub(crate) active: bool,
+    pub(crate) prompt: Option<String>,
+    pub(crate) seed: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
 }
@@ -509,19 +657,40 @@ impl SyncSettings {
         let SyncArgs {
             locked,
             frozen,
+            strict,
+            no_strict,
+            lockfile,
             extra,
             all_extras,
             no_all_extras,
             dev,
             no_dev,
-            no_clean,
+            exclude_group,
+            group,
+            only_group,
+            no_editable,
+            no_install_project,
+            no_install_workspace,
+            no_install_package,
+            inexact,
+            exact,
+            strict_markers,
+            require_hashes,
+            no_require_hashes,
+            dry_run,
+            check,
+            repair,
             installer,
             build,
             refresh,
+            package,
             python,
+            active,
+            prompt,
+            seed,
         } = args;
 
-        let modifications = if no_clean {
+        let modifications = if flag(inexact, exact).unwrap_or(false) {
             Modifications::Sufficient
         } else {
             Modifications::Exact
@@ -530,13 +699,36 @@ impl SyncSettings {
         Self {
             locked,
             frozen,
+            strict: strict_categories(strict, no_strict, filesystem.as_ref()),
+            lockfile,
             extras: ExtrasSpecification::from_args(
                 flag(all_extras, no_all_extras).unwrap_or_default(),
                 extra.unwrap_or_default(),
             ),
-            dev: flag(dev, no_dev).unwrap_or(true),
+            dev: DevSpecification::from_args(
+                flag(dev, no_dev).unwrap_or(true),
+                group,
+                only_group,
+                exclude_group,
+                &DEV_DEPENDENCIES,
+            ),
+            editable: EditableMode::from_args(no_editable),
+            install_options: InstallOptions::new(
+                no_install_project,
+                no_install_workspace,
+                no_install_package,
+            ),
             modifications,
+            strict_markers,
+            require_hashes: flag(require_hashes, no_require_hashes).unwrap_or(false),
+            dry_run,
+            check,
+            repair,
+            package,
             python,
+            active,
+            prompt,
+            seed,
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
@@ -552,6 +744,13 @@ impl SyncSettings {
 pub(crate) struct LockSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
+    pub(crate) strict: Vec<StrictCategory>,
+    pub(crate) lockfile: Option<PathBuf>,
+    pub(crate) python_range: Option<VersionSpecifiers>,
+    pub(crate) exclude_package: Vec<PackageName>,
+    pub(crate) write: bool,
+    pub(crate) check: bool,
+    pub(crate) dry_run: bool,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
@@ -564,6 +763,86 @@ impl LockSettings {
         let LockArgs {
             locked,
             frozen,
+            strict,
+            no_strict,
+            lockfile,
+            python_platform,
+            python_range,
+            exclude_package,
+            write,
+            check,
+            dry_run,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        Self {
+            locked,
+            frozen,
+            strict: strict_categories(strict, no_strict, filesystem.as_ref()),
+            lockfile,
+            python_range,
+            exclude_package,
+            write,
+            check,
+            dry_run,
+            python,
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(
+                ResolverOptions {
+                    python_platforms: Some(python_platform),
+                    ..resolver_options(resolver, build)
+                },
+                filesystem,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `export` invocation.
+#[allow(clippy::struct_excessive_bools, dead_code)]
+#[derive(Debug, Clone)]
+pub(crate) struct ExportSettings {
+    pub(crate) format: ExportFormat,
+    pub(crate) base_image: Option<String>,
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) hashes: bool,
+    pub(crate) dev: bool,
+    pub(crate) no_emit_project: bool,
+    pub(crate) output_file: Option<PathBuf>,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) python_version: Option<PythonVersion>,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) lockfile: Option<PathBuf>,
+    pub(crate) python: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl ExportSettings {
+    /// Resolve the [`ExportSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ExportArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ExportArgs {
+            format,
+            base_image,
+            extra,
+            all_extras,
+            no_all_extras,
+            hashes,
+            no_hashes,
+            dev,
+            no_dev,
+            no_emit_project,
+            output_file,
+            python_platform,
+            python_version,
+            locked,
+            frozen,
+            lockfile,
             resolver,
             build,
             refresh,
@@ -571,8 +850,21 @@ impl LockSettings {
         } = args;
 
         Self {
+            format,
+            base_image,
+            extras: ExtrasSpecification::from_args(
+                flag(all_extras, no_all_extras).unwrap_or_default(),
+                extra.unwrap_or_default(),
+            ),
+            hashes: flag(hashes, no_hashes).unwrap_or(true),
+            dev: flag(dev, no_dev).unwrap_or(true),
+            no_emit_project,
+            output_file,
+            python_platform,
+            python_version,
             locked,
             frozen,
+            lockfile,
             python,
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -590,12 +882,16 @@ pub(crate) struct AddSettings {
     pub(crate) dependency_type: DependencyType,
     pub(crate) editable: Option<bool>,
     pub(crate) extras: Vec<ExtraName>,
+    pub(crate) bounds: AddBounds,
+    pub(crate) git: Option<String>,
+    pub(crate) path: Option<PathBuf>,
     pub(crate) raw_sources: bool,
     pub(crate) rev: Option<String>,
     pub(crate) tag: Option<String>,
     pub(crate) branch: Option<String>,
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
+    pub(crate) script: Option<PathBuf>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
 }
@@ -610,18 +906,23 @@ impl AddSettings {
             optional,
             editable,
             no_editable,
+            git,
+            path,
             extra,
+            bounds,
             raw_sources,
             rev,
             tag,
             branch,
             locked,
             frozen,
+            no_sync,
             installer,
             build,
             refresh,
             package,
             python,
+            script,
         } = args;
 
         let requirements = requirements
@@ -642,14 +943,24 @@ impl AddSettings {
             frozen,
             requirements,
             dependency_type,
+            git,
+            path,
             raw_sources,
             rev,
             tag,
             branch,
             package,
             python,
+            script,
             editable: flag(editable, no_editable),
             extras: extra.unwrap_or_default(),
+            bounds: bounds
+                .combine(
+                    filesystem
+                        .as_ref()
+                        .and_then(|filesystem| filesystem.globals.add_bounds),
+                )
+                .unwrap_or_default(),
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
@@ -665,10 +976,13 @@ impl AddSettings {
 pub(crate) struct RemoveSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
+    pub(crate) no_sync: bool,
     pub(crate) requirements: Vec<PackageName>,
     pub(crate) dependency_type: DependencyType,
+    pub(crate) keep_empty: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
+    pub(crate) script: Option<PathBuf>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
 }
@@ -680,6 +994,7 @@ impl RemoveSettings {
         let RemoveArgs {
             dev,
             optional,
+            keep_empty,
             requirements,
             locked,
             frozen,
@@ -688,6 +1003,7 @@ impl RemoveSettings {
             refresh,
             package,
             python,
+            script,
         } = args;
 
         let dependency_type = if let Some(group) = optional {
@@ -701,10 +1017,13 @@ impl RemoveSettings {
         Self {
             locked,
             frozen,
+            no_sync,
             requirements,
             dependency_type,
+            keep_empty,
             package,
             python,
+            script,
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
@@ -720,13 +1039,18 @@ impl RemoveSettings {
 pub(crate) struct TreeSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
-    pub(crate) depth: u8,
+    pub(crate) lockfile: Option<PathBuf>,
+    pub(crate) depth: Option<usize>,
     pub(crate) prune: Vec<PackageName>,
     pub(crate) package: Vec<PackageName>,
     pub(crate) no_dedupe: bool,
     pub(crate) invert: bool,
     pub(crate) show_version_specifiers: bool,
+    pub(crate) dev: DevSpecification,
     pub(crate) python: Option<String>,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) python_version: Option<PythonVersion>,
+    pub(crate) licenses: bool,
     pub(crate) resolver: ResolverSettings,
 }
 
@@ -735,27 +1059,96 @@ impl TreeSettings {
     pub(crate) fn resolve(args: TreeArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let TreeArgs {
             tree,
+            dev,
+            no_dev,
+            only_dev,
             locked,
             frozen,
+            lockfile,
             build,
             resolver,
             python,
+            python_platform,
+            python_version,
+            licenses,
         } = args;
 
         Self {
             locked,
             frozen,
+            lockfile,
             depth: tree.depth,
             prune: tree.prune,
             package: tree.package,
             no_dedupe: tree.no_dedupe,
             invert: tree.invert,
             show_version_specifiers: tree.show_version_specifiers,
+            dev: DevSpecification::from_args(
+                flag(dev, no_dev).unwrap_or(true),
+                Vec::new(),
+                if only_dev {
+                    vec![DEV_DEPENDENCIES.clone()]
+                } else {
+                    Vec::new()
+                },
+                Vec::new(),
+                &DEV_DEPENDENCIES,
+            ),
             python,
+            python_platform,
+            python_version,
+            licenses,
             resolver: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
         }
     }
 }
+
+/// The resolved settings to use for a `publish` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct PublishSettings {
+    pub(crate) repository: Option<Url>,
+    pub(crate) username: Option<String>,
+    pub(crate) password: Option<String>,
+    pub(crate) skip_existing: bool,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) python: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl PublishSettings {
+    /// Resolve the [`PublishSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PublishArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PublishArgs {
+            repository,
+            username,
+            password,
+            skip_existing,
+            package,
+            installer,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        Self {
+            repository,
+            username,
+            password,
+            skip_existing,
+            package,
+            python,
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip compile` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -871,7 +1264,7 @@ impl PipCompileSettings {
                     python,
                     system: flag(system, no_system),
                     no_build: flag(no_build, build),
-                    no_binary,
+                    no_binary: pip_no_binary(no_binary),
                     only_binary,
                     no_build_isolation: flag(no_build_isolation, build_isolation),
                     extra,
@@ -972,7 +1365,7 @@ impl PipSyncSettings {
                     require_hashes: flag(require_hashes, no_require_hashes),
                     verify_hashes: flag(verify_hashes, no_verify_hashes),
                     no_build: flag(no_build, build),
-                    no_binary,
+                    no_binary: pip_no_binary(no_binary),
                     only_binary,
                     allow_empty_requirements: flag(
                         allow_empty_requirements,
@@ -1105,7 +1498,7 @@ impl PipInstallSettings {
                     target,
                     prefix,
                     no_build: flag(no_build, build),
-                    no_binary,
+                    no_binary: pip_no_binary(no_binary),
                     only_binary,
                     no_build_isolation: flag(no_build_isolation, build_isolation),
                     strict: flag(strict, no_strict),
@@ -1294,7 +1687,7 @@ impl PipShowSettings {
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct PipTreeSettings {
-    pub(crate) depth: u8,
+    pub(crate) depth: Option<usize>,
     pub(crate) prune: Vec<PackageName>,
     pub(crate) package: Vec<PackageName>,
     pub(crate) no_dedupe: bool,
@@ -1431,6 +1824,9 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) config_setting: &'a ConfigSettings,
+    pub(crate) no_build_isolation: bool,
+    pub(crate) no_build_isolation_package: &'a [PackageName],
+    pub(crate) setup_py: SetupPyStrategy,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
@@ -1451,7 +1847,12 @@ pub(crate) struct ResolverSettings {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PreReleaseMode,
     pub(crate) config_setting: ConfigSettings,
+    pub(crate) no_build_isolation: bool,
+    pub(crate) no_build_isolation_package: Vec<PackageName>,
+    pub(crate) setup_py: SetupPyStrategy,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_index: ExcludeNewerIndex,
+    pub(crate) python_platforms: Vec<TargetTriple>,
     pub(crate) link_mode: LinkMode,
     pub(crate) upgrade: Upgrade,
     pub(crate) build_options: BuildOptions,
@@ -1465,7 +1866,12 @@ pub(crate) struct ResolverSettingsRef<'a> {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PreReleaseMode,
     pub(crate) config_setting: &'a ConfigSettings,
+    pub(crate) no_build_isolation: bool,
+    pub(crate) no_build_isolation_package: &'a [PackageName],
+    pub(crate) setup_py: SetupPyStrategy,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_index: &'a ExcludeNewerIndex,
+    pub(crate) python_platforms: &'a [TargetTriple],
     pub(crate) link_mode: LinkMode,
     pub(crate) upgrade: &'a Upgrade,
     pub(crate) build_options: &'a BuildOptions,
@@ -1484,7 +1890,12 @@ impl ResolverSettings {
             resolution,
             prerelease,
             config_settings,
+            no_build_isolation,
+            no_build_isolation_package,
+            legacy_setup_py,
             exclude_newer,
+            exclude_newer_index,
+            python_platforms,
             link_mode,
             compile_bytecode: _,
             upgrade,
@@ -1523,7 +1934,29 @@ impl ResolverSettings {
                 .config_settings
                 .combine(config_settings)
                 .unwrap_or_default(),
+            no_build_isolation: args
+                .no_build_isolation
+                .combine(no_build_isolation)
+                .unwrap_or_default(),
+            no_build_isolation_package: args
+                .no_build_isolation_package
+                .combine(no_build_isolation_package)
+                .unwrap_or_default(),
+            setup_py: if args
+                .legacy_setup_py
+                .combine(legacy_setup_py)
+                .unwrap_or_default()
+            {
+                SetupPyStrategy::Setuptools
+            } else {
+                SetupPyStrategy::Pep517
+            },
             exclude_newer: args.exclude_newer.combine(exclude_newer),
+            exclude_newer_index: exclude_newer_index.unwrap_or_default(),
+            python_platforms: args
+                .python_platforms
+                .combine(python_platforms)
+                .unwrap_or_default(),
             link_mode: args.link_mode.combine(link_mode).unwrap_or_default(),
             upgrade: Upgrade::from_args(
                 args.upgrade.combine(upgrade),
@@ -1559,7 +1992,12 @@ impl ResolverSettings {
             resolution: self.resolution,
             prerelease: self.prerelease,
             config_setting: &self.config_setting,
+            no_build_isolation: self.no_build_isolation,
+            no_build_isolation_package: &self.no_build_isolation_package,
+            setup_py: self.setup_py,
             exclude_newer: self.exclude_newer,
+            exclude_newer_index: &self.exclude_newer_index,
+            python_platforms: &self.python_platforms,
             link_mode: self.link_mode,
             upgrade: &self.upgrade,
             build_options: &self.build_options,
@@ -1581,7 +2019,12 @@ pub(crate) struct ResolverInstallerSettings {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PreReleaseMode,
     pub(crate) config_setting: ConfigSettings,
+    pub(crate) no_build_isolation: bool,
+    pub(crate) no_build_isolation_package: Vec<PackageName>,
+    pub(crate) setup_py: SetupPyStrategy,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_index: ExcludeNewerIndex,
+    pub(crate) python_platforms: Vec<TargetTriple>,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
     pub(crate) upgrade: Upgrade,
@@ -1597,7 +2040,12 @@ pub(crate) struct ResolverInstallerSettingsRef<'a> {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PreReleaseMode,
     pub(crate) config_setting: &'a ConfigSettings,
+    pub(crate) no_build_isolation: bool,
+    pub(crate) no_build_isolation_package: &'a [PackageName],
+    pub(crate) setup_py: SetupPyStrategy,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_index: &'a ExcludeNewerIndex,
+    pub(crate) python_platforms: &'a [TargetTriple],
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
     pub(crate) upgrade: &'a Upgrade,
@@ -1621,7 +2069,12 @@ impl ResolverInstallerSettings {
             resolution,
             prerelease,
             config_settings,
+            no_build_isolation,
+            no_build_isolation_package,
+            legacy_setup_py,
             exclude_newer,
+            exclude_newer_index,
+            python_platforms,
             link_mode,
             compile_bytecode,
             upgrade,
@@ -1660,7 +2113,32 @@ impl ResolverInstallerSettings {
                 .config_settings
                 .combine(config_settings)
                 .unwrap_or_default(),
+            no_build_isolation: args
+                .no_build_isolation
+                .combine(no_build_isolation)
+                .unwrap_or_default(),
+            no_build_isolation_package: args
+                .no_build_isolation_package
+                .combine(no_build_isolation_package)
+                .unwrap_or_default(),
+            setup_py: if args
+                .legacy_setup_py
+                .combine(legacy_setup_py)
+                .unwrap_or_default()
+            {
+                SetupPyStrategy::Setuptools
+            } else {
+                SetupPyStrategy::Pep517
+            },
             exclude_newer: args.exclude_newer.combine(exclude_newer),
+            exclude_newer_index: args
+                .exclude_newer_index
+                .combine(exclude_newer_index)
+                .unwrap_or_default(),
+            python_platforms: args
+                .python_platforms
+                .combine(python_platforms)
+                .unwrap_or_default(),
             link_mode: args.link_mode.combine(link_mode).unwrap_or_default(),
             compile_bytecode: args
                 .compile_bytecode
@@ -1706,7 +2184,12 @@ impl ResolverInstallerSettings {
             resolution: self.resolution,
             prerelease: self.prerelease,
             config_setting: &self.config_setting,
+            no_build_isolation: self.no_build_isolation,
+            no_build_isolation_package: &self.no_build_isolation_package,
+            setup_py: self.setup_py,
             exclude_newer: self.exclude_newer,
+            exclude_newer_index: &self.exclude_newer_index,
+            python_platforms: &self.python_platforms,
             link_mode: self.link_mode,
             compile_bytecode: self.compile_bytecode,
             upgrade: &self.upgrade,
@@ -1840,7 +2323,12 @@ impl PipSettings {
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
             config_settings: top_level_config_settings,
+            no_build_isolation: top_level_no_build_isolation,
+            no_build_isolation_package: _,
+            legacy_setup_py: top_level_legacy_setup_py,
             exclude_newer: top_level_exclude_newer,
+            exclude_newer_index: _,
+            python_platforms: _,
             link_mode: top_level_link_mode,
             compile_bytecode: top_level_compile_bytecode,
             upgrade: top_level_upgrade,
@@ -1866,6 +2354,8 @@ impl PipSettings {
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
         let config_settings = config_settings.combine(top_level_config_settings);
+        let no_build_isolation = no_build_isolation.combine(top_level_no_build_isolation);
+        let legacy_setup_py = legacy_setup_py.combine(top_level_legacy_setup_py);
         let exclude_newer = exclude_newer.combine(top_level_exclude_newer);
         let link_mode = link_mode.combine(top_level_link_mode);
         let compile_bytecode = compile_bytecode.combine(top_level_compile_bytecode);
@@ -2053,7 +2543,12 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for ResolverSettingsRef<'a> {
             resolution: settings.resolution,
             prerelease: settings.prerelease,
             config_setting: settings.config_setting,
+            no_build_isolation: settings.no_build_isolation,
+            no_build_isolation_package: settings.no_build_isolation_package,
+            setup_py: settings.setup_py,
             exclude_newer: settings.exclude_newer,
+            exclude_newer_index: settings.exclude_newer_index,
+            python_platforms: settings.python_platforms,
             link_mode: settings.link_mode,
             upgrade: settings.upgrade,
             build_options: settings.build_options,
@@ -2068,6 +2563,9 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for InstallerSettingsRef<'a> {
             index_strategy: settings.index_strategy,
             keyring_provider: settings.keyring_provider,
             config_setting: settings.config_setting,
+            no_build_isolation: settings.no_build_isolation,
+            no_build_isolation_package: settings.no_build_isolation_package,
+            setup_py: settings.setup_py,
             exclude_newer: settings.exclude_newer,
             link_mode: settings.link_mode,
             compile_bytecode: settings.compile_bytecode,
@@ -2087,6 +2585,47 @@ mod env {
 
     pub(super) const CONCURRENT_INSTALLS: (&str, &str) =
         ("UV_CONCURRENT_INSTALLS", "a non-zero integer");
+
+    /// Exposed as a boolean flag for the project interface, but read here for the `pip` interface,
+    /// in which `--no-binary` accepts a list of packages.
+    pub(super) const NO_BINARY: (&str, &str) = ("UV_NO_BINARY", "a boolean");
+}
+
+/// Resolve the `--no-binary` packages for the `pip` interface, falling back to `UV_NO_BINARY`,
+/// which disables pre-built wheels for all packages (i.e., `--no-binary :all:`).
+fn pip_no_binary(
+    no_binary: Option<Vec<PackageNameSpecifier>>,
+) -> Option<Vec<PackageNameSpecifier>> {
+    if no_binary.is_some() {
+        return no_binary;
+    }
+    let (name, expected) = env::NO_BINARY;
+    let val = match std::env::var(name) {
+        Ok(val) => val,
+        Err(VarError::NotPresent) => return None,
+        Err(VarError::NotUnicode(_)) => parse_failure(name, expected),
+    };
+    match val.to_ascii_lowercase().as_str() {
+        "y" | "yes" | "t" | "true" | "on" | "1" => Some(vec![PackageNameSpecifier::All]),
+        "n" | "no" | "f" | "false" | "off" | "0" => None,
+        _ => parse_failure(name, expected),
+    }
+}
+
+/// Resolve the categories of warnings to treat as errors, from the `--strict` flag and the
+/// `strict` setting.
+fn strict_categories(
+    strict: bool,
+    no_strict: bool,
+    filesystem: Option<&FilesystemOptions>,
+) -> Vec<StrictCategory> {
+    match flag(strict, no_strict) {
+        Some(true) => StrictCategory::all().collect(),
+        Some(false) => Vec::new(),
+        None => filesystem
+            .and_then(|filesystem| filesystem.globals.strict.clone())
+            .unwrap_or_default(),
+    }
 }
 
 /// Attempt to load and parse an environment variable with the given name.
@@ -0,0 +1,68 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext};
+
+mod common;
+
+/// Create a `cache info` command with options shared across scenarios.
+fn info_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command.arg("cache").arg("info");
+    context.add_shared_args(&mut command);
+    command
+}
+
+/// `cache info --json` should report the size of the cache and of each cached package.
+#[test]
+fn info_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r#""total_size_bytes":\d+"#, r#""total_size_bytes":[SIZE]"#),
+            (r#""size_bytes":\d+"#, r#""size_bytes":[SIZE]"#),
+            (r#""last_accessed":"[^"]+""#, r#""last_accessed":"[TIME]""#),
+        ])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, info_command(&context).arg("--json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"total_size_bytes":[SIZE],"package_count":1,"packages":[{"name":"iniconfig","version":"2.0.0","size_bytes":[SIZE],"last_accessed":"[TIME]"}]}
+
+    ----- stderr -----
+    "###);
+
+    // `--top` should limit the number of entries, but not the aggregate statistics.
+    uv_snapshot!(filters, info_command(&context).arg("--json").arg("--top").arg("0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"total_size_bytes":[SIZE],"package_count":1,"packages":[]}
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
@@ -181,3 +181,73 @@ fn prune_stale_symlink() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --package` should remove the cache entries for a single package version.
+#[test]
+fn prune_package_version() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.3.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), prune_command(&context).arg("--package").arg("anyio").arg("--version").arg("4.3.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning anyio==4.3.0 from cache at: [CACHE_DIR]/
+    Removed [N] files for anyio==4.3.0 ([SIZE])
+    "###);
+
+    // Other versions of the package are unaffected.
+    uv_snapshot!(context.filters(), prune_command(&context).arg("--package").arg("anyio").arg("--version").arg("4.2.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning anyio==4.2.0 from cache at: [CACHE_DIR]/
+    No cache entries found for anyio==4.2.0
+    "###);
+
+    // The package's dependencies remain in the cache.
+    uv_snapshot!(context.filters(), prune_command(&context).arg("--package").arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning idna from cache at: [CACHE_DIR]/
+    Removed [N] files for idna ([SIZE])
+    "###);
+
+    Ok(())
+}
+
+/// `cache prune --version` requires `--package`.
+#[test]
+fn prune_version_without_package() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), prune_command(&context).arg("--version").arg("4.3.0"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --package <PACKAGE>
+
+    Usage: uv cache prune --cache-dir [CACHE_DIR] --package <PACKAGE> --version <VERSION>
+
+    For more information, try '--help'.
+    "###);
+}
@@ -430,6 +430,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv export` command with options shared across scenarios.
+    pub fn export(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("export");
+        self.add_shared_args(&mut command);
+        command
+    }
+
+    /// Create a `uv publish` command with options shared across scenarios.
+    pub fn publish(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("publish");
+        self.add_shared_args(&mut command);
+        command
+    }
+
     /// Create a `uv python find` command with options shared across scenarios.
     pub fn python_find(&self) -> Command {
         let mut command = Command::new(get_bin());
@@ -480,6 +496,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool ps` command with options shared across scenarios.
+    pub fn tool_ps(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("tool").arg("ps");
+        self.add_shared_args(&mut command);
+        command
+    }
+
+    /// Create a `uv tool stop` command with options shared across scenarios.
+    pub fn tool_stop(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("tool").arg("stop");
+        self.add_shared_args(&mut command);
+        command
+    }
+
     /// Create a `uv tool install` command with options shared across scenarios.
     pub fn tool_install(&self) -> std::process::Command {
         let mut command = self.tool_install_without_exclude_newer();
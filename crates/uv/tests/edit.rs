@@ -1,6 +1,7 @@
 #![cfg(all(feature = "python", feature = "pypi"))]
 
 use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
 use indoc::indoc;
 use insta::assert_snapshot;
@@ -745,10 +746,13 @@ fn add_remove_optional() -> Result<()> {
 
     ----- stderr -----
     warning: `uv add` is experimental and may change without warning
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
      + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
     "###);
 
     let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
@@ -772,7 +776,7 @@ fn add_remove_optional() -> Result<()> {
         );
     });
 
-    // `uv add` implies a full lock and sync, including development dependencies.
+    // `uv add` implies a full lock and sync, including optional dependencies.
     let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
 
     insta::with_settings!({
@@ -784,10 +788,46 @@ fn add_remove_optional() -> Result<()> {
         requires-python = ">=3.12"
         exclude-newer = "2024-03-25 00:00:00 UTC"
 
+        [[distribution]]
+        name = "anyio"
+        version = "3.7.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz", hash = "sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce", size = 142737 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl", hash = "sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0", size = 80873 },
+        ]
+
+        [[distribution]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567 },
+        ]
+
         [[distribution]]
         name = "project"
         version = "0.1.0"
         source = { editable = "." }
+
+        [distribution.optional-dependencies]
+        io = [
+            { name = "anyio" },
+        ]
+
+        [[distribution]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235 },
+        ]
         "###
         );
     });
@@ -800,7 +840,7 @@ fn add_remove_optional() -> Result<()> {
 
     ----- stderr -----
     warning: `uv sync` is experimental and may change without warning
-    Audited 1 package in [TIME]
+    Audited 4 packages in [TIME]
     "###);
 
     // This should fail without --optional.
@@ -825,10 +865,13 @@ fn add_remove_optional() -> Result<()> {
     warning: `uv remove` is experimental and may change without warning
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
+    Uninstalled 4 packages in [TIME]
     Installed 1 package in [TIME]
+     - anyio==3.7.0
+     - idna==3.6
      - project==0.1.0 (from file://[TEMP_DIR]/)
      + project==0.1.0 (from file://[TEMP_DIR]/)
+     - sniffio==1.3.1
     "###);
 
     let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
@@ -843,9 +886,6 @@ fn add_remove_optional() -> Result<()> {
         version = "0.1.0"
         requires-python = ">=3.12"
         dependencies = []
-
-        [project.optional-dependencies]
-        io = []
         "###
         );
     });
@@ -883,6 +923,98 @@ fn add_remove_optional() -> Result<()> {
     Ok(())
 }
 
+/// Add and remove optional dependencies in an existing group, matching the group by its normalized
+/// name.
+#[test]
+fn add_remove_optional_group() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        IO = []
+    "#})?;
+
+    // Reject invalid group names.
+    uv_snapshot!(context.filters(), context.add(&["anyio==3.7.0"]).arg("--optional=io!").arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'io!' for '--optional <OPTIONAL>': Extra names must start and end with a letter or digit and may only contain -, _, ., and alphanumeric characters
+
+    For more information, try '--help'.
+    "###);
+
+    uv_snapshot!(context.filters(), context.add(&["anyio==3.7.0"]).arg("--optional=io").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        IO = [
+            "anyio==3.7.0",
+        ]
+        "###
+        );
+    });
+
+    // Retain the group once it's empty.
+    uv_snapshot!(context.filters(), context.remove(&["anyio"]).arg("--optional=io").arg("--keep-empty").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv remove` is experimental and may change without warning
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        IO = []
+        "###
+        );
+    });
+
+    Ok(())
+}
+
 /// Add and remove a workspace dependency.
 #[test]
 fn add_remove_workspace() -> Result<()> {
@@ -1617,6 +1749,121 @@ fn add_no_clean() -> Result<()> {
     Ok(())
 }
 
+/// Reinstall a single package with `--reinstall-package` while adding a dependency.
+#[test]
+fn add_reinstall_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio == 3.7.0",
+        ]
+    "#})?;
+
+    context.sync().assert().success();
+
+    // Only the requested package (and the project itself) should be reinstalled.
+    uv_snapshot!(context.filters(), context.add(&["iniconfig==2.0.0"]).arg("--reinstall-package").arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    Resolved 5 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 3 packages in [TIME]
+     - idna==3.6
+     + idna==3.6
+     + iniconfig==2.0.0
+     - project==0.1.0 (from file://[TEMP_DIR]/)
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // Requesting a package that isn't part of the resolution should error.
+    uv_snapshot!(context.filters(), context.add(&["iniconfig==2.0.0"]).arg("--reinstall-package").arg("typing-extensions"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    Resolved 5 packages in [TIME]
+    error: Package `typing-extensions` was marked for reinstallation, but is not included in the resolved requirements
+    hint: Remove `typing-extensions` from `--reinstall-package`, or add it to the project's dependencies.
+    "###);
+
+    Ok(())
+}
+
+/// Adding a dependency should preserve the existing pins, rather than re-resolving them.
+#[test]
+fn add_preserves_locked_versions() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio<4",
+        ]
+    "#})?;
+
+    context.lock().assert().success();
+
+    // Relax the constraint, such that a newer version of `anyio` is available.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio",
+        ]
+    "#})?;
+
+    // The locked version of `anyio` should be retained.
+    uv_snapshot!(context.filters(), context.add(&["iniconfig"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + anyio==3.7.1
+     + idna==3.6
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    // Unless an upgrade is requested.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 5 packages in [TIME]
+    Updating anyio v3.7.1 -> v4.3.0
+    "###);
+
+    Ok(())
+}
+
 /// Remove a PyPI requirement.
 #[test]
 fn remove_registry() -> Result<()> {
@@ -1723,8 +1970,10 @@ fn remove_registry() -> Result<()> {
     Ok(())
 }
 
+/// Removing a dependency prunes its exclusive transitive dependencies from the lockfile and the
+/// environment, but retains any that are still required elsewhere.
 #[test]
-fn add_preserves_indentation_in_pyproject_toml() -> Result<()> {
+fn remove_shared_transitive() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -1732,39 +1981,97 @@ fn add_preserves_indentation_in_pyproject_toml() -> Result<()> {
         [project]
         name = "project"
         version = "0.1.0"
-        # ...
         requires-python = ">=3.12"
-        dependencies = [
-          "anyio==3.7.0"
-        ]
+        dependencies = ["anyio==3.7.0", "idna", "requests==2.31.0"]
     "#})?;
 
-    uv_snapshot!(context.filters(), context.add(&["requests==2.31.0"]), @r###"
+    context.sync().assert().success();
+
+    // `idna` is shared by `anyio` and `requests`, so it should survive the removal.
+    uv_snapshot!(context.filters(), context.remove(&["requests"]), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    warning: `uv add` is experimental and may change without warning
-    Resolved 8 packages in [TIME]
-    Prepared 8 packages in [TIME]
-    Installed 8 packages in [TIME]
-     + anyio==3.7.0
-     + certifi==2024.2.2
-     + charset-normalizer==3.3.2
-     + idna==3.6
+    warning: `uv remove` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 5 packages in [TIME]
+    Installed 1 package in [TIME]
+     - certifi==2024.2.2
+     - charset-normalizer==3.3.2
+     - project==0.1.0 (from file://[TEMP_DIR]/)
      + project==0.1.0 (from file://[TEMP_DIR]/)
-     + requests==2.31.0
-     + sniffio==1.3.1
-     + urllib3==2.2.1
+     - requests==2.31.0
+     - urllib3==2.2.1
     "###);
 
-    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(lock.contains("name = \"idna\""));
+    assert!(!lock.contains("name = \"urllib3\""));
 
-    insta::with_settings!({
-        filters => context.filters(),
-    }, {
-        assert_snapshot!(
+    // Removing a direct dependency that's still required transitively should explain why it was
+    // kept; with `--no-sync`, the environment should be left untouched.
+    uv_snapshot!(context.filters(), context.remove(&["idna"]).arg("--no-sync"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv remove` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    Kept `idna` in the lockfile, as it's still required by: `anyio`
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(lock.contains("name = \"idna\""));
+
+    Ok(())
+}
+
+#[test]
+fn add_preserves_indentation_in_pyproject_toml() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        # ...
+        requires-python = ">=3.12"
+        dependencies = [
+          "anyio==3.7.0"
+        ]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["requests==2.31.0"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    Resolved 8 packages in [TIME]
+    Prepared 8 packages in [TIME]
+    Installed 8 packages in [TIME]
+     + anyio==3.7.0
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + requests==2.31.0
+     + sniffio==1.3.1
+     + urllib3==2.2.1
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
             pyproject_toml, @r###"
         [project]
         name = "project"
@@ -1884,3 +2191,932 @@ fn add_frozen() -> Result<()> {
 
     Ok(())
 }
+
+/// Add requirements with local, epoch, post, and dev version segments.
+#[test]
+fn add_version_segments() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        # ...
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["torch==2.3.1+cu121", "pkg-a==1!2.0", "pkg-b==1.0.post1", "pkg-c>=1.0.dev3"]).arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        # ...
+        requires-python = ">=3.12"
+        dependencies = [
+            "torch==2.3.1+cu121",
+            "pkg-a==1!2.0",
+            "pkg-b==1.0.post1",
+            "pkg-c>=1.0.dev3",
+        ]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Add a URL requirement that references an environment variable. The variable should be
+/// expanded when resolving, but persisted as-is in the `pyproject.toml`.
+#[test]
+fn add_url_env_var() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        # ...
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["iniconfig @ https://${FILES_HOST}/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"]).arg("--preview").env("FILES_HOST", "files.pythonhosted.org"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl)
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        # ...
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig @ https://${FILES_HOST}/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+        ]
+        "###
+        );
+    });
+
+    // Install from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").env("FILES_HOST", "files.pythonhosted.org"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Audited 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Add a requirement with `--locked`, which should fail if the lockfile would change, leaving the
+/// `pyproject.toml` untouched.
+#[test]
+fn add_locked() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        # ...
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 1 package in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.add(&["iniconfig==2.0.0"]).arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided.
+    hint: To update the lockfile, run `uv lock`.
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        # ...
+        requires-python = ">=3.12"
+        dependencies = []
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// If the requested version can't be resolved, suggest the nearest version that can.
+#[test]
+fn add_nearest_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["iniconfig==3.0.0"]), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of iniconfig==3.0.0 and project==0.1.0 depends on iniconfig==3.0.0, we can conclude that project==0.1.0 cannot be used.
+          And because only project==0.1.0 is available and you require project, we can conclude that the requirements are unsatisfiable.
+    hint: `iniconfig==2.0.0` is the nearest version that resolves; try `uv add iniconfig==2.0.0`
+    "###);
+
+    // The `pyproject.toml` should be unchanged.
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "###
+        );
+    });
+
+    // In quiet mode, the search is skipped.
+    uv_snapshot!(context.filters(), context.add(&["iniconfig==3.0.0"]).arg("--quiet"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of iniconfig==3.0.0 and project==0.1.0 depends on iniconfig==3.0.0, we can conclude that project==0.1.0 cannot be used.
+          And because only project==0.1.0 is available and you require project, we can conclude that the requirements are unsatisfiable.
+    "###);
+
+    Ok(())
+}
+
+/// Add and remove development dependencies in a PEP 735 `dev` group, if one exists.
+#[test]
+fn add_remove_dev_dependency_group() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [dependency-groups]
+        # Development tooling.
+        dev = []
+    "#})?;
+
+    // Adding a production dependency as a development dependency should warn, and skip it.
+    uv_snapshot!(context.filters(), context.add(&["iniconfig"]).arg("--dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    warning: `iniconfig` is already a production dependency; skipping
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    context
+        .add(&["anyio==3.7.0"])
+        .arg("--dev")
+        .assert()
+        .success();
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [dependency-groups]
+        # Development tooling.
+        dev = [
+            "anyio==3.7.0",
+        ]
+        "###
+        );
+    });
+
+    context.remove(&["anyio"]).arg("--dev").assert().success();
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [dependency-groups]
+        # Development tooling.
+        dev = []
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Add a Git requirement via `--git`, then update its source in place.
+#[test]
+fn add_git_flag() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.sources]
+        # Pinned for reproducibility.
+        Uv_Public_Pypackage = { git = "https://github.com/astral-test/uv-public-pypackage", branch = "main" } # Pinned.
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["uv-public-pypackage"]).arg("--git").arg("https://github.com/astral-test/uv-public-pypackage").arg("--tag=0.0.1").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "uv-public-pypackage",
+        ]
+
+        [tool.uv.sources]
+        # Pinned for reproducibility.
+        Uv_Public_Pypackage = { git = "https://github.com/astral-test/uv-public-pypackage", tag = "0.0.1" } # Pinned.
+        "###
+        );
+    });
+
+    // Multiple packages can't share a single `--git` source.
+    uv_snapshot!(context.filters(), context.add(&["uv-public-pypackage", "anyio"]).arg("--git").arg("https://github.com/astral-test/uv-public-pypackage").arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    error: `--git` and `--path` can only be used to add a single package
+    "###);
+
+    // Removing the dependency also removes its source.
+    uv_snapshot!(context.filters(), context.remove(&["uv-public-pypackage"]).arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv remove` is experimental and may change without warning
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.sources]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Add a local directory via `--path`, inferring the package name from the directory.
+#[test]
+fn add_path_flag() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    let child = context.temp_dir.child("packages").child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&[]).arg("--path").arg("packages/child").arg("--editable").arg("--dev").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    "###);
+
+    // Adding the same package as a production dependency retains the source once the development
+    // dependency is removed.
+    uv_snapshot!(context.filters(), context.add(&["child"]).arg("--path").arg("packages/child").arg("--editable").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    "###);
+
+    uv_snapshot!(context.filters(), context.remove(&["child"]).arg("--dev").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv remove` is experimental and may change without warning
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "child",
+        ]
+
+        [tool.uv]
+        dev-dependencies = []
+
+        [tool.uv.sources]
+        child = { path = "./packages/child", editable = true }
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Write version bounds for requirements added without a version specifier.
+#[test]
+fn add_bounds() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        add-bounds = "lower"
+    "#})?;
+
+    // The bounds are read from the configuration by default.
+    uv_snapshot!(context.filters(), context.add(&["iniconfig"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig>=2.0.0",
+        ]
+
+        [tool.uv]
+        add-bounds = "lower"
+        "###
+        );
+    });
+
+    // Re-adding the package updates the existing bound.
+    context
+        .add(&["iniconfig"])
+        .arg("--bounds")
+        .arg("exact")
+        .assert()
+        .success();
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig==2.0.0",
+        ]
+
+        [tool.uv]
+        add-bounds = "lower"
+        "###
+        );
+    });
+
+    context
+        .add(&["iniconfig"])
+        .arg("--bounds")
+        .arg("caret")
+        .assert()
+        .success();
+
+    // Explicit version specifiers are written as given.
+    context
+        .add(&["anyio>=3"])
+        .arg("--bounds")
+        .arg("exact")
+        .assert()
+        .success();
+
+    context
+        .add(&["sniffio"])
+        .arg("--bounds")
+        .arg("none")
+        .assert()
+        .success();
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig>=2.0.0,<3",
+            "anyio>=3",
+            "sniffio",
+        ]
+
+        [tool.uv]
+        add-bounds = "lower"
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Add requirements to the inline metadata of a PEP 723 script.
+#[test]
+fn add_script() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! {r#"
+        #!/usr/bin/env python3
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #     "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+        import sniffio
+
+        print("ok")
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["sniffio"]).arg("--script").arg("script.py").arg("--bounds").arg("lower"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Updated `script.py`
+    "###);
+
+    let script_content = fs_err::read_to_string(context.temp_dir.join("script.py"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            script_content, @r###"
+        #!/usr/bin/env python3
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #     "iniconfig",
+        #     "sniffio>=1.3.1",
+        # ]
+        # ///
+
+        import iniconfig
+        import sniffio
+
+        print("ok")
+        "###
+        );
+    });
+
+    // The edited script should run with the added requirement.
+    uv_snapshot!(context.filters(), context.run().arg("--preview").arg("script.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ok
+
+    ----- stderr -----
+    Reading inline script metadata from: script.py
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    "###);
+
+    Ok(())
+}
+
+/// Add a requirement to a script without inline metadata, creating the `script` block.
+#[test]
+fn add_script_init() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! {r#"
+        #!/usr/bin/env python3
+        import iniconfig
+
+        print("ok")
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["iniconfig"]).arg("--script").arg("script.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    Resolved 1 package in [TIME]
+    Updated `script.py`
+    "###);
+
+    let script_content = fs_err::read_to_string(context.temp_dir.join("script.py"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            script_content, @r###"
+        #!/usr/bin/env python3
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = [
+        #     "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+
+        print("ok")
+        "###
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.run().arg("--preview").arg("script.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ok
+
+    ----- stderr -----
+    Reading inline script metadata from: script.py
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Edit the inline metadata of a script with CRLF line endings, preserving them.
+#[test]
+fn add_script_crlf() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("script.py");
+    script.write_str(concat!(
+        "# /// script\r\n",
+        "# requires-python = \">=3.11\"\r\n",
+        "# dependencies = []\r\n",
+        "# ///\r\n",
+        "\r\n",
+        "import iniconfig\r\n",
+        "\r\n",
+        "print(\"ok\")\r\n",
+    ))?;
+
+    context
+        .add(&["iniconfig"])
+        .arg("--script")
+        .arg("script.py")
+        .assert()
+        .success();
+
+    let script_content = fs_err::read_to_string(context.temp_dir.join("script.py"))?;
+    assert_eq!(
+        script_content,
+        concat!(
+            "# /// script\r\n",
+            "# requires-python = \">=3.11\"\r\n",
+            "# dependencies = [\r\n",
+            "#     \"iniconfig\",\r\n",
+            "# ]\r\n",
+            "# ///\r\n",
+            "\r\n",
+            "import iniconfig\r\n",
+            "\r\n",
+            "print(\"ok\")\r\n",
+        )
+    );
+
+    uv_snapshot!(context.filters(), context.run().arg("--preview").arg("script.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ok
+
+    ----- stderr -----
+    Reading inline script metadata from: script.py
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Refuse to edit a script whose `script` block is never closed.
+#[test]
+fn add_script_malformed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let contents = indoc! {r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = []
+
+        import iniconfig
+    "#};
+    let script = context.temp_dir.child("script.py");
+    script.write_str(contents)?;
+
+    uv_snapshot!(context.filters(), context.add(&["iniconfig"]).arg("--script").arg("script.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning
+    error: Failed to read inline script metadata from: `script.py`
+      Caused by: The `# /// script` metadata block is not closed by a `# ///` line
+    "###);
+
+    // The script should be unchanged.
+    assert_eq!(
+        fs_err::read_to_string(context.temp_dir.join("script.py"))?,
+        contents
+    );
+
+    Ok(())
+}
+
+/// Remove a requirement from the inline metadata of a PEP 723 script.
+#[test]
+fn remove_script() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! {r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #     "anyio",
+        #     "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+
+        print("ok")
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.remove(&["anyio"]).arg("--script").arg("script.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv remove` is experimental and may change without warning
+    Updated `script.py`
+    "###);
+
+    let script_content = fs_err::read_to_string(context.temp_dir.join("script.py"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            script_content, @r###"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #     "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+
+        print("ok")
+        "###
+        );
+    });
+
+    // Removing a requirement that isn't present should fail.
+    uv_snapshot!(context.filters(), context.remove(&["anyio"]).arg("--script").arg("script.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv remove` is experimental and may change without warning
+    error: The dependency `anyio` could not be found in `dependencies`
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("--preview").arg("script.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ok
+
+    ----- stderr -----
+    Reading inline script metadata from: script.py
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
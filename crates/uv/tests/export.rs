@@ -0,0 +1,362 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use common::{uv_snapshot, TestContext};
+
+mod common;
+
+#[test]
+fn dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated via `uv export`.
+    anyio==3.7.0
+    idna==3.6
+    -e .
+    sniffio==1.3.1
+
+    ----- stderr -----
+    warning: `uv export` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn dependency_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.optional-dependencies]
+        async = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated via `uv export`.
+    iniconfig==2.0.0
+    -e .
+
+    ----- stderr -----
+    warning: `uv export` is experimental and may change without warning
+    Resolved 5 packages in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes").arg("--extra").arg("async"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated via `uv export`.
+    anyio==3.7.0
+    idna==3.6
+    iniconfig==2.0.0
+    -e .
+    sniffio==1.3.1
+
+    ----- stderr -----
+    warning: `uv export` is experimental and may change without warning
+    Resolved 5 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn dependency_marker() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0 ; sys_platform == 'darwin'", "iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated via `uv export`.
+    anyio==3.7.0 ; sys_platform == 'darwin'
+    idna==3.6 ; sys_platform == 'darwin'
+    iniconfig==2.0.0
+    -e .
+    sniffio==1.3.1 ; sys_platform == 'darwin'
+
+    ----- stderr -----
+    warning: `uv export` is experimental and may change without warning
+    Resolved 5 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Export only the packages that are required on a given target platform.
+#[test]
+fn python_platform() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0 ; sys_platform == 'darwin'", "iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes").arg("--python-platform").arg("x86_64-unknown-linux-gnu"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated via `uv export`.
+    iniconfig==2.0.0
+    -e .
+
+    ----- stderr -----
+    warning: `uv export` is experimental and may change without warning
+    Resolved 5 packages in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes").arg("--python-platform").arg("aarch64-apple-darwin"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated via `uv export`.
+    anyio==3.7.0 ; sys_platform == 'darwin'
+    idna==3.6 ; sys_platform == 'darwin'
+    iniconfig==2.0.0
+    -e .
+    sniffio==1.3.1 ; sys_platform == 'darwin'
+
+    ----- stderr -----
+    warning: `uv export` is experimental and may change without warning
+    Resolved 5 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Omit the development dependencies and the project itself, writing the export to a file.
+#[test]
+fn no_dev_no_emit_project() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        dev-dependencies = ["typing-extensions"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated via `uv export`.
+    iniconfig==2.0.0
+    -e .
+    typing-extensions==4.10.0
+
+    ----- stderr -----
+    warning: `uv export` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes").arg("--no-dev").arg("--no-emit-project").arg("--output-file").arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated via `uv export`.
+    iniconfig==2.0.0
+
+    ----- stderr -----
+    warning: `uv export` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    "###);
+
+    let requirements = fs_err::read_to_string(context.temp_dir.join("requirements.txt"))?;
+    assert_eq!(
+        requirements,
+        "# This file was autogenerated via `uv export`.\niniconfig==2.0.0\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "git")]
+fn dependency_git() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0.0.1"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated via `uv export`.
+    -e .
+    uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979
+
+    ----- stderr -----
+    warning: `uv export` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn dockerfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child", "iniconfig"]
+
+        [project.optional-dependencies]
+        async = ["anyio==3.7.0"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("packages").child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(env!("CARGO_PKG_VERSION"), "[VERSION]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.export().arg("--format").arg("dockerfile").arg("--extra").arg("async"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated via `uv export`.
+    FROM python:3.12-slim
+    COPY --from=ghcr.io/astral-sh/uv:[VERSION] /uv /bin/uv
+
+    WORKDIR /app
+    ENV PATH="/app/.venv/bin:$PATH"
+
+    # Install the dependencies, without the workspace members.
+    COPY pyproject.toml uv.lock ./
+    COPY packages/child/pyproject.toml packages/child/
+    RUN uv sync --frozen --extra async --no-install-workspace
+
+    # Install the workspace members.
+    COPY . .
+    RUN uv sync --frozen --extra async
+
+    ----- stderr -----
+    warning: `uv export` is experimental and may change without warning
+    Resolved 6 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn license_csv() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("license-csv"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    name,version,license,source
+    iniconfig,2.0.0,MIT,License-Expression
+    project,0.1.0,unknown,
+
+    ----- stderr -----
+    warning: `uv export` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    warning: No license detected for: `project`
+    "###);
+
+    Ok(())
+}
@@ -556,3 +556,223 @@ fn init_workspace_isolated() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn init_app() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo-bar").arg("--template").arg("app"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning
+    Initialized project `foo-bar` at `[TEMP_DIR]/foo-bar`
+    "###);
+
+    let pyproject = fs_err::read_to_string(context.temp_dir.join("foo-bar/pyproject.toml"))?;
+    let main_py = fs_err::read_to_string(context.temp_dir.join("foo-bar/src/foo_bar/__main__.py"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r###"
+        [project]
+        name = "foo-bar"
+        version = "0.1.0"
+        description = "Add your description here"
+        readme = "README.md"
+        dependencies = []
+
+        [project.scripts]
+        foo-bar = "foo_bar.__main__:main"
+
+        [tool.uv]
+        dev-dependencies = []
+        "###
+        );
+    });
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            main_py, @r###"
+        def main() -> None:
+            print("Hello from foo-bar!")
+
+
+        if __name__ == "__main__":
+            main()
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Initialize an application that isn't built as a package.
+#[test]
+fn init_app_no_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo-bar").arg("--app").arg("--no-package"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning
+    Initialized project `foo-bar` at `[TEMP_DIR]/foo-bar`
+    "###);
+
+    let pyproject = fs_err::read_to_string(context.temp_dir.join("foo-bar/pyproject.toml"))?;
+    let main_py = fs_err::read_to_string(context.temp_dir.join("foo-bar/main.py"))?;
+    assert!(!context.temp_dir.join("foo-bar/src").exists());
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r###"
+        [project]
+        name = "foo-bar"
+        version = "0.1.0"
+        description = "Add your description here"
+        readme = "README.md"
+        dependencies = []
+
+        [tool.uv]
+        package = false
+        dev-dependencies = []
+        "###
+        );
+    });
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            main_py, @r###"
+        def main() -> None:
+            print("Hello from foo-bar!")
+
+
+        if __name__ == "__main__":
+            main()
+        "###
+        );
+    });
+
+    // `--no-package` is only supported for applications.
+    uv_snapshot!(context.filters(), context.init().arg("baz").arg("--no-package"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning
+    error: `--no-package` is only supported for applications; pass `--app` to create one
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn init_template_path() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let template = context.temp_dir.child("template");
+    template.child("pyproject.toml").write_str(indoc! {
+        r#"
+        [project]
+        name = "{{project_name}}"
+        version = "0.1.0"
+        requires-python = ">={{python_version}}"
+        dependencies = []
+        "#,
+    })?;
+    template
+        .child("src")
+        .child("{{module_name}}")
+        .child("__init__.py")
+        .write_str("NAME = \"{{project_name}}\"\n")?;
+
+    uv_snapshot!(context.filters(), context.init().arg("foo-bar").arg("--template").arg("template"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning
+    Initialized project `foo-bar` at `[TEMP_DIR]/foo-bar`
+    "###);
+
+    let pyproject = fs_err::read_to_string(context.temp_dir.join("foo-bar/pyproject.toml"))?;
+    let init_py = fs_err::read_to_string(context.temp_dir.join("foo-bar/src/foo_bar/__init__.py"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r###"
+        [project]
+        name = "foo-bar"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "###
+        );
+    });
+
+    assert_eq!(init_py, "NAME = \"foo-bar\"\n");
+
+    // A template without a `pyproject.toml` is rejected.
+    let empty = context.temp_dir.child("empty");
+    empty.child("README.md").touch()?;
+
+    uv_snapshot!(context.filters(), context.init().arg("baz").arg("--template").arg("empty"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning
+    error: Template at `empty` does not contain a `pyproject.toml`
+    "###);
+
+    Ok(())
+}
+
+/// Initialize a project from a template in a Git repository.
+#[test]
+#[cfg(feature = "git")]
+fn init_template_git() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init()
+        .arg("foo-bar")
+        .arg("--template")
+        .arg("git+https://github.com/astral-test/uv-public-pypackage@0.0.1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning
+    Initialized project `foo-bar` at `[TEMP_DIR]/foo-bar`
+    "###);
+
+    // The files in the repository are rendered, but neither the `.git` directory nor the marker
+    // for a completed checkout are.
+    let project = context.temp_dir.child("foo-bar");
+    project
+        .child("pyproject.toml")
+        .assert(predicates::path::is_file());
+    project.child(".git").assert(predicates::path::missing());
+    project.child(".ok").assert(predicates::path::missing());
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 #![cfg(all(feature = "python", feature = "pypi"))]
 
 use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
 use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
@@ -1610,6 +1611,151 @@ fn lock_upgrade_log() -> Result<()> {
     Ok(())
 }
 
+/// Upgrade a single package with `lock --upgrade-package`, leaving the others pinned.
+#[test]
+fn lock_upgrade_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig<2", "typing-extensions<4.10"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Relax the constraints, such that both packages could be upgraded.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "typing-extensions"]
+        "#,
+    )?;
+
+    // Only `iniconfig` should be upgraded; `anyio` isn't in the lockfile, so we should warn.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("iniconfig").arg("--upgrade-package").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    warning: `anyio` was passed to `--upgrade-package`, but is not included in the lockfile
+    Resolved 3 packages in [TIME]
+    Updating iniconfig v1.1.1 -> v2.0.0
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(lock.contains("name = \"typing-extensions\"\nversion = \"4.9.0\""));
+
+    Ok(())
+}
+
+/// Check whether the lockfile is up-to-date with `lock --check` and `lock --dry-run`, without
+/// writing it.
+#[test]
+fn lock_check() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig<2"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Lockfile is up-to-date: uv.lock
+    "###);
+
+    // Add a dependency, such that the lockfile is out-of-date.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig<2", "typing-extensions<4.10"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    Would update lockfile at: uv.lock
+    Adding typing-extensions v4.9.0
+    "###);
+
+    uv_snapshot!(context.filters(), context.lock().arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    Would update lockfile at: uv.lock
+    Adding typing-extensions v4.9.0
+    "###);
+
+    // Relax the `iniconfig` constraint, and preview a targeted upgrade.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "typing-extensions<4.10"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--dry-run").arg("--upgrade-package").arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    Updating iniconfig v1.1.1 -> v2.0.0
+    Would update lockfile at: uv.lock
+    Adding typing-extensions v4.9.0
+    "###);
+
+    // The lockfile should be unchanged.
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(lock.contains("name = \"iniconfig\"\nversion = \"1.1.1\""));
+    assert!(!lock.contains("typing-extensions"));
+
+    Ok(())
+}
+
 /// Show updated dependencies on `lock --upgrade`, with a package that resolves to multiple
 /// versions.
 #[test]
@@ -3776,6 +3922,162 @@ fn lock_cycles() -> Result<()> {
     Ok(())
 }
 
+/// Lock a project with chained self-referential extras (e.g., `project[a]` depending on
+/// `project[b]`).
+#[test]
+fn lock_self_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        a = ["project[b]"]
+        b = ["project[c]"]
+        c = ["iniconfig"]
+        "#,
+    )?;
+
+    deterministic! { context =>
+        uv_snapshot!(context.filters(), context.lock(), @r###"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        warning: `uv lock` is experimental and may change without warning
+        Resolved 2 packages in [TIME]
+        "###);
+
+        let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
+
+        insta::with_settings!({
+            filters => context.filters(),
+        }, {
+            assert_snapshot!(
+                lock, @r###"
+            version = 1
+            requires-python = ">=3.12"
+            exclude-newer = "2024-03-25 00:00:00 UTC"
+
+            [[distribution]]
+            name = "iniconfig"
+            version = "2.0.0"
+            source = { registry = "https://pypi.org/simple" }
+            sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+            wheels = [
+                { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+            ]
+
+            [[distribution]]
+            name = "project"
+            version = "0.1.0"
+            source = { editable = "." }
+
+            [distribution.optional-dependencies]
+            a = [
+                { name = "project", extra = ["b"] },
+            ]
+            b = [
+                { name = "project", extra = ["c"] },
+            ]
+            c = [
+                { name = "iniconfig" },
+            ]
+            "###
+            );
+        });
+    }
+
+    // Install the chained extras from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--extra").arg("a"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    Ok(())
+}
+
+/// Treat the selection of a yanked version as an error with `--strict` or the `strict` setting.
+#[test]
+fn lock_strict_yanked() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["attrs==21.1.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--strict"), @r###"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    error: `attrs==21.1.0` is yanked (reason: "Installable but not importable on Python 3.4.") (`yanked` warnings are treated as errors)
+    "###);
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["attrs==21.1.0"]
+
+        [tool.uv]
+        strict = ["yanked"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    error: `attrs==21.1.0` is yanked (reason: "Installable but not importable on Python 3.4.") (`yanked` warnings are treated as errors)
+    "###);
+
+    // `--no-strict` overrides the setting.
+    uv_snapshot!(context.filters(), context.lock().arg("--no-strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    warning: `attrs==21.1.0` is yanked (reason: "Installable but not importable on Python 3.4.")
+    "###);
+
+    Ok(())
+}
+
 /// Ensures that stale lockfile metadata is detected.
 #[test]
 fn lock_new_extras() -> Result<()> {
@@ -4438,3 +4740,451 @@ fn lock_same_version_multiple_urls() -> Result<()> {
 
     Ok(())
 }
+
+/// Lock with per-invocation `--index` and `--default-index` flags, ensuring that the existing
+/// lockfile is ignored if it references an index that is no longer in use.
+#[test]
+fn lock_index_flags() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // PyPI is still in use (via `--index`), so the existing lockfile is respected.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--default-index")
+        .arg("https://test.pypi.org/simple")
+        .arg("--index")
+        .arg("https://pypi.org/simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // PyPI is no longer in use, so the existing lockfile is ignored.
+    uv_snapshot!(context.filters(), context.lock().arg("--no-index"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Ignoring existing lockfile due to removal of index: `https://pypi.org/simple`
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the provided package locations and project==0.1.0 depends on iniconfig==2.0.0, we can conclude that project==0.1.0 cannot be used.
+          And because only project==0.1.0 is available and you require project, we can conclude that the requirements are unsatisfiable.
+
+          hint: Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `--find-links <uri>`)
+    "###);
+
+    Ok(())
+}
+
+/// Lock and sync against a lockfile other than `uv.lock`, via `--lockfile` and `UV_LOCKFILE`.
+#[test]
+fn lock_custom_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--lockfile").arg("minimal.lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // The custom lockfile should be written, without touching `uv.lock`.
+    assert!(context.temp_dir.child("minimal.lock").exists());
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    // Missing lockfiles should be reported by path.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: Unable to find lockfile at `uv.lock`.
+    hint: To create a lockfile, run `uv lock` or `uv sync`.
+    "###);
+
+    // The custom lockfile should be respected by `--frozen` and `--locked`, including via the
+    // environment variable.
+    uv_snapshot!(context.filters(), context.sync().arg("--locked").env("UV_LOCKFILE", "minimal.lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // Staleness checks should compare against the selected lockfile.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "typing-extensions"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--lockfile").arg("minimal.lock"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    error: The lockfile at `minimal.lock` needs to be updated, but `--locked` was provided.
+    hint: To update the lockfile, run `uv lock`.
+    "###);
+
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    Ok(())
+}
+
+/// Lock with a per-index `exclude-newer` override, which takes precedence over the global
+/// `exclude-newer` setting for the given index.
+#[test]
+fn lock_exclude_newer_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        extra-index-url = ["https://test.pypi.org/simple"]
+        index-strategy = "unsafe-best-match"
+        exclude-newer-index = { "https://pypi.org/simple" = "2023-01-01", "https://test.pypi.org/simple" = "2000-01-01" }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+
+    // `iniconfig==2.0.0` was published after the PyPI cutoff, so `iniconfig==1.1.1` is selected,
+    // even though the global `exclude-newer` would allow it.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+        exclude-newer = "2024-03-25 00:00:00 UTC"
+
+        [[distribution]]
+        name = "iniconfig"
+        version = "1.1.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/23/a2/97899f6bd0e873fed3a7e67ae8d3a08b21799430fb4da15cfedf10d6e2c2/iniconfig-1.1.1.tar.gz", hash = "sha256:bc3af051d7d14b2ee5ef9969666def0cd1a000e121eaea580d4a313df4b37f32", size = 8104 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/9b/dd/b3c12c6d707058fa947864b67f0c4e0c39ef8610988d7baea9578f3c48f3/iniconfig-1.1.1-py2.py3-none-any.whl", hash = "sha256:011e24c64b7f47f6ebd835bb12a743f2fbe9a26d4cecaa7f53bc4f35ee9da8b3", size = 4990 },
+        ]
+
+        [[distribution]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Lock a project for a specific platform, omitting dependencies that only apply to other
+/// platforms.
+#[test]
+fn lock_python_platform() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "colorama ; sys_platform == 'win32'"]
+    "#})?;
+
+    // By default, the lockfile includes dependencies for all platforms.
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(lock.contains("name = \"colorama\""));
+
+    // When locking for Linux only, `colorama` is omitted, and the platform is recorded.
+    uv_snapshot!(context.filters(), context.lock().arg("--python-platform").arg("x86_64-unknown-linux-gnu"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Ignoring existing lockfile due to addition of platforms: `x86_64-unknown-linux-gnu`
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(!lock.contains("name = \"colorama\""));
+    assert!(lock.contains("python-platforms = [\"x86_64-unknown-linux-gnu\"]"));
+
+    // Subsequent locks respect the recorded platforms, rather than widening the lockfile.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // When locking for Linux and Windows, `colorama` is included.
+    uv_snapshot!(context.filters(), context.lock().arg("--python-platform").arg("x86_64-unknown-linux-gnu").arg("--python-platform").arg("x86_64-pc-windows-msvc"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Ignoring existing lockfile due to change in platforms: `x86_64-unknown-linux-gnu` vs. `x86_64-pc-windows-msvc, x86_64-unknown-linux-gnu`
+    Resolved 3 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(lock.contains("name = \"colorama\""));
+    assert!(lock
+        .contains("python-platforms = [\"x86_64-pc-windows-msvc\", \"x86_64-unknown-linux-gnu\"]"));
+
+    // Syncing on a platform that isn't covered by the lockfile fails, rather than omitting the
+    // dependencies for the current platform.
+    let foreign = if cfg!(target_os = "macos") {
+        "aarch64-unknown-linux-gnu"
+    } else {
+        "aarch64-apple-darwin"
+    };
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(foreign, "[PLATFORM]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.lock().arg("--python-platform").arg(foreign), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Ignoring existing lockfile due to change in platforms: `x86_64-pc-windows-msvc, x86_64-unknown-linux-gnu` vs. `[PLATFORM]`
+    Resolved 2 packages in [TIME]
+    "###);
+
+    uv_snapshot!(filters, context.sync().arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: The current platform is not included in the lockfile, which is restricted to: `[PLATFORM]`
+    hint: Run `uv lock --python-platform` with a platform that matches the current environment to include it in the lockfile.
+    "###);
+
+    Ok(())
+}
+
+/// Lock the project for a narrower range of Python versions than its `requires-python`.
+#[test]
+fn lock_python_range() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.8"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--python-range").arg(">=3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // The narrowed range is recorded in the lockfile.
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(lock.contains("requires-python = \">=3.12\"\npython-range = \">=3.12\""));
+
+    // Subsequent locks respect the recorded range.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // A range that doesn't overlap with the workspace `requires-python` is rejected.
+    uv_snapshot!(context.filters(), context.lock().arg("--python-range").arg("<3.8"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    error: The requested Python range (`<3.8`) does not overlap with the project Python requirement: `>=3.8`
+    hint: Provide a `--python-range` that overlaps with `>=3.8`.
+    "###);
+
+    // Providing the workspace range removes the narrowed range from the lockfile.
+    uv_snapshot!(context.filters(), context.lock().arg("--python-range").arg(">=3.8"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(lock.contains("requires-python = \">=3.8\""));
+    assert!(!lock.contains("python-range"));
+
+    Ok(())
+}
+
+/// Resolve the project without a direct dependency, to debug an unsatisfiable resolution.
+#[test]
+fn lock_exclude_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // `anyio==3.7.0` requires `sniffio>=1.1`.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "sniffio==1.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().failure();
+
+    // The transitive dependency on `sniffio` is retained.
+    uv_snapshot!(context.filters(), context.lock().arg("--exclude-package").arg("sniffio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==3.7.0
+    idna==3.6
+    sniffio==1.3.1
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    The lockfile was left unchanged; use `--write` to update it
+    "###);
+
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicates::path::missing());
+
+    uv_snapshot!(context.filters(), context.lock().arg("--exclude-package").arg("iniconfig").arg("--exclude-package").arg("sniffio").arg("--write"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==3.7.0
+    idna==3.6
+    sniffio==1.3.1
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    warning: `iniconfig` is not a direct dependency of any workspace member
+    Resolved 4 packages in [TIME]
+    "###);
+
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicates::path::exists());
+
+    Ok(())
+}
@@ -11039,3 +11039,66 @@ fn ignore_invalid_constraint() -> Result<()> {
 
     Ok(())
 }
+
+/// Resolve two local requirements that depend on the same local directory (under different
+/// markers), which should only fetch the directory's metadata once.
+#[test]
+fn compile_shared_direct_url_metadata() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    let child_url = Url::from_file_path(child.path()).unwrap();
+
+    for (name, marker) in [("a", ""), ("b", " ; python_version >= '3.12'")] {
+        context
+            .temp_dir
+            .child(name)
+            .child("pyproject.toml")
+            .write_str(&format!(
+                indoc! {r#"
+                [project]
+                name = "{}"
+                version = "0.1.0"
+                requires-python = ">=3.12"
+                dependencies = ["child @ {}{}"]
+
+                [build-system]
+                requires = ["setuptools>=42"]
+                build-backend = "setuptools.build_meta"
+                "#},
+                name, child_url, marker
+            ))?;
+    }
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("./a\n./b")?;
+
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--verbose")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+
+    // Both `a` and `b` require `child`, but its metadata is only fetched once: the second request
+    // waits for the first, rather than reading the metadata again (e.g., from the cache).
+    let fetches = stderr
+        .lines()
+        .filter(|line| line.contains("metadata for: ") && line.trim_end().ends_with("/child"))
+        .count();
+    assert_eq!(fetches, 1, "{stderr}");
+
+    Ok(())
+}
@@ -2050,6 +2050,91 @@ fn only_binary_requirements_txt() {
     );
 }
 
+/// `UV_NO_BUILD` is equivalent to `--no-build`.
+#[test]
+fn no_build_env() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("django_allauth==0.51.0")
+        .arg("--strict")
+        .env("UV_NO_BUILD", "1"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because django-allauth==0.51.0 has no usable wheels and building from source is disabled and you require django-allauth==0.51.0, we can conclude that the requirements are unsatisfiable.
+    "###
+    );
+
+    // A falsy value leaves builds enabled.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio")
+        .arg("--strict")
+        .env("UV_NO_BUILD", "false"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+}
+
+/// `UV_NO_BINARY` is equivalent to `--no-binary :all:`.
+#[test]
+fn no_binary_env() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // With `--reinstall`, the package should be rebuilt from source.
+    let context = context.with_filtered_counts();
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio")
+        .arg("--reinstall-package")
+        .arg("anyio")
+        .arg("--strict")
+        .env("UV_NO_BINARY", "1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     - anyio==4.3.0
+     + anyio==4.3.0
+    "###
+    );
+
+    context.assert_command("import anyio").success();
+}
+
 /// `--only-binary` does not apply to editable requirements
 #[test]
 fn only_binary_editable() {
@@ -5788,6 +5873,54 @@ fn recursive_extra_transitive_url() -> Result<()> {
     Ok(())
 }
 
+/// Install a package with chained self-referential extras, then verify that the environment
+/// is considered satisfied on a subsequent install.
+#[test]
+fn recursive_extra_chained() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.0.0"
+        dependencies = []
+
+        [project.optional-dependencies]
+        a = ["project[b]"]
+        b = ["project[c]"]
+        c = ["iniconfig"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg(".[a]"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.0.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg(".[a]"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
 /// If a package is requested as both editable and non-editable, always install it as editable.
 #[test]
 fn prefer_editable() -> Result<()> {
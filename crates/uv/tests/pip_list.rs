@@ -546,13 +546,12 @@ Version: 0.1-bulbasaur
 
     uv_snapshot!(filters, list_command(&context)
         .arg("--editable"), @r###"
-    success: false
-    exit_code: 2
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to read metadata: from [SITE_PACKAGES]/paramiko.egg-link
-     Caused by: after parsing '0.1-b', found 'ulbasaur', which is not part of a valid version
+    warning: Ignoring invalid distribution at `[SITE_PACKAGES]/paramiko.egg-link`: after parsing '0.1-b', found 'ulbasaur', which is not part of a valid version
     "###
     );
 
@@ -3165,6 +3165,41 @@ fn compile() -> Result<()> {
     Ok(())
 }
 
+/// Install with bytecode compilation, enabled via `UV_COMPILE_BYTECODE`.
+#[test]
+fn compile_env() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict")
+        .env("UV_COMPILE_BYTECODE", "1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 3 files in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    assert!(context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__")
+        .join("__init__.cpython-312.pyc")
+        .exists());
+
+    Ok(())
+}
+
 /// Raise an error when an editable's `Requires-Python` constraint is not met.
 #[test]
 fn requires_python_editable() -> Result<()> {
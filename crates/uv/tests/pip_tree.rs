@@ -344,6 +344,7 @@ fn depth() {
     exit_code: 0
     ----- stdout -----
     scikit-learn v1.4.1.post1
+    └── ...
 
     ----- stderr -----
 
@@ -366,6 +367,7 @@ fn depth() {
     scikit-learn v1.4.1.post1
     ├── numpy v1.26.4
     ├── scipy v1.12.0
+    │   └── ...
     ├── joblib v1.3.2
     └── threadpoolctl v3.4.0
 
@@ -871,8 +873,8 @@ fn cyclic_dependency() {
     uv-cyclic-dependencies-c v0.1.0
     └── uv-cyclic-dependencies-a v0.1.0
         └── uv-cyclic-dependencies-b v0.1.0
-            └── uv-cyclic-dependencies-a v0.1.0 (*)
-    (*) Package tree already displayed
+            └── uv-cyclic-dependencies-a v0.1.0 (cycle)
+    (cycle) Package tree is a cycle and cannot be shown
 
     ----- stderr -----
     "###
@@ -1229,8 +1231,8 @@ fn no_dedupe_and_cycle() {
     uv-cyclic-dependencies-c v0.1.0
     └── uv-cyclic-dependencies-a v0.1.0
         └── uv-cyclic-dependencies-b v0.1.0
-            └── uv-cyclic-dependencies-a v0.1.0 (*)
-    (*) Package tree is a cycle and cannot be shown
+            └── uv-cyclic-dependencies-a v0.1.0 (cycle)
+    (cycle) Package tree is a cycle and cannot be shown
 
     ----- stderr -----
     "###
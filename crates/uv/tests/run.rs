@@ -1,6 +1,11 @@
 #![cfg(all(feature = "python", feature = "pypi"))]
 
-use anyhow::Result;
+use std::io::{BufRead, BufReader, Write};
+use std::process::Stdio;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
 use indoc::indoc;
@@ -104,7 +109,7 @@ fn run_with_python_version() -> Result<()> {
 
     ----- stderr -----
     Using Python 3.11.[X] interpreter at: [PYTHON-3.11]
-    Removed virtual environment at: .venv
+    Removed virtual environment at: .venv (Python 3.12.[X], created by uv), since Python 3.11 was requested
     Creating virtualenv at: .venv
     Resolved 5 packages in [TIME]
     Prepared 4 packages in [TIME]
@@ -134,6 +139,7 @@ fn run_with_python_version() -> Result<()> {
     ----- stderr -----
     Using Python 3.8.[X] interpreter at: [PYTHON-3.8]
     error: The requested Python interpreter (3.8.[X]) is incompatible with the project Python requirement: `>=3.11, <4`
+    hint: Try setting `requires-python` to include `3.8.[X]` in your `pyproject.toml`.
     "###);
 
     Ok(())
@@ -402,6 +408,70 @@ fn run_with() -> Result<()> {
     Ok(())
 }
 
+/// Run with `--extra`, re-syncing the project environment when the selected extras change.
+#[test]
+fn run_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["iniconfig"]
+
+        [project.optional-dependencies]
+        types = ["typing-extensions>=4"]
+        async = ["sniffio"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--extra").arg("types").arg("python").arg("-c").arg("import typing_extensions"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/)
+     + iniconfig==2.0.0
+     + typing-extensions==4.10.0
+    "###);
+
+    // Selecting a different extra should install its dependencies, rather than treating the
+    // environment as already satisfied.
+    uv_snapshot!(context.filters(), context.run().arg("--extra").arg("async").arg("python").arg("-c").arg("import sniffio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sniffio==1.3.1
+    "###);
+
+    // Re-running with the same extra should leave the environment untouched.
+    uv_snapshot!(context.filters(), context.run().arg("--extra").arg("async").arg("python").arg("-c").arg("import sniffio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    Audited 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn run_locked() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -425,7 +495,8 @@ fn run_locked() -> Result<()> {
 
     ----- stderr -----
     warning: `uv run` is experimental and may change without warning
-    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    error: Unable to find lockfile at `uv.lock`.
+    hint: To create a lockfile, run `uv lock` or `uv sync`.
     "###);
 
     // Lock the initial requirements.
@@ -453,7 +524,8 @@ fn run_locked() -> Result<()> {
     ----- stderr -----
     warning: `uv run` is experimental and may change without warning
     Resolved 2 packages in [TIME]
-    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided.
+    hint: To update the lockfile, run `uv lock`.
     "###);
 
     let updated = fs_err::read_to_string(context.temp_dir.child("uv.lock"))?;
@@ -506,7 +578,8 @@ fn run_frozen() -> Result<()> {
 
     ----- stderr -----
     warning: `uv run` is experimental and may change without warning
-    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    error: Unable to find lockfile at `uv.lock`.
+    hint: To create a lockfile, run `uv lock` or `uv sync`.
     "###);
 
     context.lock().assert().success();
@@ -541,3 +614,125 @@ fn run_frozen() -> Result<()> {
 
     Ok(())
 }
+
+/// Running a `.py` file that doesn't exist should fail before resolving anything.
+#[test]
+fn run_missing_script() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.run().arg("--preview").arg("missing.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: File not found: `missing.py`
+    "###);
+}
+
+/// The exit code of the child process should be propagated.
+#[test]
+fn run_exit_code() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.run().arg("--preview").arg("python").arg("-c").arg("import sys; sys.exit(42)"), @r###"
+    success: false
+    exit_code: 42
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+}
+
+/// The Python version requested in a script's shebang should be respected when running outside a project.
+#[test]
+fn run_script_shebang() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r#"
+        #!/usr/bin/env python3.11
+        import sys
+
+        print(".".join(map(str, sys.version_info[:2])))
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--preview").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11
+
+    ----- stderr -----
+    "###);
+
+    // An explicit `--python` request takes precedence over the shebang.
+    uv_snapshot!(context.filters(), context.run().arg("--preview").arg("--python").arg("3.12").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// With `--unbuffered`, the child's output should be delivered line by line through a pipe, rather
+/// than when the child exits.
+#[test]
+fn run_unbuffered() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r#"
+        import sys
+
+        print("ready")
+        sys.stdin.readline()
+        print("done")
+       "#
+    })?;
+
+    let mut child = context
+        .run()
+        .arg("--preview")
+        .arg("--unbuffered")
+        .arg("main.py")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    let stdout = child.stdout.take().context("Missing stdout")?;
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    // The first line must arrive while the script is still blocked on standard input.
+    let first = receiver.recv_timeout(Duration::from_secs(60));
+
+    // Unblock the script, such that it exits regardless of the outcome.
+    child
+        .stdin
+        .take()
+        .context("Missing stdin")?
+        .write_all(b"\n")?;
+    let status = child.wait()?;
+
+    assert_eq!(
+        first.context("Timed out waiting for the first line of output")??,
+        "ready"
+    );
+    assert_eq!(receiver.iter().collect::<Result<Vec<_>, _>>()?, ["done"]);
+    assert!(status.success());
+
+    Ok(())
+}
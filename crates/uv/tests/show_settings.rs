@@ -59,6 +59,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -66,6 +67,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -193,6 +195,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -200,6 +203,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -328,6 +332,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -335,6 +340,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -495,6 +501,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -502,6 +509,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -631,6 +639,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -638,6 +647,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -753,6 +763,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -760,6 +771,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -912,6 +924,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -919,6 +932,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -1071,6 +1085,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -1078,6 +1093,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -1275,6 +1291,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -1282,6 +1299,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -1433,6 +1451,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -1440,6 +1459,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -1561,6 +1581,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -1568,6 +1589,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -1717,6 +1739,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -1724,6 +1747,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -1897,6 +1921,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -1904,6 +1929,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2015,6 +2041,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -2022,6 +2049,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2133,6 +2161,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -2140,6 +2169,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2253,6 +2283,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -2260,6 +2291,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2398,6 +2430,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -2405,6 +2438,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2544,6 +2578,7 @@ fn resolve_both() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: OnlySystem,
         python_fetch: Automatic,
+        python_install_mirror: [],
         no_progress: false,
     }
     CacheSettings {
@@ -2551,6 +2586,7 @@ fn resolve_both() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_stats: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2,9 +2,11 @@
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
+use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
+use indoc::indoc;
 
-use common::{uv_snapshot, TestContext};
+use common::{site_packages_path, uv_snapshot, TestContext};
 
 mod common;
 
@@ -66,7 +68,8 @@ fn locked() -> Result<()> {
 
     ----- stderr -----
     warning: `uv sync` is experimental and may change without warning
-    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    error: Unable to find lockfile at `uv.lock`.
+    hint: To create a lockfile, run `uv lock` or `uv sync`.
     "###);
 
     // Lock the initial requirements.
@@ -94,7 +97,8 @@ fn locked() -> Result<()> {
     ----- stderr -----
     warning: `uv sync` is experimental and may change without warning
     Resolved 2 packages in [TIME]
-    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided.
+    hint: To update the lockfile, run `uv lock`.
     "###);
 
     let updated = fs_err::read_to_string(context.temp_dir.child("uv.lock"))?;
@@ -128,7 +132,8 @@ fn frozen() -> Result<()> {
 
     ----- stderr -----
     warning: `uv sync` is experimental and may change without warning
-    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    error: Unable to find lockfile at `uv.lock`.
+    hint: To create a lockfile, run `uv lock` or `uv sync`.
     "###);
 
     context.lock().assert().success();
@@ -162,3 +167,2282 @@ fn frozen() -> Result<()> {
 
     Ok(())
 }
+
+/// Refuse to sync with `--frozen` if the lockfile is missing a workspace member.
+#[test]
+fn frozen_missing_member() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    // Add a workspace member after locking.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.workspace]
+        members = ["child"]
+    "#})?;
+
+    context
+        .temp_dir
+        .child("child")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // Running with `--frozen` should error, since the lockfile doesn't include `child`.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: The lockfile at `uv.lock` does not include workspace member `child`, but `--frozen` was provided.
+    hint: The lockfile is out of date with the workspace; to update it, run `uv lock`.
+    "###);
+
+    Ok(())
+}
+
+/// Sync with `--no-build-isolation`, such that builds can access packages installed in the
+/// project environment.
+#[test]
+fn sync_build_isolation() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0", "setuptools==69.2.0", "wheel==0.43.0"]
+        "#,
+    )?;
+
+    // Install the build dependencies into the project environment.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + setuptools==69.2.0
+     + wheel==0.43.0
+    "###);
+
+    // Add a local dependency whose `setup.py` imports a package that is only available in the
+    // project environment.
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+    child.child("setup.py").write_str(indoc! {r#"
+        import iniconfig
+        from setuptools import setup
+
+        setup(name="child", version="0.1.0")
+    "#})?;
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0", "setuptools==69.2.0", "wheel==0.43.0", "child"]
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#,
+    )?;
+
+    // With build isolation, the build should fail, since `iniconfig` isn't available.
+    context.sync().assert().failure();
+
+    // Without build isolation, the build should see the project environment.
+    context
+        .sync()
+        .arg("--no-build-isolation")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// Sync with `--no-build-isolation-package`, to disable isolation for a specific package.
+#[test]
+fn sync_build_isolation_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0", "setuptools==69.2.0", "wheel==0.43.0"]
+        "#,
+    )?;
+
+    // Install the build dependencies into the project environment.
+    context.sync().assert().success();
+
+    // Add a local dependency whose `setup.py` imports a package that is only available in the
+    // project environment.
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+    child.child("setup.py").write_str(indoc! {r#"
+        import iniconfig
+        from setuptools import setup
+
+        setup(name="child", version="0.1.0")
+    "#})?;
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0", "setuptools==69.2.0", "wheel==0.43.0", "child"]
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#,
+    )?;
+
+    // Disabling isolation for an unrelated package should have no effect.
+    context
+        .sync()
+        .arg("--no-build-isolation-package")
+        .arg("iniconfig")
+        .assert()
+        .failure();
+
+    // Disabling isolation for the package itself should allow the build to succeed.
+    context
+        .sync()
+        .arg("--no-build-isolation-package")
+        .arg("child")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// Sync with `--inexact`, which should leave extraneous packages in place.
+#[test]
+fn sync_inexact() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    // Manually install a package that isn't part of the project.
+    uv_snapshot!(context.filters(), context.pip_install().arg("typing-extensions"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + typing-extensions==4.10.0
+    "###);
+
+    // An inexact sync should leave the extraneous package in place.
+    uv_snapshot!(context.filters(), context.sync().arg("--inexact"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    context.assert_command("import typing_extensions").success();
+
+    // An exact sync should remove it.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+     - typing-extensions==4.10.0
+    "###);
+
+    context.assert_command("import typing_extensions").failure();
+
+    Ok(())
+}
+
+/// Perform a dry run, which should report changes without modifying the environment.
+#[test]
+fn sync_dry_run() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    // A dry run shouldn't write the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Would update lockfile at: uv.lock
+    Would download 2 packages
+    Would install 2 packages
+     + iniconfig==2.0.0
+     + project @ file://[TEMP_DIR]/
+    "###);
+
+    assert!(!context.temp_dir.child("uv.lock").exists());
+    context.assert_command("import iniconfig").failure();
+
+    // With `--check`, the dry run should fail if any changes would be made.
+    uv_snapshot!(context.filters(), context.sync().arg("--dry-run").arg("--check"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Would update lockfile at: uv.lock
+    Would download 2 packages
+    Would install 2 packages
+     + iniconfig==2.0.0
+     + project @ file://[TEMP_DIR]/
+    "###);
+
+    // Sync the environment.
+    context.sync().assert().success();
+
+    // Once the environment is in sync, the check should pass.
+    uv_snapshot!(context.filters(), context.sync().arg("--dry-run").arg("--check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    Would make no changes
+    "###);
+
+    // `--locked` should still be respected.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "typing-extensions"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--dry-run").arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided.
+    hint: To update the lockfile, run `uv lock`.
+    "###);
+
+    Ok(())
+}
+
+/// Sync a project with bytecode compilation.
+#[test]
+fn sync_compile_bytecode() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["MarkupSafe==2.1.3"]
+    "#})?;
+
+    // The number of compiled files depends on how the project itself is built.
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"Bytecode compiled \d+ files",
+            "Bytecode compiled [N] files",
+        )])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.sync().arg("--compile-bytecode"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+    Bytecode compiled [N] files in [TIME]
+     + markupsafe==2.1.3
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    assert!(context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__")
+        .join("__init__.cpython-312.pyc")
+        .exists());
+
+    Ok(())
+}
+
+/// Reinstall a single package with `--reinstall-package`.
+#[test]
+fn sync_reinstall_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    context.sync().assert().success();
+
+    // Only the requested package should be reinstalled.
+    uv_snapshot!(context.filters(), context.sync().arg("--reinstall-package").arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==2.0.0
+     + iniconfig==2.0.0
+    "###);
+
+    // Requesting a package that isn't part of the resolution should error.
+    uv_snapshot!(context.filters(), context.sync().arg("--reinstall-package").arg("typing-extensions"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    error: Package `typing-extensions` was marked for reinstallation, but is not included in the resolved requirements
+    hint: Remove `typing-extensions` from `--reinstall-package`, or add it to the project's dependencies.
+    "###);
+
+    Ok(())
+}
+
+/// Sync an environment containing distributions with malformed metadata, as sometimes left behind
+/// by other tools.
+#[test]
+fn sync_invalid_distributions() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    context.sync().assert().success();
+
+    // Truncate the `METADATA` file of an installed package, and add a `.dist-info` directory
+    // without any `METADATA` file at all.
+    let site_packages = ChildPath::new(context.site_packages());
+    site_packages
+        .child("iniconfig-2.0.0.dist-info")
+        .child("METADATA")
+        .write_str("")?;
+    site_packages
+        .child("foo-1.0.0.dist-info")
+        .create_dir_all()?;
+
+    // The invalid distributions should be ignored, and `iniconfig` should be reinstalled.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    warning: Ignoring invalid distribution at `[SITE_PACKAGES]/foo-1.0.0.dist-info`: The `METADATA` file is missing or empty
+    warning: Ignoring invalid distribution at `[SITE_PACKAGES]/iniconfig-2.0.0.dist-info`: The `METADATA` file is missing or empty
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // With `--dry-run`, `--repair` should report the remaining invalid distribution.
+    uv_snapshot!(context.filters(), context.sync().arg("--repair").arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    warning: Ignoring invalid distribution at `[SITE_PACKAGES]/foo-1.0.0.dist-info`: The `METADATA` file is missing or empty
+    Would remove invalid distribution at: [SITE_PACKAGES]/foo-1.0.0.dist-info
+    Audited 2 packages in [TIME]
+    Would make no changes
+    "###);
+
+    site_packages
+        .child("foo-1.0.0.dist-info")
+        .assert(predicates::path::is_dir());
+
+    // `--repair` should remove it.
+    uv_snapshot!(context.filters(), context.sync().arg("--repair"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    warning: Ignoring invalid distribution at `[SITE_PACKAGES]/foo-1.0.0.dist-info`: The `METADATA` file is missing or empty
+    Removed invalid distribution at: [SITE_PACKAGES]/foo-1.0.0.dist-info
+    Audited 2 packages in [TIME]
+    "###);
+
+    site_packages
+        .child("foo-1.0.0.dist-info")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
+
+/// Sync a project that requires a specific Python implementation via
+/// `tool.uv.python-implementation`.
+#[test]
+fn sync_python_implementation() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        python-implementation = "cpython"
+    "#})?;
+
+    context.sync().assert().success();
+
+    // The existing CPython environment should be reused.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    "###);
+
+    // If the project requires PyPy, the CPython environment should be rejected, even though its
+    // Python version satisfies `requires-python`.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        python-implementation = "pypy"
+    "#})?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(" or `py` launcher", "")])
+        .collect();
+
+    uv_snapshot!(filters, context.sync().arg("--python-fetch").arg("manual"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: No interpreter found for PyPy >=3.12 in system path
+    "###);
+
+    // An unknown implementation should be rejected.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        python-implementation = "jython"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: Invalid `tool.uv.python-implementation` setting: Unknown Python implementation `jython`
+    hint: Set `tool.uv.python-implementation` to one of `cpython`, `pypy`, or `graalpy`.
+    "###);
+
+    Ok(())
+}
+
+/// Sync a project that depends on a source distribution without a `pyproject.toml`, using
+/// `setuptools` directly.
+#[test]
+fn sync_legacy_setup_py() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["flake8 @ https://files.pythonhosted.org/packages/66/53/3ad4a3b74d609b3b9008a10075c40e7c8909eae60af53623c3888f7a529a/flake8-6.0.0.tar.gz"]
+
+        [tool.uv]
+        legacy-setup-py = true
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + flake8==6.0.0 (from https://files.pythonhosted.org/packages/66/53/3ad4a3b74d609b3b9008a10075c40e7c8909eae60af53623c3888f7a529a/flake8-6.0.0.tar.gz)
+     + mccabe==0.7.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + pycodestyle==2.10.0
+     + pyflakes==3.0.1
+    "###);
+
+    Ok(())
+}
+
+/// Sync with `--exclude-group`, omitting packages that are only reachable through the excluded
+/// group.
+#[test]
+fn sync_exclude_group() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "sniffio"]
+
+        [tool.uv]
+        dev-dependencies = ["anyio==3.7.0"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 5 packages in [TIME]
+    "###);
+
+    // Excluding the `dev` group should omit `anyio` and `idna`, but retain `sniffio`, which is
+    // also a production dependency.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--exclude-group").arg("dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    // Including the `dev` group (the default) should install the remaining packages.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+    "###);
+
+    // `--no-dev` is equivalent to `--exclude-group dev`.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--no-dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Uninstalled 2 packages in [TIME]
+     - anyio==3.7.0
+     - idna==3.6
+    "###);
+
+    Ok(())
+}
+
+/// Sync a project with `--extra`, including the extra's dependencies in the environment.
+#[test]
+fn sync_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.optional-dependencies]
+        types = ["typing-extensions>=4"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--extra").arg("types"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + typing-extensions==4.10.0
+    "###);
+
+    // Requesting an extra that isn't defined by the project should error.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--extra").arg("async"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: Extra `async` is not defined in the project's `optional-dependencies` table (available extras: `types`)
+    hint: Remove `--extra async`, or define the extra under `[project.optional-dependencies]` in your `pyproject.toml`.
+    "###);
+
+    // Add a new extra without updating the lockfile.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.optional-dependencies]
+        types = ["typing-extensions>=4"]
+        async = ["sniffio"]
+    "#})?;
+
+    // Under `--frozen`, the extra is missing from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--extra").arg("async"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: The lockfile does not contain a resolution for extra `async`.
+    hint: To update the lockfile, run `uv lock`.
+    "###);
+
+    // Otherwise, the project is re-locked to include the extra.
+    uv_snapshot!(context.filters(), context.sync().arg("--extra").arg("async"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     - project==0.1.0 (from file://[TEMP_DIR]/)
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+     - typing-extensions==4.10.0
+    "###);
+
+    Ok(())
+}
+
+/// Sync a project with PEP 735 dependency groups, using `--group` and `--only-group`.
+#[test]
+fn sync_group() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        dev-dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        docs = ["sniffio"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // By default, only the `dev` group is installed.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + typing-extensions==4.10.0
+    "###);
+
+    // Include the `docs` group.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--group").arg("docs"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sniffio==1.3.1
+    "###);
+
+    // Install only the `docs` group, alongside the project itself.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--only-group").arg("docs"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Uninstalled 2 packages in [TIME]
+     - iniconfig==2.0.0
+     - typing-extensions==4.10.0
+    "###);
+
+    // Requesting an unknown group should error.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--group").arg("doc"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: Group `doc` is not defined in the project's `dependency-groups` table (available groups: `dev`, `docs`)
+    hint: Define the `doc` group under `[dependency-groups]` in your `pyproject.toml`.
+    "###);
+
+    Ok(())
+}
+
+/// Development dependencies are scoped to the workspace members that declare them: syncing a
+/// member installs its own development dependencies, but not those of the members it depends on.
+#[test]
+fn sync_package_dev_dependencies() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv]
+        dev-dependencies = ["iniconfig"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child"]
+    "#})?;
+
+    context
+        .temp_dir
+        .child("child")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        dev-dependencies = ["typing-extensions"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // The root project's development dependencies are installed, but not the child's.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // Syncing the child installs its own development dependencies only.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--package").arg("child"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Prepared 1 package in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==2.0.0
+     - project==0.1.0 (from file://[TEMP_DIR]/)
+     + typing-extensions==4.10.0
+    "###);
+
+    // Omitting development dependencies leaves the child alone.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--package").arg("child").arg("--no-dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Uninstalled 1 package in [TIME]
+     - typing-extensions==4.10.0
+    "###);
+
+    // Selecting a package that isn't in the workspace should fail.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--package").arg("sibling"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: Package `sibling` not found in workspace
+    "###);
+
+    Ok(())
+}
+
+/// Replacing a virtual environment that wasn't created by uv should warn, and describe the
+/// environment being removed.
+#[test]
+fn sync_replace_foreign_environment() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_virtualenv_bin();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    // Replace the existing environment with one that appears to be created by `virtualenv`, but
+    // whose interpreter no longer exists.
+    fs_err::remove_dir_all(&context.venv)?;
+    context.venv.child("pyvenv.cfg").write_str(indoc! { r"
+        home = /nonexistent/bin
+        implementation = CPython
+        version_info = 3.10.4.final.0
+        virtualenv = 20.25.0
+        include-system-site-packages = false
+        base-prefix = /nonexistent
+        base-exec-prefix = /nonexistent
+        base-executable = /nonexistent/bin/python3.10
+        "
+    })?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    warning: Ignoring existing virtual environment linked to non-existent Python interpreter: .venv/[BIN]/python
+    Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+    warning: The virtual environment at `.venv` was not created by uv (created by virtualenv) and will be replaced
+    Removed virtual environment at: .venv (Python 3.10.4, created by virtualenv)
+    Creating virtualenv at: .venv
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    Ok(())
+}
+
+/// Ignoring an existing virtual environment with a missing interpreter is an error with
+/// `--strict`.
+#[test]
+fn sync_strict_missing_interpreter() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_virtualenv_bin();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    // Replace the existing environment with one whose interpreter no longer exists.
+    fs_err::remove_dir_all(&context.venv)?;
+    context.venv.child("pyvenv.cfg").write_str(indoc! { r"
+        home = /nonexistent/bin
+        implementation = CPython
+        version_info = 3.10.4.final.0
+        include-system-site-packages = false
+        "
+    })?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--strict"), @r###"
+    success: false
+    exit_code: 4
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: Existing virtual environment is linked to non-existent Python interpreter: .venv/[BIN]/python (`missing-interpreter` warnings are treated as errors)
+    "###);
+
+    Ok(())
+}
+
+/// Exclude the project, workspace members, or specific packages from installation.
+#[test]
+fn no_install_project() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // Install the dependencies, but not the project itself.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--no-install-project"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###);
+
+    // A subsequent sync should install the project, without reinstalling the dependencies.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // Exclude a specific package.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--no-install-package").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Uninstalled 1 package in [TIME]
+     - anyio==3.7.0
+    "###);
+
+    // Exclude all workspace members.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--no-install-workspace"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==3.7.0
+     - project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    Ok(())
+}
+
+/// Projects with `tool.uv.package = false` have their dependencies installed, but are never built
+/// or installed themselves.
+#[test]
+fn sync_non_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [tool.uv]
+        package = false
+        "#,
+    )?;
+
+    // Multiple top-level packages would cause the default `setuptools` build to fail.
+    context.temp_dir.child("alpha/__init__.py").touch()?;
+    context.temp_dir.child("beta/__init__.py").touch()?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###);
+
+    // The project is still included in the lockfile.
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(lock.contains("name = \"project\""));
+
+    // `uv run` respects the setting, too.
+    uv_snapshot!(context.filters(), context.run().arg("python").arg("-c").arg("import anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    Audited 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// A workspace member with `tool.uv.package = false` can't be omitted if another installed member
+/// depends on it.
+#[test]
+fn sync_non_package_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child"]
+    "#})?;
+
+    context
+        .temp_dir
+        .child("child")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        package = false
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    error: Workspace member `project` depends on `child`, which sets `tool.uv.package = false` and can't be installed
+    hint: Remove `tool.uv.package = false` from `child`'s `pyproject.toml` so that it can be built, or remove the dependency on it.
+    "###);
+
+    // If the dependent isn't installed, the member is omitted, but its dependencies are retained.
+    uv_snapshot!(context.filters(), context.sync().arg("--no-install-project"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // If the member is only reachable via a dependency group, it's only required when that group is
+    // installed.
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        dev-dependencies = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--no-dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    error: Workspace member `project` depends on `child`, which sets `tool.uv.package = false` and can't be installed
+    hint: Remove `tool.uv.package = false` from `child`'s `pyproject.toml` so that it can be built, or remove the dependency on it.
+    "###);
+
+    Ok(())
+}
+
+/// Sync with `--require-hashes`, which exempts local directories from hash checking.
+#[test]
+fn sync_require_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Skipping hash verification for local directory: project
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    Ok(())
+}
+
+/// Reject a distribution whose hash doesn't match the hash recorded in the lockfile.
+#[test]
+fn sync_hash_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Replace the locked hash for the `iniconfig` wheel.
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    let digest = regex::Regex::new(r"sha256:[0-9a-f]{64}")?;
+    let lock = lock
+        .lines()
+        .map(|line| {
+            if line.contains("iniconfig-2.0.0-py3-none-any.whl") {
+                digest
+                    .replace(line, format!("sha256:{}", "0".repeat(64)))
+                    .into_owned()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    fs_err::write(context.temp_dir.join("uv.lock"), lock)?;
+
+    context
+        .sync()
+        .arg("--frozen")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Hash mismatch for `iniconfig==2.0.0`",
+        ))
+        .stderr(predicates::str::contains(format!(
+            "sha256:{}",
+            "0".repeat(64)
+        )));
+
+    Ok(())
+}
+
+/// Print a summary of cache hits and misses with `--cache-stats`.
+#[test]
+fn sync_cache_stats() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // Reinstalling `iniconfig` should use the cached wheel.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--reinstall-package").arg("iniconfig").arg("--cache-stats"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==2.0.0
+     + iniconfig==2.0.0
+    Cache: 0 metadata hits, 0 metadata misses, 1 wheel hits, 0 built wheel hits, 0 environment hits, 0 environment misses
+    "###);
+
+    // The summary should respect `--quiet`.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--reinstall-package").arg("iniconfig").arg("--cache-stats").arg("--quiet"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Install the project as a regular (non-editable) package with `--no-editable`.
+#[test]
+fn sync_no_editable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("VALUE = 42")?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--no-editable"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // Removing the source tree should not affect the installed package.
+    fs_err::remove_dir_all(context.temp_dir.child("src"))?;
+
+    context
+        .assert_command("import project; assert project.VALUE == 42")
+        .success();
+
+    Ok(())
+}
+
+/// Use the nearest `.python-version` file, searching up to the workspace root.
+#[test]
+fn sync_python_version_file_parent() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["child"]
+    "#})?;
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("3.11")?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+    "#})?;
+
+    // Running from the member should respect the `.python-version` in the workspace root.
+    uv_snapshot!(context.filters(), context.sync().current_dir(&child), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Using Python 3.11.[X] interpreter at: [PYTHON-3.11]
+    Creating virtualenv at: [TEMP_DIR]/.venv
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // A `.python-version` closer to the invocation should take precedence.
+    child.child(".python-version").write_str("3.12")?;
+
+    uv_snapshot!(context.filters(), context.sync().current_dir(&child), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+    Removed virtual environment at: [TEMP_DIR]/.venv (Python 3.11.[X], created by uv), since Python 3.12 was requested
+    Creating virtualenv at: [TEMP_DIR]/.venv
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    Ok(())
+}
+
+/// An existing environment should be retained if it only differs from the `.python-version` in
+/// the patch version.
+#[test]
+fn sync_python_version_patch_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // The test interpreter is never Python 3.12.0, so this differs in the patch version only.
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("3.12.0")?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// If there's no `.python-version` file within the workspace, use the nearest one above the
+/// workspace root.
+#[test]
+fn sync_python_version_file_above_workspace() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("3.11")?;
+
+    let workspace = context.temp_dir.child("workspace");
+    workspace.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    warning: `VIRTUAL_ENV=[TEMP_DIR]/.venv` does not match the project environment path `.venv` and will be ignored; use `--active` to target the active environment instead
+    Using Python 3.11.[X] interpreter at: [PYTHON-3.11]
+    Creating virtualenv at: .venv
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/workspace)
+    "###);
+
+    Ok(())
+}
+
+/// Reject an interpreter outside of the Python range provided to `uv lock --python-range`.
+#[test]
+fn sync_python_range() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+    "#})?;
+
+    context
+        .lock()
+        .arg("--python-range")
+        .arg(">=3.12")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.sync().arg("--python").arg("3.11"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Using Python 3.11.[X] interpreter at: [PYTHON-3.11]
+    Creating virtualenv at: .venv
+    Resolved 1 package in [TIME]
+    error: The current Python version (3.11.[X]) is not compatible with the locked Python requirement: `>=3.12` (narrowed by `--python-range >=3.12`)
+    hint: Use `--python` to select an interpreter that satisfies `>=3.12`, or run `uv lock --python-range` with a range that includes `3.11.[X]`.
+    "###);
+
+    // An interpreter within the range is accepted.
+    uv_snapshot!(context.filters(), context.sync().arg("--python").arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+    Removed virtual environment at: .venv (Python 3.11.[X], created by uv), since Python 3.12 was requested
+    Creating virtualenv at: .venv
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    Ok(())
+}
+
+/// Sync into the active virtual environment with `--active`, rather than the project environment.
+#[test]
+fn sync_active_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    // Create a virtual environment outside of the project environment.
+    let active = context.temp_dir.child("active");
+    context.venv().arg(active.as_os_str()).assert().success();
+
+    // By default, the active environment should be ignored, with a warning.
+    uv_snapshot!(context.filters(), context.sync().env("VIRTUAL_ENV", active.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    warning: `VIRTUAL_ENV=active` does not match the project environment path `.venv` and will be ignored; use `--active` to target the active environment instead
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // With `--active`, the active environment should be used instead.
+    uv_snapshot!(context.filters(), context.sync().arg("--active").env("VIRTUAL_ENV", active.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    assert!(site_packages_path(active.path(), "python3.12")
+        .join("iniconfig")
+        .is_dir());
+
+    Ok(())
+}
+
+/// Warn (or, with `--strict-markers`, fail) if the lockfile doesn't include a requirement that
+/// applies to the current environment.
+#[test]
+fn sync_strict_markers() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig ; platform_machine == 'never'"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Change the marker, such that the requirement applies to the current environment, without
+    // updating the lockfile.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig ; platform_machine != 'never'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    warning: The lockfile does not include `iniconfig` (required by `project` with marker `platform_machine != 'never'`) in the current environment; use `--strict-markers` to fail instead
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--strict-markers"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: The lockfile does not include packages required in the current environment: `iniconfig` (required by `project` with marker `platform_machine != 'never'`)
+    hint: The lockfile may have been created on a different platform; run `uv lock` on this platform to include it.
+    "###);
+
+    Ok(())
+}
+
+/// Detect a gap in the transitive dependencies, i.e., a requirement of a dependency (rather than
+/// of the project itself) that applies to the current environment, but isn't in the lockfile.
+#[test]
+fn sync_strict_markers_transitive() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig ; platform_machine == 'never'"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Change the marker of the transitive requirement, without updating the lockfile.
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig ; platform_machine != 'never'"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--strict-markers"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    error: The lockfile does not include packages required in the current environment: `iniconfig` (required by `child` with marker `platform_machine != 'never'`)
+    hint: The lockfile may have been created on a different platform; run `uv lock` on this platform to include it.
+    "###);
+
+    Ok(())
+}
+
+/// Relocate the project environment with `UV_PROJECT_ENVIRONMENT`.
+#[test]
+fn sync_project_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    // A relative path should be resolved against the workspace root.
+    uv_snapshot!(context.filters(), context.sync()
+        .env("UV_PROJECT_ENVIRONMENT", "target")
+        .env_remove("VIRTUAL_ENV"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtualenv at: target
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    assert!(
+        site_packages_path(&context.temp_dir.join("target"), "python3.12")
+            .join("iniconfig")
+            .is_dir()
+    );
+
+    // A non-empty directory that isn't a virtual environment should never be removed.
+    let data = context.temp_dir.child("data");
+    data.child("important.txt").touch()?;
+
+    uv_snapshot!(context.filters(), context.sync()
+        .env("UV_PROJECT_ENVIRONMENT", "data")
+        .env_remove("VIRTUAL_ENV"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning
+    Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+    error: The project environment path `data` exists, but is not a virtual environment; remove it or choose a different path with `UV_PROJECT_ENVIRONMENT`
+    "###);
+
+    data.child("important.txt")
+        .assert(predicates::path::exists());
+
+    Ok(())
+}
+
+/// Use the project name as the prompt for the project environment, unless overridden.
+#[test]
+fn sync_prompt() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    // Remove the existing environment, such that `uv sync` creates one.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    context.sync().assert().success();
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("prompt = project\n"));
+
+    // Any control characters in a user-provided prompt should be stripped.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    context
+        .sync()
+        .arg("--prompt")
+        .arg("my\nprompt")
+        .assert()
+        .success();
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("prompt = myprompt\n"));
+
+    Ok(())
+}
+
+/// Seed `pip` into the project environment with `--seed`, and retain it across syncs.
+#[test]
+fn sync_seed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    // Remove the existing environment, such that `uv sync` creates one.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    context.sync().arg("--seed").assert().success();
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("seed = true\n"));
+    assert!(context.site_packages().join("pip").is_dir());
+
+    // A subsequent sync should retain the seed packages, even without `--seed`.
+    context.sync().assert().success();
+
+    assert!(context.site_packages().join("pip").is_dir());
+
+    Ok(())
+}
+
+/// Write a minimal wheel for the package `crafted`, containing the given files.
+fn write_crafted_wheel(path: &std::path::Path, files: &[(&str, &str)]) -> Result<()> {
+    use std::io::Write;
+
+    let mut zip = zip::ZipWriter::new(fs_err::File::create(path)?);
+    let options = zip::write::FileOptions::default();
+
+    let metadata = [
+        (
+            "crafted-0.1.0.dist-info/METADATA",
+            "Metadata-Version: 2.1\nName: crafted\nVersion: 0.1.0\n",
+        ),
+        (
+            "crafted-0.1.0.dist-info/WHEEL",
+            "Wheel-Version: 1.0\nGenerator: uv\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+        ),
+    ];
+
+    let mut record = String::new();
+    for (name, contents) in files.iter().chain(metadata.iter()) {
+        zip.start_file(*name, options)?;
+        zip.write_all(contents.as_bytes())?;
+        record.push_str(&format!("{name},,\n"));
+    }
+    record.push_str("crafted-0.1.0.dist-info/RECORD,,\n");
+    zip.start_file("crafted-0.1.0.dist-info/RECORD", options)?;
+    zip.write_all(record.as_bytes())?;
+
+    zip.finish()?;
+    Ok(())
+}
+
+/// Sync a wheel whose contents exceed the `MAX_PATH` limit once installed into the project
+/// environment.
+#[test]
+#[cfg(windows)]
+fn sync_windows_long_path() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let nested = (0..20)
+        .map(|index| format!("directory{index:02}"))
+        .collect::<Vec<_>>()
+        .join("/");
+    let member = format!("crafted/{nested}/data.json");
+    write_crafted_wheel(
+        &context.temp_dir.join("crafted-0.1.0-py3-none-any.whl"),
+        &[("crafted/__init__.py", ""), (&member, "{}")],
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["crafted"]
+
+        [tool.uv.sources]
+        crafted = { path = "crafted-0.1.0-py3-none-any.whl" }
+        "#
+    })?;
+
+    context.sync().assert().success();
+
+    let installed = site_packages_path(&context.venv, "python3.12").join(&member);
+    assert!(installed.as_os_str().len() > 260);
+    assert!(uv_fs::verbatim_path(&installed).exists());
+
+    Ok(())
+}
+
+/// Fail clearly when a wheel contains a file name that's reserved on Windows.
+#[test]
+#[cfg(windows)]
+fn sync_windows_reserved_filename() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    write_crafted_wheel(
+        &context.temp_dir.join("crafted-0.1.0-py3-none-any.whl"),
+        &[("crafted/__init__.py", ""), ("crafted/aux.py", "")],
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["crafted"]
+
+        [tool.uv.sources]
+        crafted = { path = "crafted-0.1.0-py3-none-any.whl" }
+        "#
+    })?;
+
+    context
+        .sync()
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("crafted==0.1.0"))
+        .stderr(predicates::str::contains(
+            "Archive member `crafted/aux.py` can't be extracted, as `aux.py` is a reserved filename on Windows",
+        ));
+
+    Ok(())
+}
+
+/// Sync a wheel with a console script into a project environment at the given path, and run the
+/// generated script.
+#[cfg(unix)]
+fn sync_and_run_script(context: &TestContext, environment: &std::path::Path) -> Result<()> {
+    write_crafted_wheel(
+        &context.temp_dir.join("crafted-0.1.0-py3-none-any.whl"),
+        &[
+            (
+                "crafted/__init__.py",
+                "def main():\n    print('Hello, world!')\n",
+            ),
+            (
+                "crafted-0.1.0.dist-info/entry_points.txt",
+                "[console_scripts]\ncrafted = crafted:main\n",
+            ),
+        ],
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["crafted"]
+
+        [tool.uv.sources]
+        crafted = { path = "crafted-0.1.0-py3-none-any.whl" }
+        "#
+    })?;
+
+    context
+        .sync()
+        .env("UV_PROJECT_ENVIRONMENT", environment)
+        .env_remove("VIRTUAL_ENV")
+        .assert()
+        .success();
+
+    std::process::Command::new(environment.join("bin").join("crafted"))
+        .assert()
+        .success()
+        .stdout("Hello, world!\n");
+
+    Ok(())
+}
+
+/// Run a console script from a project environment whose path contains spaces, which requires
+/// wrapping the shebang in `/bin/sh`.
+#[test]
+#[cfg(unix)]
+fn sync_script_shebang_spaces() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let environment = context.temp_dir.join("project environment");
+    sync_and_run_script(&context, &environment)?;
+
+    let script = fs_err::read_to_string(environment.join("bin").join("crafted"))?;
+    assert!(script.starts_with("#!/bin/sh\n"));
+
+    Ok(())
+}
+
+/// Run a console script from a project environment whose interpreter path exceeds the 127-byte
+/// shebang limit, which requires wrapping the shebang in `/bin/sh`.
+#[test]
+#[cfg(unix)]
+fn sync_script_shebang_long_path() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let environment = context.temp_dir.join("a".repeat(128));
+    sync_and_run_script(&context, &environment)?;
+
+    let script = fs_err::read_to_string(environment.join("bin").join("crafted"))?;
+    assert!(script.starts_with("#!/bin/sh\n"));
+
+    Ok(())
+}
@@ -20,6 +20,28 @@ fn tool_dir() {
     ----- stdout -----
     [TEMP_DIR]/tools
 
+    ----- stderr -----
+    warning: `uv tool dir` is experimental and may change without warning
+    Using tool directory from `UV_TOOL_DIR`
+    "###);
+}
+
+#[test]
+fn tool_dir_xdg_data_home() {
+    let context = TestContext::new("3.12");
+    let data_dir = context.temp_dir.child("data");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Without `UV_TOOL_DIR`, the tools directory should be placed in the user data directory.
+    uv_snapshot!(context.filters(), context.tool_dir()
+    .env_remove("UV_TOOL_DIR")
+    .env("XDG_DATA_HOME", data_dir.as_os_str())
+    .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/data/uv/tools
+
     ----- stderr -----
     warning: `uv tool dir` is experimental and may change without warning
     "###);
@@ -3,10 +3,12 @@
 use std::process::Command;
 
 use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::{
     assert::PathAssert,
-    fixture::{FileTouch, FileWriteStr, PathChild},
+    fixture::{FileTouch, FileWriteStr, PathChild, PathCreateDir},
 };
+use indoc::indoc;
 use insta::assert_snapshot;
 use predicates::prelude::predicate;
 
@@ -80,6 +82,8 @@ fn tool_install() {
     }, {
         // We should have a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["black"]
         entrypoints = [
@@ -162,6 +166,8 @@ fn tool_install() {
     }, {
         // We should have a new tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("flask").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["flask"]
         entrypoints = [
@@ -298,6 +304,8 @@ fn tool_install_version() {
     }, {
         // We should have a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["black==24.2.0"]
         entrypoints = [
@@ -451,6 +459,8 @@ fn tool_install_already_installed() {
     }, {
         // We should have a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["black"]
         entrypoints = [
@@ -485,6 +495,8 @@ fn tool_install_already_installed() {
     }, {
         // We should not have an additional tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["black"]
         entrypoints = [
@@ -788,6 +800,8 @@ fn tool_install_entry_point_exists() {
     }, {
         // We write a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["black"]
         entrypoints = [
@@ -821,6 +835,8 @@ fn tool_install_entry_point_exists() {
     }, {
         // We should have a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["black"]
         entrypoints = [
@@ -1051,6 +1067,8 @@ fn tool_install_unnamed_package() {
     }, {
         // We should have a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["black @ https://files.pythonhosted.org/packages/0f/89/294c9a6b6c75a08da55e9d05321d0707e9418735e3062b12ef0f54c33474/black-24.4.2-py3-none-any.whl"]
         entrypoints = [
@@ -1071,6 +1089,210 @@ fn tool_install_unnamed_package() {
     "###);
 }
 
+/// Test installing a tool from a local directory in editable mode.
+#[test]
+fn tool_install_editable() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let project = context.temp_dir.child("greeter");
+    project.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "greeter"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        greeter = "greeter:main"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#
+    })?;
+    project
+        .child("src")
+        .child("greeter")
+        .child("__init__.py")
+        .write_str(indoc! { r#"
+        def main():
+            print("Hello, world!")
+        "#
+        })?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("--editable")
+        .arg("./greeter")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool install` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + greeter==0.1.0 (from file://[TEMP_DIR]/greeter)
+    Installed 1 executable: greeter
+    "###);
+
+    let mut filters = context.filters();
+    filters.push((r"source-mtime = \d+", "source-mtime = [MTIME]"));
+    insta::with_settings!({
+        filters => filters,
+    }, {
+        // The receipt should record that the tool is editable, along with the modification time
+        // of its project metadata.
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("greeter").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
+        [tool]
+        requirements = ["greeter @ file://[TEMP_DIR]/greeter"]
+        entrypoints = [
+            { name = "greeter", install-path = "[TEMP_DIR]/bin/greeter" },
+        ]
+        editable = true
+        source-mtime = [MTIME]
+        "###);
+    });
+
+    // Changes to the source should be reflected without reinstalling.
+    project
+        .child("src")
+        .child("greeter")
+        .child("__init__.py")
+        .write_str(indoc! { r#"
+        def main():
+            print("Hello, editable!")
+        "#
+        })?;
+
+    uv_snapshot!(context.filters(), Command::new("greeter").env("PATH", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, editable!
+
+    ----- stderr -----
+    "###);
+
+    // Re-installing the unchanged tool should be a no-op.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("--editable")
+        .arg("./greeter")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool install` is experimental and may change without warning
+    `greeter @ file://[TEMP_DIR]/greeter` is already installed
+    "###);
+
+    // Modify the project metadata; running the tool should suggest reinstalling it.
+    project.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "greeter"
+        version = "0.1.0"
+        description = "A friendly greeter"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        greeter = "greeter:main"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("greeter")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, editable!
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    warning: `greeter` was installed in editable mode, but `greeter/pyproject.toml` has changed since; run `uv tool install --editable greeter` to reinstall it
+    "###);
+
+    // Rewriting the receipt (e.g., when migrating it) shouldn't hide the change.
+    let receipt = tool_dir.join("greeter").join("uv-receipt.toml");
+    fs_err::write(&receipt, fs_err::read_to_string(&receipt)?)?;
+
+    // With `--yes`, the tool is reinstalled before running it.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--yes")
+        .arg("greeter")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, editable!
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    warning: `uv tool install` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     - greeter==0.1.0 (from file://[TEMP_DIR]/greeter)
+     + greeter==0.1.0 (from file://[TEMP_DIR]/greeter)
+    Installed 1 executable: greeter
+    "###);
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("greeter")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, editable!
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    "###);
+
+    // A directory that isn't a Python project should be rejected.
+    context.temp_dir.child("empty").create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("--editable")
+        .arg("./empty")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool install` is experimental and may change without warning
+    error: Tool directory `./empty` does not contain a `pyproject.toml` or `setup.py`
+    "###);
+
+    Ok(())
+}
+
 /// Test installing a tool with a bare URL requirement using `--from`, where the URL and the package
 /// name conflict.
 #[test]
@@ -1163,6 +1385,8 @@ fn tool_install_unnamed_from() {
     }, {
         // We should have a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["black @ https://files.pythonhosted.org/packages/0f/89/294c9a6b6c75a08da55e9d05321d0707e9418735e3062b12ef0f54c33474/black-24.4.2-py3-none-any.whl"]
         entrypoints = [
@@ -1250,6 +1474,8 @@ fn tool_install_unnamed_with() {
     }, {
         // We should have a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = [
             "black",
@@ -1311,6 +1537,8 @@ fn tool_install_upgrade() {
     }, {
         // We should have a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["black==24.1.1"]
         entrypoints = [
@@ -1341,6 +1569,8 @@ fn tool_install_upgrade() {
     }, {
         // We should have a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["black"]
         entrypoints = [
@@ -1376,6 +1606,8 @@ fn tool_install_upgrade() {
     }, {
         // We should have a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = [
             "black",
@@ -1417,6 +1649,8 @@ fn tool_install_upgrade() {
     }, {
         // We should have a tool receipt
         assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
         [tool]
         requirements = ["black"]
         entrypoints = [
@@ -1680,3 +1914,294 @@ fn tool_install_bad_receipt() -> Result<()> {
 
     Ok(())
 }
+
+/// Time out while another process holds the lock on the tools directory.
+#[test]
+fn tool_install_lock_timeout() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Hold the lock for the duration of the test.
+    fs_err::create_dir_all(&tool_dir)?;
+    let _lock = uv_fs::LockedFile::acquire(tool_dir.child(".lock"), "tools")?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("UV_TOOL_LOCK_TIMEOUT", "0")
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool install` is experimental and may change without warning
+    warning: Waiting to acquire lock for tools (lockfile: tools/.lock)
+    error: Timed out after 0s waiting to acquire lock at `tools/.lock` (set `UV_TOOL_LOCK_TIMEOUT` to wait longer)
+    "###);
+
+    Ok(())
+}
+
+/// The staged copy of the tool environment should be removed after installation, and omitted
+/// entirely with `--no-atomic`.
+#[test]
+fn tool_install_atomic() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    context
+        .tool_install()
+        .arg("black")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    let directories = uv_fs::directories(&tool_dir)
+        .filter_map(|path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(directories, vec!["black".to_string()]);
+
+    context
+        .tool_install()
+        .arg("flask")
+        .arg("--no-atomic")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    tool_dir
+        .child("flask")
+        .child("pyvenv.cfg")
+        .assert(predicate::path::exists());
+}
+
+/// If installing into an existing tool environment fails partway through, the previous environment
+/// should be restored, unless `--no-atomic` is provided.
+#[test]
+#[cfg(unix)]
+fn tool_install_atomic_restore() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    context
+        .tool_install()
+        .arg("black")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // A wheel without a `RECORD` fails to install after its files are linked into the environment.
+    write_wheel_without_record(&context.temp_dir.join("crafted-0.1.0-py3-none-any.whl"))?;
+
+    context
+        .tool_install()
+        .arg("black")
+        .arg("--with")
+        .arg("./crafted-0.1.0-py3-none-any.whl")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str())
+        .assert()
+        .failure();
+
+    // The partially-installed files were discarded along with the staged environment.
+    let site_packages = tool_dir
+        .child("black")
+        .child("lib")
+        .child("python3.12")
+        .child("site-packages");
+    site_packages
+        .child("crafted")
+        .assert(predicate::path::missing());
+    site_packages
+        .child("black")
+        .assert(predicate::path::is_dir());
+    let directories = uv_fs::directories(&tool_dir)
+        .filter_map(|path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(directories, vec!["black".to_string()]);
+
+    // With `--no-atomic`, the environment is left partially installed.
+    context
+        .tool_install()
+        .arg("black")
+        .arg("--with")
+        .arg("./crafted-0.1.0-py3-none-any.whl")
+        .arg("--no-atomic")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str())
+        .assert()
+        .failure();
+
+    site_packages
+        .child("crafted")
+        .assert(predicate::path::is_dir());
+
+    Ok(())
+}
+
+/// Write a wheel for the package `crafted` that lacks a `RECORD` file.
+#[cfg(unix)]
+fn write_wheel_without_record(path: &std::path::Path) -> Result<()> {
+    use std::io::Write;
+
+    let mut zip = zip::ZipWriter::new(fs_err::File::create(path)?);
+    let options = zip::write::FileOptions::default();
+    for (name, contents) in [
+        ("crafted/__init__.py", ""),
+        (
+            "crafted-0.1.0.dist-info/METADATA",
+            "Metadata-Version: 2.1\nName: crafted\nVersion: 0.1.0\n",
+        ),
+        (
+            "crafted-0.1.0.dist-info/WHEEL",
+            "Wheel-Version: 1.0\nGenerator: uv\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+        ),
+    ] {
+        zip.start_file(name, options)?;
+        zip.write_all(contents.as_bytes())?;
+    }
+    zip.finish()?;
+    Ok(())
+}
+
+/// Test running a post-install script with `uv tool install --post-install-script`.
+#[test]
+fn tool_install_post_install_script() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // The script runs with the tool's interpreter.
+    let script = context.temp_dir.child("setup.py");
+    script.write_str(indoc::indoc! {r#"
+        import pathlib
+        import sys
+
+        pathlib.Path(sys.prefix, "setup-done").write_text("ok")
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--post-install-script")
+        .arg("setup.py")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool install` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Running post-install script: setup.py
+    Installed 2 executables: black, blackd
+    "###);
+
+    tool_dir
+        .child("black")
+        .child("setup-done")
+        .assert(predicate::path::exists());
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        // The script should be stored in the receipt.
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        schema-version = 1
+
+        [tool]
+        requirements = ["black"]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
+        ]
+        post-install-script = "[TEMP_DIR]/setup.py"
+        "###);
+    });
+
+    // Reinstalling the tool should re-run the stored script.
+    fs_err::remove_file(tool_dir.join("black").join("setup-done"))?;
+
+    context
+        .tool_install()
+        .arg("black")
+        .arg("--reinstall")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    tool_dir
+        .child("black")
+        .child("setup-done")
+        .assert(predicate::path::exists());
+
+    // If the script fails, the installation should fail, too.
+    let script = context.temp_dir.child("fail.py");
+    script.write_str("raise SystemExit(1)\n")?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("flask")
+        .arg("--post-install-script")
+        .arg("fail.py")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("PATH", bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool install` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    Running post-install script: fail.py
+    error: Post-install script `fail.py` failed with exit status: 1
+    "###);
+
+    tool_dir.child("flask").assert(predicate::path::missing());
+    bin_dir
+        .child(format!("flask{}", std::env::consts::EXE_SUFFIX))
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
@@ -118,6 +118,83 @@ fn tool_list_missing_receipt() {
     "###);
 }
 
+/// Migrate a receipt written before the receipt schema was versioned.
+#[test]
+fn tool_list_migrate_receipt() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Remove the schema version from the receipt, as in receipts written by older versions of uv.
+    let receipt_path = tool_dir.join("black").join("uv-receipt.toml");
+    let receipt = fs_err::read_to_string(&receipt_path).unwrap();
+    let legacy = receipt.strip_prefix("schema-version = 1\n\n").unwrap();
+    fs_err::write(&receipt_path, legacy).unwrap();
+
+    uv_snapshot!(context.filters(), context.tool_list()
+    .env("UV_TOOL_DIR", tool_dir.as_os_str())
+    .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black v24.2.0
+    - black
+    - blackd
+
+    ----- stderr -----
+    warning: `uv tool list` is experimental and may change without warning
+    "###);
+
+    // The receipt is rewritten with the current schema version.
+    assert_eq!(fs_err::read_to_string(&receipt_path).unwrap(), receipt);
+}
+
+/// Ignore receipts written with a newer, unsupported schema version.
+#[test]
+fn tool_list_unsupported_receipt_version() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    let receipt_path = tool_dir.join("black").join("uv-receipt.toml");
+    let receipt = fs_err::read_to_string(&receipt_path).unwrap();
+    let future = receipt.replace("schema-version = 1", "schema-version = 999");
+    fs_err::write(&receipt_path, &future).unwrap();
+
+    uv_snapshot!(context.filters(), context.tool_list()
+    .env("UV_TOOL_DIR", tool_dir.as_os_str())
+    .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool list` is experimental and may change without warning
+    warning: Ignoring malformed tool `black` (run `uv tool uninstall black` to remove)
+    "###);
+
+    // The receipt is left untouched.
+    assert_eq!(fs_err::read_to_string(&receipt_path).unwrap(), future);
+}
+
 #[test]
 fn tool_list_bad_environment() -> Result<()> {
     let context = TestContext::new("3.12")
@@ -1,7 +1,10 @@
 #![cfg(all(feature = "python", feature = "pypi"))]
 
+use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
+use indoc::{formatdoc, indoc};
+use predicates::prelude::{predicate, PredicateBooleanExt};
 
 use common::{uv_snapshot, TestContext};
 
@@ -146,7 +149,7 @@ fn tool_run_at_version() {
     .env("UV_TOOL_DIR", tool_dir.as_os_str())
     .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
     success: false
-    exit_code: 1
+    exit_code: 127
     ----- stdout -----
     The executable `pytest@8.0.0` was not found.
     The following executables are provided by `pytest`:
@@ -209,7 +212,7 @@ fn tool_run_suggest_valid_commands() {
     .env("UV_TOOL_DIR", tool_dir.as_os_str())
     .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
     success: false
-    exit_code: 1
+    exit_code: 127
     ----- stdout -----
     The executable `orange` was not found.
     The following executables are provided by `black`:
@@ -235,7 +238,7 @@ fn tool_run_suggest_valid_commands() {
     .env("UV_TOOL_DIR", tool_dir.as_os_str())
     .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
     success: false
-    exit_code: 1
+    exit_code: 127
     ----- stdout -----
     The executable `fastapi-cli` was not found.
 
@@ -554,6 +557,53 @@ fn tool_run_cache() {
     Resolved [N] packages in [TIME]
     "###);
 
+    // Verify that `--refresh` rebuilds the cached environment.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("-p")
+        .arg("3.12")
+        .arg("--refresh")
+        .arg("black")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    "###);
+
+    // And that the rebuilt environment is reused on subsequent invocations.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("-p")
+        .arg("3.12")
+        .arg("black")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    "###);
+
     // Verify that `--with` leads to a fresh environment.
     uv_snapshot!(context.filters(), context.tool_run()
         .arg("-p")
@@ -585,6 +635,96 @@ fn tool_run_cache() {
     "###);
 }
 
+/// Verify that a cached environment created with a different Python version is rebuilt.
+#[test]
+fn tool_run_cache_python_version() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("black")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    "###);
+
+    // Simulate an in-place upgrade of the interpreter by rewriting the receipt of each cached
+    // environment.
+    for interpreter in fs_err::read_dir(context.cache_dir.join("environments-v1"))? {
+        let interpreter = interpreter?.path();
+        if !interpreter.is_dir() {
+            continue;
+        }
+        for environment in fs_err::read_dir(interpreter)? {
+            let receipt = environment?.path().join(".ok");
+            if receipt.is_file() {
+                fs_err::write(receipt, "cpython 3.11.0")?;
+            }
+        }
+    }
+
+    // The stale environment should be rebuilt.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("black")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    "###);
+
+    // And then reused on subsequent invocations.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("black")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn tool_run_url() {
     let context = TestContext::new("3.12").with_filtered_counts();
@@ -619,3 +759,746 @@ fn tool_run_url() {
      + werkzeug==3.0.1
     "###);
 }
+
+#[test]
+fn tool_run_working_dir() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // A missing working directory should be rejected.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--working-dir")
+        .arg("missing")
+        .arg("pytest")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    error: Working directory does not exist: `missing`
+    "###);
+
+    // As should a file.
+    context.temp_dir.child("file.txt").touch()?;
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--working-dir")
+        .arg("file.txt")
+        .arg("pytest")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    error: Working directory is not a directory: `file.txt`
+    "###);
+
+    // The command should be run from within the working directory, such that relative paths
+    // resolve against it.
+    let subdir = context.temp_dir.child("subdir");
+    subdir.child("test_example.py").write_str(indoc! {r"
+        def test_example():
+            pass
+    "})?;
+
+    context
+        .tool_run()
+        .arg("--working-dir")
+        .arg("subdir")
+        .arg("pytest")
+        .arg("test_example.py")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// Repeated `--with` requirements (and those that repeat the tool itself) are only resolved once.
+#[test]
+fn tool_run_with_duplicates() {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("--with")
+        .arg("black")
+        .arg("black")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + iniconfig==2.0.0
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    "###);
+}
+
+/// Run a tool from a local directory, using the script declared in its `pyproject.toml`.
+#[test]
+fn tool_run_local_directory() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let project = context.temp_dir.child("greeter");
+    project.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "greeter"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        greeter = "greeter:main"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#
+    })?;
+    project
+        .child("src")
+        .child("greeter")
+        .child("__init__.py")
+        .write_str(indoc! { r#"
+        def main():
+            print("Hello, world!")
+        "#
+        })?;
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("./greeter")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + greeter==0.1.0 (from file://[TEMP_DIR]/greeter)
+    "###);
+
+    // A directory without a `pyproject.toml` should be rejected.
+    context.temp_dir.child("empty").create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("./empty")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    error: Tool directory `./empty` does not contain a `pyproject.toml`
+    "###);
+
+    Ok(())
+}
+
+/// Run a tool from a Git repository, using the entry point that matches the package name.
+#[test]
+#[cfg(feature = "git")]
+fn tool_run_git() {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let mut filters = context.filters();
+    filters.push((r"@[0-9a-f]{40}", "@[COMMIT]"));
+
+    uv_snapshot!(filters, context.tool_run()
+        .arg("git+https://github.com/psf/black@24.4.2")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.4.2 (compiled: no)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.4.2 (from git+https://github.com/psf/black@[COMMIT])
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    "###);
+}
+
+#[test]
+fn tool_run_detach_exits_during_startup() {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let data_dir = context.temp_dir.child("data");
+    let mut filters = context.filters();
+    filters.push((r"pytest-\d+", "pytest-[TIMESTAMP]"));
+    filters.push((r"(?m)^\d+ pytest", "[PID] pytest"));
+    filters.push((r"pid \d+", "pid [PID]"));
+
+    // `pytest --version` exits immediately, so the detached process should be reported as failed.
+    uv_snapshot!(filters, context.tool_run()
+        .arg("--detach")
+        .arg("--from")
+        .arg("pytest==8.0.0")
+        .arg("pytest")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("XDG_DATA_HOME", data_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.0
+    `pytest` exited during startup (exit status: 0); see the log at: data/uv/tool-runs/pytest-[TIMESTAMP]/stderr.log
+    "###);
+
+    // The run is still listed, such that its logs can be inspected.
+    uv_snapshot!(filters, context.tool_ps()
+        .env("XDG_DATA_HOME", data_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PID] pytest (exited)
+    - [TEMP_DIR]/data/uv/tool-runs/pytest-[TIMESTAMP]/stdout.log
+    - [TEMP_DIR]/data/uv/tool-runs/pytest-[TIMESTAMP]/stderr.log
+
+    ----- stderr -----
+    warning: `uv tool ps` is experimental and may change without warning
+    "###);
+
+    // Stopping an exited run removes it.
+    uv_snapshot!(filters, context.tool_stop()
+        .arg("pytest")
+        .env("XDG_DATA_HOME", data_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool stop` is experimental and may change without warning
+    `pytest` (pid [PID]) already exited
+    "###);
+
+    uv_snapshot!(filters, context.tool_ps()
+        .env("XDG_DATA_HOME", data_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool ps` is experimental and may change without warning
+    No tools running in the background
+    "###);
+}
+
+#[test]
+fn tool_run_detach_stop() {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let data_dir = context.temp_dir.child("data");
+    let mut filters = context.filters();
+    filters.push((r"python-\d+", "python-[TIMESTAMP]"));
+    filters.push((r"(?m)^\d+$", "[PID]"));
+    filters.push((r"(?m)^\d+ python", "[PID] python"));
+    filters.push((r"pid \d+", "pid [PID]"));
+
+    // A long-running process outlives the startup timeout, so it's reported as started.
+    uv_snapshot!(filters, context.tool_run()
+        .arg("--detach")
+        .arg("--from")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("import time; time.sleep(60)")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .env("XDG_DATA_HOME", data_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PID]
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + iniconfig==2.0.0
+    Started `python` in the background (pid [PID])
+    Logging output to: data/uv/tool-runs/python-[TIMESTAMP]/stdout.log and data/uv/tool-runs/python-[TIMESTAMP]/stderr.log
+    "###);
+
+    uv_snapshot!(filters, context.tool_ps()
+        .env("XDG_DATA_HOME", data_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PID] python (running)
+    - [TEMP_DIR]/data/uv/tool-runs/python-[TIMESTAMP]/stdout.log
+    - [TEMP_DIR]/data/uv/tool-runs/python-[TIMESTAMP]/stderr.log
+
+    ----- stderr -----
+    warning: `uv tool ps` is experimental and may change without warning
+    "###);
+
+    // Stopping the run terminates the process and removes the run.
+    uv_snapshot!(filters, context.tool_stop()
+        .arg("python")
+        .env("XDG_DATA_HOME", data_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool stop` is experimental and may change without warning
+    Stopped `python` (pid [PID])
+    "###);
+
+    uv_snapshot!(filters, context.tool_ps()
+        .env("XDG_DATA_HOME", data_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool ps` is experimental and may change without warning
+    No tools running in the background
+    "###);
+}
+
+/// Python processes spawned by a tool shouldn't see the tool's packages, unless
+/// `--propagate-pythonpath` is provided.
+#[test]
+fn tool_run_propagate_pythonpath() {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // From the tool environment, spawn the interpreter of an unrelated (project) environment, and
+    // check whether it can import the tool's packages.
+    let script = format!(
+        "import subprocess; subprocess.run([{:?}, '-c', 'import importlib.util; print(importlib.util.find_spec(\"iniconfig\") is not None)'], check=True)",
+        context.interpreter().display().to_string()
+    );
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--from")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg(&script)
+        .env_remove("PYTHONPATH")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    False
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--propagate-pythonpath")
+        .arg("--from")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg(&script)
+        .env_remove("PYTHONPATH")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    True
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    "###);
+}
+
+#[test]
+fn tool_run_list_entrypoints() {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // The executables are listed in sorted order, without running anything.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--from")
+        .arg("pytest")
+        .arg("--list-entrypoints")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    py.test
+    pytest
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.1.1
+    "###);
+}
+
+/// With `--yes`, an executable that's provided by a single dependency of the requested package
+/// should be run from that dependency.
+#[test]
+fn tool_run_retarget_dependency() {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    context
+        .tool_run()
+        .arg("--from")
+        .arg("pytest-xdist")
+        .arg("--yes")
+        .arg("pytest")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success()
+        .stdout("pytest 8.1.1\n")
+        .stderr(predicates::str::contains("Running `pytest` from `pytest`"))
+        .stderr(predicates::str::contains("is not provided by package").not());
+}
+
+/// Create a local package named `name` that provides a `shared` executable.
+fn shared_executable_package(context: &TestContext, name: &str) -> Result<()> {
+    let project = context.temp_dir.child(name);
+    project.child("pyproject.toml").write_str(&formatdoc! { r#"
+        [project]
+        name = "{name}"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        shared = "{name}:main"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#
+    })?;
+    project
+        .child("src")
+        .child(name)
+        .child("__init__.py")
+        .write_str(indoc! { r#"
+        def main():
+            print("Hello, world!")
+        "#
+        })?;
+    Ok(())
+}
+
+/// An executable that's provided by multiple packages should be attributed to each of them.
+#[test]
+fn tool_run_executable_multiple_providers() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    shared_executable_package(&context, "alpha")?;
+    shared_executable_package(&context, "beta")?;
+
+    // Neither provider is the requested package, so both should be listed.
+    context
+        .tool_run()
+        .arg("--from")
+        .arg("iniconfig")
+        .arg("--with")
+        .arg("./alpha")
+        .arg("--with")
+        .arg("./beta")
+        .arg("shared")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success()
+        .stdout("Hello, world!\n")
+        .stderr(predicates::str::contains(
+            "warning: An executable named `shared` is not provided by package `iniconfig` but is available via the following dependencies:\n- alpha\n- beta\nConsider using `uv tool run --from PKG shared` instead.",
+        ));
+
+    // If the requested package is one of the providers, there's nothing to warn about, regardless
+    // of the order in which the packages are installed.
+    for (from, with) in [("./alpha", "./beta"), ("./beta", "./alpha")] {
+        context
+            .tool_run()
+            .arg("--from")
+            .arg(from)
+            .arg("--with")
+            .arg(with)
+            .arg("shared")
+            .env("UV_TOOL_DIR", tool_dir.as_os_str())
+            .env("XDG_BIN_HOME", bin_dir.as_os_str())
+            .assert()
+            .success()
+            .stdout("Hello, world!\n")
+            .stderr(predicates::str::contains("is not provided by package").not());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn tool_run_timeout() {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // A command that exits before the timeout is unaffected.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--timeout")
+        .arg("60")
+        .arg("--from")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("print('hello')")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // A command that exceeds the timeout is terminated.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--timeout")
+        .arg("1")
+        .arg("--from")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("import time; time.sleep(60)")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 124
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    `python` timed out after 1s
+    "###);
+}
+
+/// Environment variables can be provided via `--env` and `--env-file`, with `--no-inherit-env`
+/// hiding uv's own environment from the command.
+#[test]
+fn tool_run_env() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let env_file = context.temp_dir.child("tool.env");
+    env_file.write_str(indoc! {r#"
+        # Overridden by `--env`.
+        FOO=file
+        export BAR="from file"
+    "#})?;
+
+    let script = "import os; print(os.environ.get('FOO'), os.environ.get('BAR'), os.environ.get('UV_TEST_INHERITED'))";
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--env")
+        .arg("FOO=flag")
+        .arg("--env-file")
+        .arg("tool.env")
+        .arg("--from")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg(script)
+        .env("UV_TEST_INHERITED", "1")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    flag from file 1
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // With `--no-inherit-env`, only the provided variables are visible.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--no-inherit-env")
+        .arg("--env")
+        .arg("FOO=flag")
+        .arg("--env-file")
+        .arg("tool.env")
+        .arg("--from")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg(script)
+        .env("UV_TEST_INHERITED", "1")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    flag from file None
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved [N] packages in [TIME]
+    "###);
+
+    // Malformed lines are reported with their location.
+    env_file.write_str("FOO\n")?;
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--env-file")
+        .arg("tool.env")
+        .arg("--from")
+        .arg("iniconfig")
+        .arg("python")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    error: Invalid line 1 in `tool.env`: expected `KEY=VALUE`, found `FOO`
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn tool_run_offline() {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Without network access, a package that isn't in the cache can't be resolved.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--offline")
+        .arg("pytest")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    error: Network connectivity is disabled, but the following packages weren't found in the cache: `pytest`
+    hint: Run the command once with network access to populate the cache, or remove `--offline` (or `UV_OFFLINE`).
+    "###);
+
+    // Populate the cache.
+    context
+        .tool_run()
+        .arg("pytest")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Once the cache is populated, the tool can be run offline.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--offline")
+        .arg("pytest")
+        .arg("--version")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.1.1
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    "###);
+}
@@ -50,6 +50,77 @@ fn nested_dependencies() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn depth() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        # ...
+        requires-python = ">=3.12"
+        dependencies = [
+            "scikit-learn==1.4.1.post1"
+        ]
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--depth").arg("1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── scikit-learn v1.4.1.post1
+        └── ...
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    // Root the tree at a specific dependency.
+    uv_snapshot!(context.filters(), context.tree().arg("--package").arg("scikit-learn"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    scikit-learn v1.4.1.post1
+    ├── joblib v1.3.2
+    ├── numpy v1.26.4
+    ├── scipy v1.12.0
+    │   └── numpy v1.26.4
+    └── threadpoolctl v3.4.0
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    // Combine `--package` and `--depth`.
+    uv_snapshot!(context.filters(), context.tree().arg("--package").arg("scikit-learn").arg("--depth").arg("1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    scikit-learn v1.4.1.post1
+    ├── joblib v1.3.2
+    ├── numpy v1.26.4
+    ├── scipy v1.12.0
+    │   └── ...
+    └── threadpoolctl v3.4.0
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn invert() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -112,6 +183,115 @@ fn invert() -> Result<()> {
     "###
     );
 
+    // Combined with `--package`, show why a given package is included.
+    uv_snapshot!(context.filters(), context.tree().arg("--invert").arg("--package").arg("numpy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    numpy v1.26.4
+    ├── scikit-learn v1.4.1.post1
+    │   └── project v0.1.0
+    └── scipy v1.12.0
+        └── scikit-learn v1.4.1.post1 (*)
+    (*) Package tree already displayed
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Dependency cycles are marked with `(cycle)`, rather than expanded, even with `--no-dedupe`.
+#[test]
+fn cycle() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child-a"]
+
+        [tool.uv.sources]
+        child-a = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child-a", "child-b"]
+    "#,
+    )?;
+
+    context
+        .temp_dir
+        .child("child-a")
+        .child("pyproject.toml")
+        .write_str(
+            r#"
+        [project]
+        name = "child-a"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child-b"]
+
+        [tool.uv.sources]
+        child-b = { workspace = true }
+    "#,
+        )?;
+
+    context
+        .temp_dir
+        .child("child-b")
+        .child("pyproject.toml")
+        .write_str(
+            r#"
+        [project]
+        name = "child-b"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child-a"]
+
+        [tool.uv.sources]
+        child-a = { workspace = true }
+    "#,
+        )?;
+
+    uv_snapshot!(context.filters(), context.tree(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── child-a v0.1.0
+        └── child-b v0.1.0
+            └── child-a v0.1.0 (cycle)
+    (cycle) Package tree is a cycle and cannot be shown
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.tree().arg("--no-dedupe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── child-a v0.1.0
+        └── child-b v0.1.0
+            └── child-a v0.1.0 (cycle)
+    (cycle) Package tree is a cycle and cannot be shown
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
     Ok(())
 }
 
@@ -180,3 +360,200 @@ fn frozen() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn python_platform() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = [
+            "iniconfig; sys_platform == 'win32'",
+            "typing-extensions; python_version < '3.12'",
+        ]
+    "#,
+    )?;
+
+    // Markers should be evaluated against the simulated environment, which need not be installed.
+    uv_snapshot!(context.filters(), context.tree().arg("--python-platform").arg("windows").arg("--python-version").arg("3.11"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Simulated environment: win32 (x86_64), Python 3.11
+    project v0.1.0
+    ├── iniconfig v2.0.0
+    └── typing-extensions v4.10.0
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    // Unknown platforms should be rejected.
+    uv_snapshot!(context.filters(), context.tree().arg("--python-platform").arg("plan9"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'plan9' for '--python-platform <PYTHON_PLATFORM>'
+      [possible values: windows, linux, macos, x86_64-pc-windows-msvc, x86_64-unknown-linux-gnu, aarch64-apple-darwin, x86_64-apple-darwin, aarch64-unknown-linux-gnu, aarch64-unknown-linux-musl, x86_64-unknown-linux-musl, x86_64-manylinux_2_17, x86_64-manylinux_2_28, x86_64-manylinux_2_31, aarch64-manylinux_2_17, aarch64-manylinux_2_28, aarch64-manylinux_2_31]
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn licenses() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+    "#,
+    )?;
+
+    // Licenses are read from the installed packages, so they're unknown prior to syncing.
+    uv_snapshot!(context.filters(), context.tree().arg("--licenses"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0 (unknown license)
+    └── iniconfig v2.0.0 (unknown license)
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 2 packages in [TIME]
+    warning: No license detected for: `iniconfig`, `project`
+    "###
+    );
+
+    context.sync().arg("--frozen").assert().success();
+
+    uv_snapshot!(context.filters(), context.tree().arg("--licenses").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0 (unknown license)
+    └── iniconfig v2.0.0 (MIT)
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    warning: No license detected for: `project`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Development dependencies are displayed for the selected workspace members only.
+#[test]
+fn workspace_dev_dependencies() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv]
+        dev-dependencies = ["iniconfig"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child"]
+    "#,
+    )?;
+
+    let child = context.temp_dir.child("child").child("pyproject.toml");
+    child.write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        dev-dependencies = ["typing-extensions"]
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    ├── child v0.1.0
+    │   └── typing-extensions v4.10.0
+    └── iniconfig v2.0.0
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    // The child's development dependencies are omitted when selecting the root project.
+    uv_snapshot!(context.filters(), context.tree().arg("--package").arg("project"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    ├── child v0.1.0
+    └── iniconfig v2.0.0
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.tree().arg("--package").arg("child").arg("--only-dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    child v0.1.0
+    └── typing-extensions v4.10.0
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.tree().arg("--no-dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── child v0.1.0
+
+    ----- stderr -----
+    warning: `uv tree` is experimental and may change without warning
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
@@ -375,6 +375,7 @@ fn test_uv_run_with_package_virtual_workspace() -> Result<()> {
     Success
 
     ----- stderr -----
+    warning: `VIRTUAL_ENV=[TEMP_DIR]/.venv` does not match the project environment path `.venv` and will be ignored; use `--active` to target the active environment instead
     Using Python 3.12.[X] interpreter at: [PYTHON]
     Creating virtualenv at: .venv
     Resolved 8 packages in [TIME]
@@ -401,6 +402,7 @@ fn test_uv_run_with_package_virtual_workspace() -> Result<()> {
     Success
 
     ----- stderr -----
+    warning: `VIRTUAL_ENV=[TEMP_DIR]/.venv` does not match the project environment path `.venv` and will be ignored; use `--active` to target the active environment instead
     Resolved 8 packages in [TIME]
     Prepared 2 packages in [TIME]
     Installed 2 packages in [TIME]
@@ -435,6 +437,7 @@ fn test_uv_run_virtual_workspace_root() -> Result<()> {
     Success
 
     ----- stderr -----
+    warning: `VIRTUAL_ENV=[TEMP_DIR]/.venv` does not match the project environment path `.venv` and will be ignored; use `--active` to target the active environment instead
     Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
     Creating virtualenv at: .venv
     Resolved 8 packages in [TIME]
@@ -480,6 +483,7 @@ fn test_uv_run_with_package_root_workspace() -> Result<()> {
     Success
 
     ----- stderr -----
+    warning: `VIRTUAL_ENV=[TEMP_DIR]/.venv` does not match the project environment path `.venv` and will be ignored; use `--active` to target the active environment instead
     Using Python 3.12.[X] interpreter at: [PYTHON]
     Creating virtualenv at: .venv
     Resolved 8 packages in [TIME]
@@ -506,6 +510,7 @@ fn test_uv_run_with_package_root_workspace() -> Result<()> {
     Success
 
     ----- stderr -----
+    warning: `VIRTUAL_ENV=[TEMP_DIR]/.venv` does not match the project environment path `.venv` and will be ignored; use `--active` to target the active environment instead
     Resolved 8 packages in [TIME]
     Prepared 2 packages in [TIME]
     Installed 2 packages in [TIME]
@@ -690,3 +695,44 @@ fn workspace_to_workspace_paths_dependencies() -> Result<()> {
 
     Ok(())
 }
+
+/// Discover and lock a workspace with many members.
+#[test]
+fn workspace_many_members() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let workspace = context.temp_dir.child("workspace");
+    workspace.child("pyproject.toml").write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#})?;
+    for i in 0..100 {
+        let name = format!("member-{i:03}");
+        make_project(&workspace.join("packages").join(&name), &name, "")?;
+    }
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preview").current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 100 packages in [TIME]
+    "###
+    );
+
+    // Re-discovering the members (read concurrently) must produce the same lockfile.
+    uv_snapshot!(context.filters(), context.lock().arg("--preview").arg("--locked").current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 100 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}